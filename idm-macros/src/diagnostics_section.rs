@@ -8,6 +8,12 @@ struct FieldAttrs {
     skip: bool,
 }
 
+#[derive(Debug, Default, PartialEq)]
+struct SectionAttrs {
+    id: Option<String>,
+    section: Option<String>,
+}
+
 fn idm_crate() -> proc_macro2::TokenStream {
     match crate_name("idm").expect("idm crate not found in Cargo.toml") {
         FoundCrate::Itself => quote!(crate),
@@ -26,8 +32,12 @@ pub fn expand(input: TokenStream) -> TokenStream {
 
     let name = &input.ident;
     let krate = idm_crate();
-    let section_id = parse_section_attr(&input, "id").unwrap_or_else(|| name.to_string());
-    let section_name = parse_section_attr(&input, "section").unwrap_or_else(|| name.to_string());
+    let section_attrs = match parse_section_attrs(&input) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let section_id = section_attrs.id.unwrap_or_else(|| name.to_string());
+    let section_name = section_attrs.section.unwrap_or_else(|| name.to_string());
 
     let fields = match &input.data {
         Data::Struct(data) => match &data.fields {
@@ -90,27 +100,29 @@ pub fn expand(input: TokenStream) -> TokenStream {
     .into()
 }
 
-fn parse_section_attr(input: &DeriveInput, key: &str) -> Option<String> {
+fn parse_section_attrs(input: &DeriveInput) -> Result<SectionAttrs, syn::Error> {
+    let mut attrs = SectionAttrs::default();
+
     for attr in &input.attrs {
         if !attr.path().is_ident("diagnostics") {
             continue;
         }
 
-        let mut parsed = None;
-        let _ = attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident(key) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                let value: LitStr = meta.value()?.parse()?;
+                attrs.id = Some(value.value());
+            } else if meta.path.is_ident("section") {
                 let value: LitStr = meta.value()?.parse()?;
-                parsed = Some(value.value());
+                attrs.section = Some(value.value());
+            } else {
+                return Err(meta.error("expected `id` or `section`"));
             }
             Ok(())
-        });
-
-        if parsed.is_some() {
-            return parsed;
-        }
+        })?;
     }
 
-    None
+    Ok(attrs)
 }
 
 fn parse_field_attrs(field: &syn::Field) -> Result<FieldAttrs, syn::Error> {
@@ -153,3 +165,41 @@ fn field_name_to_display(ident: &syn::Ident) -> String {
         .collect::<Vec<_>>()
         .join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn section_attrs_read_both_keys_when_id_comes_first() {
+        let input: DeriveInput = parse_quote! {
+            #[diagnostics(id = "scan_identity", section = "Scan identity")]
+            struct ScanIdentitySection {}
+        };
+
+        let attrs = parse_section_attrs(&input).expect("section attributes should parse");
+
+        assert_eq!(
+            SectionAttrs {
+                id: Some("scan_identity".to_string()),
+                section: Some("Scan identity".to_string()),
+            },
+            attrs
+        );
+    }
+
+    #[test]
+    fn section_attrs_reject_unknown_keys() {
+        let input: DeriveInput = parse_quote! {
+            #[diagnostics(id = "scan_identity", title = "Scan identity")]
+            struct ScanIdentitySection {}
+        };
+
+        let error = parse_section_attrs(&input).expect_err("unknown key should be rejected");
+
+        assert_eq!("expected `id` or `section`", error.to_string());
+    }
+}
@@ -1,4 +1,7 @@
 use std::io;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use bon::Builder;
@@ -6,9 +9,11 @@ use idm_macros::progress;
 use owo_colors::OwoColorize;
 use tracing::instrument;
 
-use crate::cli::{Command, FakeArgs, LogLevel, OutputFormat};
+use crate::cli::outcome::write_outcome_json;
+use crate::cli::{Command, CommandOutcome, FakeArgs, LogLevel, OutputFormat};
 use crate::hw::{
-    DeviceSession, HardwareClient, ModelResolutionConfig,
+    ConnectionObserver, DeviceConnectAttempt, DeviceSession, EndpointProbe, HardwareClient,
+    ModelResolutionConfig, NoopConnectionObserver, ReconnectCycle,
     fake_hardware_client as build_fake_hardware_client,
     real_hardware_client as build_real_hardware_client,
     real_hardware_client_with_model_resolution as build_real_hardware_client_with_model_resolution,
@@ -44,6 +49,8 @@ pub struct SessionHandler {
     hardware_client: Box<dyn HardwareClient>,
     #[builder(default = DEFAULT_DEVICE_NAME_PREFIX.to_string())]
     name_prefix: String,
+    #[builder(default = Arc::new(NoopConnectionObserver))]
+    connection_observer: Arc<dyn ConnectionObserver>,
 }
 
 impl SessionHandler {
@@ -67,9 +74,35 @@ impl SessionHandler {
         Self {
             hardware_client,
             name_prefix: DEFAULT_DEVICE_NAME_PREFIX.to_string(),
+            connection_observer: Arc::new(NoopConnectionObserver),
         }
     }
 
+    /// Reports connection milestones from [`connect_first`](Self::connect_first) to `observer`.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use idm::{ConnectionEvent, ConnectionObserver};
+    ///
+    /// struct LogObserver;
+    ///
+    /// impl ConnectionObserver for LogObserver {
+    ///     fn on_event(&self, event: &ConnectionEvent) {
+    ///         eprintln!("{event:?}");
+    ///     }
+    /// }
+    ///
+    /// let handler = idm::SessionHandler::new(idm::real_hardware_client())
+    ///     .with_connection_observer(Arc::new(LogObserver));
+    /// let _ = handler;
+    /// ```
+    #[must_use]
+    pub fn with_connection_observer(mut self, observer: Arc<dyn ConnectionObserver>) -> Self {
+        self.connection_observer = observer;
+        self
+    }
+
     /// Connects to the first matching iDotMatrix peripheral.
     ///
     /// # Errors
@@ -86,13 +119,93 @@ impl SessionHandler {
         fields(name_prefix = %self.name_prefix),
     )]
     pub async fn connect_first(self) -> Result<DeviceSession> {
-        let name_prefix = self.name_prefix;
-        let hardware_client = self.hardware_client;
+        let Self {
+            hardware_client,
+            name_prefix,
+            connection_observer,
+        } = self;
         hardware_client
-            .connect_first_device(name_prefix.as_str())
+            .connect_first_device_observed(name_prefix.as_str(), connection_observer)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Scans for `scan_window`, then connects to every matching iDotMatrix peripheral.
+    ///
+    /// Every connection is made before this returns, so all sessions are
+    /// open at once. A device that fails to connect is reported in its attempt
+    /// rather than aborting the others.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if discovery fails or no device matches.
+    ///
+    /// ```
+    /// # async fn demo() -> anyhow::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// let handler = idm::SessionHandler::new(idm::real_hardware_client());
+    /// for attempt in handler.connect_all(Duration::from_secs(5)).await? {
+    ///     if let Ok(session) = attempt.session {
+    ///         session.close().await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_all(self, scan_window: Duration) -> Result<Vec<DeviceConnectAttempt>> {
+        self.hardware_client
+            .connect_all_devices(self.name_prefix.as_str(), scan_window)
             .await
             .map_err(Into::into)
     }
+
+    /// Connects to the first matching peripheral, reports endpoint presence, then disconnects.
+    ///
+    /// The LED-info query and profile resolution are skipped, so this also
+    /// works for devices whose model would otherwise need an override.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if discovery, connection or disconnection fails.
+    ///
+    /// ```
+    /// # async fn demo() -> anyhow::Result<()> {
+    /// let probe = idm::SessionHandler::new(idm::real_hardware_client())
+    ///     .probe_first()
+    ///     .await?;
+    /// let _complete = probe.is_complete();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn probe_first(self) -> Result<EndpointProbe> {
+        self.hardware_client
+            .probe_first_device(self.name_prefix.as_str())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Connects to and closes the first matching peripheral `cycles` times.
+    ///
+    /// Failures are recorded per cycle rather than stopping the loop, so the
+    /// result always holds one entry per cycle that ran.
+    ///
+    /// ```
+    /// # async fn demo() {
+    /// use std::num::NonZeroUsize;
+    ///
+    /// let cycles = NonZeroUsize::new(5).expect("5 is non-zero");
+    /// let results = idm::SessionHandler::new(idm::real_hardware_client())
+    ///     .reconnect_cycles(cycles)
+    ///     .await;
+    /// let _failed = results.iter().filter(|cycle| !cycle.succeeded()).count();
+    /// # }
+    /// ```
+    pub async fn reconnect_cycles(self, cycles: NonZeroUsize) -> Vec<ReconnectCycle> {
+        self.hardware_client
+            .reconnect_cycles(self.name_prefix.as_str(), cycles)
+            .await
+    }
 }
 
 /// Runs the CLI command with injected clients.
@@ -179,7 +292,7 @@ where
     run_with_clients_and_log_level(
         command,
         out,
-        &SystemTerminalClient,
+        &SystemTerminalClient::default(),
         hardware_client,
         log_level,
         output_format,
@@ -280,12 +393,93 @@ where
         terminal_client.stderr_is_terminal(),
         log_level.map(LogLevel::as_level_filter),
         output_format,
+        terminal_client.spinner_style(),
     )?;
 
-    match command {
-        Command::Inspect => {
-            crate::cli::inspect::run(hardware_client, out, terminal_client, output_format).await
+    execute(
+        command,
+        out,
+        terminal_client,
+        hardware_client,
+        output_format,
+    )
+    .await
+    .map(|_outcome| ())
+}
+
+/// Runs the CLI command and returns its typed outcome instead of printing it.
+///
+/// ```
+/// # async fn run() -> anyhow::Result<()> {
+/// use clap::Parser;
+///
+/// let args = idm::Args::try_parse_from([
+///     "idm",
+///     "--fake",
+///     "--fake-scan",
+///     "hci0|AA:BB:CC|IDM-Clock|-43",
+///     "control",
+///     "brightness",
+///     "50",
+/// ])?;
+/// let (command, maybe_fake_args) = args.into_command_and_fake_args()?;
+/// let hardware_client = match maybe_fake_args {
+///     Some(fake_args) => idm::fake_hardware_client(fake_args),
+///     None => idm::real_hardware_client(),
+/// };
+/// let outcome = idm::run_with_result(command, hardware_client).await?;
+/// assert!(matches!(outcome, idm::CommandOutcome::Brightness(_)));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if tracing initialisation fails or the command fails.
+#[instrument(
+    skip(hardware_client),
+    level = "info",
+    fields(command = %command_name(&command))
+)]
+pub async fn run_with_result(
+    command: Command,
+    hardware_client: Box<dyn HardwareClient>,
+) -> Result<CommandOutcome> {
+    let terminal_client = SystemTerminalClient::default();
+    telemetry::initialise_tracing(
+        "idm",
+        terminal_client.stderr_is_terminal(),
+        None,
+        OutputFormat::Pretty,
+        terminal_client.spinner_style(),
+    )?;
+
+    execute(
+        command,
+        &mut io::sink(),
+        &terminal_client,
+        hardware_client,
+        OutputFormat::Pretty,
+    )
+    .await
+}
+
+async fn execute<W>(
+    command: Command,
+    out: &mut W,
+    terminal_client: &dyn TerminalClient,
+    hardware_client: Box<dyn HardwareClient>,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let outcome = match command {
+        Command::Inspect(args) => {
+            crate::cli::inspect::run(hardware_client, &args, out, terminal_client, output_format)
+                .await
         }
+        Command::Ping => crate::cli::ping::run(hardware_client, out, output_format).await,
         Command::Listen(args) => {
             crate::cli::listen::run(hardware_client, &args, out, terminal_client, output_format)
                 .await
@@ -296,14 +490,64 @@ where
         Command::Image(args) => {
             crate::cli::image::run(hardware_client, &args, out, output_format).await
         }
+        Command::Animate(args) => {
+            crate::cli::animate::run(hardware_client, &args, out, output_format).await
+        }
+        Command::ScreenTimeout(args) => {
+            crate::cli::screen_timeout::run(hardware_client, &args, out, output_format).await
+        }
+        Command::FactoryReset(args) => {
+            crate::cli::factory_reset::run(hardware_client, &args, out, output_format).await
+        }
+        Command::JointMode(args) => {
+            crate::cli::joint_mode::run(hardware_client, &args, out, output_format).await
+        }
+        Command::Scene(args) => {
+            crate::cli::scene::run(hardware_client, &args, out, output_format).await
+        }
+        Command::Slots => {
+            crate::cli::slots::run(hardware_client, out, terminal_client, output_format).await
+        }
+        Command::ReconnectLoop(args) => {
+            crate::cli::reconnect_loop::run(hardware_client, &args, out, output_format).await
+        }
+        #[cfg(unix)]
+        Command::Daemon(args) => {
+            crate::cli::daemon::run(hardware_client, &args, out, output_format).await
+        }
+        Command::ValidateGif(args) => crate::cli::validate_gif::run(&args, out, output_format),
+        Command::Crc(args) => crate::cli::crc::run(&args, out, output_format),
+        Command::Describe(args) => crate::cli::describe::run(&args, out, output_format),
+        Command::SelfTest => crate::cli::self_test::run(out, output_format),
+        Command::Doctor => crate::cli::doctor::run(hardware_client, out, output_format).await,
+    }?;
+
+    if output_format.is_json() {
+        write_outcome_json(out, &outcome, output_format)?;
     }
+    Ok(outcome)
 }
 
 fn command_name(command: &Command) -> &'static str {
     match command {
-        Command::Inspect => "inspect",
+        Command::Inspect(_) => "inspect",
+        Command::Ping => "ping",
         Command::Listen(_args) => "listen",
         Command::Control(_args) => "control",
         Command::Image(_args) => "image",
+        Command::Animate(_args) => "animate",
+        Command::ScreenTimeout(_args) => "screen-timeout",
+        Command::FactoryReset(_args) => "factory-reset",
+        Command::JointMode(_args) => "joint-mode",
+        Command::Scene(_args) => "scene",
+        Command::Slots => "slots",
+        Command::ReconnectLoop(_args) => "reconnect-loop",
+        #[cfg(unix)]
+        Command::Daemon(_args) => "daemon",
+        Command::ValidateGif(_args) => "validate-gif",
+        Command::Crc(_args) => "crc",
+        Command::Describe(_args) => "describe",
+        Command::SelfTest => "self-test",
+        Command::Doctor => "doctor",
     }
 }
@@ -0,0 +1,165 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::command::parse_duration;
+use crate::cli::outcome::write_warnings;
+use crate::cli::{AnimateOutcome, CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::{
+    GifAnimation, GifUploadHandler, GifUploadRequest, ImagePreparationOptions, ImagePreprocessor,
+    SessionHandler,
+};
+
+const DEFAULT_FRAME_DELAY: &str = "200ms";
+
+/// Arguments for the top-level `animate` slideshow command.
+#[derive(Debug, Args)]
+pub struct AnimateArgs {
+    /// Still images to show in order, one animation frame each.
+    #[arg(required = true, value_name = "IMAGE_FILE")]
+    image_files: Vec<PathBuf>,
+    /// How long each frame is shown.
+    #[arg(long, value_name = "DURATION", default_value = DEFAULT_FRAME_DELAY, value_parser = parse_duration)]
+    delay: Duration,
+}
+
+impl AnimateArgs {
+    /// Creates animation arguments for the given still images.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use std::time::Duration;
+    ///
+    /// use idm::AnimateArgs;
+    ///
+    /// let args = AnimateArgs::new(vec![PathBuf::from("a.png"), PathBuf::from("b.png")]);
+    /// assert_eq!(2, args.paths().len());
+    /// assert_eq!(Duration::from_millis(200), args.delay());
+    /// ```
+    #[must_use]
+    pub fn new(image_files: Vec<PathBuf>) -> Self {
+        Self {
+            image_files,
+            delay: Duration::from_millis(200),
+        }
+    }
+
+    /// Sets how long each frame is shown.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use std::time::Duration;
+    ///
+    /// use idm::AnimateArgs;
+    ///
+    /// let args = AnimateArgs::new(vec![PathBuf::from("a.png")])
+    ///     .with_delay(Duration::from_millis(500));
+    /// assert_eq!(Duration::from_millis(500), args.delay());
+    /// ```
+    #[must_use]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the still image paths in frame order.
+    #[must_use]
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.image_files
+    }
+
+    /// Returns how long each frame is shown.
+    #[must_use]
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+}
+
+/// Executes the top-level `animate` command.
+#[instrument(skip(client, args, out), level = "info", fields(frames = args.image_files.len(), ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &AnimateArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let command_result = run_with_session(&session, args, out, output_format).await;
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close animate session cleanly");
+    }
+
+    command_result
+}
+
+#[instrument(skip(session, args, out), level = "debug", fields(?output_format))]
+async fn run_with_session<W>(
+    session: &crate::DeviceSession,
+    args: &AnimateArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let panel_dimensions = session.device_profile().panel_dimensions().context(
+        "cannot build animation because panel dimensions are unresolved for this device",
+    )?;
+
+    let frames = args
+        .paths()
+        .iter()
+        .map(|path| {
+            let source_bytes = read_source(path)?;
+            let still = ImagePreprocessor::prepare_first_frame(
+                &source_bytes,
+                panel_dimensions,
+                ImagePreparationOptions::default(),
+            )
+            .with_context(|| format!("failed to prepare {}", path.display()))?;
+            Ok(still.into_frame())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let animation = GifAnimation::from_frames(&frames, args.delay())
+        .context("failed to assemble animation frames")?;
+
+    let receipt = GifUploadHandler::upload(session, GifUploadRequest::new(animation)).await?;
+    let outcome = AnimateOutcome {
+        frames: frames.len(),
+        bytes_written: receipt.bytes_written(),
+        chunks_written: receipt.chunks_written(),
+        logical_chunks_sent: receipt.logical_chunks_sent(),
+        warnings: session.warnings(),
+    };
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(
+                out,
+                "Uploaded animation: {} frame(s), {} bytes in {} chunk(s)",
+                outcome.frames, outcome.bytes_written, outcome.chunks_written,
+            )?;
+            write_warnings(out, &outcome.warnings)?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::Animate(outcome))
+}
+
+fn read_source(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).with_context(|| format!("failed to read image file {}", path.display()))
+}
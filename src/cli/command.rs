@@ -1,3 +1,4 @@
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -5,20 +6,37 @@ use bon::Builder;
 use clap::{Parser, Subcommand, ValueEnum};
 use tracing_subscriber::filter::LevelFilter;
 
+use crate::cli::animate::AnimateArgs;
 use crate::cli::control::ControlArgs;
+use crate::cli::crc::CrcArgs;
+#[cfg(unix)]
+use crate::cli::daemon::DaemonArgs;
+use crate::cli::describe::DescribeArgs;
+use crate::cli::factory_reset::FactoryResetArgs;
 use crate::cli::image::ImageArgs;
+use crate::cli::inspect::InspectArgs;
+use crate::cli::joint_mode::JointModeArgs;
 use crate::cli::listen::ListenArgs;
+use crate::cli::reconnect_loop::ReconnectLoopArgs;
+use crate::cli::scene::SceneArgs;
+use crate::cli::screen_timeout::ScreenTimeoutArgs;
+use crate::cli::validate_gif::ValidateGifArgs;
 use crate::error::CliConfigError;
 use crate::hw::{
     FakeBackendConfig, GifScenario, HexPayload, ImageScenario, ListenScenario,
-    ModelResolutionConfig, NotificationPayloads, ScanFixture, ScanScenario, TextScenario,
+    ModelResolutionConfig, NotificationPayloads, NotifyDelivery, RetryPolicy, ScanFixture,
+    ScanScenario, TextCharCountEndian, TextScenario,
 };
+use crate::notification::AckPolicy;
+use crate::spinner::{SpinnerCharset, SpinnerStyle};
 
 /// Command-line options for the iDotMatrix BLE tool.
 #[derive(Debug, Parser)]
 #[command(name = "idm", about = "Interact with iDotMatrix BLE devices.")]
 pub struct Args {
     /// Uses the fake BLE backend with fixture-driven discovery and payloads.
+    ///
+    /// Requires either `--fake-scan` or `--fake-scan-file`.
     #[arg(long, global = true, hide = true)]
     fake: bool,
     /// Fake scan fixtures in the form `adapter|device_id|local_name|rssi;...`.
@@ -26,16 +44,44 @@ pub struct Args {
         long,
         global = true,
         requires = "fake",
-        required_if_eq("fake", "true"),
+        conflicts_with = "fake_scan_file",
         hide = true
     )]
     fake_scan: Option<ScanFixture>,
+    /// Path to a fake scan fixture file with one or more records per line.
+    #[arg(long, global = true, requires = "fake", hide = true)]
+    fake_scan_file: Option<PathBuf>,
     /// Fake `fa03` initial read payload as hexadecimal bytes.
     #[arg(long, global = true, requires = "fake", hide = true)]
     fake_read: Option<HexPayload>,
+    /// Fake material-slot query response as hexadecimal bytes.
+    #[arg(long, global = true, requires = "fake", hide = true)]
+    fake_material_slots: Option<HexPayload>,
     /// Fake notification payloads as comma-separated hexadecimal payloads.
     #[arg(long, global = true, requires = "fake", hide = true)]
     fake_notifications: Option<NotificationPayloads>,
+    /// Path to a fake notification fixture file with one or more payloads per line.
+    #[arg(
+        long,
+        global = true,
+        requires = "fake",
+        conflicts_with = "fake_notifications",
+        hide = true
+    )]
+    fake_listen_file: Option<PathBuf>,
+    /// Advance the paused fake clock by this much per tick while listening (e.g. `10ms`).
+    ///
+    /// Needs a current-thread runtime whose clock is paused, as in tests
+    /// driving the CLI through `run_with_clients`; listening fails otherwise,
+    /// including from the `idm` binary.
+    #[arg(
+        long,
+        global = true,
+        requires = "fake",
+        value_parser = parse_duration,
+        hide = true
+    )]
+    listen_auto_advance: Option<Duration>,
     /// Artificial fake scan delay (e.g. `250ms`, `2s`).
     #[arg(
         long,
@@ -51,13 +97,81 @@ pub struct Args {
     /// Path to the persisted model-overrides file.
     #[arg(long, global = true)]
     model_overrides_path: Option<PathBuf>,
+    /// Skip the connect-time LED-info probe and rely on scan identity and overrides.
+    #[arg(long, global = true)]
+    skip_led_info: bool,
+    /// Leave the device's joint mode as-is instead of applying the resolved one on connect.
+    #[arg(long, global = true)]
+    no_joint_mode: bool,
+    /// How long each LED-info probe attempt waits for a response (e.g. `500ms`, `2s`).
+    #[arg(long, global = true, value_parser = parse_duration)]
+    led_info_timeout: Option<Duration>,
+    /// Always run the LED-info probe instead of reusing a cached result for the device.
+    #[arg(long, global = true)]
+    no_profile_cache: bool,
+    /// How long a cached LED-info result stays fresh (e.g. `1h`, `7days`).
+    #[arg(long, global = true, value_parser = parse_duration, conflicts_with = "no_profile_cache")]
+    profile_cache_ttl: Option<Duration>,
+    /// How long session teardown may take before giving up (e.g. `500ms`, `10s`).
+    #[arg(long, global = true, value_parser = parse_duration)]
+    close_timeout: Option<Duration>,
+    /// How long the scan loop waits between peripheral polls (e.g. `50ms`, `1s`).
+    ///
+    /// Shorter intervals connect sooner at the cost of more CPU. Defaults to `250ms`.
+    #[arg(long, global = true, value_parser = parse_scan_poll)]
+    scan_poll: Option<Duration>,
+    /// How long each BLE write may take before the command fails (e.g. `500ms`, `10s`).
+    ///
+    /// Applies to real hardware only. Defaults to `5s`.
+    #[arg(long, global = true, value_parser = parse_duration)]
+    write_timeout: Option<Duration>,
+    /// Connect attempts to make before giving up, the first one included.
+    ///
+    /// Only transient BLE failures are retried, with exponential backoff.
+    /// Applies to real hardware only. Defaults to `1`.
+    #[arg(long, global = true)]
+    connect_attempts: Option<NonZeroUsize>,
+    /// Ceiling on the backoff between connect attempts (e.g. `2s`, `30s`).
+    ///
+    /// Defaults to `5s`.
+    #[arg(long, global = true, value_parser = parse_duration, requires = "connect_attempts")]
+    connect_backoff_max: Option<Duration>,
+    /// Debug override for the byte order of the text-upload character count.
+    #[arg(long, global = true, value_enum)]
+    text_count_endian: Option<TextCharCountEndian>,
     /// Override the telemetry log verbosity.
     #[arg(long, global = true, value_enum)]
     log_level: Option<LogLevel>,
     /// Output format for command results. Defaults to `pretty` when stdout is a
-    /// terminal, `json` otherwise.
+    /// terminal, `json` otherwise. `ndjson` always prints one document per line.
     #[arg(long, global = true, value_enum)]
     output_format: Option<OutputFormat>,
+    /// Indent JSON output even when stdout is not a terminal. Selects JSON output
+    /// when no `--output-format` is given.
+    #[arg(long, global = true, conflicts_with = "json_compact")]
+    json_pretty: bool,
+    /// Print each JSON document on one line even when stdout is a terminal.
+    /// Selects JSON output when no `--output-format` is given.
+    #[arg(long, global = true)]
+    json_compact: bool,
+    /// Disable ANSI colour in pretty output. Also honours the `NO_COLOR` environment variable.
+    #[arg(long = "no-color", global = true, alias = "no-colour")]
+    no_colour: bool,
+    /// Colour pretty output even when stdout is not a terminal (e.g. when piped
+    /// into a pager that understands ANSI colour).
+    #[arg(
+        long = "force-color",
+        global = true,
+        alias = "force-colour",
+        conflicts_with = "no_colour"
+    )]
+    force_colour: bool,
+    /// Character set for the progress spinner shown on interactive terminals.
+    #[arg(long, global = true, value_enum)]
+    spinner: Option<SpinnerCharset>,
+    /// Interval between progress spinner frames (e.g. `90ms`, `250ms`).
+    #[arg(long, global = true, value_parser = parse_spinner_interval)]
+    spinner_interval: Option<Duration>,
     #[arg(skip)]
     fake_args_override: Option<FakeArgs>,
     #[command(subcommand)]
@@ -68,9 +182,9 @@ impl Args {
     /// Creates argument values directly without CLI parsing.
     ///
     /// ```
-    /// use idm::{Args, Command, ListenArgs};
+    /// use idm::{Args, Command, InspectArgs, ListenArgs};
     ///
-    /// let inspect = Args::new(Command::Inspect);
+    /// let inspect = Args::new(Command::Inspect(InspectArgs::default()));
     /// let listen = Args::new(Command::Listen(ListenArgs::new(Some(10))));
     /// let _ = (inspect, listen);
     /// ```
@@ -79,13 +193,34 @@ impl Args {
         Self {
             fake: false,
             fake_scan: None,
+            fake_scan_file: None,
             fake_read: None,
+            fake_material_slots: None,
             fake_notifications: None,
+            fake_listen_file: None,
+            listen_auto_advance: None,
             fake_discovery_delay: None,
             model_led_type: None,
             model_overrides_path: None,
+            skip_led_info: false,
+            no_joint_mode: false,
+            led_info_timeout: None,
+            no_profile_cache: false,
+            profile_cache_ttl: None,
+            close_timeout: None,
+            scan_poll: None,
+            write_timeout: None,
+            connect_attempts: None,
+            connect_backoff_max: None,
+            text_count_endian: None,
             log_level: None,
             output_format: None,
+            json_pretty: false,
+            json_compact: false,
+            no_colour: false,
+            force_colour: false,
+            spinner: None,
+            spinner_interval: None,
             fake_args_override: None,
             command,
         }
@@ -102,7 +237,48 @@ impl Args {
     /// Returns model-resolution options derived from CLI arguments.
     #[must_use]
     pub fn model_resolution(&self) -> ModelResolutionConfig {
-        ModelResolutionConfig::new(self.model_led_type, self.model_overrides_path.clone())
+        let model_resolution =
+            ModelResolutionConfig::new(self.model_led_type, self.model_overrides_path.clone())
+                .with_skip_led_info(self.skip_led_info)
+                .with_skip_joint_mode(self.no_joint_mode)
+                .with_profile_cache(!self.no_profile_cache);
+        let model_resolution = match self.led_info_timeout {
+            Some(led_info_timeout) => model_resolution.with_led_info_timeout(led_info_timeout),
+            None => model_resolution,
+        };
+        let model_resolution = match self.close_timeout {
+            Some(close_timeout) => model_resolution.with_close_timeout(close_timeout),
+            None => model_resolution,
+        };
+        let model_resolution = match self.scan_poll {
+            Some(scan_poll) => model_resolution.with_scan_poll_interval(scan_poll),
+            None => model_resolution,
+        };
+        let model_resolution = match self.write_timeout {
+            Some(write_timeout) => model_resolution.with_write_timeout(write_timeout),
+            None => model_resolution,
+        };
+        let model_resolution = match self.text_count_endian {
+            Some(endian) => model_resolution.with_text_count_endian_override(endian),
+            None => model_resolution,
+        };
+        let model_resolution = match self.connect_retry() {
+            Some(connect_retry) => model_resolution.with_connect_retry(connect_retry),
+            None => model_resolution,
+        };
+        match self.profile_cache_ttl {
+            Some(profile_cache_ttl) => model_resolution.with_profile_cache_ttl(profile_cache_ttl),
+            None => model_resolution,
+        }
+    }
+
+    fn connect_retry(&self) -> Option<RetryPolicy> {
+        let max_attempts = self.connect_attempts?;
+        let connect_retry = RetryPolicy::builder()
+            .max_attempts(max_attempts)
+            .maybe_max_backoff(self.connect_backoff_max)
+            .build();
+        Some(connect_retry)
     }
 
     /// Returns an optional CLI override for telemetry log level.
@@ -117,6 +293,67 @@ impl Args {
         self.output_format
     }
 
+    /// Resolves the output format for a run whose stdout may be a terminal.
+    ///
+    /// Without an explicit `--output-format`, a terminal gets `pretty` output
+    /// and a pipe gets JSON. JSON is indented on a terminal and printed one
+    /// document per line when piped, unless `--json-pretty` or `--json-compact`
+    /// says otherwise.
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use idm::{Args, OutputFormat};
+    ///
+    /// let args = Args::try_parse_from(["idm", "--output-format", "json", "inspect"])?;
+    /// assert_eq!(OutputFormat::Json, args.resolve_output_format(true));
+    /// assert_eq!(OutputFormat::JsonCompact, args.resolve_output_format(false));
+    ///
+    /// let args = Args::try_parse_from(["idm", "--json-pretty", "inspect"])?;
+    /// assert_eq!(OutputFormat::Json, args.resolve_output_format(false));
+    /// # Ok::<(), clap::Error>(())
+    /// ```
+    #[must_use]
+    pub fn resolve_output_format(&self, stdout_is_terminal: bool) -> OutputFormat {
+        let json_style_requested = self.json_pretty || self.json_compact;
+        let output_format =
+            self.output_format
+                .unwrap_or(if stdout_is_terminal && !json_style_requested {
+                    OutputFormat::Pretty
+                } else {
+                    OutputFormat::Json
+                });
+        if !output_format.is_json() || output_format == OutputFormat::Ndjson {
+            return output_format;
+        }
+        if self.json_compact || (!self.json_pretty && !stdout_is_terminal) {
+            OutputFormat::JsonCompact
+        } else {
+            OutputFormat::Json
+        }
+    }
+
+    /// Returns whether colour was explicitly disabled for pretty output.
+    #[must_use]
+    pub fn no_colour(&self) -> bool {
+        self.no_colour
+    }
+
+    /// Returns whether colour was explicitly forced for non-terminal output.
+    #[must_use]
+    pub fn force_colour(&self) -> bool {
+        self.force_colour
+    }
+
+    /// Returns the progress spinner appearance selected on the command line.
+    #[must_use]
+    pub fn spinner_style(&self) -> SpinnerStyle {
+        let spinner_style = SpinnerStyle::new(self.spinner.unwrap_or_default());
+        match self.spinner_interval {
+            Some(spinner_interval) => spinner_style.with_tick_interval(spinner_interval),
+            None => spinner_style,
+        }
+    }
+
     /// Splits parsed CLI arguments into command and optional fake-client settings.
     ///
     /// # Errors
@@ -126,13 +363,34 @@ impl Args {
         let Args {
             fake,
             fake_scan,
+            fake_scan_file,
             fake_read,
+            fake_material_slots,
             fake_notifications,
+            fake_listen_file,
+            listen_auto_advance,
             fake_discovery_delay,
             model_led_type,
             model_overrides_path,
+            skip_led_info,
+            no_joint_mode,
+            led_info_timeout: _,
+            no_profile_cache: _,
+            profile_cache_ttl: _,
+            close_timeout,
+            scan_poll: _,
+            write_timeout: _,
+            connect_attempts: _,
+            connect_backoff_max: _,
+            text_count_endian,
             log_level: _,
             output_format: _,
+            json_pretty: _,
+            json_compact: _,
+            no_colour: _,
+            force_colour: _,
+            spinner: _,
+            spinner_interval: _,
             fake_args_override,
             command,
         } = self;
@@ -140,12 +398,19 @@ impl Args {
         let fake_args = if let Some(fake_args) = fake_args_override {
             Some(fake_args)
         } else if fake {
-            let Some(scan_fixture) = fake_scan else {
-                return Err(CliConfigError::MissingFakeScanFixture.into());
+            let scan_fixture = match (fake_scan, fake_scan_file) {
+                (Some(scan_fixture), _) => scan_fixture,
+                (None, Some(path)) => ScanFixture::from_file(&path)?,
+                (None, None) => return Err(CliConfigError::MissingFakeScanFixture.into()),
+            };
+            let listen = match (fake_notifications, fake_listen_file) {
+                (Some(notifications), _) => ListenScenario::from(notifications),
+                (None, Some(path)) => ListenScenario::from_file(path)?,
+                (None, None) => ListenScenario::default(),
             };
-            let listen = match fake_notifications {
-                Some(notifications) => ListenScenario::from(notifications),
-                None => ListenScenario::default(),
+            let listen = match listen_auto_advance {
+                Some(interval) => listen.with_auto_advance_interval(interval),
+                None => listen,
             };
             Some(FakeArgs {
                 scan: ScanScenario::from((
@@ -159,6 +424,16 @@ impl Args {
                 text: TextScenario::default(),
                 model_led_type,
                 model_overrides_path,
+                skip_led_info,
+                skip_joint_mode: no_joint_mode,
+                profile_cache_path: None,
+                text_count_endian,
+                ack_policy: None,
+                close_timeout,
+                write_mtu: None,
+                notify_delivery: None,
+                write_notify_delivery: None,
+                material_slots: fake_material_slots,
             })
         } else {
             None
@@ -173,8 +448,37 @@ impl Args {
 pub enum OutputFormat {
     /// Human-readable styled output.
     Pretty,
-    /// Machine-readable JSON output.
+    /// Machine-readable JSON output, indented for reading.
     Json,
+    /// One line per device for quick status checks.
+    Compact,
+    /// Machine-readable JSON output, one document per line.
+    ///
+    /// Selected with `--json-compact`, or by default when JSON goes to a pipe.
+    #[value(skip)]
+    JsonCompact,
+    /// Newline-delimited JSON: every document on its own line, whatever
+    /// stdout is.
+    ///
+    /// `listen` writes one line per notification, reporting decode errors as
+    /// structured records, then the summary as the last line.
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// Returns whether results are written as JSON, indented or not.
+    ///
+    /// ```
+    /// use idm::OutputFormat;
+    ///
+    /// assert!(OutputFormat::JsonCompact.is_json());
+    /// assert!(OutputFormat::Ndjson.is_json());
+    /// assert!(!OutputFormat::Compact.is_json());
+    /// ```
+    #[must_use]
+    pub fn is_json(self) -> bool {
+        matches!(self, Self::Json | Self::JsonCompact | Self::Ndjson)
+    }
 }
 
 /// Log verbosity override for tracing and log events.
@@ -222,6 +526,19 @@ pub struct FakeArgs {
     text: TextScenario,
     model_led_type: Option<u8>,
     model_overrides_path: Option<PathBuf>,
+    #[builder(default)]
+    skip_led_info: bool,
+    #[builder(default)]
+    skip_joint_mode: bool,
+    profile_cache_path: Option<PathBuf>,
+    text_count_endian: Option<TextCharCountEndian>,
+    ack_policy: Option<AckPolicy>,
+    close_timeout: Option<Duration>,
+    write_mtu: Option<usize>,
+    notify_delivery: Option<NotifyDelivery>,
+    write_notify_delivery: Option<NotifyDelivery>,
+    #[builder(with = |value: &str| -> std::result::Result<_, crate::error::FixtureError> { value.parse() })]
+    material_slots: Option<HexPayload>,
 }
 
 impl FakeArgs {
@@ -235,8 +552,39 @@ impl FakeArgs {
             text,
             model_led_type,
             model_overrides_path,
+            skip_led_info,
+            skip_joint_mode,
+            profile_cache_path,
+            text_count_endian,
+            ack_policy,
+            close_timeout,
+            write_mtu,
+            notify_delivery,
+            write_notify_delivery,
+            material_slots,
         } = self;
 
+        let model_resolution = ModelResolutionConfig::new(model_led_type, model_overrides_path)
+            .with_skip_led_info(skip_led_info)
+            .with_skip_joint_mode(skip_joint_mode);
+        let model_resolution = match profile_cache_path {
+            Some(path) => model_resolution
+                .with_profile_cache(true)
+                .with_profile_cache_path(path),
+            None => model_resolution,
+        };
+        let model_resolution = match text_count_endian {
+            Some(endian) => model_resolution.with_text_count_endian_override(endian),
+            None => model_resolution,
+        };
+        let model_resolution = match ack_policy {
+            Some(ack_policy) => model_resolution.with_ack_policy(ack_policy),
+            None => model_resolution,
+        };
+        let model_resolution = match close_timeout {
+            Some(close_timeout) => model_resolution.with_close_timeout(close_timeout),
+            None => model_resolution,
+        };
         FakeBackendConfig::builder()
             .scan(scan)
             .maybe_initial_read(initial_read)
@@ -244,10 +592,11 @@ impl FakeArgs {
             .gif(gif)
             .image(image)
             .text(text)
-            .model_resolution(ModelResolutionConfig::new(
-                model_led_type,
-                model_overrides_path,
-            ))
+            .model_resolution(model_resolution)
+            .maybe_write_mtu(write_mtu)
+            .maybe_notify_delivery(notify_delivery)
+            .maybe_write_notify_delivery(write_notify_delivery)
+            .maybe_material_slots(material_slots)
             .build()
     }
 }
@@ -277,19 +626,66 @@ impl<S: fake_args_builder::State> FakeArgsBuilder<S> {
 #[derive(Debug, Subcommand)]
 pub enum Command {
     /// Scan until the first iDotMatrix device is found, connect, and print GATT details.
-    Inspect,
+    Inspect(InspectArgs),
+    /// Scan until the first iDotMatrix device is found, connect, print endpoint presence, and disconnect.
+    ///
+    /// Skips the LED-info query and profile resolution, as if `--skip-led-info` were set.
+    Ping,
     /// Scan until the first iDotMatrix device is found, connect, read once, then listen for notifications.
     Listen(ListenArgs),
     /// Scan until the first iDotMatrix device is found, connect, then send one control command.
     Control(ControlArgs),
     /// Scan until the first iDotMatrix device is found, connect, then upload one image.
     Image(ImageArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then upload still images as one animation.
+    Animate(AnimateArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then set and confirm the screen-light timeout.
+    ScreenTimeout(ScreenTimeoutArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then restore factory defaults (requires `--yes`).
+    FactoryReset(FactoryResetArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then turn multi-panel joint mode on or off.
+    JointMode(JointModeArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then apply power, colour, and brightness in that order.
+    Scene(SceneArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then list the stored material slots (experimental).
+    Slots,
+    /// Scan until the first iDotMatrix device is found, connect, then disconnect and repeat, timing each cycle.
+    ReconnectLoop(ReconnectLoopArgs),
+    /// Scan until the first iDotMatrix device is found, connect, then serve control commands on a Unix socket.
+    #[cfg(unix)]
+    Daemon(DaemonArgs),
+    /// Check a GIF against panel dimensions and upload limits without connecting.
+    ValidateGif(ValidateGifArgs),
+    /// Print the protocol CRC32 of a payload without connecting.
+    Crc(CrcArgs),
+    /// Explain a short frame or media header field by field without connecting.
+    Describe(DescribeArgs),
+    /// Run the built-in encode, media-preparation, and notification-decode checks without connecting.
+    SelfTest,
+    /// Report Bluetooth adapters, permissions, runtime, and state-file paths without connecting.
+    Doctor,
 }
 
-fn parse_duration(value: &str) -> Result<Duration, String> {
+pub(crate) fn parse_duration(value: &str) -> Result<Duration, String> {
     humantime::parse_duration(value).map_err(|error| error.to_string())
 }
 
+fn parse_spinner_interval(value: &str) -> Result<Duration, String> {
+    let interval = parse_duration(value)?;
+    if interval.is_zero() {
+        return Err("spinner interval must be greater than zero".to_string());
+    }
+    Ok(interval)
+}
+
+fn parse_scan_poll(value: &str) -> Result<Duration, String> {
+    let interval = parse_duration(value)?;
+    if interval.is_zero() {
+        return Err("scan poll interval must be greater than zero".to_string());
+    }
+    Ok(interval)
+}
+
 fn parse_led_type(value: &str) -> Result<u8, String> {
     let parsed = value.parse::<u8>().map_err(|error| error.to_string())?;
     if !matches!(parsed, 1 | 2 | 3 | 4 | 6 | 7 | 11) {
@@ -303,15 +699,42 @@ mod tests {
     use assert_matches::assert_matches;
     use clap::error::ErrorKind;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use std::path::Path;
 
     use super::*;
+    use crate::cli::joint_mode::JointModeState;
+    use crate::{DitherMode, PanelDimensions, Rgb, ScalingMode, TransparencyMode};
 
     #[test]
     fn fake_mode_requires_scan_fixture() {
-        let result = Args::try_parse_from(["idm", "--fake", "inspect"]);
+        let cli =
+            Args::try_parse_from(["idm", "--fake", "inspect"]).expect("--fake alone should parse");
 
-        let error = result.expect_err("missing --fake-scan should fail argument parsing");
-        assert_eq!(ErrorKind::MissingRequiredArgument, error.kind());
+        let error = cli
+            .into_command_and_fake_args()
+            .expect_err("missing scan fixture should be rejected");
+        assert_eq!(
+            "missing fake scan fixture while fake mode is enabled",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn fake_scan_conflicts_with_fake_scan_file() {
+        let result = Args::try_parse_from([
+            "idm",
+            "--fake",
+            "--fake-scan",
+            "hci0|AA:BB:CC|IDM-Clock|-43",
+            "--fake-scan-file",
+            "scan.txt",
+            "inspect",
+        ]);
+
+        let error = result.expect_err("both scan sources should conflict");
+        assert_eq!(ErrorKind::ArgumentConflict, error.kind());
     }
 
     #[test]
@@ -349,10 +772,64 @@ mod tests {
         let (command, fake_args) = cli
             .into_command_and_fake_args()
             .expect("valid fake arguments should resolve fake settings");
-        assert_matches!(command, Command::Inspect);
+        assert_matches!(command, Command::Inspect(_));
         assert_matches!(fake_args, Some(_));
     }
 
+    #[test]
+    fn listen_auto_advance_sets_fake_listen_interval() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "--fake",
+            "--fake-scan",
+            "hci0|AA:BB:CC|IDM-Clock|-43",
+            "--listen-auto-advance",
+            "10ms",
+            "listen",
+        ])
+        .expect("listen auto-advance should parse");
+
+        let (_command, fake_args) = cli
+            .into_command_and_fake_args()
+            .expect("valid fake arguments should resolve fake settings");
+        let fake_args = fake_args.expect("fake mode should build fake settings");
+        assert_eq!(
+            Some(Duration::from_millis(10)),
+            fake_args.listen_scenario.auto_advance_interval()
+        );
+    }
+
+    #[test]
+    fn listen_auto_advance_requires_fake_mode() {
+        let result = Args::try_parse_from(["idm", "--listen-auto-advance", "10ms", "listen"]);
+
+        let error = result.expect_err("--listen-auto-advance should require --fake");
+        assert_eq!(ErrorKind::MissingRequiredArgument, error.kind());
+    }
+
+    #[test]
+    fn ping_command_parses_without_arguments() {
+        let cli = Args::try_parse_from(["idm", "ping"]).expect("ping should parse");
+
+        let Args { command, .. } = cli;
+        assert_matches!(command, Command::Ping);
+    }
+
+    #[rstest]
+    #[case::none(&["inspect"], &[])]
+    #[case::comma_separated(&["inspect", "--props", "write,Notify"], &["write", "notify"])]
+    #[case::repeated(&["inspect", "--props", "read", "--props", "indicate"], &["read", "indicate"])]
+    fn inspect_command_parses_property_filter(#[case] argv: &[&str], #[case] expected: &[&str]) {
+        let cli = Args::try_parse_from(["idm"].iter().chain(argv))
+            .expect("inspect property filter should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Inspect(args) = command else {
+            panic!("expected inspect command");
+        };
+        assert_eq!(expected, args.properties());
+    }
+
     #[test]
     fn model_led_type_rejects_unsupported_value() {
         let result = Args::try_parse_from([
@@ -392,6 +869,192 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skip_led_info_is_exposed_via_model_resolution() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "--skip-led-info",
+            "--fake",
+            "--fake-scan",
+            "hci0|AA:BB:CC|IDM-Clock|-43",
+            "inspect",
+        ])
+        .expect("skip-led-info should parse");
+
+        assert_eq!(true, cli.model_resolution().skip_led_info());
+    }
+
+    #[rstest]
+    #[case::default(&[][..], false)]
+    #[case::suppressed(&["--no-joint-mode"][..], true)]
+    fn no_joint_mode_is_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected: bool,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("no-joint-mode should parse");
+
+        assert_eq!(expected, cli.model_resolution().skip_joint_mode());
+    }
+
+    #[test]
+    fn text_count_endian_is_exposed_via_model_resolution() {
+        let cli = Args::try_parse_from(["idm", "--text-count-endian", "big", "inspect"])
+            .expect("text-count-endian should parse");
+
+        assert_eq!(
+            Some(TextCharCountEndian::Big),
+            cli.model_resolution().text_count_endian_override()
+        );
+    }
+
+    #[rstest]
+    #[case(&[][..], Duration::from_millis(1_000))]
+    #[case(&["--led-info-timeout", "250ms"][..], Duration::from_millis(250))]
+    #[case(&["--led-info-timeout", "2s"][..], Duration::from_secs(2))]
+    fn led_info_timeout_is_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected: Duration,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&[
+            "--fake",
+            "--fake-scan",
+            "hci0|AA:BB:CC|IDM-Clock|-43",
+            "inspect",
+        ]);
+        let cli = Args::try_parse_from(argv).expect("led-info-timeout should parse");
+
+        assert_eq!(expected, cli.model_resolution().led_info_timeout());
+    }
+
+    #[rstest]
+    #[case(&[][..], Duration::from_secs(3))]
+    #[case(&["--close-timeout", "500ms"][..], Duration::from_millis(500))]
+    #[case(&["--close-timeout", "10s"][..], Duration::from_secs(10))]
+    fn close_timeout_is_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected: Duration,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("close-timeout should parse");
+
+        assert_eq!(expected, cli.model_resolution().close_timeout());
+    }
+
+    #[rstest]
+    #[case(&[][..], Duration::from_secs(5))]
+    #[case(&["--write-timeout", "750ms"][..], Duration::from_millis(750))]
+    #[case(&["--write-timeout", "30s"][..], Duration::from_secs(30))]
+    fn write_timeout_is_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected: Duration,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("write-timeout should parse");
+
+        assert_eq!(expected, cli.model_resolution().write_timeout());
+    }
+
+    #[rstest]
+    #[case(&[][..], 1, Duration::from_secs(5))]
+    #[case(&["--connect-attempts", "4"][..], 4, Duration::from_secs(5))]
+    #[case(
+        &["--connect-attempts", "3", "--connect-backoff-max", "2s"][..],
+        3,
+        Duration::from_secs(2)
+    )]
+    fn connect_retry_is_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected_attempts: usize,
+        #[case] expected_max_backoff: Duration,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("connect retry flags should parse");
+        let connect_retry = cli.model_resolution().connect_retry();
+
+        assert_eq!(
+            (expected_attempts, expected_max_backoff),
+            (
+                connect_retry.max_attempts().get(),
+                connect_retry.max_backoff()
+            )
+        );
+    }
+
+    #[rstest]
+    #[case::zero_attempts(&["--connect-attempts", "0"][..])]
+    #[case::backoff_without_attempts(&["--connect-backoff-max", "2s"][..])]
+    fn connect_retry_flags_reject_invalid_combinations(#[case] flags: &[&str]) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+
+        assert!(Args::try_parse_from(argv).is_err());
+    }
+
+    #[rstest]
+    #[case(&[][..], Duration::from_millis(250))]
+    #[case(&["--scan-poll", "50ms"][..], Duration::from_millis(50))]
+    fn scan_poll_is_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected: Duration,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("scan-poll should parse");
+
+        assert_eq!(expected, cli.model_resolution().scan_poll_interval());
+    }
+
+    #[test]
+    fn scan_poll_rejects_zero_interval() {
+        let result = Args::try_parse_from(["idm", "--scan-poll", "0s", "inspect"]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(&[][..], SpinnerStyle::default())]
+    #[case(&["--spinner", "ascii"][..], SpinnerStyle::new(SpinnerCharset::Ascii))]
+    #[case(
+        &["--spinner", "ascii", "--spinner-interval", "250ms"][..],
+        SpinnerStyle::new(SpinnerCharset::Ascii).with_tick_interval(Duration::from_millis(250))
+    )]
+    fn spinner_flags_build_spinner_style(#[case] flags: &[&str], #[case] expected: SpinnerStyle) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("spinner flags should parse");
+
+        assert_eq!(expected, cli.spinner_style());
+    }
+
+    #[test]
+    fn force_colour_conflicts_with_no_colour() {
+        let error = Args::try_parse_from(["idm", "--force-color", "--no-color", "inspect"])
+            .expect_err("forcing and disabling colour together should fail");
+        assert_eq!(ErrorKind::ArgumentConflict, error.kind());
+    }
+
+    #[test]
+    fn zero_spinner_interval_is_rejected() {
+        let error = Args::try_parse_from(["idm", "--spinner-interval", "0ms", "inspect"])
+            .expect_err("a zero spinner interval should be rejected");
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+    }
+
+    #[rstest]
+    #[case(&[][..], true, Duration::from_secs(24 * 60 * 60))]
+    #[case(&["--no-profile-cache"][..], false, Duration::from_secs(24 * 60 * 60))]
+    #[case(&["--profile-cache-ttl", "1h"][..], true, Duration::from_secs(60 * 60))]
+    fn profile_cache_flags_are_exposed_via_model_resolution(
+        #[case] flags: &[&str],
+        #[case] expected_enabled: bool,
+        #[case] expected_ttl: Duration,
+    ) {
+        let argv = ["idm"].iter().chain(flags).chain(&["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("profile cache flags should parse");
+
+        let model_resolution = cli.model_resolution();
+        assert_eq!(expected_enabled, model_resolution.profile_cache());
+        assert_eq!(expected_ttl, model_resolution.profile_cache_ttl());
+    }
+
     #[test]
     fn output_format_argument_parses() {
         let cli = Args::try_parse_from([
@@ -408,6 +1071,37 @@ mod tests {
         assert_eq!(Some(OutputFormat::Json), cli.output_format());
     }
 
+    #[rstest]
+    #[case::terminal_default(&[], true, OutputFormat::Pretty)]
+    #[case::piped_default(&[], false, OutputFormat::JsonCompact)]
+    #[case::terminal_json(&["--output-format", "json"], true, OutputFormat::Json)]
+    #[case::piped_json(&["--output-format", "json"], false, OutputFormat::JsonCompact)]
+    #[case::forced_pretty(&["--json-pretty"], false, OutputFormat::Json)]
+    #[case::forced_compact(&["--json-compact"], true, OutputFormat::JsonCompact)]
+    #[case::ndjson_ignores_json_pretty(&["--output-format", "ndjson", "--json-pretty"], true, OutputFormat::Ndjson)]
+    #[case::explicit_pretty_wins(&["--output-format", "pretty", "--json-compact"], true, OutputFormat::Pretty)]
+    fn resolve_output_format_follows_terminal_and_json_flags(
+        #[case] flags: &[&str],
+        #[case] stdout_is_terminal: bool,
+        #[case] expected: OutputFormat,
+    ) {
+        let argv = ["idm"]
+            .into_iter()
+            .chain(flags.iter().copied())
+            .chain(["inspect"]);
+        let cli = Args::try_parse_from(argv).expect("output flags should parse");
+
+        assert_eq!(expected, cli.resolve_output_format(stdout_is_terminal));
+    }
+
+    #[test]
+    fn json_pretty_conflicts_with_json_compact() {
+        let error = Args::try_parse_from(["idm", "--json-pretty", "--json-compact", "inspect"])
+            .expect_err("json style flags should conflict");
+
+        assert_eq!(ErrorKind::ArgumentConflict, error.kind());
+    }
+
     #[test]
     fn output_format_defaults_to_none() {
         let cli = Args::try_parse_from([
@@ -422,6 +1116,23 @@ mod tests {
         assert_eq!(None, cli.output_format());
     }
 
+    #[rstest]
+    #[case("--no-color")]
+    #[case("--no-colour")]
+    fn no_colour_argument_parses(#[case] flag: &str) {
+        let cli = Args::try_parse_from([
+            "idm",
+            flag,
+            "--fake",
+            "--fake-scan",
+            "hci0|AA:BB:CC|IDM-Clock|-43",
+            "inspect",
+        ])
+        .expect("no-color should parse as a flag");
+
+        assert_eq!(true, cli.no_colour());
+    }
+
     #[test]
     fn log_level_argument_parses() {
         let cli = Args::try_parse_from([
@@ -438,6 +1149,157 @@ mod tests {
         assert_eq!(Some(LogLevel::Trace), cli.log_level());
     }
 
+    #[test]
+    fn listen_command_parses_error_filter_flags() {
+        let cli = Args::try_parse_from(["idm", "listen", "--errors-only", "--fail-on-error"])
+            .expect("listen error flags should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Listen(listen) = command else {
+            panic!("expected listen command");
+        };
+
+        assert!(listen.errors_only());
+        assert!(listen.fail_on_error());
+    }
+
+    #[test]
+    fn control_command_parses_all_devices_flags() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "control",
+            "--all",
+            "--scan-window",
+            "2s",
+            "brightness",
+            "50",
+        ])
+        .expect("control --all should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Control(control) = command else {
+            panic!("expected control command");
+        };
+
+        assert!(control.all_devices());
+        assert_eq!(Duration::from_secs(2), control.scan_window());
+    }
+
+    #[test]
+    fn control_scan_window_requires_all_devices() {
+        let result =
+            Args::try_parse_from(["idm", "control", "--scan-window", "2s", "brightness", "50"]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("5m", 5)]
+    #[case("90s", 2)]
+    fn screen_timeout_command_rounds_to_whole_minutes(#[case] value: &str, #[case] minutes: u8) {
+        let cli = Args::try_parse_from(["idm", "screen-timeout", value])
+            .expect("screen-timeout should parse");
+
+        let Args { command, .. } = cli;
+        let Command::ScreenTimeout(screen_timeout) = command else {
+            panic!("expected screen-timeout command");
+        };
+
+        assert_eq!(minutes, screen_timeout.timeout().minutes());
+    }
+
+    #[test]
+    fn screen_timeout_command_rejects_zero_minutes() {
+        let result = Args::try_parse_from(["idm", "screen-timeout", "10s"]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case::confirmed(&["idm", "factory-reset", "--yes"], true)]
+    #[case::unconfirmed(&["idm", "factory-reset"], false)]
+    fn factory_reset_command_parses_confirmation(#[case] argv: &[&str], #[case] confirmed: bool) {
+        let cli = Args::try_parse_from(argv).expect("factory-reset should parse");
+
+        let Args { command, .. } = cli;
+        let Command::FactoryReset(factory_reset) = command else {
+            panic!("expected factory-reset command");
+        };
+
+        assert_eq!(confirmed, factory_reset.confirmed());
+    }
+
+    #[rstest]
+    #[case::on("on", JointModeState::On)]
+    #[case::off("off", JointModeState::Off)]
+    fn joint_mode_command_parses_state(#[case] state: &str, #[case] expected: JointModeState) {
+        let cli =
+            Args::try_parse_from(["idm", "joint-mode", state]).expect("joint-mode should parse");
+
+        let Args { command, .. } = cli;
+        let Command::JointMode(joint_mode) = command else {
+            panic!("expected joint-mode command");
+        };
+
+        assert_eq!(expected, joint_mode.state());
+    }
+
+    #[test]
+    fn scene_command_parses_each_setting() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "scene",
+            "--power",
+            "on",
+            "--colour",
+            "#ff8000",
+            "--brightness",
+            "60",
+        ])
+        .expect("scene should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Scene(scene) = command else {
+            panic!("expected scene command");
+        };
+
+        let expected = crate::SceneRequest::builder()
+            .power(crate::ScreenPower::On)
+            .colour(crate::Rgb::new(0xFF, 0x80, 0x00))
+            .brightness(crate::Brightness::new(60).expect("60 is a valid brightness"))
+            .build();
+        assert_eq!(expected, scene.request());
+    }
+
+    #[rstest]
+    #[case::no_settings(&["idm", "scene"])]
+    #[case::brightness_out_of_range(&["idm", "scene", "--brightness", "101"])]
+    fn scene_command_rejects_invalid_settings(#[case] argv: &[&str]) {
+        assert!(Args::try_parse_from(argv).is_err());
+    }
+
+    #[rstest]
+    #[case::default_count(&["idm", "reconnect-loop"], 10)]
+    #[case::explicit_count(&["idm", "reconnect-loop", "--count", "20"], 20)]
+    fn reconnect_loop_command_parses_count(#[case] argv: &[&str], #[case] expected: usize) {
+        let cli = Args::try_parse_from(argv).expect("reconnect-loop should parse");
+
+        let Args { command, .. } = cli;
+        let Command::ReconnectLoop(reconnect_loop) = command else {
+            panic!("expected reconnect-loop command");
+        };
+
+        assert_eq!(expected, reconnect_loop.count().get());
+    }
+
+    #[test]
+    fn reconnect_loop_rejects_zero_count() {
+        let result = Args::try_parse_from(["idm", "reconnect-loop", "--count", "0"]);
+
+        let error = result.expect_err("a zero cycle count should fail parsing");
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+    }
+
     #[test]
     fn image_command_parses_path_argument() {
         let cli = Args::try_parse_from(["idm", "image", "photo.jpg"])
@@ -447,6 +1309,135 @@ mod tests {
         assert_matches!(command, Command::Image(_));
     }
 
+    #[test]
+    fn image_command_parses_concurrency_for_all_devices() {
+        let cli = Args::try_parse_from(["idm", "image", "anim.gif", "--all", "--concurrency", "3"])
+            .expect("image --all --concurrency should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert!(image.all_devices());
+        assert_eq!(3, image.concurrency().get());
+    }
+
+    #[test]
+    fn image_command_parses_max_source_pixels() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "image",
+            "photo.jpg",
+            "--max-source-pixels",
+            "1000000",
+        ])
+        .expect("image --max-source-pixels should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert_eq!(1_000_000, image.max_source_pixels());
+    }
+
+    #[rstest]
+    #[case::default(&[], TransparencyMode::FlattenToPadding)]
+    #[case::off_as_black(&["--transparency", "off-as-black"], TransparencyMode::OffAsBlack)]
+    fn image_command_parses_transparency_mode(
+        #[case] flags: &[&str],
+        #[case] expected: TransparencyMode,
+    ) {
+        let argv = ["idm", "image", "logo.png"].iter().chain(flags);
+        let cli = Args::try_parse_from(argv).expect("image --transparency should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert_eq!(expected, image.transparency());
+    }
+
+    #[rstest]
+    #[case::default(&[], DitherMode::None)]
+    #[case::floyd_steinberg(&["--dither", "floyd-steinberg"], DitherMode::FloydSteinberg)]
+    #[case::ordered(&["--dither", "ordered"], DitherMode::Ordered)]
+    fn image_command_parses_dither_mode(#[case] flags: &[&str], #[case] expected: DitherMode) {
+        let argv = ["idm", "image", "gradient.png"].iter().chain(flags);
+        let cli = Args::try_parse_from(argv).expect("image --dither should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert_eq!(expected, image.dither());
+    }
+
+    #[rstest]
+    #[case::default(&[], ScalingMode::Contain, Rgb::new(0, 0, 0))]
+    #[case::cover(&["--scaling", "cover"], ScalingMode::Cover, Rgb::new(0, 0, 0))]
+    #[case::stretch(&["--scaling", "stretch"], ScalingMode::Stretch, Rgb::new(0, 0, 0))]
+    #[case::white_bars(&["--pad-colour", "#ffffff"], ScalingMode::Contain, Rgb::new(0xFF, 0xFF, 0xFF))]
+    fn image_command_parses_scaling_and_pad_colour(
+        #[case] flags: &[&str],
+        #[case] expected_scaling: ScalingMode,
+        #[case] expected_pad_colour: Rgb,
+    ) {
+        let argv = ["idm", "image", "banner.png"].iter().chain(flags);
+        let cli = Args::try_parse_from(argv).expect("image --scaling should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert_eq!(expected_scaling, image.scaling());
+        assert_eq!(expected_pad_colour, image.pad_colour());
+    }
+
+    #[test]
+    fn image_command_parses_preview_dir_and_panel() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "image",
+            "anim.gif",
+            "--preview-dir",
+            "frames",
+            "--panel",
+            "16x16",
+        ])
+        .expect("image --preview-dir should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert_eq!(Some(Path::new("frames")), image.preview_dir());
+        assert_eq!(PanelDimensions::new(16, 16), image.panel());
+    }
+
+    #[rstest]
+    #[case::without_panel(&["--preview-dir", "frames"])]
+    #[case::without_preview_dir(&["--panel", "16x16"])]
+    #[case::with_scroll(&["--preview-dir", "frames", "--panel", "16x16", "--scroll"])]
+    fn image_command_rejects_incomplete_or_conflicting_preview(#[case] flags: &[&str]) {
+        let argv = ["idm", "image", "anim.gif"].iter().chain(flags);
+
+        assert!(Args::try_parse_from(argv).is_err());
+    }
+
+    #[test]
+    fn image_command_rejects_zero_concurrency() {
+        let result =
+            Args::try_parse_from(["idm", "image", "anim.gif", "--all", "--concurrency", "0"]);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn image_command_parses_save_gif_argument() {
         let cli =
@@ -464,4 +1455,95 @@ mod tests {
             image.save_gif_path()
         );
     }
+
+    #[test]
+    fn image_command_parses_first_frame_flag() {
+        let cli = Args::try_parse_from(["idm", "image", "anim.gif", "--first-frame"])
+            .expect("image --first-frame should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert!(image.first_frame());
+    }
+
+    #[test]
+    fn image_command_parses_repeat_count() {
+        let cli = Args::try_parse_from(["idm", "image", "anim.gif", "--repeat", "3"])
+            .expect("image --repeat should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert_eq!(3, image.repeat().get());
+    }
+
+    #[test]
+    fn image_command_parses_force_flag() {
+        let cli = Args::try_parse_from(["idm", "image", "anim.gif", "--force"])
+            .expect("image --force should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert!(image.force());
+    }
+
+    #[test]
+    fn image_command_parses_scroll_options() {
+        let cli = Args::try_parse_from([
+            "idm",
+            "image",
+            "banner.png",
+            "--scroll",
+            "--step",
+            "2",
+            "--delay",
+            "100ms",
+        ])
+        .expect("image --scroll should parse");
+
+        let Args { command, .. } = cli;
+        let Command::Image(image) = command else {
+            panic!("expected image command");
+        };
+
+        assert!(image.scroll());
+        assert_eq!(2, image.scroll_step().get());
+        assert_eq!(Duration::from_millis(100), image.scroll_delay());
+    }
+
+    #[test]
+    fn image_step_requires_scroll() {
+        let error = Args::try_parse_from(["idm", "image", "banner.png", "--step", "2"])
+            .expect_err("--step without --scroll should fail");
+        assert_eq!(ErrorKind::MissingRequiredArgument, error.kind());
+    }
+
+    #[test]
+    fn image_repeat_rejects_zero() {
+        let error = Args::try_parse_from(["idm", "image", "anim.gif", "--repeat", "0"])
+            .expect_err("zero repeats should fail");
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+    }
+
+    #[test]
+    fn image_first_frame_conflicts_with_save_gif() {
+        let error = Args::try_parse_from([
+            "idm",
+            "image",
+            "anim.gif",
+            "--first-frame",
+            "--save-gif",
+            "normalised.gif",
+        ])
+        .expect_err("a still preview has no GIF payload to save");
+        assert_eq!(ErrorKind::ArgumentConflict, error.kind());
+    }
 }
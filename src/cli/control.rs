@@ -1,45 +1,40 @@
 use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand, ValueEnum};
-use serde::Serialize;
 use time::OffsetDateTime;
 use tracing::instrument;
 
-use crate::cli::OutputFormat;
-use crate::hw::HardwareClient;
+use crate::cli::command::parse_duration;
+use crate::cli::outcome::{write_outcome_json, write_warnings};
+use crate::cli::ui::UploadSummaryView;
+use crate::cli::validate_gif::parse_panel_dimensions;
+use crate::cli::{CommandOutcome, DeviceOutcome, OutputFormat, TextOutcome, TextPreviewOutcome};
+use crate::hw::{DeviceConnectAttempt, FoundDevice, HardwareClient, TextPath};
 use crate::{
-    Brightness, BrightnessHandler, FullscreenColourHandler, PowerHandler, Rgb, ScreenPower,
-    SessionHandler, TextUploadHandler, TextUploadRequest, TimeSyncHandler,
+    Brightness, BrightnessHandler, FullscreenColourHandler, PanelDimensions, PowerHandler, Rgb,
+    ScreenPower, SessionHandler, TextDisplayMode, TextOptions, TextSpeed, TextUploadHandler,
+    TextUploadRequest, TimeSyncHandler, Warning,
 };
 
-/// JSON result emitted by a `control` action.
-#[derive(Serialize)]
-#[serde(tag = "action", rename_all = "snake_case")]
-enum ControlResult {
-    Power {
-        state: String,
-    },
-    Brightness {
-        value: u8,
-    },
-    Colour {
-        red: u8,
-        green: u8,
-        blue: u8,
-    },
-    SyncTime {
-        unix_timestamp: i64,
-    },
-    Text {
-        bytes_written: usize,
-        chunks_written: usize,
-    },
-}
+const DEFAULT_SCAN_WINDOW: Duration = Duration::from_secs(5);
 
 /// Arguments for the `control` command.
 #[derive(Debug, Args)]
 pub struct ControlArgs {
+    /// Apply the action to every matching device instead of only the first.
+    #[arg(long)]
+    all: bool,
+    /// How long to scan for matching devices when `--all` is set.
+    #[arg(
+        long,
+        value_parser = parse_duration,
+        default_value = "5s",
+        requires = "all"
+    )]
+    scan_window: Duration,
     #[command(subcommand)]
     action: ControlAction,
 }
@@ -55,7 +50,42 @@ impl ControlArgs {
     /// ```
     #[must_use]
     pub fn new(action: ControlAction) -> Self {
-        Self { action }
+        Self {
+            all: false,
+            scan_window: DEFAULT_SCAN_WINDOW,
+            action,
+        }
+    }
+
+    /// Applies the action to every device matched within `scan_window`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{BrightnessArgs, ControlAction, ControlArgs};
+    ///
+    /// let args = ControlArgs::new(ControlAction::Brightness(BrightnessArgs::new(50)?))
+    ///     .with_all_devices(Duration::from_secs(2));
+    /// assert!(args.all_devices());
+    /// # Ok::<(), idm::BrightnessError>(())
+    /// ```
+    #[must_use]
+    pub fn with_all_devices(mut self, scan_window: Duration) -> Self {
+        self.all = true;
+        self.scan_window = scan_window;
+        self
+    }
+
+    /// Returns whether the action targets every matching device.
+    #[must_use]
+    pub fn all_devices(&self) -> bool {
+        self.all
+    }
+
+    /// Returns how long `--all` scans for matching devices.
+    #[must_use]
+    pub fn scan_window(&self) -> Duration {
+        self.scan_window
     }
 }
 
@@ -106,7 +136,7 @@ pub enum PowerState {
 }
 
 impl PowerState {
-    fn to_handler_power(self) -> ScreenPower {
+    pub(crate) fn to_handler_power(self) -> ScreenPower {
         match self {
             Self::Off => ScreenPower::Off,
             Self::On => ScreenPower::On,
@@ -159,29 +189,29 @@ impl BrightnessArgs {
 /// Arguments for `control colour`.
 #[derive(Debug, Args)]
 pub struct ColourArgs {
-    red: u8,
-    green: u8,
-    blue: u8,
+    /// Colour as `#rrggbb`, `rrggbb`, or decimal `r,g,b`.
+    #[arg(value_name = "COLOUR")]
+    colour: Rgb,
 }
 
 impl ColourArgs {
     /// Creates colour-control arguments.
     ///
     /// ```
-    /// use idm::ColourArgs;
+    /// use idm::{ColourArgs, Rgb};
     ///
-    /// let args = ColourArgs::new(0x11, 0x22, 0x33);
-    /// assert_eq!(0x11, args.red());
+    /// let args = ColourArgs::new(Rgb::new(0x11, 0x22, 0x33));
+    /// assert_eq!(Rgb::new(0x11, 0x22, 0x33), args.colour());
     /// ```
     #[must_use]
-    pub fn new(red: u8, green: u8, blue: u8) -> Self {
-        Self { red, green, blue }
+    pub fn new(colour: Rgb) -> Self {
+        Self { colour }
     }
 
-    /// Returns the red byte.
+    /// Returns the colour to fill the display with.
     #[must_use]
-    pub fn red(&self) -> u8 {
-        self.red
+    pub fn colour(&self) -> Rgb {
+        self.colour
     }
 }
 
@@ -220,7 +250,34 @@ impl SyncTimeArgs {
 #[derive(Debug, Args)]
 pub struct TextArgs {
     /// Text content to render and upload using standard CLI defaults.
-    text: String,
+    #[arg(required_unless_present = "file", conflicts_with = "file")]
+    text: Option<String>,
+    /// Read the text from a UTF-8 file instead; a leading BOM is stripped.
+    #[arg(long, value_name = "PATH")]
+    file: Option<PathBuf>,
+    /// Warn when the text is estimated to take longer than this to scroll once (e.g. `1m`).
+    #[arg(long, value_parser = parse_duration, value_name = "DURATION")]
+    max_scroll: Option<Duration>,
+    /// Fail instead of warning when `--max-scroll` is exceeded.
+    #[arg(long, requires = "max_scroll")]
+    strict: bool,
+    /// Scroll speed: `slow`, `normal`, `fast`, or a raw byte in 0..=255.
+    #[arg(long, value_name = "SPEED")]
+    speed: Option<TextSpeed>,
+    /// How the text is presented: `static`, `marquee`, or `scroll`.
+    #[arg(long, value_enum, value_name = "MODE")]
+    mode: Option<TextDisplayMode>,
+    /// Render the text to this PNG instead of uploading it; needs `--panel`.
+    #[arg(long, value_name = "PATH", requires = "panel")]
+    preview: Option<PathBuf>,
+    /// Panel dimensions for `--preview`, for example `32x32`.
+    #[arg(
+        long,
+        value_name = "WIDTHxHEIGHT",
+        value_parser = parse_panel_dimensions,
+        requires = "preview"
+    )]
+    panel: Option<PanelDimensions>,
 }
 
 impl TextArgs {
@@ -234,11 +291,198 @@ impl TextArgs {
     /// ```
     #[must_use]
     pub fn new(text: impl Into<String>) -> Self {
-        Self { text: text.into() }
+        Self {
+            text: Some(text.into()),
+            file: None,
+            max_scroll: None,
+            strict: false,
+            speed: None,
+            mode: None,
+            preview: None,
+            panel: None,
+        }
+    }
+
+    /// Creates text-upload arguments that read the text from `file`.
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use idm::TextArgs;
+    ///
+    /// let args = TextArgs::from_file("message.txt");
+    /// assert_eq!(Some(Path::new("message.txt")), args.file());
+    /// ```
+    #[must_use]
+    pub fn from_file(file: impl Into<PathBuf>) -> Self {
+        Self {
+            text: None,
+            file: Some(file.into()),
+            max_scroll: None,
+            strict: false,
+            speed: None,
+            mode: None,
+            preview: None,
+            panel: None,
+        }
+    }
+
+    /// Warns when the text is estimated to take longer than `threshold` to
+    /// scroll once, or fails the upload when `strict` is set.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::TextArgs;
+    ///
+    /// let args = TextArgs::new("Hello").with_max_scroll(Duration::from_secs(30), true);
+    /// assert_eq!(Some(Duration::from_secs(30)), args.max_scroll());
+    /// assert!(args.strict());
+    /// ```
+    #[must_use]
+    pub fn with_max_scroll(mut self, threshold: Duration, strict: bool) -> Self {
+        self.max_scroll = Some(threshold);
+        self.strict = strict;
+        self
+    }
+
+    /// Scrolls the text at `speed` instead of the default.
+    ///
+    /// ```
+    /// use idm::{TextArgs, TextSpeed};
+    ///
+    /// let args = TextArgs::new("Hello").with_speed(TextSpeed::Fast);
+    /// assert_eq!(Some(TextSpeed::Fast), args.speed());
+    /// ```
+    #[must_use]
+    pub fn with_speed(mut self, speed: TextSpeed) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Presents the text in display `mode` instead of the default.
+    ///
+    /// ```
+    /// use idm::{TextArgs, TextDisplayMode};
+    ///
+    /// let args = TextArgs::new("Hi").with_mode(TextDisplayMode::Static);
+    /// assert_eq!(Some(TextDisplayMode::Static), args.mode());
+    /// ```
+    #[must_use]
+    pub fn with_mode(mut self, mode: TextDisplayMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Renders the text to a PNG at `path` for a `panel`-sized display
+    /// instead of uploading it.
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use idm::{PanelDimensions, TextArgs};
+    ///
+    /// let panel = PanelDimensions::new(32, 32).expect("32x32 should be valid dimensions");
+    /// let args = TextArgs::new("Hi").with_preview("out.png", panel);
+    /// assert_eq!(Some(Path::new("out.png")), args.preview());
+    /// assert_eq!(Some(panel), args.panel());
+    /// ```
+    #[must_use]
+    pub fn with_preview(mut self, path: impl Into<PathBuf>, panel: PanelDimensions) -> Self {
+        self.preview = Some(path.into());
+        self.panel = Some(panel);
+        self
+    }
+
+    /// Returns the text file path, if the text is read from a file.
+    #[must_use]
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Returns the scroll-duration threshold, if one was set.
+    #[must_use]
+    pub fn max_scroll(&self) -> Option<Duration> {
+        self.max_scroll
+    }
+
+    /// Returns whether exceeding the scroll-duration threshold fails the upload.
+    #[must_use]
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns the requested scroll speed, if one was set.
+    #[must_use]
+    pub fn speed(&self) -> Option<TextSpeed> {
+        self.speed
+    }
+
+    /// Returns the requested display mode, if one was set.
+    #[must_use]
+    pub fn mode(&self) -> Option<TextDisplayMode> {
+        self.mode
+    }
+
+    /// Returns the PNG path the text is previewed to, if one was set.
+    #[must_use]
+    pub fn preview(&self) -> Option<&Path> {
+        self.preview.as_deref()
+    }
+
+    /// Returns the panel dimensions the preview is rendered for, if set.
+    #[must_use]
+    pub fn panel(&self) -> Option<PanelDimensions> {
+        self.panel
+    }
+
+    fn request(&self) -> Result<TextUploadRequest> {
+        let request = match (&self.text, &self.file) {
+            (Some(text), _) => default_cli_text_request(text),
+            (None, Some(file)) => TextUploadRequest::from_file(file)?,
+            (None, None) => bail!("text or a --file path is required"),
+        };
+        Ok(request.with_options(self.text_options()))
+    }
+
+    fn text_options(&self) -> TextOptions {
+        TextOptions::default()
+            .with_speed(self.speed.unwrap_or_default())
+            .with_display_mode(self.mode.unwrap_or_default())
+    }
+
+    fn write_preview(&self, path: &Path, panel: PanelDimensions) -> Result<TextPreviewOutcome> {
+        let frame = self.request()?.render_preview(panel)?;
+        image::save_buffer(
+            path,
+            frame.payload(),
+            u32::from(panel.width()),
+            u32::from(panel.height()),
+            image::ExtendedColorType::Rgb8,
+        )
+        .with_context(|| format!("failed to write text preview `{}`", path.display()))?;
+        Ok(TextPreviewOutcome {
+            path: path.to_path_buf(),
+            panel,
+        })
+    }
+
+    fn check_scroll_duration(
+        &self,
+        request: &TextUploadRequest,
+        text_path: TextPath,
+    ) -> Result<Vec<Warning>> {
+        let Some(threshold) = self.max_scroll else {
+            return Ok(Vec::new());
+        };
+        match request.scroll_duration_warning(text_path, threshold) {
+            Some(warning) if self.strict => bail!("{warning}; refusing to upload under --strict"),
+            warning => Ok(warning.into_iter().collect()),
+        }
     }
 }
 
-fn parse_brightness(value: &str) -> Result<Brightness, String> {
+pub(crate) fn parse_brightness(value: &str) -> Result<Brightness, String> {
     let parsed = value.parse::<u8>().map_err(|error| error.to_string())?;
     Brightness::new(parsed).map_err(|error| error.to_string())
 }
@@ -250,10 +494,27 @@ pub(crate) async fn run<W>(
     args: &ControlArgs,
     out: &mut W,
     output_format: OutputFormat,
-) -> Result<()>
+) -> Result<CommandOutcome>
 where
     W: io::Write,
 {
+    if let ControlAction::Text(text_args) = &args.action
+        && let (Some(path), Some(panel)) = (text_args.preview(), text_args.panel())
+    {
+        let preview = text_args.write_preview(path, panel)?;
+        match output_format {
+            OutputFormat::Pretty | OutputFormat::Compact => {
+                writeln!(out, "Wrote {panel} text preview to {}", path.display())?;
+            }
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+        }
+        return Ok(CommandOutcome::TextPreview(preview));
+    }
+
+    if args.all {
+        return run_on_all_devices(client, args, out, output_format).await;
+    }
+
     let session = SessionHandler::new(client).connect_first().await?;
 
     let command_result = run_with_session(&session, args, out, output_format).await;
@@ -269,130 +530,201 @@ where
     command_result
 }
 
+#[instrument(skip(client, args, out), level = "info", fields(action = ?args.action, scan_window = ?args.scan_window))]
+async fn run_on_all_devices<W>(
+    client: Box<dyn HardwareClient>,
+    args: &ControlArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let attempts = SessionHandler::new(client)
+        .connect_all(args.scan_window)
+        .await?;
+    let total = attempts.len();
+
+    let mut outcomes = Vec::with_capacity(total);
+    let mut failed = 0;
+    for DeviceConnectAttempt { device, session } in attempts {
+        let mut device_out = Vec::new();
+        let outcome = match session {
+            Ok(session) => run_and_close(session, args, &mut device_out, output_format).await,
+            Err(error) => Err(error.into()),
+        };
+        if outcome.is_err() {
+            failed += 1;
+        }
+
+        match output_format {
+            OutputFormat::Pretty | OutputFormat::Compact => {
+                let label = device_label(&device);
+                match &outcome {
+                    Ok(_outcome) => {
+                        write!(out, "{label}: ")?;
+                        out.write_all(&device_out)?;
+                    }
+                    Err(error) => writeln!(out, "{label}: failed: {error:#}")?,
+                }
+            }
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+        }
+        outcomes.push(DeviceOutcome {
+            device,
+            outcome: outcome.map_err(|error| format!("{error:#}")),
+        });
+    }
+
+    let succeeded = total - failed;
+    let outcome = CommandOutcome::ControlAll(outcomes);
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(out, "Applied to {succeeded} of {total} device(s)")?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson if failed > 0 => {
+            write_outcome_json(out, &outcome, output_format)?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    if failed > 0 {
+        bail!("control action failed on {failed} of {total} device(s)");
+    }
+    Ok(outcome)
+}
+
+async fn run_and_close<W>(
+    session: crate::DeviceSession,
+    args: &ControlArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let command_result = run_with_session(&session, args, out, output_format).await;
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close control session cleanly");
+    }
+
+    command_result
+}
+
+pub(crate) fn device_label(device: &FoundDevice) -> String {
+    match device.local_name() {
+        Some(name) => format!("{name} ({})", device.device_id_display()),
+        None => device.device_id_display().to_string(),
+    }
+}
+
 #[instrument(skip(session, args, out), level = "debug", fields(action = ?args.action, ?output_format))]
-async fn run_with_session<W>(
+pub(crate) async fn run_with_session<W>(
     session: &crate::DeviceSession,
     args: &ControlArgs,
     out: &mut W,
     output_format: OutputFormat,
-) -> Result<()>
+) -> Result<CommandOutcome>
 where
     W: io::Write,
 {
-    match &args.action {
+    let outcome = match &args.action {
         ControlAction::Power(power_args) => {
             PowerHandler::set_power(session, power_args.state.to_handler_power()).await?;
             match output_format {
-                OutputFormat::Pretty => {
+                OutputFormat::Pretty | OutputFormat::Compact => {
                     writeln!(out, "Applied power state: {}", power_args.state)?;
                 }
-                OutputFormat::Json => {
-                    write_json_line(
-                        out,
-                        &ControlResult::Power {
-                            state: power_args.state.to_string(),
-                        },
-                    )?;
-                }
+                OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
             }
+            CommandOutcome::Power(power_args.state.to_handler_power())
         }
         ControlAction::Brightness(brightness_args) => {
             BrightnessHandler::set_brightness(session, brightness_args.brightness).await?;
             match output_format {
-                OutputFormat::Pretty => {
+                OutputFormat::Pretty | OutputFormat::Compact => {
                     writeln!(
                         out,
                         "Applied brightness: {}",
                         brightness_args.brightness.value()
                     )?;
                 }
-                OutputFormat::Json => {
-                    write_json_line(
-                        out,
-                        &ControlResult::Brightness {
-                            value: brightness_args.brightness.value(),
-                        },
-                    )?;
-                }
+                OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
             }
+            CommandOutcome::Brightness(brightness_args.brightness)
         }
         ControlAction::Colour(colour_args) => {
-            let colour = Rgb::new(colour_args.red, colour_args.green, colour_args.blue);
+            let colour = colour_args.colour();
             FullscreenColourHandler::set_colour(session, colour).await?;
             match output_format {
-                OutputFormat::Pretty => {
-                    writeln!(
-                        out,
-                        "Applied fullscreen colour: #{:02X}{:02X}{:02X}",
-                        colour.r, colour.g, colour.b
-                    )?;
-                }
-                OutputFormat::Json => {
-                    write_json_line(
-                        out,
-                        &ControlResult::Colour {
-                            red: colour.r,
-                            green: colour.g,
-                            blue: colour.b,
-                        },
-                    )?;
+                OutputFormat::Pretty | OutputFormat::Compact => {
+                    writeln!(out, "Applied fullscreen colour: {colour}")?;
                 }
+                OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
             }
+            CommandOutcome::Colour(colour)
         }
         ControlAction::SyncTime(sync_time_args) => {
             let timestamp = sync_time_args.resolve_timestamp()?;
             TimeSyncHandler::sync_time(session, timestamp).await?;
             match output_format {
-                OutputFormat::Pretty => {
+                OutputFormat::Pretty | OutputFormat::Compact => {
                     writeln!(
                         out,
                         "Synced time (UTC unix): {}",
                         timestamp.unix_timestamp()
                     )?;
                 }
-                OutputFormat::Json => {
-                    write_json_line(
-                        out,
-                        &ControlResult::SyncTime {
-                            unix_timestamp: timestamp.unix_timestamp(),
-                        },
-                    )?;
-                }
+                OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
             }
+            CommandOutcome::SyncTime(timestamp)
         }
         ControlAction::Text(text_args) => {
-            let receipt =
-                TextUploadHandler::upload(session, default_cli_text_request(&text_args.text))
-                    .await?;
+            let request = text_args.request()?;
+            let text_path = session
+                .device_profile()
+                .text_path()
+                .unwrap_or(TextPath::Path1616);
+            let warnings = text_args.check_scroll_duration(&request, text_path)?;
+            let started = tokio::time::Instant::now();
+            let receipt = TextUploadHandler::upload(session, request).await?;
             match output_format {
                 OutputFormat::Pretty => {
                     writeln!(
                         out,
-                        "Uploaded text payload: {} bytes in {} chunk(s)",
-                        receipt.bytes_written(),
-                        receipt.chunks_written(),
+                        "{}",
+                        UploadSummaryView::new(
+                            receipt.bytes_written(),
+                            receipt.chunks_written(),
+                            started.elapsed(),
+                        )
                     )?;
+                    write_warnings(out, &warnings)?;
                 }
-                OutputFormat::Json => {
-                    write_json_line(
+                OutputFormat::Compact => {
+                    writeln!(
                         out,
-                        &ControlResult::Text {
-                            bytes_written: receipt.bytes_written(),
-                            chunks_written: receipt.chunks_written(),
-                        },
+                        "Uploaded text payload: {} bytes in {} chunk(s)",
+                        receipt.bytes_written(),
+                        receipt.chunks_written(),
                     )?;
                 }
+                OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
             }
+            CommandOutcome::Text(TextOutcome {
+                bytes_written: receipt.bytes_written(),
+                chunks_written: receipt.chunks_written(),
+                warnings,
+            })
         }
-    }
+    };
 
-    Ok(())
-}
-
-fn write_json_line(out: &mut impl io::Write, value: &impl Serialize) -> Result<()> {
-    serde_json::to_writer_pretty(&mut *out, value)?;
-    writeln!(out)?;
-    Ok(())
+    Ok(outcome)
 }
 
 fn default_cli_text_request(text: &str) -> TextUploadRequest {
@@ -401,9 +733,139 @@ fn default_cli_text_request(text: &str) -> TextUploadRequest {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
+    use clap::Parser;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     use super::*;
+    use crate::cli::Command;
+
+    #[rstest]
+    #[case::hash_hex("#112233")]
+    #[case::bare_hex("112233")]
+    #[case::decimal("17,34,51")]
+    fn colour_command_parses_each_colour_format(#[case] value: &str) {
+        let (command, _) = crate::Args::try_parse_from(["idm", "control", "colour", value])
+            .expect("control colour should parse")
+            .into_command_and_fake_args()
+            .expect("non-fake args should split cleanly");
+
+        let Command::Control(control) = command else {
+            panic!("expected control command");
+        };
+        assert_matches!(
+            control.action,
+            ControlAction::Colour(colour_args) if colour_args.colour() == Rgb::new(0x11, 0x22, 0x33)
+        );
+    }
+
+    #[test]
+    fn colour_command_rejects_unparseable_colour() {
+        let result = crate::Args::try_parse_from(["idm", "control", "colour", "#12345"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn text_command_reads_text_from_file() {
+        let (command, _) =
+            crate::Args::try_parse_from(["idm", "control", "text", "--file", "message.txt"])
+                .expect("control text --file should parse")
+                .into_command_and_fake_args()
+                .expect("non-fake args should split cleanly");
+
+        let Command::Control(control) = command else {
+            panic!("expected control command");
+        };
+        assert_matches!(
+            control.action,
+            ControlAction::Text(text_args) if text_args.file() == Some(Path::new("message.txt"))
+        );
+    }
+
+    #[rstest]
+    #[case::both(&["idm", "control", "text", "Hello", "--file", "message.txt"])]
+    #[case::neither(&["idm", "control", "text"])]
+    fn text_command_requires_exactly_one_text_source(#[case] argv: &[&str]) {
+        let result = crate::Args::try_parse_from(argv);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case::preset("fast", TextSpeed::Fast)]
+    #[case::raw("100", TextSpeed::Raw(100))]
+    fn text_command_parses_speed(#[case] value: &str, #[case] expected: TextSpeed) {
+        let (command, _) =
+            crate::Args::try_parse_from(["idm", "control", "text", "Hello", "--speed", value])
+                .expect("control text --speed should parse")
+                .into_command_and_fake_args()
+                .expect("non-fake args should split cleanly");
+
+        let Command::Control(control) = command else {
+            panic!("expected control command");
+        };
+        assert_matches!(
+            control.action,
+            ControlAction::Text(text_args) if text_args.speed() == Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::static_text("static", TextDisplayMode::Static)]
+    #[case::marquee("marquee", TextDisplayMode::Marquee)]
+    #[case::scroll("scroll", TextDisplayMode::Scroll)]
+    fn text_command_parses_display_mode(#[case] value: &str, #[case] expected: TextDisplayMode) {
+        let (command, _) =
+            crate::Args::try_parse_from(["idm", "control", "text", "Hi", "--mode", value])
+                .expect("control text --mode should parse")
+                .into_command_and_fake_args()
+                .expect("non-fake args should split cleanly");
+
+        let Command::Control(control) = command else {
+            panic!("expected control command");
+        };
+        assert_matches!(
+            control.action,
+            ControlAction::Text(text_args) if text_args.mode() == Some(expected)
+        );
+    }
+
+    #[test]
+    fn text_command_parses_preview_path_and_panel() {
+        let (command, _) = crate::Args::try_parse_from([
+            "idm",
+            "control",
+            "text",
+            "Hi",
+            "--preview",
+            "out.png",
+            "--panel",
+            "32x32",
+        ])
+        .expect("control text --preview should parse")
+        .into_command_and_fake_args()
+        .expect("non-fake args should split cleanly");
+
+        let Command::Control(control) = command else {
+            panic!("expected control command");
+        };
+        assert_matches!(
+            control.action,
+            ControlAction::Text(text_args)
+                if text_args.preview() == Some(Path::new("out.png"))
+                    && text_args.panel() == PanelDimensions::new(32, 32)
+        );
+    }
+
+    #[test]
+    fn text_command_rejects_unknown_speed() {
+        let result =
+            crate::Args::try_parse_from(["idm", "control", "text", "Hello", "--speed", "brisk"]);
+
+        assert!(result.is_err());
+    }
 
     #[test]
     fn default_cli_text_request_uses_stable_defaults() {
@@ -0,0 +1,113 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, CrcOutcome, OutputFormat};
+
+/// Arguments for the offline `crc` command.
+#[derive(Debug, Args)]
+pub struct CrcArgs {
+    /// Path to the payload file to checksum.
+    #[arg(required_unless_present = "hex", conflicts_with = "hex")]
+    file: Option<PathBuf>,
+    /// Hex-encoded payload bytes to checksum instead of a file.
+    #[arg(long, value_name = "HEX")]
+    hex: Option<String>,
+}
+
+impl CrcArgs {
+    /// Creates arguments that checksum the bytes of `file`.
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use idm::CrcArgs;
+    ///
+    /// let args = CrcArgs::from_file("payload.bin");
+    /// assert_eq!(Some(Path::new("payload.bin")), args.file());
+    /// ```
+    #[must_use]
+    pub fn from_file(file: impl Into<PathBuf>) -> Self {
+        Self {
+            file: Some(file.into()),
+            hex: None,
+        }
+    }
+
+    /// Creates arguments that checksum hex-encoded bytes.
+    ///
+    /// ```
+    /// use idm::CrcArgs;
+    ///
+    /// let args = CrcArgs::from_hex("313233");
+    /// assert_eq!(Some("313233"), args.hex());
+    /// ```
+    #[must_use]
+    pub fn from_hex(hex: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            hex: Some(hex.into()),
+        }
+    }
+
+    /// Returns the payload file path, if checksumming a file.
+    #[must_use]
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Returns the hex-encoded payload, if checksumming inline bytes.
+    #[must_use]
+    pub fn hex(&self) -> Option<&str> {
+        self.hex.as_deref()
+    }
+
+    fn payload(&self) -> Result<Vec<u8>> {
+        match (&self.file, &self.hex) {
+            (_, Some(hex)) => hex::decode(hex.trim()).context("failed to decode --hex payload"),
+            (Some(file), None) => std::fs::read(file)
+                .with_context(|| format!("failed to read payload file {}", file.display())),
+            (None, None) => anyhow::bail!("a payload file or --hex bytes are required"),
+        }
+    }
+}
+
+/// Executes the `crc` command without connecting to a device.
+#[instrument(skip(args, out), level = "info", fields(?output_format))]
+pub(crate) fn run<W>(
+    args: &CrcArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let payload = args.payload()?;
+    let crc32 = crc32fast::hash(&payload);
+    let header_bytes = crc32.to_le_bytes();
+
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(out, "Payload length: {} bytes", payload.len())?;
+            writeln!(out, "CRC32: 0x{crc32:08X}")?;
+            writeln!(
+                out,
+                "Header bytes (LE): {}",
+                header_bytes
+                    .iter()
+                    .map(|byte| format!("{byte:02X}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::Crc(CrcOutcome {
+        payload_len: payload.len(),
+        crc32,
+    }))
+}
@@ -0,0 +1,327 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Args, Parser};
+use serde::Serialize;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tracing::instrument;
+
+use crate::cli::control::{self, ControlAction, ControlArgs};
+use crate::cli::outcome::{write_json_document, write_outcome_json};
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::{DeviceSession, SessionHandler};
+
+/// JSON event emitted by the `daemon` command on its own output.
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum DaemonEvent<'a> {
+    Ready { socket: &'a Path },
+}
+
+/// JSON result written back to a socket client when a command fails.
+#[derive(Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+enum DaemonResult {
+    Error { message: String },
+}
+
+/// One command line received on the daemon socket.
+///
+/// Lines use the `control` grammar without the `idm control` prefix, for
+/// example `brightness 50` or `colour 255 0 0`.
+#[derive(Debug, Parser)]
+#[command(name = "idm daemon", no_binary_name = true)]
+struct DaemonLine {
+    #[command(subcommand)]
+    action: ControlAction,
+}
+
+/// Arguments for the `daemon` command.
+#[derive(Debug, Args)]
+pub struct DaemonArgs {
+    /// Path of the Unix domain socket to listen on.
+    #[arg(long, value_name = "PATH")]
+    socket: PathBuf,
+}
+
+impl DaemonArgs {
+    /// Creates daemon arguments listening on `socket`.
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use idm::DaemonArgs;
+    ///
+    /// let args = DaemonArgs::new("/tmp/idm.sock");
+    /// assert_eq!(Path::new("/tmp/idm.sock"), args.socket());
+    /// ```
+    #[must_use]
+    pub fn new(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            socket: socket.into(),
+        }
+    }
+
+    /// Returns the control socket path.
+    #[must_use]
+    pub fn socket(&self) -> &Path {
+        &self.socket
+    }
+}
+
+/// Executes the `daemon` command until SIGTERM or Ctrl-C.
+#[instrument(skip(client, args, out), level = "info", fields(socket = %args.socket.display(), ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &DaemonArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let serve_result = serve(&session, args, out, output_format).await;
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if serve_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close daemon session cleanly");
+    }
+
+    serve_result.map(|()| CommandOutcome::Daemon)
+}
+
+async fn serve<W>(
+    session: &DeviceSession,
+    args: &DaemonArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<()>
+where
+    W: io::Write,
+{
+    let listener = UnixListener::bind(&args.socket)
+        .with_context(|| format!("failed to bind daemon socket at {}", args.socket.display()))?;
+
+    let accept_result = accept_until_shutdown(session, &listener, args, out, output_format).await;
+
+    drop(listener);
+    if let Err(error) = std::fs::remove_file(&args.socket) {
+        tracing::debug!(?error, "failed to remove daemon socket");
+    }
+
+    accept_result
+}
+
+async fn accept_until_shutdown<W>(
+    session: &DeviceSession,
+    listener: &UnixListener,
+    args: &DaemonArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<()>
+where
+    W: io::Write,
+{
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(out, "Daemon listening on {}", args.socket.display())?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            write_json_document(
+                out,
+                &DaemonEvent::Ready {
+                    socket: &args.socket,
+                },
+                output_format,
+            )?;
+        }
+    }
+    out.flush()?;
+
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+
+    loop {
+        let stream = tokio::select! {
+            () = &mut shutdown => break,
+            accepted = listener.accept() => {
+                let (stream, _address) = accepted.context("failed to accept daemon client")?;
+                stream
+            }
+        };
+
+        // Clients are served one at a time so commands never interleave on the
+        // held session.
+        tokio::select! {
+            () = &mut shutdown => break,
+            result = serve_client(session, stream, output_format) => {
+                if let Err(error) = result {
+                    tracing::debug!(?error, "daemon client disconnected with an error");
+                }
+            }
+        }
+    }
+
+    tracing::info!("daemon shutting down");
+    Ok(())
+}
+
+#[instrument(skip(session, stream), level = "debug")]
+async fn serve_client(
+    session: &DeviceSession,
+    stream: UnixStream,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        if words.is_empty() {
+            continue;
+        }
+
+        let mut response = Vec::new();
+        dispatch_line(session, &words, &mut response, output_format).await?;
+        writer.write_all(&response).await?;
+        writer.flush().await?;
+    }
+
+    tracing::debug!("daemon client disconnected");
+    Ok(())
+}
+
+async fn dispatch_line(
+    session: &DeviceSession,
+    words: &[&str],
+    out: &mut Vec<u8>,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let command_result = match DaemonLine::try_parse_from(words) {
+        Ok(line) => {
+            let args = ControlArgs::new(line.action);
+            control::run_with_session(session, &args, out, output_format).await
+        }
+        Err(error) => Err(error.into()),
+    };
+
+    let error = match command_result {
+        Ok(outcome) => {
+            if output_format.is_json() {
+                write_outcome_json(out, &outcome, output_format)?;
+            }
+            return Ok(());
+        }
+        Err(error) => error,
+    };
+    let message = format!("{error:#}").trim_end().to_string();
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            io::Write::write_all(out, message.as_bytes())?;
+            io::Write::write_all(out, b"\n")?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            write_json_document(out, &DaemonResult::Error { message }, output_format)?;
+        }
+    }
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let terminate = async {
+        let mut signal = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+        signal.recv().await;
+        Ok::<(), io::Error>(())
+    };
+    wait_for_shutdown(tokio::signal::ctrl_c(), terminate).await;
+}
+
+/// Resolves when either signal source fires.
+///
+/// A source that fails to install never resolves. Without a handler the
+/// daemon must keep serving rather than treat the failed registration as a
+/// shutdown request.
+async fn wait_for_shutdown(
+    interrupt: impl Future<Output = io::Result<()>>,
+    terminate: impl Future<Output = io::Result<()>>,
+) {
+    let interrupt = async {
+        if let Err(error) = interrupt.await {
+            tracing::debug!(?error, "failed to install Ctrl-C handler");
+            std::future::pending::<()>().await;
+        }
+    };
+    let terminate = async {
+        if let Err(error) = terminate.await {
+            tracing::debug!(?error, "failed to install SIGTERM handler");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    tokio::select! {
+        () = interrupt => {}
+        () = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future;
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    fn install_failure() -> io::Result<()> {
+        Err(io::Error::other("signal handler unavailable"))
+    }
+
+    #[test]
+    fn daemon_line_parses_control_grammar() {
+        let line =
+            DaemonLine::try_parse_from(["brightness", "50"]).expect("brightness line should parse");
+
+        assert_matches!(line.action, ControlAction::Brightness(_));
+    }
+
+    #[test]
+    fn daemon_line_rejects_unknown_command() {
+        let parsed = DaemonLine::try_parse_from(["dance"]);
+
+        assert_matches!(parsed, Err(_));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_signal_installs_do_not_request_shutdown() {
+        let waited = tokio::time::timeout(
+            Duration::from_secs(60),
+            wait_for_shutdown(
+                future::ready(install_failure()),
+                future::ready(install_failure()),
+            ),
+        )
+        .await;
+
+        assert_matches!(waited, Err(_));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn installed_signal_still_shuts_down_when_the_other_fails() {
+        let waited = tokio::time::timeout(
+            Duration::from_secs(60),
+            wait_for_shutdown(future::ready(install_failure()), future::ready(Ok(()))),
+        )
+        .await;
+
+        assert_matches!(waited, Ok(()));
+    }
+}
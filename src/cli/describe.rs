@@ -0,0 +1,220 @@
+use std::io;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, FrameDescription, OutputFormat};
+use crate::{
+    FrameCodec, FrameCodecError, GifChunkFlag, MaterialSlot, MediaHeader, MediaHeaderKind,
+    ScreenPower, ShortFrame,
+};
+
+const MEDIA_HEADER_LEN: usize = 16;
+
+/// Arguments for the offline `describe` command.
+#[derive(Debug, Args)]
+pub struct DescribeArgs {
+    /// Hex-encoded frame bytes; groups may be split across arguments.
+    #[arg(required = true, value_name = "HEX")]
+    hex: Vec<String>,
+}
+
+impl DescribeArgs {
+    /// Creates arguments that describe hex-encoded frame bytes.
+    ///
+    /// ```
+    /// use idm::DescribeArgs;
+    ///
+    /// let args = DescribeArgs::new("0500 0480 50");
+    /// assert_eq!("0500048050", args.hex());
+    /// ```
+    #[must_use]
+    pub fn new(hex: impl Into<String>) -> Self {
+        Self {
+            hex: vec![hex.into()],
+        }
+    }
+
+    /// Returns the frame hex with whitespace between groups removed.
+    #[must_use]
+    pub fn hex(&self) -> String {
+        self.hex
+            .iter()
+            .flat_map(|group| group.split_whitespace())
+            .collect()
+    }
+}
+
+/// Executes the `describe` command without connecting to a device.
+#[instrument(skip(args, out), level = "info", fields(?output_format))]
+pub(crate) fn run<W>(
+    args: &DescribeArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let frame = hex::decode(args.hex()).context("failed to decode frame hex")?;
+    let description = describe_frame(&frame).context("failed to decode frame")?;
+
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => writeln!(out, "{description}")?,
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::Describe(description))
+}
+
+/// Explains one short frame or 16-byte media header field by field.
+///
+/// Bytes that start with a known media type and a zero namespace byte are read
+/// as a media header; anything else must be a complete short frame.
+pub(crate) fn describe_frame(frame: &[u8]) -> Result<FrameDescription, FrameCodecError> {
+    if frame.len() >= MEDIA_HEADER_LEN && frame[3] == 0x00 {
+        match FrameCodec::decode_media_header(frame) {
+            Ok(header) => return Ok(describe_media_header(&header, frame.len())),
+            Err(FrameCodecError::UnknownMediaType { .. }) => {}
+            Err(error) => return Err(error),
+        }
+    }
+    FrameCodec::decode_short(frame).map(|short| describe_short_frame(&short))
+}
+
+fn describe_media_header(header: &MediaHeader, frame_len: usize) -> FrameDescription {
+    let kind = match header.kind() {
+        MediaHeaderKind::Gif => "gif header",
+        MediaHeaderKind::Image => "image header",
+        MediaHeaderKind::Text => "text header",
+    };
+    let mut fields = vec![format!("block len {}", header.block_len())];
+    if header.kind() != MediaHeaderKind::Text {
+        fields.push(match header.chunk_flag() {
+            Some(GifChunkFlag::First) => "first chunk".to_string(),
+            Some(GifChunkFlag::Continuation) => "continuation chunk".to_string(),
+            None => "unknown chunk flag".to_string(),
+        });
+    }
+    fields.push(format!("payload_len {}", header.payload_len()));
+    fields.push(format!("crc 0x{:08X}", header.crc32()));
+    if header.slot() == MaterialSlot::NO_TIME_SIGNATURE {
+        fields.push("no-time-signature".to_string());
+    } else {
+        fields.push(format!("slot {}", header.slot()));
+        fields.push(format!("duration {}s", header.duration_seconds()));
+    }
+    if frame_len > MEDIA_HEADER_LEN {
+        fields.push(format!(
+            "chunk data {} of {} bytes",
+            frame_len - MEDIA_HEADER_LEN,
+            header.chunk_payload_len()
+        ));
+    }
+
+    FrameDescription {
+        kind: kind.to_string(),
+        fields,
+    }
+}
+
+fn describe_short_frame(frame: &ShortFrame<'_>) -> FrameDescription {
+    let (kind, fields) = if let Some(brightness) = frame.brightness() {
+        (
+            "brightness command",
+            vec![format!("level {}", brightness.value())],
+        )
+    } else if let Some(power) = frame.power() {
+        let state = match power {
+            ScreenPower::Off => "screen off",
+            ScreenPower::On => "screen on",
+        };
+        ("power command", vec![state.to_string()])
+    } else if let Some(colour) = frame.fullscreen_colour() {
+        (
+            "fullscreen colour command",
+            vec![format!("colour {colour}")],
+        )
+    } else {
+        let payload = if frame.payload().is_empty() {
+            "no payload".to_string()
+        } else {
+            format!("payload {}", hex::encode_upper(frame.payload()))
+        };
+        (
+            "short frame",
+            vec![
+                format!("command 0x{:02X}", frame.command_id()),
+                format!("namespace 0x{:02X}", frame.command_ns()),
+                payload,
+            ],
+        )
+    };
+
+    FrameDescription {
+        kind: kind.to_string(),
+        fields,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::text_header(
+        "1E000300000E00000044332211000 00C",
+        "text header, block len 30, payload_len 14, crc 0x11223344, no-time-signature"
+    )]
+    #[case::gif_header(
+        "C908010002B9180000DB42CB1400000C",
+        "gif header, block len 2249, continuation chunk, payload_len 6329, crc 0x14CB42DB, no-time-signature"
+    )]
+    #[case::timed_image_header(
+        "14100200000400000044332211 0A000D",
+        "image header, block len 4116, first chunk, payload_len 4, crc 0x11223344, slot 13, duration 10s"
+    )]
+    #[case::brightness("0500048050", "brightness command, level 80")]
+    #[case::power("0500070101", "power command, screen on")]
+    #[case::colour("0700020211 2233", "fullscreen colour command, colour #112233")]
+    #[case::unknown_short("05000F8005", "short frame, command 0x0F, namespace 0x80, payload 05")]
+    fn describe_frame_explains_each_field(#[case] hex: &str, #[case] expected: &str) {
+        let frame = hex::decode(DescribeArgs::new(hex).hex()).expect("test hex should decode");
+
+        let description = describe_frame(&frame).expect("test frame should describe");
+
+        assert_eq!(expected, description.to_string());
+    }
+
+    #[test]
+    fn describe_frame_counts_chunk_data_after_header() {
+        let mut frame =
+            hex::decode("1E000300000E0000004433221100000C").expect("test hex should decode");
+        frame.extend_from_slice(&[0x00; 14]);
+
+        let description = describe_frame(&frame).expect("test frame should describe");
+
+        assert_eq!(
+            Some("chunk data 14 of 14 bytes"),
+            description.fields.last().map(String::as_str)
+        );
+    }
+
+    #[test]
+    fn describe_frame_rejects_truncated_short_frame() {
+        let error = describe_frame(&[0x06, 0x00, 0x04, 0x80, 0x50])
+            .expect_err("length mismatch should fail");
+
+        assert_matches!(
+            error,
+            FrameCodecError::ShortFrameLengthMismatch {
+                declared: 6,
+                actual: 5,
+            }
+        );
+    }
+}
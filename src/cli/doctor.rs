@@ -0,0 +1,302 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::Result;
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, DoctorCheck, DoctorOutcome, DoctorStatus, OutputFormat};
+use crate::error::InteractionError;
+use crate::hw::{HardwareClient, model_overrides_path, profile_cache_path};
+
+/// Executes the `doctor` command without scanning or connecting.
+#[instrument(skip(client, out), level = "info", fields(?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let outcome = run_checks(&*client).await;
+
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            for check in &outcome.checks {
+                let verdict = match check.status {
+                    DoctorStatus::Ok => "OK",
+                    DoctorStatus::Failed => "FAIL",
+                    DoctorStatus::Skipped => "SKIP",
+                };
+                writeln!(out, "{verdict}: {}: {}", check.name, check.detail)?;
+            }
+            writeln!(
+                out,
+                "{} of {} checks found a problem",
+                outcome.failed(),
+                outcome.checks.len()
+            )?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::Doctor(outcome))
+}
+
+/// Runs every environment check, recording failures instead of stopping at
+/// them.
+pub(crate) async fn run_checks(client: &dyn HardwareClient) -> DoctorOutcome {
+    let adapters = client.adapter_names().await;
+    if let Err(error) = &adapters {
+        tracing::debug!(%error, "doctor could not list Bluetooth adapters");
+    }
+    let model_resolution = client.model_resolution();
+
+    DoctorOutcome {
+        checks: vec![
+            adapter_check(&adapters),
+            permission_check(&adapters, cfg!(target_os = "linux")),
+            runtime_check(),
+            state_file_check(
+                "model overrides file",
+                &model_overrides_path(&model_resolution),
+            ),
+            state_file_check("profile cache file", &profile_cache_path(&model_resolution)),
+        ],
+    }
+}
+
+fn check(name: &str, status: DoctorStatus, detail: impl Into<String>) -> DoctorCheck {
+    DoctorCheck {
+        name: name.to_string(),
+        status,
+        detail: detail.into(),
+    }
+}
+
+fn adapter_check(adapters: &Result<Vec<String>, InteractionError>) -> DoctorCheck {
+    const NAME: &str = "bluetooth adapter";
+    match adapters {
+        Ok(names) if names.is_empty() => check(
+            NAME,
+            DoctorStatus::Failed,
+            InteractionError::NoAdapters.to_string(),
+        ),
+        Ok(names) => check(NAME, DoctorStatus::Ok, names.join(", ")),
+        Err(error) => check(NAME, DoctorStatus::Failed, error.to_string()),
+    }
+}
+
+/// Reports whether the adapter could be opened; only Linux gates this on
+/// user permissions.
+fn permission_check(
+    adapters: &Result<Vec<String>, InteractionError>,
+    is_linux: bool,
+) -> DoctorCheck {
+    const NAME: &str = "adapter permissions";
+    if !is_linux {
+        return check(NAME, DoctorStatus::Skipped, "only checked on Linux");
+    }
+    match adapters {
+        Ok(names) if !names.is_empty() => check(NAME, DoctorStatus::Ok, "adapter opened"),
+        Err(InteractionError::Ble(btleplug::Error::PermissionDenied)) => check(
+            NAME,
+            DoctorStatus::Failed,
+            "permission denied opening the adapter; can this user reach BlueZ over D-Bus?",
+        ),
+        Ok(_) | Err(_) => check(
+            NAME,
+            DoctorStatus::Skipped,
+            "no adapter could be opened to check",
+        ),
+    }
+}
+
+fn runtime_check() -> DoctorCheck {
+    const NAME: &str = "tokio runtime";
+    let Ok(handle) = Handle::try_current() else {
+        return check(NAME, DoctorStatus::Failed, "no tokio runtime is running");
+    };
+    let detail = match handle.runtime_flavor() {
+        RuntimeFlavor::CurrentThread => "current-thread".to_string(),
+        RuntimeFlavor::MultiThread => {
+            format!("multi-thread, {} worker(s)", handle.metrics().num_workers())
+        }
+        other => format!("{other:?}"),
+    };
+    check(NAME, DoctorStatus::Ok, detail)
+}
+
+/// Reports where a state file lives and whether it can be read.
+///
+/// A missing file is fine: it is created on first write.
+fn state_file_check(name: &str, path: &Path) -> DoctorCheck {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_file() => check(
+            name,
+            DoctorStatus::Ok,
+            format!("{} (present)", path.display()),
+        ),
+        Ok(_metadata) => check(
+            name,
+            DoctorStatus::Failed,
+            format!("{} is not a regular file", path.display()),
+        ),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => check(
+            name,
+            DoctorStatus::Ok,
+            format!("{} (not created yet)", path.display()),
+        ),
+        Err(error) => check(
+            name,
+            DoctorStatus::Failed,
+            format!("{}: {error}", path.display()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use async_trait::async_trait;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ModelResolutionConfig;
+    use crate::hw::DeviceSession;
+
+    /// Hardware client whose only capability is reporting a fixed adapter list.
+    struct StubAdapters {
+        adapters: fn() -> Result<Vec<String>, InteractionError>,
+        overrides_path: PathBuf,
+    }
+
+    #[async_trait]
+    impl HardwareClient for StubAdapters {
+        async fn connect_first_device(
+            self: Box<Self>,
+            _name_prefix: &str,
+        ) -> Result<DeviceSession, InteractionError> {
+            Err(InteractionError::NoAdapters)
+        }
+
+        async fn adapter_names(&self) -> Result<Vec<String>, InteractionError> {
+            (self.adapters)()
+        }
+
+        fn model_resolution(&self) -> ModelResolutionConfig {
+            ModelResolutionConfig::new(None, Some(self.overrides_path.clone()))
+        }
+    }
+
+    fn unique_temp_dir() -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock should be after the epoch")
+            .as_nanos();
+        std::env::temp_dir().join(format!("idm-doctor-{}-{nanos}", std::process::id()))
+    }
+
+    fn statuses(outcome: &DoctorOutcome) -> Vec<(&str, DoctorStatus)> {
+        outcome
+            .checks
+            .iter()
+            .map(|check| (check.name.as_str(), check.status))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn report_lists_adapters_runtime_and_state_paths() {
+        let directory = unique_temp_dir();
+        fs::create_dir_all(&directory).expect("temp directory should be created");
+        let overrides_path = directory.join("overrides.tsv");
+        fs::write(&overrides_path, "").expect("overrides file should be written");
+        let client = StubAdapters {
+            adapters: || Ok(vec!["hci0".to_string(), "hci1".to_string()]),
+            overrides_path: overrides_path.clone(),
+        };
+
+        let outcome = run_checks(&client).await;
+
+        let details = outcome
+            .checks
+            .iter()
+            .map(|check| check.detail.clone())
+            .collect::<Vec<_>>();
+        fs::remove_dir_all(&directory).expect("temp directory should be removable");
+        assert_eq!(0, outcome.failed(), "{outcome:?}");
+        assert_eq!(
+            vec![
+                "hci0, hci1".to_string(),
+                if cfg!(target_os = "linux") {
+                    "adapter opened".to_string()
+                } else {
+                    "only checked on Linux".to_string()
+                },
+                "current-thread".to_string(),
+                format!("{} (present)", overrides_path.display()),
+                format!(
+                    "{} (not created yet)",
+                    directory.join("profile-cache.tsv").display()
+                ),
+            ],
+            details
+        );
+    }
+
+    #[tokio::test]
+    async fn report_degrades_when_no_adapter_can_be_listed() {
+        let client = StubAdapters {
+            adapters: || Err(InteractionError::NoAdapters),
+            overrides_path: unique_temp_dir().join("overrides.tsv"),
+        };
+
+        let outcome = run_checks(&client).await;
+
+        assert_eq!(
+            vec![
+                ("bluetooth adapter", DoctorStatus::Failed),
+                ("adapter permissions", DoctorStatus::Skipped),
+                ("tokio runtime", DoctorStatus::Ok),
+                ("model overrides file", DoctorStatus::Ok),
+                ("profile cache file", DoctorStatus::Ok),
+            ],
+            statuses(&outcome)
+        );
+        assert_eq!(
+            InteractionError::NoAdapters.to_string(),
+            outcome.checks[0].detail
+        );
+    }
+
+    #[rstest]
+    #[case::opened(Ok(vec!["hci0".to_string()]), true, DoctorStatus::Ok)]
+    #[case::denied(
+        Err(InteractionError::Ble(btleplug::Error::PermissionDenied)),
+        true,
+        DoctorStatus::Failed
+    )]
+    #[case::other_failure(Err(InteractionError::NoAdapters), true, DoctorStatus::Skipped)]
+    #[case::not_linux(Ok(vec!["hci0".to_string()]), false, DoctorStatus::Skipped)]
+    fn permission_check_classifies_adapter_result(
+        #[case] adapters: Result<Vec<String>, InteractionError>,
+        #[case] is_linux: bool,
+        #[case] expected: DoctorStatus,
+    ) {
+        assert_eq!(expected, permission_check(&adapters, is_linux).status);
+    }
+
+    #[test]
+    fn state_file_check_rejects_a_directory() {
+        let directory = std::env::temp_dir();
+
+        let report = state_file_check("profile cache file", &directory);
+
+        assert_eq!(DoctorStatus::Failed, report.status);
+    }
+}
@@ -0,0 +1,118 @@
+use std::process::ExitCode;
+
+use crate::error::{InteractionError, ProtocolError};
+
+/// Process exit status for a finished command.
+///
+/// Codes `0` and `1` follow the usual success/failure convention and `2` is
+/// left to argument parsing errors, so specific failures start at `3`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CommandExit {
+    /// The command completed.
+    Success,
+    /// The command failed for a reason without a dedicated code.
+    Failure,
+    /// No Bluetooth adapter was available to scan with.
+    NoAdapter,
+}
+
+impl CommandExit {
+    /// Classifies a command result into its exit status.
+    ///
+    /// ```
+    /// use idm::{CommandExit, InteractionError};
+    ///
+    /// let result: anyhow::Result<()> = Err(InteractionError::NoAdapters.into());
+    /// assert_eq!(CommandExit::NoAdapter, CommandExit::from_result(&result));
+    /// assert_eq!(3, CommandExit::NoAdapter.code());
+    /// ```
+    #[must_use]
+    pub fn from_result<T>(result: &anyhow::Result<T>) -> Self {
+        match result {
+            Ok(_value) => Self::Success,
+            Err(error) if is_no_adapter(error) => Self::NoAdapter,
+            Err(_error) => Self::Failure,
+        }
+    }
+
+    /// Returns the numeric process exit code.
+    #[must_use]
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::Failure => 1,
+            Self::NoAdapter => 3,
+        }
+    }
+}
+
+impl From<CommandExit> for ExitCode {
+    fn from(value: CommandExit) -> Self {
+        Self::from(value.code())
+    }
+}
+
+fn is_no_adapter(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        let interaction = cause
+            .downcast_ref::<InteractionError>()
+            .or_else(|| match cause.downcast_ref::<ProtocolError>() {
+                Some(ProtocolError::Interaction(inner)) => Some(inner.as_ref()),
+                _ => None,
+            });
+        matches!(interaction, Some(InteractionError::NoAdapters))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::direct(anyhow::Error::from(InteractionError::NoAdapters))]
+    #[case::with_context(
+        Err::<(), _>(InteractionError::NoAdapters)
+            .context("failed to start scanning")
+            .expect_err("context keeps the error")
+    )]
+    #[case::protocol(anyhow::Error::from(ProtocolError::from(InteractionError::NoAdapters)))]
+    fn no_adapters_maps_to_distinct_exit_code(#[case] error: anyhow::Error) {
+        let exit = CommandExit::from_result::<()>(&Err(error));
+
+        assert_eq!(CommandExit::NoAdapter, exit);
+        assert_eq!(3, exit.code());
+    }
+
+    #[test]
+    fn no_adapters_message_is_actionable() {
+        let message = InteractionError::NoAdapters.to_string();
+
+        assert_eq!(
+            "no Bluetooth adapter found; is the Bluetooth service running, or is rfkill blocking it?",
+            message
+        );
+    }
+
+    #[rstest]
+    #[case::other_interaction(
+        anyhow::Error::from(InteractionError::NoMatchingFixtureDevice {
+            prefix: "IDM-".to_string(),
+        })
+    )]
+    #[case::plain(anyhow::anyhow!("write failed"))]
+    fn other_errors_use_generic_failure_code(#[case] error: anyhow::Error) {
+        let exit = CommandExit::from_result::<()>(&Err(error));
+
+        assert_eq!(CommandExit::Failure, exit);
+        assert_eq!(1, exit.code());
+    }
+
+    #[test]
+    fn success_maps_to_zero() {
+        assert_eq!(0, CommandExit::from_result(&Ok(())).code());
+    }
+}
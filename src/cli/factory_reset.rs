@@ -0,0 +1,80 @@
+use std::io;
+
+use anyhow::{Result, bail};
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::{FactoryResetHandler, SessionHandler};
+
+/// Arguments for the top-level `factory-reset` command.
+#[derive(Debug, Args)]
+pub struct FactoryResetArgs {
+    /// Confirm that the device should be restored to its factory defaults.
+    #[arg(long)]
+    yes: bool,
+}
+
+impl FactoryResetArgs {
+    /// Creates factory-reset arguments; `confirmed` mirrors `--yes`.
+    ///
+    /// ```
+    /// use idm::FactoryResetArgs;
+    ///
+    /// let args = FactoryResetArgs::new(true);
+    /// assert!(args.confirmed());
+    /// ```
+    #[must_use]
+    pub fn new(confirmed: bool) -> Self {
+        Self { yes: confirmed }
+    }
+
+    /// Returns whether the reset was confirmed with `--yes`.
+    #[must_use]
+    pub fn confirmed(&self) -> bool {
+        self.yes
+    }
+}
+
+/// Executes the top-level `factory-reset` command.
+///
+/// Refuses to connect unless the reset was confirmed, so an unconfirmed run
+/// never touches the device.
+#[instrument(skip(client, args, out), level = "info", fields(confirmed = args.yes, ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &FactoryResetArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    if !args.yes {
+        bail!("factory-reset restores every device setting to its default; pass --yes to confirm");
+    }
+
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let command_result = FactoryResetHandler::reset(&session)
+        .await
+        .map_err(anyhow::Error::from);
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close factory-reset session cleanly");
+    }
+
+    command_result?;
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(out, "Factory reset acknowledged")?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+    Ok(CommandOutcome::FactoryReset)
+}
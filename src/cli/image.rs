@@ -1,29 +1,32 @@
 use std::io;
+use std::num::{NonZeroU8, NonZeroU16, NonZeroUsize};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
 use clap::Args;
-use serde::Serialize;
+use futures::future::join_all;
+use tokio::sync::Semaphore;
 use tracing::instrument;
 
-use crate::cli::OutputFormat;
-use crate::hw::HardwareClient;
+use crate::cli::command::parse_duration;
+use crate::cli::control::device_label;
+use crate::cli::outcome::{write_outcome_json, write_warnings};
+use crate::cli::ui::UploadSummaryView;
+use crate::cli::validate_gif::parse_panel_dimensions;
+use crate::cli::{
+    CommandOutcome, DeviceOutcome, ImageMediaType, ImageOutcome, ImagePreviewOutcome, OutputFormat,
+};
+use crate::hw::{DeviceConnectAttempt, FoundDevice, HardwareClient};
 use crate::{
-    GifUploadHandler, GifUploadRequest, ImagePreprocessor, ImageUploadHandler, ImageUploadRequest,
-    PreparedImageUpload, SessionHandler,
+    DEFAULT_MAX_SOURCE_PIXELS, DitherMode, GifAnimation, GifUploadHandler, GifUploadReceipt,
+    GifUploadRequest, ImagePreparationError, ImagePreparationOptions, ImagePreprocessor,
+    ImageUploadHandler, ImageUploadRequest, PanelDimensions, PreparedImageUpload, Rgb, ScalingMode,
+    SessionHandler, TransparencyMode,
 };
 
-/// JSON result emitted by `image` command.
-#[derive(Serialize)]
-#[serde(tag = "action", rename_all = "snake_case")]
-enum ImageResult {
-    Image {
-        media_type: String,
-        bytes_written: usize,
-        chunks_written: usize,
-        logical_chunks_sent: usize,
-    },
-}
+const DEFAULT_SCROLL_DELAY: &str = "100ms";
+const DEFAULT_SCAN_WINDOW: Duration = Duration::from_secs(5);
 
 /// Arguments for top-level `image` upload command.
 #[derive(Debug, Args)]
@@ -33,6 +36,83 @@ pub struct ImageArgs {
     /// Writes the preprocessed GIF payload to this path before upload.
     #[arg(long, value_name = "PATH")]
     save_gif: Option<PathBuf>,
+    /// Uploads only the first frame of a GIF as a still image preview.
+    #[arg(long, conflicts_with = "save_gif")]
+    first_frame: bool,
+    /// Sends a GIF this many times to re-trigger playback.
+    #[arg(long, value_name = "COUNT", default_value = "1")]
+    repeat: NonZeroU8,
+    /// Merges runs of identical consecutive GIF frames, summing their delays.
+    #[arg(long, conflicts_with_all = ["first_frame", "scroll"])]
+    dedupe_frames: bool,
+    /// Sends a GIF as-is when the device panel dimensions are unknown.
+    #[arg(long)]
+    force: bool,
+    /// Pans a wide image across the panel as a scrolling GIF.
+    #[arg(long, conflicts_with = "first_frame")]
+    scroll: bool,
+    /// Pixels the scroll window advances per frame.
+    #[arg(long, value_name = "PIXELS", default_value = "1", requires = "scroll")]
+    step: NonZeroU16,
+    /// How long each scroll frame is shown.
+    #[arg(
+        long,
+        value_name = "DURATION",
+        default_value = DEFAULT_SCROLL_DELAY,
+        value_parser = parse_duration,
+        requires = "scroll"
+    )]
+    delay: Duration,
+    /// Upload to every matching device instead of only the first.
+    #[arg(long)]
+    all: bool,
+    /// How long to scan for matching devices when `--all` is set.
+    #[arg(
+        long,
+        value_name = "DURATION",
+        default_value = "5s",
+        value_parser = parse_duration,
+        requires = "all"
+    )]
+    scan_window: Duration,
+    /// Maximum number of devices uploaded to at once when `--all` is set.
+    /// Every matching device is still connected up front; this bounds
+    /// uploads, not open BLE connections.
+    #[arg(long, value_name = "N", default_value = "1", requires = "all")]
+    concurrency: NonZeroUsize,
+    /// Rejects source images declaring more than this many pixels before decoding.
+    #[arg(long, value_name = "PIXELS", default_value_t = DEFAULT_MAX_SOURCE_PIXELS)]
+    max_source_pixels: u64,
+    /// How transparent pixels in still images are rendered.
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = TransparencyMode::default())]
+    transparency: TransparencyMode,
+    /// How colours are approximated after resizing to the panel.
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = DitherMode::default())]
+    dither: DitherMode,
+    /// How images are fitted to a panel of a different aspect ratio.
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = ScalingMode::default())]
+    scaling: ScalingMode,
+    /// Colour of the margins left by `--scaling contain`, as `#rrggbb`,
+    /// `rrggbb`, or decimal `r,g,b`.
+    #[arg(long, value_name = "COLOUR", default_value = "#000000")]
+    pad_colour: Rgb,
+    /// Write each prepared frame as a PNG into this directory instead of
+    /// uploading; needs `--panel`.
+    #[arg(
+        long,
+        value_name = "DIR",
+        requires = "panel",
+        conflicts_with_all = ["save_gif", "first_frame", "scroll", "all"]
+    )]
+    preview_dir: Option<PathBuf>,
+    /// Panel dimensions for `--preview-dir`, for example `16x16`.
+    #[arg(
+        long,
+        value_name = "WIDTHxHEIGHT",
+        value_parser = parse_panel_dimensions,
+        requires = "preview_dir"
+    )]
+    panel: Option<PanelDimensions>,
 }
 
 impl ImageArgs {
@@ -52,6 +132,23 @@ impl ImageArgs {
         Self {
             image_file: path.into(),
             save_gif: None,
+            first_frame: false,
+            repeat: NonZeroU8::MIN,
+            dedupe_frames: false,
+            force: false,
+            scroll: false,
+            step: NonZeroU16::MIN,
+            delay: Duration::from_millis(100),
+            all: false,
+            scan_window: DEFAULT_SCAN_WINDOW,
+            concurrency: NonZeroUsize::MIN,
+            max_source_pixels: DEFAULT_MAX_SOURCE_PIXELS,
+            transparency: TransparencyMode::default(),
+            dither: DitherMode::default(),
+            scaling: ScalingMode::default(),
+            pad_colour: Rgb::new(0x00, 0x00, 0x00),
+            preview_dir: None,
+            panel: None,
         }
     }
 
@@ -76,6 +173,244 @@ impl ImageArgs {
         self
     }
 
+    /// Uploads only the first GIF frame through the still-image path.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif")).with_first_frame(true);
+    /// assert!(args.first_frame());
+    /// ```
+    #[must_use]
+    pub fn with_first_frame(mut self, first_frame: bool) -> Self {
+        self.first_frame = first_frame;
+        self
+    }
+
+    /// Sets how many times a GIF is sent to the device.
+    ///
+    /// ```
+    /// use std::num::NonZeroU8;
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let times = NonZeroU8::new(3).expect("3 is non-zero");
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif")).with_repeat(times);
+    /// assert_eq!(times, args.repeat());
+    /// ```
+    #[must_use]
+    pub fn with_repeat(mut self, repeat: NonZeroU8) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Merges runs of pixel-identical consecutive GIF frames before upload.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif")).with_dedupe_frames(true);
+    /// assert!(args.dedupe_frames());
+    /// ```
+    #[must_use]
+    pub fn with_dedupe_frames(mut self, dedupe_frames: bool) -> Self {
+        self.dedupe_frames = dedupe_frames;
+        self
+    }
+
+    /// Allows GIF uploads to devices whose panel dimensions are unknown.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif")).with_force(true);
+    /// assert!(args.force());
+    /// ```
+    #[must_use]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Uploads the image as a horizontally scrolling GIF.
+    ///
+    /// ```
+    /// use std::num::NonZeroU16;
+    /// use std::path::PathBuf;
+    /// use std::time::Duration;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let step = NonZeroU16::new(2).expect("2 is non-zero");
+    /// let args = ImageArgs::new(PathBuf::from("banner.png"))
+    ///     .with_scroll(true)
+    ///     .with_scroll_step(step)
+    ///     .with_scroll_delay(Duration::from_millis(50));
+    /// assert!(args.scroll());
+    /// assert_eq!(step, args.scroll_step());
+    /// assert_eq!(Duration::from_millis(50), args.scroll_delay());
+    /// ```
+    #[must_use]
+    pub fn with_scroll(mut self, scroll: bool) -> Self {
+        self.scroll = scroll;
+        self
+    }
+
+    /// Sets how many pixels the scroll window advances per frame.
+    #[must_use]
+    pub fn with_scroll_step(mut self, step: NonZeroU16) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets how long each scroll frame is shown.
+    #[must_use]
+    pub fn with_scroll_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Uploads to every device matched within `scan_window`, `concurrency` at a time.
+    ///
+    /// All matching devices are connected before any upload starts, so
+    /// `concurrency` limits simultaneous uploads, not open connections.
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use std::path::PathBuf;
+    /// use std::time::Duration;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let concurrency = NonZeroUsize::new(2).expect("2 is non-zero");
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif"))
+    ///     .with_all_devices(Duration::from_secs(2), concurrency);
+    /// assert!(args.all_devices());
+    /// assert_eq!(Duration::from_secs(2), args.scan_window());
+    /// assert_eq!(concurrency, args.concurrency());
+    /// ```
+    #[must_use]
+    pub fn with_all_devices(mut self, scan_window: Duration, concurrency: NonZeroUsize) -> Self {
+        self.all = true;
+        self.scan_window = scan_window;
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the largest source image, in pixels, accepted before decoding.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("photo.jpg")).with_max_source_pixels(1_000_000);
+    /// assert_eq!(1_000_000, args.max_source_pixels());
+    /// ```
+    #[must_use]
+    pub fn with_max_source_pixels(mut self, max_source_pixels: u64) -> Self {
+        self.max_source_pixels = max_source_pixels;
+        self
+    }
+
+    /// Sets how transparent pixels in still images are rendered.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{ImageArgs, TransparencyMode};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("logo.png"))
+    ///     .with_transparency(TransparencyMode::OffAsBlack);
+    /// assert_eq!(TransparencyMode::OffAsBlack, args.transparency());
+    /// ```
+    #[must_use]
+    pub fn with_transparency(mut self, transparency: TransparencyMode) -> Self {
+        self.transparency = transparency;
+        self
+    }
+
+    /// Sets how colours are approximated after resizing to the panel.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{DitherMode, ImageArgs};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("gradient.png"))
+    ///     .with_dither(DitherMode::FloydSteinberg);
+    /// assert_eq!(DitherMode::FloydSteinberg, args.dither());
+    /// ```
+    #[must_use]
+    pub fn with_dither(mut self, dither: DitherMode) -> Self {
+        self.dither = dither;
+        self
+    }
+
+    /// Sets how images are fitted to a panel of a different aspect ratio.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{ImageArgs, ScalingMode};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("banner.png")).with_scaling(ScalingMode::Cover);
+    /// assert_eq!(ScalingMode::Cover, args.scaling());
+    /// ```
+    #[must_use]
+    pub fn with_scaling(mut self, scaling: ScalingMode) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Sets the colour of the margins left by [`ScalingMode::Contain`].
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{ImageArgs, Rgb};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("banner.png"))
+    ///     .with_pad_colour(Rgb::new(0xFF, 0xFF, 0xFF));
+    /// assert_eq!(Rgb::new(0xFF, 0xFF, 0xFF), args.pad_colour());
+    /// ```
+    #[must_use]
+    pub fn with_pad_colour(mut self, pad_colour: Rgb) -> Self {
+        self.pad_colour = pad_colour;
+        self
+    }
+
+    /// Writes each prepared frame for a `panel`-sized display as a PNG into
+    /// `directory` instead of uploading.
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use idm::{ImageArgs, PanelDimensions};
+    ///
+    /// let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid dimensions");
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif")).with_preview_dir("frames", panel);
+    /// assert_eq!(Some(Path::new("frames")), args.preview_dir());
+    /// assert_eq!(Some(panel), args.panel());
+    /// ```
+    #[must_use]
+    pub fn with_preview_dir(
+        mut self,
+        directory: impl Into<PathBuf>,
+        panel: PanelDimensions,
+    ) -> Self {
+        self.preview_dir = Some(directory.into());
+        self.panel = Some(panel);
+        self
+    }
+
     /// Returns the selected image file path.
     ///
     /// ```
@@ -111,6 +446,211 @@ impl ImageArgs {
     pub fn save_gif_path(&self) -> Option<&Path> {
         self.save_gif.as_deref()
     }
+
+    /// Returns whether only the first GIF frame should be uploaded as a still.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif"));
+    /// assert!(!args.first_frame());
+    /// ```
+    #[must_use]
+    pub fn first_frame(&self) -> bool {
+        self.first_frame
+    }
+
+    /// Returns how many times a GIF is sent to the device.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif"));
+    /// assert_eq!(1, args.repeat().get());
+    /// ```
+    #[must_use]
+    pub fn repeat(&self) -> NonZeroU8 {
+        self.repeat
+    }
+
+    /// Returns whether identical consecutive GIF frames are merged.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif"));
+    /// assert!(!args.dedupe_frames());
+    /// ```
+    #[must_use]
+    pub fn dedupe_frames(&self) -> bool {
+        self.dedupe_frames
+    }
+
+    /// Returns whether GIFs may be sent without known panel dimensions.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("anim.gif"));
+    /// assert!(!args.force());
+    /// ```
+    #[must_use]
+    pub fn force(&self) -> bool {
+        self.force
+    }
+
+    /// Returns whether the image is uploaded as a scrolling GIF.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::ImageArgs;
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("banner.png"));
+    /// assert!(!args.scroll());
+    /// ```
+    #[must_use]
+    pub fn scroll(&self) -> bool {
+        self.scroll
+    }
+
+    /// Returns how many pixels the scroll window advances per frame.
+    #[must_use]
+    pub fn scroll_step(&self) -> NonZeroU16 {
+        self.step
+    }
+
+    /// Returns how long each scroll frame is shown.
+    #[must_use]
+    pub fn scroll_delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Returns whether the upload targets every matching device.
+    #[must_use]
+    pub fn all_devices(&self) -> bool {
+        self.all
+    }
+
+    /// Returns how long `--all` scans for matching devices.
+    #[must_use]
+    pub fn scan_window(&self) -> Duration {
+        self.scan_window
+    }
+
+    /// Returns how many devices `--all` uploads to at once.
+    ///
+    /// This does not limit how many devices are connected.
+    #[must_use]
+    pub fn concurrency(&self) -> NonZeroUsize {
+        self.concurrency
+    }
+
+    /// Returns the largest source image, in pixels, accepted before decoding.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{DEFAULT_MAX_SOURCE_PIXELS, ImageArgs};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("photo.jpg"));
+    /// assert_eq!(DEFAULT_MAX_SOURCE_PIXELS, args.max_source_pixels());
+    /// ```
+    #[must_use]
+    pub fn max_source_pixels(&self) -> u64 {
+        self.max_source_pixels
+    }
+
+    /// Returns how transparent pixels in still images are rendered.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{ImageArgs, TransparencyMode};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("logo.png"));
+    /// assert_eq!(TransparencyMode::FlattenToPadding, args.transparency());
+    /// ```
+    #[must_use]
+    pub fn transparency(&self) -> TransparencyMode {
+        self.transparency
+    }
+
+    /// Returns how colours are approximated after resizing to the panel.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{DitherMode, ImageArgs};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("gradient.png"));
+    /// assert_eq!(DitherMode::None, args.dither());
+    /// ```
+    #[must_use]
+    pub fn dither(&self) -> DitherMode {
+        self.dither
+    }
+
+    /// Returns how images are fitted to a panel of a different aspect ratio.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{ImageArgs, ScalingMode};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("banner.png"));
+    /// assert_eq!(ScalingMode::Contain, args.scaling());
+    /// ```
+    #[must_use]
+    pub fn scaling(&self) -> ScalingMode {
+        self.scaling
+    }
+
+    /// Returns the colour of the margins left by [`ScalingMode::Contain`].
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use idm::{ImageArgs, Rgb};
+    ///
+    /// let args = ImageArgs::new(PathBuf::from("banner.png"));
+    /// assert_eq!(Rgb::new(0, 0, 0), args.pad_colour());
+    /// ```
+    #[must_use]
+    pub fn pad_colour(&self) -> Rgb {
+        self.pad_colour
+    }
+
+    /// Returns the directory prepared frames are previewed into, if set.
+    #[must_use]
+    pub fn preview_dir(&self) -> Option<&Path> {
+        self.preview_dir.as_deref()
+    }
+
+    /// Returns the panel dimensions the preview is prepared for, if set.
+    #[must_use]
+    pub fn panel(&self) -> Option<PanelDimensions> {
+        self.panel
+    }
+
+    fn preparation_options(&self) -> ImagePreparationOptions {
+        ImagePreparationOptions::builder()
+            .max_source_pixels(self.max_source_pixels)
+            .dedupe_frames(self.dedupe_frames)
+            .transparency(self.transparency)
+            .dither(self.dither)
+            .scaling(self.scaling)
+            .pad_colour(self.pad_colour)
+            .build()
+    }
 }
 
 /// Executes the top-level `image` command.
@@ -120,13 +660,33 @@ pub(crate) async fn run<W>(
     args: &ImageArgs,
     out: &mut W,
     output_format: OutputFormat,
-) -> Result<()>
+) -> Result<CommandOutcome>
 where
     W: io::Write,
 {
+    if let (Some(directory), Some(panel)) = (args.preview_dir(), args.panel()) {
+        let preview = write_preview_frames(args, directory, panel)?;
+        match output_format {
+            OutputFormat::Pretty | OutputFormat::Compact => {
+                writeln!(
+                    out,
+                    "Wrote {} {panel} frame preview(s) to {}",
+                    preview.paths.len(),
+                    directory.display()
+                )?;
+            }
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+        }
+        return Ok(CommandOutcome::ImagePreview(preview));
+    }
+
+    if args.all {
+        return run_on_all_devices(client, args, out, output_format).await;
+    }
+
     let session = SessionHandler::new(client).connect_first().await?;
 
-    let command_result = run_with_session(&session, args, out, output_format).await;
+    let command_result = upload(&session, args).await;
     let close_result = session.close().await;
 
     if let Err(error) = close_result {
@@ -136,79 +696,281 @@ where
         tracing::trace!(?error, "failed to close image session cleanly");
     }
 
-    command_result
+    let TimedImageOutcome { outcome, elapsed } = command_result?;
+    if output_format == OutputFormat::Pretty {
+        writeln!(
+            out,
+            "{}",
+            UploadSummaryView::new(outcome.bytes_written, outcome.chunks_written, elapsed)
+        )?;
+        write_warnings(out, &outcome.warnings)?;
+    }
+    Ok(CommandOutcome::Image(outcome))
 }
 
-#[instrument(skip(session, args, out), level = "debug", fields(?output_format))]
-async fn run_with_session<W>(
-    session: &crate::DeviceSession,
+/// An image outcome with the time spent in the upload handlers.
+struct TimedImageOutcome {
+    outcome: ImageOutcome,
+    elapsed: Duration,
+}
+
+#[instrument(
+    skip(client, args, out),
+    level = "info",
+    fields(scan_window = ?args.scan_window, concurrency = args.concurrency.get())
+)]
+async fn run_on_all_devices<W>(
+    client: Box<dyn HardwareClient>,
     args: &ImageArgs,
     out: &mut W,
     output_format: OutputFormat,
-) -> Result<()>
+) -> Result<CommandOutcome>
 where
     W: io::Write,
 {
-    let panel_dimensions = session
-        .device_profile()
-        .panel_dimensions()
-        .context("cannot upload image because panel dimensions are unresolved for this device")?;
+    let attempts = SessionHandler::new(client)
+        .connect_all(args.scan_window)
+        .await?;
+    let total = attempts.len();
+
+    let permits = Semaphore::new(args.concurrency.get());
+    let outcomes = join_all(
+        attempts
+            .into_iter()
+            .map(|attempt| upload_to_attempt(attempt, args, &permits)),
+    )
+    .await;
+
+    let failed = outcomes
+        .iter()
+        .filter(|(_device, outcome)| outcome.is_err())
+        .count();
+    let succeeded = total - failed;
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            for (device, outcome) in &outcomes {
+                let label = device_label(device);
+                match outcome {
+                    Ok(outcome) => writeln!(
+                        out,
+                        "{label}: uploaded {}: {} bytes in {} chunk(s)",
+                        outcome.media_type.label(),
+                        outcome.bytes_written,
+                        outcome.chunks_written,
+                    )?,
+                    Err(error) => writeln!(out, "{label}: failed: {error:#}")?,
+                }
+            }
+            writeln!(out, "Uploaded to {succeeded} of {total} device(s)")?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    let outcome = CommandOutcome::ImageAll(
+        outcomes
+            .into_iter()
+            .map(|(device, outcome)| DeviceOutcome {
+                device,
+                outcome: outcome
+                    .map(CommandOutcome::Image)
+                    .map_err(|error| format!("{error:#}")),
+            })
+            .collect(),
+    );
+    if failed > 0 {
+        if output_format.is_json() {
+            write_outcome_json(out, &outcome, output_format)?;
+        }
+        bail!("image upload failed on {failed} of {total} device(s)");
+    }
+    Ok(outcome)
+}
+
+async fn upload_to_attempt(
+    attempt: DeviceConnectAttempt,
+    args: &ImageArgs,
+    permits: &Semaphore,
+) -> (FoundDevice, Result<ImageOutcome>) {
+    let DeviceConnectAttempt { device, session } = attempt;
+    let session = match session {
+        Ok(session) => session,
+        Err(error) => return (device, Err(error.into())),
+    };
+    let outcome = match permits.acquire().await {
+        Ok(_permit) => upload(&session, args).await.map(|timed| timed.outcome),
+        Err(error) => Err(error.into()),
+    };
+    let close_result = session.close().await;
+
+    let outcome = match (outcome, close_result) {
+        (Ok(result), Ok(())) => Ok(result),
+        (Ok(_result), Err(error)) => Err(error.into()),
+        (Err(error), close_result) => {
+            if let Err(close_error) = close_result {
+                tracing::trace!(?close_error, "failed to close image session cleanly");
+            }
+            Err(error)
+        }
+    };
+    (device, outcome)
+}
+
+#[instrument(skip(session, args), level = "debug")]
+async fn upload(session: &crate::DeviceSession, args: &ImageArgs) -> Result<TimedImageOutcome> {
+    let panel_dimensions = session.device_profile().panel_dimensions();
+    if panel_dimensions.is_none() && !args.force() {
+        bail!(
+            "cannot upload image because panel dimensions are unresolved for this device; pass `--force` to send a GIF as-is"
+        );
+    }
     let source_bytes = std::fs::read(args.path())
         .with_context(|| format!("failed to read image file `{}`", args.path().display()))?;
-    let prepared = ImagePreprocessor::prepare_for_upload(&source_bytes, panel_dimensions)
-        .with_context(|| format!("failed to prepare image file `{}`", args.path().display()))?;
+    let (prepared, mut warnings) = match panel_dimensions {
+        Some(panel_dimensions) if args.scroll() => ImagePreprocessor::prepare_scroll(
+            &source_bytes,
+            panel_dimensions,
+            args.scroll_step(),
+            args.scroll_delay(),
+            args.preparation_options(),
+        )
+        .map(|gif| (PreparedImageUpload::Gif(gif), Vec::new())),
+        Some(panel_dimensions) if args.first_frame() => ImagePreprocessor::prepare_first_frame(
+            &source_bytes,
+            panel_dimensions,
+            args.preparation_options(),
+        )
+        .map(|still| (PreparedImageUpload::Still(still), Vec::new())),
+        Some(panel_dimensions) => ImagePreprocessor::prepare_for_upload_with_warnings(
+            &source_bytes,
+            panel_dimensions,
+            args.preparation_options(),
+        ),
+        None => {
+            if args.first_frame() {
+                bail!("cannot use `--first-frame` because panel dimensions are unresolved");
+            }
+            if args.scroll() {
+                bail!("cannot use `--scroll` because panel dimensions are unresolved");
+            }
+            GifAnimation::try_from(source_bytes.as_slice())
+                .map(|gif| (PreparedImageUpload::Gif(gif), Vec::new()))
+                .map_err(ImagePreparationError::from)
+        }
+    }
+    .with_context(|| format!("failed to prepare image file `{}`", args.path().display()))?;
 
     match prepared {
         PreparedImageUpload::Still(still) => {
             if args.save_gif_path().is_some() {
                 bail!("cannot use `--save-gif` because input normalised to a still image payload");
             }
+            if args.repeat() > NonZeroU8::MIN {
+                bail!("cannot use `--repeat` because input normalised to a still image payload");
+            }
             let request = ImageUploadRequest::new(still.into_frame());
+            let started = tokio::time::Instant::now();
             let receipt = ImageUploadHandler::upload(session, request).await?;
-            match output_format {
-                OutputFormat::Pretty => {}
-                OutputFormat::Json => {
-                    write_json_line(
-                        out,
-                        &ImageResult::Image {
-                            media_type: "image".to_string(),
-                            bytes_written: receipt.bytes_written(),
-                            chunks_written: receipt.chunks_written(),
-                            logical_chunks_sent: receipt.logical_chunks_sent(),
-                        },
-                    )?;
-                }
-            }
+            warnings.extend(session.warnings());
+            Ok(TimedImageOutcome {
+                outcome: ImageOutcome {
+                    media_type: ImageMediaType::Still,
+                    bytes_written: receipt.bytes_written(),
+                    chunks_written: receipt.chunks_written(),
+                    logical_chunks_sent: receipt.logical_chunks_sent(),
+                    uploads: 1,
+                    warnings,
+                },
+                elapsed: started.elapsed(),
+            })
         }
         PreparedImageUpload::Gif(gif) => {
             if let Some(path) = args.save_gif_path() {
                 save_preprocessed_gif(path, gif.payload())?;
             }
-            let request = GifUploadRequest::new(gif);
-            let receipt = GifUploadHandler::upload(session, request).await?;
-            match output_format {
-                OutputFormat::Pretty => {}
-                OutputFormat::Json => {
-                    write_json_line(
-                        out,
-                        &ImageResult::Image {
-                            media_type: "gif".to_string(),
-                            bytes_written: receipt.bytes_written(),
-                            chunks_written: receipt.chunks_written(),
-                            logical_chunks_sent: receipt.logical_chunks_sent(),
-                        },
-                    )?;
-                }
-            }
+            let request = GifUploadRequest::new(gif).with_allow_unknown_dimensions(args.force());
+            let started = tokio::time::Instant::now();
+            let receipts =
+                GifUploadHandler::upload_repeated(session, request, args.repeat()).await?;
+            warnings.extend(session.warnings());
+            Ok(TimedImageOutcome {
+                outcome: ImageOutcome {
+                    media_type: ImageMediaType::Gif,
+                    bytes_written: receipts.iter().map(GifUploadReceipt::bytes_written).sum(),
+                    chunks_written: receipts.iter().map(GifUploadReceipt::chunks_written).sum(),
+                    logical_chunks_sent: receipts
+                        .iter()
+                        .map(GifUploadReceipt::logical_chunks_sent)
+                        .sum(),
+                    uploads: receipts.len(),
+                    warnings,
+                },
+                elapsed: started.elapsed(),
+            })
         }
     }
-    Ok(())
 }
 
-fn write_json_line(out: &mut impl io::Write, value: &impl Serialize) -> Result<()> {
-    serde_json::to_writer_pretty(&mut *out, value)?;
-    writeln!(out)?;
-    Ok(())
+/// Prepares the image for `panel` and writes each frame as an RGB PNG.
+///
+/// GIFs yield one `frame-NNN.png` per transformed frame, before palette
+/// reduction; other images yield a single `frame-000.png`.
+fn write_preview_frames(
+    args: &ImageArgs,
+    directory: &Path,
+    panel: PanelDimensions,
+) -> Result<ImagePreviewOutcome> {
+    let source_bytes = std::fs::read(args.path())
+        .with_context(|| format!("failed to read image file `{}`", args.path().display()))?;
+    let frames = match image::guess_format(&source_bytes) {
+        Ok(image::ImageFormat::Gif) => {
+            ImagePreprocessor::prepare_gif_frames(&source_bytes, panel, args.preparation_options())
+                .map(|frames| {
+                    frames
+                        .iter()
+                        .map(|frame| {
+                            frame
+                                .rgba_pixels()
+                                .chunks_exact(4)
+                                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                })
+        }
+        _other => {
+            ImagePreprocessor::prepare_first_frame(&source_bytes, panel, args.preparation_options())
+                .map(|still| vec![still.into_frame().into_payload()])
+        }
+    }
+    .with_context(|| format!("failed to prepare image file `{}`", args.path().display()))?;
+
+    std::fs::create_dir_all(directory).with_context(|| {
+        format!(
+            "failed to create preview directory `{}`",
+            directory.display()
+        )
+    })?;
+    let paths = frames
+        .iter()
+        .enumerate()
+        .map(|(index, rgb_pixels)| {
+            let path = directory.join(format!("frame-{index:03}.png"));
+            image::save_buffer(
+                &path,
+                rgb_pixels,
+                u32::from(panel.width()),
+                u32::from(panel.height()),
+                image::ExtendedColorType::Rgb8,
+            )
+            .with_context(|| format!("failed to write frame preview `{}`", path.display()))?;
+            Ok(path)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ImagePreviewOutcome {
+        directory: directory.to_path_buf(),
+        panel,
+        paths,
+    })
 }
 
 fn save_preprocessed_gif(path: &Path, payload: &[u8]) -> Result<()> {
@@ -1,24 +1,67 @@
 use std::io;
 
 use anyhow::Result;
+use clap::Args;
 use tracing::{debug, instrument};
 
-use crate::cli::OutputFormat;
+use crate::cli::{CommandOutcome, OutputFormat};
 use crate::handlers::ScreenLightTimeoutHandler;
 use crate::hw::HardwareClient;
 use crate::hw::diagnostics::DiagnosticSectionSnapshot;
 use crate::terminal::TerminalClient;
 
-use super::ui::{InspectReportView, Painter};
+use super::ui::{DeviceSummaryView, InspectReportView, Painter};
+
+/// Arguments for the `inspect` command.
+#[derive(Debug, Default, Args)]
+pub struct InspectArgs {
+    /// Only show characteristics with at least one of these properties (e.g. `write,notify`).
+    ///
+    /// Filters the pretty report only; JSON output always lists every characteristic.
+    #[arg(long = "props", value_delimiter = ',', value_parser = parse_property_label)]
+    properties: Vec<String>,
+}
+
+impl InspectArgs {
+    /// Creates inspect arguments filtering characteristics by property labels.
+    ///
+    /// An empty list shows every characteristic.
+    ///
+    /// ```
+    /// use idm::InspectArgs;
+    ///
+    /// let args = InspectArgs::new(vec!["write".to_string(), "notify".to_string()]);
+    /// assert_eq!(["write", "notify"], args.properties());
+    /// ```
+    #[must_use]
+    pub fn new(properties: Vec<String>) -> Self {
+        Self { properties }
+    }
+
+    /// Returns the property labels characteristics are filtered by.
+    #[must_use]
+    pub fn properties(&self) -> &[String] {
+        &self.properties
+    }
+}
+
+fn parse_property_label(value: &str) -> Result<String, String> {
+    let label = value.trim().to_lowercase();
+    if label.is_empty() {
+        return Err("property label must not be empty".to_string());
+    }
+    Ok(label)
+}
 
 /// Executes the `inspect` command.
-#[instrument(skip(client, out, terminal_client), level = "info", fields(?output_format))]
+#[instrument(skip(client, args, out, terminal_client), level = "info", fields(properties = ?args.properties, ?output_format))]
 pub(crate) async fn run<W>(
     client: Box<dyn HardwareClient>,
+    args: &InspectArgs,
     out: &mut W,
     terminal_client: &dyn TerminalClient,
     output_format: OutputFormat,
-) -> Result<()>
+) -> Result<CommandOutcome>
 where
     W: io::Write,
 {
@@ -37,19 +80,24 @@ where
 
     match output_format {
         OutputFormat::Pretty => {
-            let painter = Painter::new(terminal_client.stdout_is_terminal());
+            let painter = Painter::for_stdout(terminal_client);
             writeln!(
                 out,
                 "{}",
                 InspectReportView::new(&report, &painter)
                     .with_runtime_diagnostics(&runtime_diagnostics)
+                    .with_property_filter(&args.properties)
             )?;
         }
-        OutputFormat::Json => {
-            serde_json::to_writer_pretty(&mut *out, &report)?;
-            writeln!(out)?;
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+        OutputFormat::Compact => {
+            writeln!(
+                out,
+                "{}",
+                DeviceSummaryView::new(report.device(), report.session_metadata().device_profile())
+            )?;
         }
     }
 
-    Ok(())
+    Ok(CommandOutcome::Inspect(report))
 }
@@ -0,0 +1,100 @@
+use std::io;
+
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::{JointMode, JointModeHandler, SessionHandler};
+
+/// Arguments for the top-level `joint-mode` command.
+#[derive(Debug, Args)]
+pub struct JointModeArgs {
+    #[arg(value_enum)]
+    state: JointModeState,
+}
+
+impl JointModeArgs {
+    /// Creates joint-mode arguments.
+    ///
+    /// ```
+    /// use idm::{JointModeArgs, JointModeState};
+    ///
+    /// let args = JointModeArgs::new(JointModeState::On);
+    /// assert_eq!(JointModeState::On, args.state());
+    /// ```
+    #[must_use]
+    pub fn new(state: JointModeState) -> Self {
+        Self { state }
+    }
+
+    /// Returns the requested joint-mode state.
+    #[must_use]
+    pub fn state(&self) -> JointModeState {
+        self.state
+    }
+}
+
+/// Requested joint-mode state.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum JointModeState {
+    /// Clear joint mode.
+    Off,
+    /// Enable the device's canonical joint mode.
+    On,
+}
+
+impl JointModeState {
+    fn to_handler_mode(self) -> JointMode {
+        match self {
+            Self::Off => JointMode::Off,
+            Self::On => JointMode::On,
+        }
+    }
+}
+
+impl std::fmt::Display for JointModeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::On => write!(f, "on"),
+        }
+    }
+}
+
+/// Executes the top-level `joint-mode` command.
+#[instrument(skip(client, args, out), level = "info", fields(state = %args.state, ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &JointModeArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let mode = args.state.to_handler_mode();
+    let command_result = JointModeHandler::set_joint_mode(&session, mode)
+        .await
+        .map_err(anyhow::Error::from);
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close joint-mode session cleanly");
+    }
+
+    command_result?;
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(out, "Joint mode {}", args.state)?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+    Ok(CommandOutcome::JointMode(mode))
+}
@@ -1,14 +1,18 @@
 use clap::Args;
 use std::io;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use serde::Serialize;
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
 use tracing::instrument;
 
-use crate::cli::OutputFormat;
-use crate::hw::{HardwareClient, ListenSummary, NotificationRunSummary};
+use crate::cli::outcome::{write_json_document, write_outcome_json};
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::{
+    CharacteristicInfo, HardwareClient, InitialReadStatus, ListenSummary, NotificationRunSummary,
+    NotifyEventCounts,
+};
 use crate::notification::NotificationDecodeError;
 use crate::protocol::EndpointId;
 use crate::terminal::TerminalClient;
@@ -23,30 +27,130 @@ enum ListenEvent<'a> {
     Ready {
         device: &'a FoundDevice,
         initial_read: Option<String>,
+        initial_read_status: Option<InitialReadStatus>,
     },
     Notification {
         index: usize,
         event_label: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        raw: Option<String>,
     },
-    Summary {
-        #[serde(flatten)]
-        data: &'a ListenSummary,
+    /// A payload that could not be decoded; only written for
+    /// [`OutputFormat::Ndjson`], where it replaces the label-only notification.
+    DecodeError {
+        index: usize,
+        error: DecodeErrorJson,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        raw: Option<String>,
     },
 }
 
+#[derive(Serialize)]
+struct DecodeErrorJson {
+    kind: &'static str,
+    message: String,
+}
+
+impl From<NotificationDecodeError> for DecodeErrorJson {
+    fn from(error: NotificationDecodeError) -> Self {
+        let kind = match error {
+            NotificationDecodeError::EmptyPayload => "empty_payload",
+        };
+        Self {
+            kind,
+            message: error.to_string(),
+        }
+    }
+}
+
 /// Arguments for the `listen` command.
 #[derive(Debug, Args)]
 pub struct ListenArgs {
     /// Stop after this many notification packets. If omitted, listen until Ctrl+C.
     #[arg(long)]
     max_notifications: Option<usize>,
+    /// Print only transfer error notifications.
+    #[arg(long)]
+    errors_only: bool,
+    /// Exit with a failure status if any transfer error notification was received.
+    #[arg(long)]
+    fail_on_error: bool,
+    /// Endpoint to subscribe to; it must support notify or indicate.
+    #[arg(long, default_value_t = EndpointId::ReadNotifyCharacteristic)]
+    endpoint: EndpointId,
+    /// Include each notification's raw payload as hex in JSON output.
+    #[arg(long)]
+    include_raw: bool,
 }
 
 impl ListenArgs {
     /// Creates listen arguments with an optional notification limit.
     #[must_use]
     pub fn new(max_notifications: Option<usize>) -> Self {
-        Self { max_notifications }
+        Self {
+            max_notifications,
+            errors_only: false,
+            fail_on_error: false,
+            endpoint: EndpointId::ReadNotifyCharacteristic,
+            include_raw: false,
+        }
+    }
+
+    /// Subscribes to `endpoint` instead of the read/notify characteristic.
+    ///
+    /// ```
+    /// use idm::{EndpointId, ListenArgs};
+    ///
+    /// let args = ListenArgs::new(None).with_endpoint(EndpointId::WriteCharacteristic);
+    /// assert_eq!(EndpointId::WriteCharacteristic, args.endpoint());
+    /// ```
+    #[must_use]
+    pub fn with_endpoint(mut self, endpoint: EndpointId) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Prints only transfer error notifications when `errors_only` is set.
+    ///
+    /// ```
+    /// use idm::ListenArgs;
+    ///
+    /// let args = ListenArgs::new(None).with_errors_only(true);
+    /// assert!(args.errors_only());
+    /// ```
+    #[must_use]
+    pub fn with_errors_only(mut self, errors_only: bool) -> Self {
+        self.errors_only = errors_only;
+        self
+    }
+
+    /// Fails the command after listening if any transfer error was received.
+    ///
+    /// ```
+    /// use idm::ListenArgs;
+    ///
+    /// let args = ListenArgs::new(None).with_fail_on_error(true);
+    /// assert!(args.fail_on_error());
+    /// ```
+    #[must_use]
+    pub fn with_fail_on_error(mut self, fail_on_error: bool) -> Self {
+        self.fail_on_error = fail_on_error;
+        self
+    }
+
+    /// Adds the raw payload hex to each JSON notification when `include_raw`
+    /// is set.
+    ///
+    /// ```
+    /// use idm::ListenArgs;
+    ///
+    /// let args = ListenArgs::new(None).with_include_raw(true);
+    /// assert!(args.include_raw());
+    /// ```
+    #[must_use]
+    pub fn with_include_raw(mut self, include_raw: bool) -> Self {
+        self.include_raw = include_raw;
+        self
     }
 
     /// Returns the optional notification limit.
@@ -54,13 +158,43 @@ impl ListenArgs {
     pub(crate) fn max_notifications(&self) -> Option<usize> {
         self.max_notifications
     }
+
+    /// Returns whether only transfer error notifications are printed.
+    #[must_use]
+    pub fn errors_only(&self) -> bool {
+        self.errors_only
+    }
+
+    /// Returns whether a received transfer error fails the command.
+    #[must_use]
+    pub fn fail_on_error(&self) -> bool {
+        self.fail_on_error
+    }
+
+    /// Returns the endpoint notifications are read from.
+    #[must_use]
+    pub fn endpoint(&self) -> EndpointId {
+        self.endpoint
+    }
+
+    /// Returns whether JSON notifications carry their raw payload hex.
+    #[must_use]
+    pub fn include_raw(&self) -> bool {
+        self.include_raw
+    }
 }
 
 /// Executes the `listen` command.
 #[instrument(
     skip(client, args, out, terminal_client),
     level = "info",
-    fields(max_notifications = ?args.max_notifications(), ?output_format)
+    fields(
+        max_notifications = ?args.max_notifications(),
+        errors_only = args.errors_only(),
+        fail_on_error = args.fail_on_error(),
+        endpoint = %args.endpoint(),
+        ?output_format
+    )
 )]
 pub(crate) async fn run<W>(
     client: Box<dyn HardwareClient>,
@@ -68,40 +202,26 @@ pub(crate) async fn run<W>(
     out: &mut W,
     terminal_client: &dyn TerminalClient,
     output_format: OutputFormat,
-) -> Result<()>
-where
-    W: io::Write,
-{
-    run_with_limit(
-        client,
-        args.max_notifications(),
-        out,
-        terminal_client,
-        output_format,
-    )
-    .await
-}
-
-/// Executes listen with an explicit notification limit.
-#[instrument(
-    skip(client, out, terminal_client),
-    level = "info",
-    fields(max_notifications = ?max_notifications, ?output_format)
-)]
-pub(crate) async fn run_with_limit<W>(
-    client: Box<dyn HardwareClient>,
-    max_notifications: Option<usize>,
-    out: &mut W,
-    terminal_client: &dyn TerminalClient,
-    output_format: OutputFormat,
-) -> Result<()>
+) -> Result<CommandOutcome>
 where
     W: io::Write,
 {
     let session = crate::SessionHandler::new(client).connect_first().await?;
     let device = session.device().clone();
-    let endpoint = EndpointId::ReadNotifyCharacteristic;
-    let initial_read = match session.read_endpoint_optional(endpoint).await {
+    let endpoint = args.endpoint();
+    if session
+        .inspect_report()
+        .endpoint_characteristic(endpoint)
+        .and_then(CharacteristicInfo::notify_delivery)
+        .is_none()
+    {
+        session.close().await?;
+        return Err(InteractionError::EndpointNotSubscribable { endpoint }.into());
+    }
+    let initial_read = match session
+        .read_endpoint_optional(EndpointId::ReadNotifyCharacteristic)
+        .await
+    {
         Ok(payload) => payload,
         Err(error) => {
             session.close().await?;
@@ -109,23 +229,26 @@ where
         }
     };
     match output_format {
-        OutputFormat::Pretty => {
-            let painter = Painter::new(terminal_client.stdout_is_terminal());
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            let painter = Painter::for_stdout(terminal_client);
             writeln!(
                 out,
                 "{}",
-                ListenReadyView::new(&device, initial_read.as_deref(), &painter)
+                ListenReadyView::new(&device, endpoint, initial_read.as_deref(), &painter)
             )?;
         }
-        OutputFormat::Json => {
-            serde_json::to_writer_pretty(
-                &mut *out,
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            write_json_document(
+                out,
                 &ListenEvent::Ready {
                     device: &device,
                     initial_read: initial_read.as_deref().map(hex::encode),
+                    initial_read_status: initial_read
+                        .as_deref()
+                        .and_then(InitialReadStatus::decode),
                 },
+                output_format,
             )?;
-            writeln!(out)?;
         }
     }
 
@@ -139,8 +262,9 @@ where
 
     let mut write_error: Option<io::Error> = None;
     let mut stream_error: Option<InteractionError> = None;
+    let mut event_counts = NotifyEventCounts::default();
     let mut stream = match session
-        .notification_stream(endpoint, max_notifications, cancel)
+        .notification_stream(endpoint, args.max_notifications(), cancel)
         .await
     {
         Ok(stream) => stream,
@@ -163,22 +287,39 @@ where
             break;
         }
 
-        let event_label = decode_event_label(message.event);
-        let result = match output_format {
-            OutputFormat::Pretty => {
-                let painter = Painter::new(terminal_client.stdout_is_terminal());
-                let view = ListenNotificationView::new(message.index, event_label, &painter);
+        event_counts.record(&message.event);
+        if args.errors_only() && !matches!(message.event, Ok(NotifyEvent::Error(_, _))) {
+            continue;
+        }
+
+        let raw = args.include_raw().then(|| hex::encode(&message.raw));
+        let result = match (output_format, message.event) {
+            (OutputFormat::Pretty | OutputFormat::Compact, event) => {
+                let painter = Painter::for_stdout(terminal_client);
+                let view =
+                    ListenNotificationView::new(message.index, decode_event_label(event), &painter);
                 writeln!(out, "{view}")
             }
-            OutputFormat::Json => serde_json::to_writer_pretty(
-                &mut *out,
-                &ListenEvent::Notification {
+            (OutputFormat::Ndjson, Err(error)) => write_json_document(
+                out,
+                &ListenEvent::DecodeError {
                     index: message.index,
-                    event_label,
+                    error: error.into(),
+                    raw,
                 },
-            )
-            .map_err(io::Error::other)
-            .and_then(|()| writeln!(out)),
+                output_format,
+            ),
+            (OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson, event) => {
+                write_json_document(
+                    out,
+                    &ListenEvent::Notification {
+                        index: message.index,
+                        event_label: decode_event_label(event),
+                        raw,
+                    },
+                    output_format,
+                )
+            }
         };
         if let Err(error) = result {
             write_error = Some(error);
@@ -204,21 +345,28 @@ where
         initial_read,
         run_result.received_notifications(),
         run_result.stop_reason().clone(),
+        event_counts,
     );
 
     match output_format {
-        OutputFormat::Pretty => {
-            let painter = Painter::new(terminal_client.stdout_is_terminal());
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            let painter = Painter::for_stdout(terminal_client);
             writeln!(out)?;
             writeln!(out, "{}", ListenSummaryView::new(&summary, &painter))?;
         }
-        OutputFormat::Json => {
-            serde_json::to_writer_pretty(&mut *out, &ListenEvent::Summary { data: &summary })?;
-            writeln!(out)?;
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    let error_count = summary.event_counts().error();
+    let outcome = CommandOutcome::Listen(summary);
+    if args.fail_on_error() && error_count > 0 {
+        if output_format.is_json() {
+            write_outcome_json(out, &outcome, output_format)?;
         }
+        bail!("received {error_count} transfer error notification(s)");
     }
 
-    Ok(())
+    Ok(outcome)
 }
 
 fn decode_event_label(event: Result<NotifyEvent, NotificationDecodeError>) -> Option<String> {
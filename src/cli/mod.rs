@@ -1,14 +1,50 @@
+pub(crate) mod animate;
 pub(crate) mod command;
 pub(crate) mod control;
+pub(crate) mod crc;
+#[cfg(unix)]
+pub(crate) mod daemon;
+pub(crate) mod describe;
+pub(crate) mod doctor;
+pub(crate) mod exit;
+pub(crate) mod factory_reset;
 pub(crate) mod image;
 pub(crate) mod inspect;
+pub(crate) mod joint_mode;
 pub(crate) mod listen;
+pub(crate) mod outcome;
+pub(crate) mod ping;
+pub(crate) mod reconnect_loop;
+pub(crate) mod scene;
+pub(crate) mod screen_timeout;
+pub(crate) mod self_test;
+pub(crate) mod slots;
 pub(crate) mod ui;
+pub(crate) mod validate_gif;
 
+pub use self::animate::AnimateArgs;
 pub use self::command::{Args, Command, FakeArgs, LogLevel, OutputFormat};
 pub use self::control::{
     BrightnessArgs, ColourArgs, ControlAction, ControlArgs, PowerArgs, PowerState, SyncTimeArgs,
     TextArgs,
 };
+pub use self::crc::CrcArgs;
+#[cfg(unix)]
+pub use self::daemon::DaemonArgs;
+pub use self::describe::DescribeArgs;
+pub use self::exit::CommandExit;
+pub use self::factory_reset::FactoryResetArgs;
 pub use self::image::ImageArgs;
+pub use self::inspect::InspectArgs;
+pub use self::joint_mode::{JointModeArgs, JointModeState};
 pub use self::listen::ListenArgs;
+pub use self::outcome::{
+    AnimateOutcome, CommandOutcome, CrcOutcome, DeviceOutcome, DoctorCheck, DoctorOutcome,
+    DoctorStatus, FrameDescription, GifValidationOutcome, ImageMediaType, ImageOutcome,
+    ImagePreviewOutcome, ReconnectLoopOutcome, SelfTestCheck, SelfTestOutcome, TextOutcome,
+    TextPreviewOutcome,
+};
+pub use self::reconnect_loop::ReconnectLoopArgs;
+pub use self::scene::SceneArgs;
+pub use self::screen_timeout::ScreenTimeoutArgs;
+pub use self::validate_gif::ValidateGifArgs;
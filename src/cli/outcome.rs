@@ -0,0 +1,1197 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::{Serialize, Serializer};
+use strum_macros::Display as StrumDisplay;
+use time::OffsetDateTime;
+
+use crate::cli::OutputFormat;
+use crate::{
+    Brightness, EndpointProbe, FoundDevice, InspectReport, JointMode, ListenSummary,
+    PanelDimensions, ReconnectCycle, Rgb, SceneStep, ScreenLightTimeout, ScreenPower,
+    StoredMaterialSlot, Warning,
+};
+
+/// Typed result of one CLI command, returned by [`crate::run_with_result`].
+///
+/// JSON output serialises the outcome as `{"command": <tag>, "data": <shape>}`,
+/// where the tag names the variant in kebab case.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CommandOutcome {
+    /// `inspect` collected a fresh report for the connected device.
+    Inspect(InspectReport),
+    /// `ping` reported endpoint presence without resolving a profile.
+    Ping(EndpointProbe),
+    /// `listen` stopped and summarised the notification stream.
+    Listen(ListenSummary),
+    /// `control power` applied a screen power state.
+    Power(ScreenPower),
+    /// `control brightness` applied a brightness level.
+    Brightness(Brightness),
+    /// `control colour` filled the display with one colour.
+    Colour(Rgb),
+    /// `control sync-time` synchronised the device clock to this instant.
+    SyncTime(OffsetDateTime),
+    /// `control text` uploaded a text payload.
+    Text(TextOutcome),
+    /// `control text --preview` rendered the text to a PNG offline.
+    TextPreview(TextPreviewOutcome),
+    /// `image` uploaded a still image or GIF.
+    Image(ImageOutcome),
+    /// `image --preview-dir` wrote the prepared frames as PNGs offline.
+    ImagePreview(ImagePreviewOutcome),
+    /// `animate` uploaded an assembled animation.
+    Animate(AnimateOutcome),
+    /// `control --all` ran the action against every matching device.
+    ControlAll(Vec<DeviceOutcome>),
+    /// `image --all` uploaded to every matching device.
+    ImageAll(Vec<DeviceOutcome>),
+    /// `daemon` served control commands until shutdown.
+    Daemon,
+    /// `validate-gif` checked a GIF offline.
+    ValidateGif(GifValidationOutcome),
+    /// `crc` computed a payload checksum.
+    Crc(CrcOutcome),
+    /// `describe` explained one protocol frame.
+    Describe(FrameDescription),
+    /// `self-test` ran the built-in offline checks.
+    SelfTest(SelfTestOutcome),
+    /// `doctor` checked the local Bluetooth and state-file setup.
+    Doctor(DoctorOutcome),
+    /// `screen-timeout` set and confirmed the screen-light timeout.
+    ScreenTimeout(ScreenLightTimeout),
+    /// `factory-reset` restored the device to its defaults.
+    FactoryReset,
+    /// `joint-mode` turned multi-panel joint mode on or off.
+    JointMode(JointMode),
+    /// `scene` applied these steps, in order.
+    Scene(Vec<SceneStep>),
+    /// `slots` listed the material slots the device reported as occupied.
+    Slots(Vec<StoredMaterialSlot>),
+    /// `reconnect-loop` connected and closed repeatedly, timing each cycle.
+    ReconnectLoop(ReconnectLoopOutcome),
+}
+
+impl CommandOutcome {
+    /// Returns the warnings collected while the command ran.
+    ///
+    /// Multi-device runs keep warnings on each device's own outcome, so this
+    /// is empty for [`CommandOutcome::ControlAll`] and
+    /// [`CommandOutcome::ImageAll`].
+    ///
+    /// ```
+    /// use idm::{CommandOutcome, ImageMediaType, ImageOutcome, Warning};
+    ///
+    /// let outcome = CommandOutcome::Image(ImageOutcome {
+    ///     media_type: ImageMediaType::Gif,
+    ///     bytes_written: 300,
+    ///     chunks_written: 2,
+    ///     logical_chunks_sent: 1,
+    ///     uploads: 1,
+    ///     warnings: vec![Warning::GifFramesTruncated { dropped: 8 }],
+    /// });
+    /// assert_eq!(&[Warning::GifFramesTruncated { dropped: 8 }], outcome.warnings());
+    /// ```
+    #[must_use]
+    pub fn warnings(&self) -> &[Warning] {
+        match self {
+            Self::Text(text) => &text.warnings,
+            Self::Image(image) => &image.warnings,
+            Self::Animate(animate) => &animate.warnings,
+            _other => &[],
+        }
+    }
+}
+
+/// Kind of payload sent by the `image` command.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImageMediaType {
+    /// A still RGB frame sent through the image upload path.
+    Still,
+    /// A GIF sent through the GIF upload path.
+    Gif,
+}
+
+impl ImageMediaType {
+    /// Returns the label used in command output.
+    ///
+    /// ```
+    /// assert_eq!("gif", idm::ImageMediaType::Gif.label());
+    /// ```
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Still => "image",
+            Self::Gif => "gif",
+        }
+    }
+}
+
+/// Totals reported by the `image` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImageOutcome {
+    /// Which upload path carried the payload.
+    pub media_type: ImageMediaType,
+    /// Bytes written across every upload.
+    pub bytes_written: usize,
+    /// Transport chunks written across every upload.
+    pub chunks_written: usize,
+    /// Protocol-level chunks sent across every upload.
+    pub logical_chunks_sent: usize,
+    /// Number of times the payload was sent.
+    pub uploads: usize,
+    /// Non-fatal conditions raised while preparing or sending the payload.
+    pub warnings: Vec<Warning>,
+}
+
+/// Totals reported by `control text`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TextOutcome {
+    /// Bytes written for the text payload.
+    pub bytes_written: usize,
+    /// Transport chunks written for the text payload.
+    pub chunks_written: usize,
+    /// Non-fatal conditions raised while checking or sending the text.
+    pub warnings: Vec<Warning>,
+}
+
+/// Preview written by `control text --preview`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TextPreviewOutcome {
+    /// Path the PNG was written to.
+    pub path: PathBuf,
+    /// Panel dimensions the text was rendered for.
+    pub panel: PanelDimensions,
+}
+
+/// Frames written by `image --preview-dir`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImagePreviewOutcome {
+    /// Directory the PNGs were written to.
+    pub directory: PathBuf,
+    /// Panel dimensions the frames were prepared for.
+    pub panel: PanelDimensions,
+    /// Path of each frame's PNG, in playback order.
+    pub paths: Vec<PathBuf>,
+}
+
+/// Totals reported by the `animate` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AnimateOutcome {
+    /// Number of frames in the assembled animation.
+    pub frames: usize,
+    /// Bytes written for the animation.
+    pub bytes_written: usize,
+    /// Transport chunks written for the animation.
+    pub chunks_written: usize,
+    /// Protocol-level chunks sent for the animation.
+    pub logical_chunks_sent: usize,
+    /// Non-fatal conditions raised while sending the animation.
+    pub warnings: Vec<Warning>,
+}
+
+/// Result for one device in a multi-device run.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DeviceOutcome {
+    /// Device as seen during discovery.
+    pub device: FoundDevice,
+    /// Command outcome, or the rendered error that stopped this device.
+    pub outcome: Result<CommandOutcome, String>,
+}
+
+/// Verdict reported by the `validate-gif` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GifValidationOutcome {
+    /// Whether the GIF passed every check.
+    pub passed: bool,
+    /// Panel dimensions the GIF was checked against.
+    pub panel: PanelDimensions,
+    /// GIF logical-screen dimensions, when the header parsed.
+    pub dimensions: Option<PanelDimensions>,
+    /// Frame count, when the frames could be decoded.
+    pub frame_count: Option<usize>,
+    /// Size of the GIF payload in bytes.
+    pub payload_len: usize,
+    /// Reasons the GIF failed, empty when it passed.
+    pub reasons: Vec<String>,
+}
+
+/// Checksum reported by the `crc` command.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CrcOutcome {
+    /// Length of the checksummed payload in bytes.
+    pub payload_len: usize,
+    /// Protocol CRC32 of the payload.
+    pub crc32: u32,
+}
+
+/// One built-in check run by the `self-test` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SelfTestCheck {
+    /// Short description of what the check exercises.
+    pub name: String,
+    /// Whether the check produced the known-good result.
+    pub passed: bool,
+    /// Why the check failed, `None` when it passed.
+    pub detail: Option<String>,
+}
+
+/// Verdicts reported by the `self-test` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SelfTestOutcome {
+    /// One entry per check, in the order they ran.
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestOutcome {
+    /// Returns whether every check passed.
+    ///
+    /// ```
+    /// use idm::{SelfTestCheck, SelfTestOutcome};
+    ///
+    /// let outcome = SelfTestOutcome {
+    ///     checks: vec![SelfTestCheck {
+    ///         name: "encode power frame".to_string(),
+    ///         passed: true,
+    ///         detail: None,
+    ///     }],
+    /// };
+    /// assert!(outcome.passed());
+    /// ```
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.failed() == 0
+    }
+
+    /// Returns the number of checks that failed.
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.checks.iter().filter(|check| !check.passed).count()
+    }
+}
+
+/// Result of one `doctor` check.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, StrumDisplay)]
+#[strum(serialize_all = "lowercase")]
+pub enum DoctorStatus {
+    /// The check found nothing wrong.
+    Ok,
+    /// The check found a problem.
+    Failed,
+    /// The check does not apply here, or an earlier failure hid its answer.
+    Skipped,
+}
+
+/// One environment check run by the `doctor` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DoctorCheck {
+    /// Short description of what the check inspects.
+    pub name: String,
+    /// What the check found.
+    pub status: DoctorStatus,
+    /// The value found, or why the check failed or was skipped.
+    pub detail: String,
+}
+
+/// Environment report produced by the `doctor` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DoctorOutcome {
+    /// One entry per check, in the order they ran.
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorOutcome {
+    /// Returns the number of checks that found a problem.
+    ///
+    /// ```
+    /// use idm::{DoctorCheck, DoctorOutcome, DoctorStatus};
+    ///
+    /// let outcome = DoctorOutcome {
+    ///     checks: vec![DoctorCheck {
+    ///         name: "bluetooth adapter".to_string(),
+    ///         status: DoctorStatus::Failed,
+    ///         detail: "no Bluetooth adapter found".to_string(),
+    ///     }],
+    /// };
+    /// assert_eq!(1, outcome.failed());
+    /// ```
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status == DoctorStatus::Failed)
+            .count()
+    }
+}
+
+/// Per-cycle timings reported by the `reconnect-loop` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReconnectLoopOutcome {
+    /// One entry per cycle, in the order they ran.
+    pub cycles: Vec<ReconnectCycle>,
+}
+
+impl ReconnectLoopOutcome {
+    /// Returns the number of cycles that connected and closed cleanly.
+    #[must_use]
+    pub fn succeeded(&self) -> usize {
+        self.cycles.iter().filter(|cycle| cycle.succeeded()).count()
+    }
+
+    /// Returns the number of cycles that failed.
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.cycles.len() - self.succeeded()
+    }
+
+    /// Returns the fastest, mean and slowest successful cycle, or `None` when
+    /// every cycle failed.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{ReconnectCycle, ReconnectLoopOutcome};
+    ///
+    /// let cycle = |cycle, millis| ReconnectCycle {
+    ///     cycle,
+    ///     elapsed: Duration::from_millis(millis),
+    ///     error: None,
+    /// };
+    /// let outcome = ReconnectLoopOutcome {
+    ///     cycles: vec![cycle(1, 100), cycle(2, 300)],
+    /// };
+    /// assert_eq!(
+    ///     Some((
+    ///         Duration::from_millis(100),
+    ///         Duration::from_millis(200),
+    ///         Duration::from_millis(300),
+    ///     )),
+    ///     outcome.timing()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn timing(&self) -> Option<(Duration, Duration, Duration)> {
+        let successful = self
+            .cycles
+            .iter()
+            .filter(|cycle| cycle.succeeded())
+            .map(|cycle| cycle.elapsed);
+        let min = successful.clone().min()?;
+        let max = successful.clone().max()?;
+        let count = u32::try_from(self.succeeded()).unwrap_or(u32::MAX);
+        let mean = successful.sum::<Duration>() / count;
+        Some((min, mean, max))
+    }
+
+    /// Returns the one-line summary printed after the cycles.
+    pub(crate) fn summary_line(&self) -> String {
+        let totals = format!(
+            "{} cycle(s): {} succeeded, {} failed",
+            self.cycles.len(),
+            self.succeeded(),
+            self.failed()
+        );
+        match self.timing() {
+            Some((min, mean, max)) => format!(
+                "{totals}; min {}ms, mean {}ms, max {}ms",
+                min.as_millis(),
+                mean.as_millis(),
+                max.as_millis()
+            ),
+            None => totals,
+        }
+    }
+}
+
+/// Field-by-field explanation reported by the `describe` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FrameDescription {
+    /// Frame family, for example `text header` or `brightness command`.
+    pub kind: String,
+    /// One annotation per decoded field, in wire order.
+    pub fields: Vec<String>,
+}
+
+impl fmt::Display for FrameDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for field in &self.fields {
+            write!(f, ", {field}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for CommandOutcome {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        OutcomeEnvelopeJson {
+            outcome: OutcomeJson::from(self),
+            warnings: self.warnings().iter().map(WarningJson::from).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Top-level JSON document: the tagged outcome plus any warnings.
+#[derive(Serialize)]
+struct OutcomeEnvelopeJson<'a> {
+    #[serde(flatten)]
+    outcome: OutcomeJson<'a>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<WarningJson>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum WarningJson {
+    GifFramesTruncated {
+        dropped: usize,
+    },
+    TransportDegraded {
+        chunk_size: usize,
+    },
+    TextScrollTooLong {
+        estimated_ms: u128,
+        threshold_ms: u128,
+    },
+}
+
+impl From<&Warning> for WarningJson {
+    fn from(warning: &Warning) -> Self {
+        match *warning {
+            Warning::GifFramesTruncated { dropped } => Self::GifFramesTruncated { dropped },
+            Warning::TransportDegraded { chunk_size } => Self::TransportDegraded { chunk_size },
+            Warning::TextScrollTooLong {
+                estimated,
+                threshold,
+            } => Self::TextScrollTooLong {
+                estimated_ms: estimated.as_millis(),
+                threshold_ms: threshold.as_millis(),
+            },
+        }
+    }
+}
+
+/// JSON envelope for [`CommandOutcome`], keeping domain types free of serde.
+#[derive(Serialize)]
+#[serde(tag = "command", content = "data", rename_all = "kebab-case")]
+enum OutcomeJson<'a> {
+    Inspect(&'a InspectReport),
+    Ping(&'a EndpointProbe),
+    Listen(&'a ListenSummary),
+    Power {
+        state: &'static str,
+    },
+    Brightness {
+        value: u8,
+    },
+    Colour {
+        colour: Rgb,
+    },
+    SyncTime {
+        unix_timestamp: i64,
+    },
+    Text {
+        bytes_written: usize,
+        chunks_written: usize,
+    },
+    TextPreview {
+        path: &'a Path,
+        panel: PanelDimensions,
+    },
+    Image {
+        media_type: &'static str,
+        bytes_written: usize,
+        chunks_written: usize,
+        logical_chunks_sent: usize,
+        uploads: usize,
+    },
+    ImagePreview {
+        directory: &'a Path,
+        panel: PanelDimensions,
+        paths: &'a [PathBuf],
+    },
+    Animate {
+        frames: usize,
+        bytes_written: usize,
+        chunks_written: usize,
+        logical_chunks_sent: usize,
+    },
+    #[serde(rename = "control")]
+    ControlAll(DevicesJson<'a>),
+    #[serde(rename = "image")]
+    ImageAll(DevicesJson<'a>),
+    Daemon,
+    ValidateGif {
+        passed: bool,
+        panel: PanelDimensions,
+        dimensions: Option<PanelDimensions>,
+        frame_count: Option<usize>,
+        payload_len: usize,
+        reasons: &'a [String],
+    },
+    Crc {
+        payload_len: usize,
+        crc32: u32,
+        crc32_hex: String,
+        header_bytes: String,
+    },
+    Describe {
+        kind: &'a str,
+        fields: &'a [String],
+        description: String,
+    },
+    SelfTest {
+        passed: bool,
+        failed: usize,
+        checks: Vec<SelfTestCheckJson<'a>>,
+    },
+    Doctor {
+        failed: usize,
+        checks: Vec<DoctorCheckJson<'a>>,
+    },
+    ScreenTimeout {
+        minutes: u8,
+    },
+    FactoryReset,
+    JointMode {
+        state: &'static str,
+    },
+    Scene {
+        applied: Vec<String>,
+    },
+    Slots {
+        slots: Vec<StoredMaterialSlotJson>,
+    },
+    ReconnectLoop {
+        cycles: Vec<ReconnectCycleJson<'a>>,
+        succeeded: usize,
+        failed: usize,
+        min_ms: Option<u128>,
+        mean_ms: Option<u128>,
+        max_ms: Option<u128>,
+    },
+}
+
+#[derive(Serialize)]
+struct SelfTestCheckJson<'a> {
+    name: &'a str,
+    passed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct DoctorCheckJson<'a> {
+    name: &'a str,
+    status: String,
+    detail: &'a str,
+}
+
+#[derive(Serialize)]
+struct StoredMaterialSlotJson {
+    slot: u8,
+    kind: String,
+    duration_secs: u64,
+}
+
+#[derive(Serialize)]
+struct ReconnectCycleJson<'a> {
+    cycle: usize,
+    elapsed_ms: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct DevicesJson<'a> {
+    devices: Vec<DeviceOutcomeJson<'a>>,
+    succeeded: usize,
+    failed: usize,
+}
+
+impl<'a> From<&'a [DeviceOutcome]> for DevicesJson<'a> {
+    fn from(devices: &'a [DeviceOutcome]) -> Self {
+        let failed = devices
+            .iter()
+            .filter(|device| device.outcome.is_err())
+            .count();
+        Self {
+            devices: devices
+                .iter()
+                .map(|device| DeviceOutcomeJson {
+                    device_id: device.device.device_id(),
+                    local_name: device.device.local_name(),
+                    outcome: device.outcome.as_ref().ok(),
+                    error: device.outcome.as_ref().err().map(String::as_str),
+                })
+                .collect(),
+            succeeded: devices.len() - failed,
+            failed,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DeviceOutcomeJson<'a> {
+    device_id: &'a str,
+    local_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outcome: Option<&'a CommandOutcome>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+impl<'a> From<&'a CommandOutcome> for OutcomeJson<'a> {
+    fn from(outcome: &'a CommandOutcome) -> Self {
+        match outcome {
+            CommandOutcome::Inspect(report) => Self::Inspect(report),
+            CommandOutcome::Ping(probe) => Self::Ping(probe),
+            CommandOutcome::Listen(summary) => Self::Listen(summary),
+            CommandOutcome::Power(power) => Self::Power {
+                state: match power {
+                    ScreenPower::Off => "off",
+                    ScreenPower::On => "on",
+                },
+            },
+            CommandOutcome::Brightness(brightness) => Self::Brightness {
+                value: brightness.value(),
+            },
+            CommandOutcome::Colour(colour) => Self::Colour { colour: *colour },
+            CommandOutcome::SyncTime(timestamp) => Self::SyncTime {
+                unix_timestamp: timestamp.unix_timestamp(),
+            },
+            CommandOutcome::Text(text) => Self::Text {
+                bytes_written: text.bytes_written,
+                chunks_written: text.chunks_written,
+            },
+            CommandOutcome::TextPreview(preview) => Self::TextPreview {
+                path: &preview.path,
+                panel: preview.panel,
+            },
+            CommandOutcome::Image(image) => Self::Image {
+                media_type: image.media_type.label(),
+                bytes_written: image.bytes_written,
+                chunks_written: image.chunks_written,
+                logical_chunks_sent: image.logical_chunks_sent,
+                uploads: image.uploads,
+            },
+            CommandOutcome::ImagePreview(preview) => Self::ImagePreview {
+                directory: &preview.directory,
+                panel: preview.panel,
+                paths: &preview.paths,
+            },
+            CommandOutcome::Animate(animate) => Self::Animate {
+                frames: animate.frames,
+                bytes_written: animate.bytes_written,
+                chunks_written: animate.chunks_written,
+                logical_chunks_sent: animate.logical_chunks_sent,
+            },
+            CommandOutcome::ControlAll(devices) => Self::ControlAll(devices.as_slice().into()),
+            CommandOutcome::ImageAll(devices) => Self::ImageAll(devices.as_slice().into()),
+            CommandOutcome::Daemon => Self::Daemon,
+            CommandOutcome::ValidateGif(validation) => Self::ValidateGif {
+                passed: validation.passed,
+                panel: validation.panel,
+                dimensions: validation.dimensions,
+                frame_count: validation.frame_count,
+                payload_len: validation.payload_len,
+                reasons: &validation.reasons,
+            },
+            CommandOutcome::Crc(crc) => Self::Crc {
+                payload_len: crc.payload_len,
+                crc32: crc.crc32,
+                crc32_hex: format!("{:08x}", crc.crc32),
+                header_bytes: hex::encode(crc.crc32.to_le_bytes()),
+            },
+            CommandOutcome::Describe(description) => Self::Describe {
+                kind: &description.kind,
+                fields: &description.fields,
+                description: description.to_string(),
+            },
+            CommandOutcome::SelfTest(self_test) => Self::SelfTest {
+                passed: self_test.passed(),
+                failed: self_test.failed(),
+                checks: self_test
+                    .checks
+                    .iter()
+                    .map(|check| SelfTestCheckJson {
+                        name: &check.name,
+                        passed: check.passed,
+                        detail: check.detail.as_deref(),
+                    })
+                    .collect(),
+            },
+            CommandOutcome::Doctor(doctor) => Self::Doctor {
+                failed: doctor.failed(),
+                checks: doctor
+                    .checks
+                    .iter()
+                    .map(|check| DoctorCheckJson {
+                        name: &check.name,
+                        status: check.status.to_string(),
+                        detail: &check.detail,
+                    })
+                    .collect(),
+            },
+            CommandOutcome::ScreenTimeout(timeout) => Self::ScreenTimeout {
+                minutes: timeout.minutes(),
+            },
+            CommandOutcome::FactoryReset => Self::FactoryReset,
+            CommandOutcome::JointMode(mode) => Self::JointMode {
+                state: match mode {
+                    JointMode::Off => "off",
+                    JointMode::On => "on",
+                },
+            },
+            CommandOutcome::Scene(applied) => Self::Scene {
+                applied: applied.iter().map(ToString::to_string).collect(),
+            },
+            CommandOutcome::Slots(slots) => Self::Slots {
+                slots: slots
+                    .iter()
+                    .map(|stored| StoredMaterialSlotJson {
+                        slot: stored.slot().value(),
+                        kind: stored.kind().to_string(),
+                        duration_secs: stored.duration().as_secs(),
+                    })
+                    .collect(),
+            },
+            CommandOutcome::ReconnectLoop(reconnect) => {
+                let timing = reconnect.timing();
+                Self::ReconnectLoop {
+                    cycles: reconnect
+                        .cycles
+                        .iter()
+                        .map(|cycle| ReconnectCycleJson {
+                            cycle: cycle.cycle,
+                            elapsed_ms: cycle.elapsed.as_millis(),
+                            error: cycle.error.as_deref(),
+                        })
+                        .collect(),
+                    succeeded: reconnect.succeeded(),
+                    failed: reconnect.failed(),
+                    min_ms: timing.map(|(min, _mean, _max)| min.as_millis()),
+                    mean_ms: timing.map(|(_min, mean, _max)| mean.as_millis()),
+                    max_ms: timing.map(|(_min, _mean, max)| max.as_millis()),
+                }
+            }
+        }
+    }
+}
+
+/// Writes one `Warning:` line per warning for human-readable output.
+pub(crate) fn write_warnings(out: &mut impl io::Write, warnings: &[Warning]) -> Result<()> {
+    for warning in warnings {
+        writeln!(out, "Warning: {warning}")?;
+    }
+    Ok(())
+}
+
+/// Writes `outcome` as one JSON document followed by a newline.
+pub(crate) fn write_outcome_json(
+    out: &mut impl io::Write,
+    outcome: &CommandOutcome,
+    output_format: OutputFormat,
+) -> Result<()> {
+    write_json_document(out, outcome, output_format)?;
+    Ok(())
+}
+
+/// Writes `value` as one JSON document followed by a newline, indented
+/// unless `output_format` is [`OutputFormat::JsonCompact`] or
+/// [`OutputFormat::Ndjson`].
+pub(crate) fn write_json_document(
+    out: &mut impl io::Write,
+    value: &impl Serialize,
+    output_format: OutputFormat,
+) -> io::Result<()> {
+    match output_format {
+        OutputFormat::JsonCompact | OutputFormat::Ndjson => serde_json::to_writer(&mut *out, value),
+        _other => serde_json::to_writer_pretty(&mut *out, value),
+    }
+    .map_err(io::Error::other)?;
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn found_device(device_id: &str, local_name: &str) -> FoundDevice {
+        FoundDevice::new(
+            "hci0".to_string(),
+            device_id.to_string(),
+            Some(local_name.to_string()),
+            Some(-43),
+        )
+    }
+
+    #[rstest]
+    #[case(
+        CommandOutcome::Power(ScreenPower::On),
+        json!({ "command": "power", "data": { "state": "on" } })
+    )]
+    #[case(
+        CommandOutcome::Brightness(Brightness::new(50).expect("50 is a valid brightness")),
+        json!({ "command": "brightness", "data": { "value": 50 } })
+    )]
+    #[case(
+        CommandOutcome::Colour(Rgb::new(0x11, 0x22, 0x33)),
+        json!({ "command": "colour", "data": { "colour": "#112233" } })
+    )]
+    #[case(
+        CommandOutcome::SyncTime(
+            OffsetDateTime::from_unix_timestamp(1_700_000_000).expect("timestamp is in range"),
+        ),
+        json!({ "command": "sync-time", "data": { "unix_timestamp": 1_700_000_000 } })
+    )]
+    #[case(
+        CommandOutcome::Text(TextOutcome {
+            bytes_written: 123,
+            chunks_written: 2,
+            warnings: Vec::new(),
+        }),
+        json!({ "command": "text", "data": { "bytes_written": 123, "chunks_written": 2 } })
+    )]
+    #[case(
+        CommandOutcome::TextPreview(TextPreviewOutcome {
+            path: PathBuf::from("out.png"),
+            panel: PanelDimensions::new(32, 32).expect("32x32 is valid"),
+        }),
+        json!({
+            "command": "text-preview",
+            "data": { "path": "out.png", "panel": { "width": 32, "height": 32 } },
+        })
+    )]
+    #[case(
+        CommandOutcome::Image(ImageOutcome {
+            media_type: ImageMediaType::Gif,
+            bytes_written: 300,
+            chunks_written: 2,
+            logical_chunks_sent: 1,
+            uploads: 1,
+            warnings: Vec::new(),
+        }),
+        json!({
+            "command": "image",
+            "data": {
+                "media_type": "gif",
+                "bytes_written": 300,
+                "chunks_written": 2,
+                "logical_chunks_sent": 1,
+                "uploads": 1,
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::ImagePreview(ImagePreviewOutcome {
+            directory: PathBuf::from("frames"),
+            panel: PanelDimensions::new(16, 16).expect("16x16 is valid"),
+            paths: vec![PathBuf::from("frames/frame-000.png")],
+        }),
+        json!({
+            "command": "image-preview",
+            "data": {
+                "directory": "frames",
+                "panel": { "width": 16, "height": 16 },
+                "paths": ["frames/frame-000.png"],
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::Animate(AnimateOutcome {
+            frames: 2,
+            bytes_written: 400,
+            chunks_written: 3,
+            logical_chunks_sent: 1,
+            warnings: Vec::new(),
+        }),
+        json!({
+            "command": "animate",
+            "data": {
+                "frames": 2,
+                "bytes_written": 400,
+                "chunks_written": 3,
+                "logical_chunks_sent": 1,
+            },
+        })
+    )]
+    #[case(CommandOutcome::Daemon, json!({ "command": "daemon" }))]
+    #[case(CommandOutcome::FactoryReset, json!({ "command": "factory-reset" }))]
+    #[case(
+        CommandOutcome::JointMode(JointMode::On),
+        json!({ "command": "joint-mode", "data": { "state": "on" } })
+    )]
+    #[case(
+        CommandOutcome::Scene(vec![SceneStep::Power, SceneStep::Brightness]),
+        json!({ "command": "scene", "data": { "applied": ["power", "brightness"] } })
+    )]
+    #[case(
+        CommandOutcome::Slots(vec![StoredMaterialSlot::new(
+            crate::MaterialSlot::new(1),
+            crate::MaterialKind::Image,
+            crate::MaterialTimeSign::SixtySeconds,
+        )]),
+        json!({
+            "command": "slots",
+            "data": { "slots": [{ "slot": 1, "kind": "image", "duration_secs": 60 }] },
+        })
+    )]
+    #[case(
+        CommandOutcome::ReconnectLoop(ReconnectLoopOutcome {
+            cycles: vec![
+                ReconnectCycle {
+                    cycle: 1,
+                    elapsed: Duration::from_millis(120),
+                    error: None,
+                },
+                ReconnectCycle {
+                    cycle: 2,
+                    elapsed: Duration::from_millis(900),
+                    error: Some("connection timed out".to_string()),
+                },
+            ],
+        }),
+        json!({
+            "command": "reconnect-loop",
+            "data": {
+                "cycles": [
+                    { "cycle": 1, "elapsed_ms": 120 },
+                    { "cycle": 2, "elapsed_ms": 900, "error": "connection timed out" },
+                ],
+                "succeeded": 1,
+                "failed": 1,
+                "min_ms": 120,
+                "mean_ms": 120,
+                "max_ms": 120,
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::ValidateGif(GifValidationOutcome {
+            passed: false,
+            panel: PanelDimensions::new(32, 32).expect("32x32 is valid"),
+            dimensions: None,
+            frame_count: None,
+            payload_len: 4,
+            reasons: vec!["not a GIF".to_string()],
+        }),
+        json!({
+            "command": "validate-gif",
+            "data": {
+                "passed": false,
+                "panel": { "width": 32, "height": 32 },
+                "dimensions": null,
+                "frame_count": null,
+                "payload_len": 4,
+                "reasons": ["not a GIF"],
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::Crc(CrcOutcome {
+            payload_len: 9,
+            crc32: 0xCBF4_3926,
+        }),
+        json!({
+            "command": "crc",
+            "data": {
+                "payload_len": 9,
+                "crc32": 0xCBF4_3926_u32,
+                "crc32_hex": "cbf43926",
+                "header_bytes": "2639f4cb",
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::ScreenTimeout(
+            ScreenLightTimeout::from_minutes(5).expect("5 minutes is in range"),
+        ),
+        json!({ "command": "screen-timeout", "data": { "minutes": 5 } })
+    )]
+    #[case(
+        CommandOutcome::Describe(FrameDescription {
+            kind: "brightness command".to_string(),
+            fields: vec!["level 80".to_string()],
+        }),
+        json!({
+            "command": "describe",
+            "data": {
+                "kind": "brightness command",
+                "fields": ["level 80"],
+                "description": "brightness command, level 80",
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::SelfTest(SelfTestOutcome {
+            checks: vec![
+                SelfTestCheck {
+                    name: "encode power frame".to_string(),
+                    passed: true,
+                    detail: None,
+                },
+                SelfTestCheck {
+                    name: "prepare gif".to_string(),
+                    passed: false,
+                    detail: Some("expected 2 frames, got 1".to_string()),
+                },
+            ],
+        }),
+        json!({
+            "command": "self-test",
+            "data": {
+                "passed": false,
+                "failed": 1,
+                "checks": [
+                    { "name": "encode power frame", "passed": true },
+                    {
+                        "name": "prepare gif",
+                        "passed": false,
+                        "detail": "expected 2 frames, got 1",
+                    },
+                ],
+            },
+        })
+    )]
+    #[case(
+        CommandOutcome::Doctor(DoctorOutcome {
+            checks: vec![
+                DoctorCheck {
+                    name: "bluetooth adapter".to_string(),
+                    status: DoctorStatus::Ok,
+                    detail: "hci0".to_string(),
+                },
+                DoctorCheck {
+                    name: "adapter permissions".to_string(),
+                    status: DoctorStatus::Skipped,
+                    detail: "only checked on Linux".to_string(),
+                },
+            ],
+        }),
+        json!({
+            "command": "doctor",
+            "data": {
+                "failed": 0,
+                "checks": [
+                    { "name": "bluetooth adapter", "status": "ok", "detail": "hci0" },
+                    {
+                        "name": "adapter permissions",
+                        "status": "skipped",
+                        "detail": "only checked on Linux",
+                    },
+                ],
+            },
+        })
+    )]
+    fn outcome_serialises_with_command_tag(
+        #[case] outcome: CommandOutcome,
+        #[case] expected: Value,
+    ) {
+        let serialised = serde_json::to_value(&outcome).expect("outcome should serialise");
+
+        assert_eq!(expected, serialised);
+    }
+
+    #[test]
+    fn outcome_serialises_warnings_beside_data() {
+        let outcome = CommandOutcome::Image(ImageOutcome {
+            media_type: ImageMediaType::Gif,
+            bytes_written: 300,
+            chunks_written: 2,
+            logical_chunks_sent: 1,
+            uploads: 1,
+            warnings: vec![
+                Warning::GifFramesTruncated { dropped: 8 },
+                Warning::TransportDegraded { chunk_size: 254 },
+            ],
+        });
+
+        let serialised = serde_json::to_value(&outcome).expect("outcome should serialise");
+
+        assert_eq!(
+            json!([
+                { "kind": "gif-frames-truncated", "dropped": 8 },
+                { "kind": "transport-degraded", "chunk_size": 254 },
+            ]),
+            serialised["warnings"]
+        );
+    }
+
+    #[test]
+    fn text_outcome_serialises_scroll_warning_in_milliseconds() {
+        let outcome = CommandOutcome::Text(TextOutcome {
+            bytes_written: 123,
+            chunks_written: 2,
+            warnings: vec![Warning::TextScrollTooLong {
+                estimated: Duration::from_millis(250_500),
+                threshold: Duration::from_secs(60),
+            }],
+        });
+
+        let serialised = serde_json::to_value(&outcome).expect("outcome should serialise");
+
+        assert_eq!(
+            json!([
+                {
+                    "kind": "text-scroll-too-long",
+                    "estimated_ms": 250_500,
+                    "threshold_ms": 60_000,
+                },
+            ]),
+            serialised["warnings"]
+        );
+    }
+
+    #[rstest]
+    #[case::control(CommandOutcome::ControlAll, "control")]
+    #[case::image(CommandOutcome::ImageAll, "image")]
+    fn all_devices_outcome_serialises_each_device_under_its_command(
+        #[case] outcome: fn(Vec<DeviceOutcome>) -> CommandOutcome,
+        #[case] command: &str,
+    ) {
+        let outcome = outcome(vec![
+            DeviceOutcome {
+                device: found_device("AA:BB:CC", "IDM-Clock"),
+                outcome: Ok(CommandOutcome::Power(ScreenPower::Off)),
+            },
+            DeviceOutcome {
+                device: found_device("DD:EE:FF", "IDM-Desk"),
+                outcome: Err("connection lost".to_string()),
+            },
+        ]);
+
+        let serialised = serde_json::to_value(&outcome).expect("outcome should serialise");
+
+        assert_eq!(
+            json!({
+                "command": command,
+                "data": {
+                    "devices": [
+                        {
+                            "device_id": "AA:BB:CC",
+                            "local_name": "IDM-Clock",
+                            "outcome": { "command": "power", "data": { "state": "off" } },
+                        },
+                        {
+                            "device_id": "DD:EE:FF",
+                            "local_name": "IDM-Desk",
+                            "error": "connection lost",
+                        },
+                    ],
+                    "succeeded": 1,
+                    "failed": 1,
+                },
+            }),
+            serialised
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::io;
+
+use anyhow::Result;
+use tracing::instrument;
+
+use crate::cli::control::device_label;
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::protocol;
+
+/// Executes the `ping` command.
+///
+/// Connects, reports which endpoints were negotiated, and disconnects without
+/// running the LED-info query or resolving a device profile.
+#[instrument(skip(client, out), level = "info", fields(?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let probe = crate::SessionHandler::new(client).probe_first().await?;
+    let presence = probe.endpoint_presence();
+    let label = device_label(probe.device());
+
+    match output_format {
+        OutputFormat::Pretty => {
+            match probe.gatt_profile() {
+                Some(gatt_profile) => writeln!(out, "{label}: GATT profile {gatt_profile}")?,
+                None => writeln!(out, "{label}: no usable GATT profile")?,
+            }
+            for endpoint in protocol::known_endpoints() {
+                let status = if presence.is_present(endpoint) {
+                    "present"
+                } else {
+                    "missing"
+                };
+                writeln!(out, "  {endpoint}: {status}")?;
+            }
+        }
+        OutputFormat::Compact => {
+            let total = protocol::known_endpoints().count();
+            let present = protocol::known_endpoints()
+                .filter(|endpoint| presence.is_present(*endpoint))
+                .count();
+            writeln!(out, "{label}: {present} of {total} endpoint(s) present")?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::Ping(probe))
+}
@@ -0,0 +1,88 @@
+use std::io;
+use std::num::NonZeroUsize;
+
+use anyhow::Result;
+use clap::Args;
+use tracing::instrument;
+
+use crate::SessionHandler;
+use crate::cli::{CommandOutcome, OutputFormat, ReconnectLoopOutcome};
+use crate::hw::HardwareClient;
+
+/// Arguments for the `reconnect-loop` command.
+#[derive(Debug, Args)]
+pub struct ReconnectLoopArgs {
+    /// Number of connect-then-close cycles to run.
+    #[arg(long, default_value = "10")]
+    count: NonZeroUsize,
+}
+
+impl ReconnectLoopArgs {
+    /// Creates reconnect-loop arguments running `count` cycles.
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use idm::ReconnectLoopArgs;
+    ///
+    /// let count = NonZeroUsize::new(20).expect("20 is non-zero");
+    /// let args = ReconnectLoopArgs::new(count);
+    /// assert_eq!(count, args.count());
+    /// ```
+    #[must_use]
+    pub fn new(count: NonZeroUsize) -> Self {
+        Self { count }
+    }
+
+    /// Returns the number of cycles to run.
+    #[must_use]
+    pub fn count(&self) -> NonZeroUsize {
+        self.count
+    }
+}
+
+/// Executes the `reconnect-loop` command.
+///
+/// Failed cycles are reported alongside the timing summary rather than
+/// aborting the run.
+#[instrument(skip(client, args, out), level = "info", fields(count = args.count.get(), ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &ReconnectLoopArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let cycles = SessionHandler::new(client)
+        .reconnect_cycles(args.count)
+        .await;
+    let outcome = ReconnectLoopOutcome { cycles };
+
+    match output_format {
+        OutputFormat::Pretty => {
+            let total = outcome.cycles.len();
+            for cycle in &outcome.cycles {
+                let elapsed_ms = cycle.elapsed.as_millis();
+                match &cycle.error {
+                    None => writeln!(
+                        out,
+                        "[{}/{total}] connected and closed in {elapsed_ms}ms",
+                        cycle.cycle
+                    )?,
+                    Some(error) => writeln!(
+                        out,
+                        "[{}/{total}] failed after {elapsed_ms}ms: {error}",
+                        cycle.cycle
+                    )?,
+                }
+            }
+            writeln!(out, "{}", outcome.summary_line())?;
+        }
+        OutputFormat::Compact => writeln!(out, "{}", outcome.summary_line())?,
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::ReconnectLoop(outcome))
+}
@@ -0,0 +1,105 @@
+use std::io;
+
+use anyhow::Result;
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::control::{PowerState, parse_brightness};
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::{Brightness, Rgb, SceneHandler, SceneRequest, SessionHandler};
+
+/// Arguments for the top-level `scene` command.
+#[derive(Debug, Args)]
+#[group(required = true, multiple = true)]
+pub struct SceneArgs {
+    /// Screen power state, applied first.
+    #[arg(long, value_enum)]
+    power: Option<PowerState>,
+    /// Fullscreen colour as `#rrggbb`, `rrggbb`, or decimal `r,g,b`, applied second.
+    #[arg(long, value_name = "COLOUR")]
+    colour: Option<Rgb>,
+    /// Panel brightness (0..=100), applied last.
+    #[arg(long, value_parser = parse_brightness)]
+    brightness: Option<Brightness>,
+}
+
+impl SceneArgs {
+    /// Creates scene arguments from the settings to apply.
+    ///
+    /// ```
+    /// use idm::{PowerState, Rgb, SceneArgs};
+    ///
+    /// let args = SceneArgs::new(Some(PowerState::On), Some(Rgb::new(0, 0, 255)), None);
+    /// assert_eq!(Some(Rgb::new(0, 0, 255)), args.request().colour());
+    /// ```
+    #[must_use]
+    pub fn new(
+        power: Option<PowerState>,
+        colour: Option<Rgb>,
+        brightness: Option<Brightness>,
+    ) -> Self {
+        Self {
+            power,
+            colour,
+            brightness,
+        }
+    }
+
+    /// Returns the scene request these arguments describe.
+    ///
+    /// ```
+    /// use idm::{PowerState, SceneArgs, ScreenPower};
+    ///
+    /// let args = SceneArgs::new(Some(PowerState::On), None, None);
+    /// assert_eq!(Some(ScreenPower::On), args.request().power());
+    /// ```
+    #[must_use]
+    pub fn request(&self) -> SceneRequest {
+        SceneRequest::builder()
+            .maybe_power(self.power.map(PowerState::to_handler_power))
+            .maybe_colour(self.colour)
+            .maybe_brightness(self.brightness)
+            .build()
+    }
+}
+
+/// Executes the top-level `scene` command.
+#[instrument(skip(client, args, out), level = "info", fields(?args, ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &SceneArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let command_result = SceneHandler::apply(&session, args.request())
+        .await
+        .map_err(anyhow::Error::from);
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close scene session cleanly");
+    }
+
+    let applied = command_result?;
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            let steps = applied
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(out, "Applied scene: {steps}")?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+    Ok(CommandOutcome::Scene(applied))
+}
@@ -0,0 +1,84 @@
+use std::io;
+
+use anyhow::Result;
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::command::parse_duration;
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::{ScreenLightTimeout, ScreenLightTimeoutHandler, SessionHandler};
+
+/// Arguments for the top-level `screen-timeout` command.
+#[derive(Debug, Args)]
+pub struct ScreenTimeoutArgs {
+    /// How long the screen stays lit, rounded to the nearest minute (for example `5m`).
+    #[arg(value_name = "DURATION", value_parser = parse_screen_timeout)]
+    timeout: ScreenLightTimeout,
+}
+
+impl ScreenTimeoutArgs {
+    /// Creates screen-timeout arguments.
+    ///
+    /// ```
+    /// use idm::{ScreenLightTimeout, ScreenTimeoutArgs};
+    ///
+    /// let timeout = ScreenLightTimeout::from_minutes(5)?;
+    /// let args = ScreenTimeoutArgs::new(timeout);
+    /// assert_eq!(timeout, args.timeout());
+    /// # Ok::<(), idm::ScreenLightTimeoutError>(())
+    /// ```
+    #[must_use]
+    pub fn new(timeout: ScreenLightTimeout) -> Self {
+        Self { timeout }
+    }
+
+    /// Returns the requested timeout.
+    #[must_use]
+    pub fn timeout(&self) -> ScreenLightTimeout {
+        self.timeout
+    }
+}
+
+fn parse_screen_timeout(value: &str) -> Result<ScreenLightTimeout, String> {
+    ScreenLightTimeout::from_duration(parse_duration(value)?).map_err(|error| error.to_string())
+}
+
+/// Executes the top-level `screen-timeout` command.
+#[instrument(skip(client, args, out), level = "info", fields(timeout = %args.timeout, ?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    args: &ScreenTimeoutArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let command_result = ScreenLightTimeoutHandler::set_timeout_confirmed(&session, args.timeout)
+        .await
+        .map_err(anyhow::Error::from);
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close screen-timeout session cleanly");
+    }
+
+    let confirmed = command_result?;
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            writeln!(
+                out,
+                "Applied screen-light timeout: {} minute(s)",
+                confirmed.minutes()
+            )?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+    Ok(CommandOutcome::ScreenTimeout(confirmed))
+}
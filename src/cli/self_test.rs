@@ -0,0 +1,293 @@
+use std::io::{self, Cursor};
+
+use anyhow::{Context, Result, ensure};
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, OutputFormat, SelfTestCheck, SelfTestOutcome};
+use crate::notification::NotificationHandler;
+use crate::{
+    Brightness, DiyPrefixFields, FrameCodec, GifAnimation, GifChunkFlag, GifHeaderFields,
+    ImageHeaderFields, ImagePreparationOptions, ImagePreprocessor, LedInfoResponse, NotifyEvent,
+    PanelDimensions, PreparedImageUpload, Rgb, Rgb888Frame, ScreenPower, TextHeaderFields,
+    TransferFamily,
+};
+
+const SAMPLE_PANEL: (u16, u16) = (32, 32);
+
+type Check = fn() -> Result<()>;
+
+const CHECKS: [(&str, Check); 13] = [
+    ("encode brightness frame", encode_brightness_frame),
+    ("encode power frame", encode_power_frame),
+    (
+        "encode fullscreen colour frame",
+        encode_fullscreen_colour_frame,
+    ),
+    ("encode text header", encode_text_header),
+    ("encode gif header", encode_gif_header),
+    ("encode image header", encode_image_header),
+    ("encode diy prefix", encode_diy_prefix),
+    ("prepare still image", prepare_still_image),
+    ("prepare gif", prepare_gif),
+    ("decode transfer notification", decode_transfer_notification),
+    ("decode led info notification", decode_led_info_notification),
+    (
+        "decode screen-light timeout notification",
+        decode_screen_light_timeout_notification,
+    ),
+    ("decode unknown notification", decode_unknown_notification),
+];
+
+/// Executes the `self-test` command without connecting to a device.
+#[instrument(skip(out), level = "info", fields(?output_format))]
+pub(crate) fn run<W>(out: &mut W, output_format: OutputFormat) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let outcome = run_checks();
+
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            for check in &outcome.checks {
+                let verdict = if check.passed { "PASS" } else { "FAIL" };
+                writeln!(out, "{verdict}: {}", check.name)?;
+                if let Some(detail) = &check.detail {
+                    writeln!(out, "  - {detail}")?;
+                }
+            }
+            writeln!(
+                out,
+                "{} of {} checks passed",
+                outcome.checks.len() - outcome.failed(),
+                outcome.checks.len()
+            )?;
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::SelfTest(outcome))
+}
+
+/// Runs every built-in check, recording failures instead of stopping at them.
+pub(crate) fn run_checks() -> SelfTestOutcome {
+    let checks = CHECKS
+        .iter()
+        .map(|(name, check)| {
+            let result = check();
+            if let Err(error) = &result {
+                tracing::debug!(check = name, error = %format_args!("{error:#}"), "self-test check failed");
+            }
+            SelfTestCheck {
+                name: (*name).to_string(),
+                passed: result.is_ok(),
+                detail: result.err().map(|error| format!("{error:#}")),
+            }
+        })
+        .collect();
+    SelfTestOutcome { checks }
+}
+
+fn expect_bytes(expected: &[u8], actual: &[u8]) -> Result<()> {
+    ensure!(
+        expected == actual,
+        "expected {}, got {}",
+        hex::encode_upper(expected),
+        hex::encode_upper(actual)
+    );
+    Ok(())
+}
+
+fn expect_event(expected: &NotifyEvent, payload: &[u8]) -> Result<()> {
+    let actual = NotificationHandler::decode(payload)?;
+    ensure!(*expected == actual, "expected {expected}, got {actual}");
+    Ok(())
+}
+
+fn sample_panel() -> Result<PanelDimensions> {
+    let (width, height) = SAMPLE_PANEL;
+    PanelDimensions::new(width, height).context("sample panel dimensions are invalid")
+}
+
+fn encode_brightness_frame() -> Result<()> {
+    let frame = FrameCodec::encode_brightness(Brightness::new(80)?)?;
+    expect_bytes(&[0x05, 0x00, 0x04, 0x80, 0x50], &frame)
+}
+
+fn encode_power_frame() -> Result<()> {
+    let frame = FrameCodec::encode_power(ScreenPower::On)?;
+    expect_bytes(&[0x05, 0x00, 0x07, 0x01, 0x01], &frame)
+}
+
+fn encode_fullscreen_colour_frame() -> Result<()> {
+    let frame = FrameCodec::encode_fullscreen_colour(Rgb::new(0x11, 0x22, 0x33))?;
+    expect_bytes(&[0x07, 0x00, 0x02, 0x02, 0x11, 0x22, 0x33], &frame)
+}
+
+fn encode_text_header() -> Result<()> {
+    let header = FrameCodec::encode_text_header(TextHeaderFields::new(14, 14, 0x1122_3344)?);
+    expect_bytes(
+        &[
+            0x1E, 0x00, 0x03, 0x00, 0x00, 0x0E, 0x00, 0x00, 0x00, 0x44, 0x33, 0x22, 0x11, 0x00,
+            0x00, 0x0C,
+        ],
+        &header,
+    )
+}
+
+fn encode_gif_header() -> Result<()> {
+    let header = FrameCodec::encode_gif_header(GifHeaderFields::new(
+        0x08B9,
+        GifChunkFlag::Continuation,
+        0x0000_18B9,
+        0x14CB_42DB,
+    )?);
+    expect_bytes(
+        &[
+            0xC9, 0x08, 0x01, 0x00, 0x02, 0xB9, 0x18, 0x00, 0x00, 0xDB, 0x42, 0xCB, 0x14, 0x00,
+            0x00, 0x0C,
+        ],
+        &header,
+    )
+}
+
+fn encode_image_header() -> Result<()> {
+    let header = FrameCodec::encode_image_header(ImageHeaderFields::new(
+        0x1000,
+        GifChunkFlag::Continuation,
+        0x0000_2000,
+        0x1122_3344,
+    )?);
+    expect_bytes(
+        &[
+            0x10, 0x10, 0x02, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x44, 0x33, 0x22, 0x11, 0x00,
+            0x00, 0x0C,
+        ],
+        &header,
+    )
+}
+
+fn encode_diy_prefix() -> Result<()> {
+    let prefix = FrameCodec::encode_diy_prefix(DiyPrefixFields::new(
+        0x1000,
+        GifChunkFlag::Continuation,
+        0x0000_18B9,
+    )?);
+    expect_bytes(
+        &[0x09, 0x10, 0x00, 0x00, 0x02, 0xB9, 0x18, 0x00, 0x00],
+        &prefix,
+    )
+}
+
+fn prepare_still_image() -> Result<()> {
+    let panel = sample_panel()?;
+    let source = image::RgbImage::from_pixel(4, 4, image::Rgb([0xFF, 0x00, 0x00]));
+    let mut png = Cursor::new(Vec::new());
+    source
+        .write_to(&mut png, image::ImageFormat::Png)
+        .context("failed to encode sample PNG")?;
+
+    let prepared = ImagePreprocessor::prepare_for_upload(
+        png.get_ref(),
+        panel,
+        ImagePreparationOptions::default(),
+    )?;
+    let PreparedImageUpload::Still(still) = prepared else {
+        anyhow::bail!("sample PNG was not prepared as a still image");
+    };
+    ensure!(
+        still.frame().dimensions() == panel,
+        "expected {panel} frame, got {}",
+        still.frame().dimensions()
+    );
+    expect_bytes(
+        &[0xFF, 0x00, 0x00].repeat(usize::from(panel.width()) * usize::from(panel.height())),
+        still.frame().payload(),
+    )
+}
+
+fn prepare_gif() -> Result<()> {
+    let panel = sample_panel()?;
+    let source_dimensions = PanelDimensions::new(4, 4).context("sample GIF size is invalid")?;
+    let frames = [[0xFF, 0x00, 0x00], [0x00, 0x00, 0xFF]]
+        .into_iter()
+        .map(|colour| Rgb888Frame::try_from((source_dimensions, colour.repeat(16))))
+        .collect::<Result<Vec<_>, _>>()?;
+    let source = GifAnimation::from_frames(&frames, std::time::Duration::from_millis(100))?;
+
+    let prepared = ImagePreprocessor::prepare_for_upload(
+        source.payload(),
+        panel,
+        ImagePreparationOptions::default(),
+    )?;
+    let PreparedImageUpload::Gif(gif) = prepared else {
+        anyhow::bail!("sample GIF was not prepared as a GIF");
+    };
+    ensure!(
+        gif.dimensions() == panel,
+        "expected {panel} GIF, got {}",
+        gif.dimensions()
+    );
+    let frame_count = gif.frame_count()?;
+    ensure!(frame_count == 2, "expected 2 frames, got {frame_count}");
+    Ok(())
+}
+
+fn decode_transfer_notification() -> Result<()> {
+    expect_event(
+        &NotifyEvent::Finished(TransferFamily::Gif),
+        &[0x05, 0x00, 0x01, 0x00, 0x03],
+    )
+}
+
+fn decode_led_info_notification() -> Result<()> {
+    expect_event(
+        &NotifyEvent::LedInfo(LedInfoResponse {
+            mcu_major_version: 0x02,
+            mcu_minor_version: 0x0A,
+            status: 0x01,
+            screen_type: 0x04,
+            password_enabled: false,
+        }),
+        &[0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x04, 0x00],
+    )
+}
+
+fn decode_screen_light_timeout_notification() -> Result<()> {
+    expect_event(
+        &NotifyEvent::ScreenLightTimeout(0x1E),
+        &[0x05, 0x00, 0x0F, 0x80, 0x1E],
+    )
+}
+
+fn decode_unknown_notification() -> Result<()> {
+    let payload = [0x06, 0x00, 0x7E, 0x7F, 0x01, 0x02];
+    expect_event(&NotifyEvent::Unknown(payload.to_vec()), &payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn every_check_passes_in_a_clean_build() {
+        let outcome = run_checks();
+
+        let failures = outcome
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| format!("{}: {:?}", check.name, check.detail))
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::<String>::new(), failures);
+        assert_eq!(CHECKS.len(), outcome.checks.len());
+    }
+
+    #[test]
+    fn expect_bytes_reports_both_sides_in_hex() {
+        let error = expect_bytes(&[0x01, 0x02], &[0x01, 0x03]).expect_err("bytes differ");
+
+        assert_eq!("expected 0102, got 0103", error.to_string());
+    }
+}
@@ -0,0 +1,60 @@
+use std::io;
+
+use anyhow::Result;
+use tracing::instrument;
+
+use crate::cli::ui::{Painter, SlotsView};
+use crate::cli::{CommandOutcome, OutputFormat};
+use crate::hw::HardwareClient;
+use crate::terminal::TerminalClient;
+use crate::{MaterialSlotsHandler, SessionHandler};
+
+/// Executes the top-level `slots` command.
+#[instrument(skip(client, out, terminal_client), level = "info", fields(?output_format))]
+pub(crate) async fn run<W>(
+    client: Box<dyn HardwareClient>,
+    out: &mut W,
+    terminal_client: &dyn TerminalClient,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let session = SessionHandler::new(client).connect_first().await?;
+
+    let command_result = MaterialSlotsHandler::read_slots(&session)
+        .await
+        .map_err(anyhow::Error::from);
+    let close_result = session.close().await;
+
+    if let Err(error) = close_result {
+        if command_result.is_ok() {
+            return Err(error.into());
+        }
+        tracing::trace!(?error, "failed to close slots session cleanly");
+    }
+
+    let slots = command_result?;
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact if slots.is_empty() => {
+            writeln!(out, "No stored material slots")?;
+        }
+        OutputFormat::Pretty => {
+            let painter = Painter::for_stdout(terminal_client);
+            writeln!(out, "{}", SlotsView::new(&slots, &painter))?;
+        }
+        OutputFormat::Compact => {
+            for stored in &slots {
+                writeln!(
+                    out,
+                    "Slot {}: {}, {}s",
+                    stored.slot(),
+                    stored.kind(),
+                    stored.duration().as_secs()
+                )?;
+            }
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+    Ok(CommandOutcome::Slots(slots))
+}
@@ -2,9 +2,9 @@ use std::fmt::{self, Display, Formatter};
 
 use idm_macros::DiagnosticsSection;
 
-use crate::hw::FoundDevice;
 use crate::hw::diagnostic_value::{Rssi, UnknownOr};
 use crate::hw::diagnostics::DiagnosticsSection as _;
+use crate::hw::{DeviceProfile, FoundDevice};
 
 use super::painter::Painter;
 use super::table::Table;
@@ -21,6 +21,21 @@ impl<'a> DeviceView<'a> {
     }
 }
 
+/// Renders a `FoundDevice` as a single summary line.
+///
+/// The line reads `<name> <device id> <rssi>dBm <panel>`, with `-` standing in
+/// for any value the device did not report.
+pub(crate) struct DeviceSummaryView<'a> {
+    device: &'a FoundDevice,
+    profile: DeviceProfile,
+}
+
+impl<'a> DeviceSummaryView<'a> {
+    pub(crate) fn new(device: &'a FoundDevice, profile: DeviceProfile) -> Self {
+        Self { device, profile }
+    }
+}
+
 #[derive(Debug, DiagnosticsSection)]
 #[diagnostics(id = "connected_device", section = "Connected device")]
 struct ConnectedDeviceSection {
@@ -69,18 +84,40 @@ impl Display for DeviceView<'_> {
         let section_rows = section.rows();
         let rows = section_rows
             .iter()
-            .map(|row| (row.label(), self.painter.value(row.value())))
+            .map(|row| (row.label(), row.value(), self.painter.value(row.value())))
             .collect();
-        let table = Table::key_value(self.painter, rows);
+        let table = Table::key_value_aligned(self.painter, rows);
         write!(f, "{table}")
     }
 }
 
+impl Display for DeviceSummaryView<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} ",
+            self.device.local_name().unwrap_or("-"),
+            self.device.device_id_display()
+        )?;
+        match self.device.rssi() {
+            Some(rssi) => write!(f, "{rssi}dBm ")?,
+            None => write!(f, "- ")?,
+        }
+        match self.profile.panel_dimensions() {
+            Some(dimensions) => write!(f, "{dimensions}"),
+            None => write!(f, "-"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::hw::{GifHeaderProfile, ImageUploadMode, PanelDimensions};
+
     use super::*;
 
     fn device(name: Option<&str>, rssi: Option<i16>) -> FoundDevice {
@@ -105,4 +142,35 @@ mod tests {
         let painter = Painter::new(false);
         assert_snapshot!(snapshot_name, DeviceView::new(&dev, &painter).to_string());
     }
+
+    #[rstest]
+    #[case::all_fields(
+        Some("IDM-Cube"),
+        Some(-43),
+        PanelDimensions::new(32, 32),
+        "IDM-Cube AA:BB:CC -43dBm 32x32"
+    )]
+    #[case::missing_name(None, Some(-43), PanelDimensions::new(32, 32), "- AA:BB:CC -43dBm 32x32")]
+    #[case::missing_rssi(
+        Some("IDM-Cube"),
+        None,
+        PanelDimensions::new(16, 64),
+        "IDM-Cube AA:BB:CC - 16x64"
+    )]
+    #[case::unknown_panel(Some("IDM-Cube"), Some(-70), None, "IDM-Cube AA:BB:CC -70dBm -")]
+    fn device_summary_view_renders_single_line(
+        #[case] name: Option<&str>,
+        #[case] rssi: Option<i16>,
+        #[case] dimensions: Option<PanelDimensions>,
+        #[case] expected: &str,
+    ) {
+        let dev = device(name, rssi);
+        let profile = DeviceProfile::new(
+            dimensions,
+            GifHeaderProfile::Timed,
+            ImageUploadMode::PngFile,
+            512,
+        );
+        assert_eq!(expected, DeviceSummaryView::new(&dev, profile).to_string());
+    }
 }
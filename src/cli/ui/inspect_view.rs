@@ -4,18 +4,23 @@ use idm_macros::DiagnosticsSection;
 
 use crate::hw::diagnostic_value::{Bytes, MissingOr, NoneOr, UnknownOr, YesNo};
 use crate::hw::diagnostics::{DiagnosticRow, DiagnosticSectionSnapshot};
-use crate::hw::{GattProfile, InspectReport, ServiceInfo, TextPath};
+use crate::hw::{
+    CharacteristicInfo, GattProfile, InspectReport, NotifyDelivery, ServiceInfo, TextPath,
+};
 use crate::protocol;
 
 use super::device_view::DeviceView;
 use super::painter::Painter;
-use super::table::Table;
+use super::table::{ColumnAlignment, Table};
+
+const PRIMARY_COLUMN: usize = 1;
 
 /// Renders a full inspect report with device, endpoint, and service tables.
 pub(crate) struct InspectReportView<'a> {
     report: &'a InspectReport,
     painter: &'a Painter,
     runtime_diagnostics: &'a [DiagnosticSectionSnapshot],
+    property_filter: &'a [String],
 }
 
 #[derive(Debug, DiagnosticsSection)]
@@ -25,6 +30,8 @@ struct SessionMetadataSection {
     required_endpoints_verified: YesNo,
     #[diagnostic(name = "GATT profile")]
     gatt_profile: UnknownOr<GattProfile>,
+    #[diagnostic(name = "Negotiated ATT MTU")]
+    negotiated_mtu: UnknownOr<Bytes>,
     #[diagnostic(name = "Write-without-response limit")]
     write_without_response_limit: UnknownOr<Bytes>,
     #[diagnostic(name = "Discovered services")]
@@ -35,6 +42,8 @@ struct SessionMetadataSection {
     write_characteristic_properties: MissingOr<String>,
     #[diagnostic(name = "Read/notify characteristic properties")]
     read_notify_characteristic_properties: MissingOr<String>,
+    #[diagnostic(name = "Read/notify delivery")]
+    read_notify_delivery: NoneOr<NotifyDelivery>,
     #[diagnostic(name = "Resolved write characteristic UUID")]
     resolved_write_characteristic_uuid: UnknownOr<String>,
     #[diagnostic(name = "Resolved read/notify UUID")]
@@ -47,6 +56,8 @@ struct SessionMetadataSection {
     profile_text_path: UnknownOr<TextPath>,
     #[diagnostic(name = "Profile joint mode")]
     profile_joint_mode: NoneOr<u8>,
+    #[diagnostic(name = "Applied joint mode")]
+    applied_joint_mode: NoneOr<u8>,
     #[diagnostic(name = "Profile image upload mode")]
     profile_image_upload_mode: crate::hw::ImageUploadMode,
     #[diagnostic(name = "Profile GIF header")]
@@ -59,17 +70,9 @@ fn endpoint_properties(
     report: &InspectReport,
     endpoint: protocol::EndpointId,
 ) -> MissingOr<String> {
-    let expected_uuid = report
-        .session_metadata()
-        .resolved_endpoint_uuid(endpoint)
-        .unwrap_or_else(|| protocol::endpoint_metadata(endpoint).uuid());
-
     MissingOr(
         report
-            .services()
-            .iter()
-            .flat_map(|service| service.characteristics().iter())
-            .find(|characteristic| characteristic.uuid().eq_ignore_ascii_case(expected_uuid))
+            .endpoint_characteristic(endpoint)
             .map(|characteristic| characteristic.properties().join(",")),
     )
 }
@@ -117,6 +120,7 @@ impl From<&InspectReport> for SessionMetadataSection {
         Self {
             required_endpoints_verified: YesNo(metadata.required_endpoints_verified()),
             gatt_profile: UnknownOr(metadata.gatt_profile()),
+            negotiated_mtu: UnknownOr(metadata.negotiated_mtu().map(|mtu| Bytes(usize::from(mtu)))),
             write_without_response_limit: UnknownOr(
                 metadata.write_without_response_limit().map(Bytes),
             ),
@@ -130,6 +134,7 @@ impl From<&InspectReport> for SessionMetadataSection {
                 report,
                 protocol::EndpointId::ReadNotifyCharacteristic,
             ),
+            read_notify_delivery: NoneOr(report.notify_delivery()),
             resolved_write_characteristic_uuid: UnknownOr(
                 metadata
                     .resolved_endpoint_uuid(protocol::EndpointId::WriteCharacteristic)
@@ -144,6 +149,7 @@ impl From<&InspectReport> for SessionMetadataSection {
             profile_led_type: UnknownOr(profile.led_type()),
             profile_text_path: UnknownOr(profile.text_path()),
             profile_joint_mode: NoneOr(profile.joint_mode()),
+            applied_joint_mode: NoneOr(metadata.joint_mode()),
             profile_image_upload_mode: profile.image_upload_mode(),
             profile_gif_header: profile.gif_header_profile(),
             profile_write_chunk_fallback: Bytes(profile.write_without_response_fallback()),
@@ -157,9 +163,27 @@ impl<'a> InspectReportView<'a> {
             report,
             painter,
             runtime_diagnostics: &[],
+            property_filter: &[],
         }
     }
 
+    /// Restricts the services table to characteristics with any of these property labels.
+    ///
+    /// Services left without a matching characteristic are omitted. An empty
+    /// filter shows everything.
+    pub(crate) fn with_property_filter(mut self, property_filter: &'a [String]) -> Self {
+        self.property_filter = property_filter;
+        self
+    }
+
+    fn shows_characteristic(&self, characteristic: &CharacteristicInfo) -> bool {
+        self.property_filter.is_empty()
+            || characteristic
+                .properties()
+                .iter()
+                .any(|label| self.property_filter.contains(label))
+    }
+
     pub(crate) fn with_runtime_diagnostics(
         mut self,
         runtime_diagnostics: &'a [DiagnosticSectionSnapshot],
@@ -197,7 +221,15 @@ impl<'a> InspectReportView<'a> {
     fn services_table(&self) -> Table {
         let mut rows = Vec::new();
         for service in self.report.services() {
-            if service.characteristics().is_empty() {
+            let characteristics: Vec<&CharacteristicInfo> = service
+                .characteristics()
+                .iter()
+                .filter(|characteristic| self.shows_characteristic(characteristic))
+                .collect();
+            if characteristics.is_empty() {
+                if !self.property_filter.is_empty() {
+                    continue;
+                }
                 rows.push(vec![
                     self.painter.value(service.uuid()),
                     if service.is_primary() {
@@ -211,7 +243,7 @@ impl<'a> InspectReportView<'a> {
                 continue;
             }
 
-            for characteristic in service.characteristics() {
+            for characteristic in characteristics {
                 rows.push(vec![
                     self.painter.value(service.uuid()),
                     if service.is_primary() {
@@ -233,6 +265,7 @@ impl<'a> InspectReportView<'a> {
             ],
             rows,
         )
+        .with_column_alignment(PRIMARY_COLUMN, ColumnAlignment::Center)
     }
 
     fn diagnostic_value(&self, value: &str) -> String {
@@ -252,9 +285,9 @@ impl<'a> InspectReportView<'a> {
     fn rows_table(&self, rows: &[DiagnosticRow]) -> Table {
         let rows = rows
             .iter()
-            .map(|row| (row.label(), self.diagnostic_value(row.value())))
+            .map(|row| (row.label(), row.value(), self.diagnostic_value(row.value())))
             .collect();
-        Table::key_value(self.painter, rows)
+        Table::key_value_aligned(self.painter, rows)
     }
 
     fn section_table(&self, section: &dyn crate::hw::diagnostics::DiagnosticsSection) -> Table {
@@ -386,6 +419,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn property_filter_shows_only_matching_characteristics() {
+        let report = full_report();
+        let painter = Painter::new(false);
+        let filter = vec!["notify".to_string(), "indicate".to_string()];
+
+        let rendered = InspectReportView::new(&report, &painter)
+            .with_property_filter(&filter)
+            .to_string();
+        let services = rendered
+            .split("Discovered GATT services:")
+            .nth(1)
+            .expect("services table should render");
+
+        assert!(services.contains("0000fa03-0000-1000-8000-00805f9b34fb"));
+        assert!(!services.contains("0000fa02-0000-1000-8000-00805f9b34fb"));
+    }
+
+    #[test]
+    fn property_filter_omits_services_without_matches() {
+        let report = full_report();
+        let painter = Painter::new(false);
+        let filter = vec!["indicate".to_string()];
+
+        let rendered = InspectReportView::new(&report, &painter)
+            .with_property_filter(&filter)
+            .to_string();
+        let services = rendered
+            .split("Discovered GATT services:")
+            .nth(1)
+            .expect("services table should render");
+
+        assert!(!services.contains("000000fa-0000-1000-8000-00805f9b34fb"));
+        assert!(!services.contains("<none>"));
+    }
+
     #[test]
     fn service_without_characteristics() {
         let device = FoundDevice::new(
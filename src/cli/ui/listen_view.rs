@@ -1,6 +1,6 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::hw::{FoundDevice, ListenStopReason, ListenSummary};
+use crate::hw::{FoundDevice, InitialReadStatus, ListenStopReason, ListenSummary};
 use crate::protocol::{self, EndpointId};
 use crate::utils::format_hex;
 
@@ -11,6 +11,7 @@ use super::table::Table;
 /// Renders the listen-session readiness output.
 pub(crate) struct ListenReadyView<'a> {
     device: &'a FoundDevice,
+    endpoint: EndpointId,
     initial_read: Option<&'a [u8]>,
     painter: &'a Painter,
 }
@@ -18,11 +19,13 @@ pub(crate) struct ListenReadyView<'a> {
 impl<'a> ListenReadyView<'a> {
     pub(crate) fn new(
         device: &'a FoundDevice,
+        endpoint: EndpointId,
         initial_read: Option<&'a [u8]>,
         painter: &'a Painter,
     ) -> Self {
         Self {
             device,
+            endpoint,
             initial_read,
             painter,
         }
@@ -31,11 +34,16 @@ impl<'a> ListenReadyView<'a> {
 
 impl Display for ListenReadyView<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let endpoint = protocol::endpoint_metadata(EndpointId::ReadNotifyCharacteristic);
+        let endpoint = protocol::endpoint_metadata(self.endpoint);
         let initial_read_value = match self.initial_read {
             Some(payload) => format_hex(payload),
             None => "<none>".to_string(),
         };
+        let read_status = self.initial_read.and_then(InitialReadStatus::decode);
+        let read_status_value = match read_status {
+            Some(status) => self.painter.value(status.to_string()),
+            None => self.painter.muted("<undecoded>"),
+        };
 
         let session_table = Table::key_value(
             self.painter,
@@ -48,6 +56,7 @@ impl Display for ListenReadyView<'_> {
                         self.painter.warning(&initial_read_value)
                     },
                 ),
+                ("read_status", read_status_value),
                 (
                     "listening_on",
                     format!(
@@ -125,6 +134,22 @@ impl Display for ListenSummaryView<'_> {
                 "- received {} notification(s)",
                 self.summary.received_notifications()
             ))
+        )?;
+
+        let tallies = self
+            .summary
+            .event_counts()
+            .non_zero()
+            .map(|(label, count)| format!("{count} {label}"))
+            .collect::<Vec<_>>();
+        if tallies.is_empty() {
+            return Ok(());
+        }
+        write!(
+            f,
+            "\n{} {}",
+            self.painter.heading("Events:"),
+            self.painter.value(tallies.join(", "))
         )
     }
 }
@@ -134,7 +159,8 @@ mod tests {
     use insta::assert_snapshot;
     use rstest::rstest;
 
-    use crate::hw::FoundDevice;
+    use crate::hw::{FoundDevice, NotifyEventCounts};
+    use crate::notification::{NotificationDecodeError, NotifyEvent, TransferFamily};
 
     use super::*;
 
@@ -149,11 +175,20 @@ mod tests {
 
     #[rstest]
     #[case::with_read(Some(vec![0xDE, 0xAD, 0xBE, 0xEF]), "listen_ready_with_read")]
+    #[case::with_status_read(
+        Some(vec![0x05, 0x00, 0x01, 0x00, 0x01]),
+        "listen_ready_with_status_read"
+    )]
     #[case::no_read(None, "listen_ready_no_read")]
     fn listen_ready_renders(#[case] initial_read: Option<Vec<u8>>, #[case] snapshot_name: &str) {
         let dev = device();
         let painter = Painter::new(false);
-        let view = ListenReadyView::new(&dev, initial_read.as_deref(), &painter);
+        let view = ListenReadyView::new(
+            &dev,
+            EndpointId::ReadNotifyCharacteristic,
+            initial_read.as_deref(),
+            &painter,
+        );
         assert_snapshot!(snapshot_name, view.to_string());
     }
 
@@ -179,11 +214,35 @@ mod tests {
         #[case] snapshot_name: &str,
     ) {
         let dev = device();
-        let summary = ListenSummary::new(dev, None, 5, stop_reason);
+        let summary = ListenSummary::new(dev, None, 5, stop_reason, NotifyEventCounts::default());
         let painter = Painter::new(false);
         assert_snapshot!(
             snapshot_name,
             ListenSummaryView::new(&summary, &painter).to_string()
         );
     }
+
+    #[test]
+    fn summary_renders_event_counts() {
+        let mut counts = NotifyEventCounts::default();
+        for event in [
+            Ok(NotifyEvent::NextPackage(TransferFamily::Text)),
+            Ok(NotifyEvent::Finished(TransferFamily::Text)),
+            Err(NotificationDecodeError::EmptyPayload),
+        ] {
+            counts.record(&event);
+        }
+        let summary = ListenSummary::new(
+            device(),
+            None,
+            3,
+            ListenStopReason::NotificationStreamClosed,
+            counts,
+        );
+        let painter = Painter::new(false);
+        assert_snapshot!(
+            "summary_event_counts",
+            ListenSummaryView::new(&summary, &painter).to_string()
+        );
+    }
 }
@@ -2,8 +2,13 @@ mod device_view;
 mod inspect_view;
 mod listen_view;
 mod painter;
+mod slots_view;
 mod table;
+mod upload_summary;
 
+pub(crate) use self::device_view::DeviceSummaryView;
 pub(crate) use self::inspect_view::InspectReportView;
 pub(crate) use self::listen_view::{ListenNotificationView, ListenReadyView, ListenSummaryView};
 pub(crate) use self::painter::Painter;
+pub(crate) use self::slots_view::SlotsView;
+pub(crate) use self::upload_summary::UploadSummaryView;
@@ -1,5 +1,7 @@
 use owo_colors::{OwoColorize, Style as OwoStyle};
 
+use crate::terminal::TerminalClient;
+
 /// Applies colour and style to terminal text.
 #[derive(Debug)]
 pub(crate) struct Painter {
@@ -12,6 +14,12 @@ impl Painter {
         Self { use_colour }
     }
 
+    /// Creates a painter for standard output, colouring only when the
+    /// terminal client says stdout can take ANSI sequences.
+    pub(crate) fn for_stdout(terminal_client: &dyn TerminalClient) -> Self {
+        Self::new(terminal_client.stdout_supports_colour())
+    }
+
     pub(crate) fn heading<T: AsRef<str>>(&self, text: T) -> String {
         self.paint(text.as_ref(), OwoStyle::new().bold().cyan())
     }
@@ -48,6 +56,20 @@ mod tests {
 
     use super::*;
 
+    struct FixedTerminal {
+        is_terminal: bool,
+    }
+
+    impl TerminalClient for FixedTerminal {
+        fn stdout_is_terminal(&self) -> bool {
+            self.is_terminal
+        }
+
+        fn stderr_is_terminal(&self) -> bool {
+            self.is_terminal
+        }
+    }
+
     fn apply(painter: &Painter, style: &str, text: &str) -> String {
         match style {
             "heading" => painter.heading(text),
@@ -70,6 +92,18 @@ mod tests {
         assert_eq!(input, apply(&painter, style, input));
     }
 
+    #[rstest]
+    #[case::heading("heading", "hello")]
+    #[case::success("success", "ok")]
+    #[case::warning("warning", "warn")]
+    #[case::muted("muted", "dim")]
+    #[case::value("value", "bold")]
+    fn plain_output_contains_no_ansi_escapes(#[case] style: &str, #[case] input: &str) {
+        let painter = Painter::new(false);
+        let rendered = apply(&painter, style, input);
+        assert_eq!(false, rendered.contains('\u{1b}'));
+    }
+
     #[rstest]
     #[case::heading("heading", "hello")]
     #[case::success("success", "ok")]
@@ -82,4 +116,26 @@ mod tests {
         assert_ne!(styled, input);
         assert!(styled.contains(input));
     }
+
+    #[rstest]
+    #[case::heading("heading", "hello")]
+    #[case::success("success", "ok")]
+    #[case::warning("warning", "warn")]
+    #[case::muted("muted", "dim")]
+    #[case::value("value", "bold")]
+    fn painter_over_non_terminal_emits_no_control_sequences(
+        #[case] style: &str,
+        #[case] input: &str,
+    ) {
+        let painter = Painter::for_stdout(&FixedTerminal { is_terminal: false });
+        let rendered = apply(&painter, style, input);
+        assert_eq!(input, rendered);
+        assert_eq!(false, rendered.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn painter_over_terminal_emits_colour() {
+        let painter = Painter::for_stdout(&FixedTerminal { is_terminal: true });
+        assert_eq!(true, painter.heading("hello").contains('\u{1b}'));
+    }
 }
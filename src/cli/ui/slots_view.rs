@@ -0,0 +1,77 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::StoredMaterialSlot;
+
+use super::painter::Painter;
+use super::table::{ColumnAlignment, Table};
+
+const SLOT_COLUMN: usize = 0;
+const DURATION_COLUMN: usize = 2;
+
+/// Renders stored material slots as a table with right-aligned numeric columns.
+pub(crate) struct SlotsView<'a> {
+    slots: &'a [StoredMaterialSlot],
+    painter: &'a Painter,
+}
+
+impl<'a> SlotsView<'a> {
+    pub(crate) fn new(slots: &'a [StoredMaterialSlot], painter: &'a Painter) -> Self {
+        Self { slots, painter }
+    }
+}
+
+impl Display for SlotsView<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .slots
+            .iter()
+            .map(|stored| {
+                vec![
+                    self.painter.value(stored.slot().to_string()),
+                    self.painter.value(stored.kind().to_string()),
+                    self.painter
+                        .value(format!("{}s", stored.duration().as_secs())),
+                ]
+            })
+            .collect();
+        let table = Table::grid(["slot", "kind", "duration"], rows)
+            .with_column_alignment(SLOT_COLUMN, ColumnAlignment::Right)
+            .with_column_alignment(DURATION_COLUMN, ColumnAlignment::Right);
+        write!(f, "{table}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{MaterialKind, MaterialSlot, MaterialTimeSign};
+
+    #[test]
+    fn slots_view_right_aligns_slot_and_duration() {
+        let slots = [
+            StoredMaterialSlot::new(
+                MaterialSlot::new(3),
+                MaterialKind::Gif,
+                MaterialTimeSign::TenSeconds,
+            ),
+            StoredMaterialSlot::new(
+                MaterialSlot::new(12),
+                MaterialKind::Image,
+                MaterialTimeSign::ThirtySeconds,
+            ),
+        ];
+        let painter = Painter::new(false);
+
+        assert_eq!(
+            "╭──────┬───────┬──────────╮\n\
+             │ slot │ kind  │ duration │\n\
+             ├──────┼───────┼──────────┤\n\
+             │    3 │ gif   │      10s │\n\
+             │   12 │ image │      30s │\n\
+             ╰──────┴───────┴──────────╯",
+            SlotsView::new(&slots, &painter).to_string()
+        );
+    }
+}
@@ -3,16 +3,57 @@ use std::io::IsTerminal;
 
 use tabled::{
     builder::Builder,
-    settings::{Style as TableStyle, Width as TableWidth, peaker::Priority},
+    settings::{
+        Alignment, Style as TableStyle, Width as TableWidth,
+        object::{Cell, Columns},
+        peaker::Priority,
+    },
 };
 
 use super::painter::Painter;
 
+/// Horizontal alignment applied to a table column or cell.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub(crate) enum ColumnAlignment {
+    /// Pads content on the right.
+    #[default]
+    Left,
+    /// Pads content on the left, so numbers line up by their last digit.
+    Right,
+    /// Splits padding evenly on both sides.
+    Center,
+}
+
+impl ColumnAlignment {
+    /// Returns `Right` for numeric values and `Left` for everything else.
+    ///
+    /// Plain integers (such as RSSI readings and counts) and byte counts
+    /// rendered as `"<n> bytes"` are treated as numeric.
+    pub(crate) fn for_value(value: &str) -> Self {
+        let number = value.strip_suffix(" bytes").unwrap_or(value);
+        if number.parse::<i64>().is_ok() {
+            Self::Right
+        } else {
+            Self::Left
+        }
+    }
+
+    fn to_tabled(self) -> Alignment {
+        match self {
+            Self::Left => Alignment::left(),
+            Self::Right => Alignment::right(),
+            Self::Center => Alignment::center(),
+        }
+    }
+}
+
 /// A structured table that renders via `Display`.
 #[derive(Debug)]
 pub(crate) struct Table {
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
+    column_alignments: Vec<(usize, ColumnAlignment)>,
+    cell_alignments: Vec<((usize, usize), ColumnAlignment)>,
 }
 
 impl Table {
@@ -24,6 +65,8 @@ impl Table {
         Self {
             headers: headers.into_iter().map(Into::into).collect(),
             rows,
+            column_alignments: Vec::new(),
+            cell_alignments: Vec::new(),
         }
     }
 
@@ -35,6 +78,52 @@ impl Table {
             .collect();
         Self::grid(["field", "value"], records)
     }
+
+    /// Creates a field/value table whose numeric values are right-aligned.
+    ///
+    /// Alignment is decided from the unstyled value, so callers pass the raw
+    /// text alongside the painted text they want rendered.
+    pub(crate) fn key_value_aligned(painter: &Painter, rows: Vec<(&str, &str, String)>) -> Self {
+        let alignments: Vec<_> = rows
+            .iter()
+            .map(|(_, raw, _)| ColumnAlignment::for_value(raw))
+            .collect();
+        let table = Self::key_value(
+            painter,
+            rows.into_iter()
+                .map(|(field, _, value)| (field, value))
+                .collect(),
+        );
+        alignments
+            .into_iter()
+            .enumerate()
+            .fold(table, |table, (row, alignment)| {
+                table.with_cell_alignment(row, 1, alignment)
+            })
+    }
+
+    /// Aligns every cell in `column`, including its header.
+    pub(crate) fn with_column_alignment(
+        mut self,
+        column: usize,
+        alignment: ColumnAlignment,
+    ) -> Self {
+        self.column_alignments.push((column, alignment));
+        self
+    }
+
+    /// Aligns a single data cell, overriding any column alignment.
+    ///
+    /// `row` indexes data rows and does not count the header.
+    pub(crate) fn with_cell_alignment(
+        mut self,
+        row: usize,
+        column: usize,
+        alignment: ColumnAlignment,
+    ) -> Self {
+        self.cell_alignments.push(((row, column), alignment));
+        self
+    }
 }
 
 impl Display for Table {
@@ -46,6 +135,12 @@ impl Display for Table {
         }
         let mut table = builder.build();
         table.with(TableStyle::rounded());
+        for &(column, alignment) in &self.column_alignments {
+            table.modify(Columns::new(column..=column), alignment.to_tabled());
+        }
+        for &((row, column), alignment) in &self.cell_alignments {
+            table.modify(Cell::new(row + 1, column), alignment.to_tabled());
+        }
         if let Some(width) = terminal_width() {
             table.with(TableWidth::wrap(width).priority(Priority::right()));
         }
@@ -66,6 +161,8 @@ fn terminal_width() -> Option<usize> {
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     use super::*;
 
@@ -90,4 +187,43 @@ mod tests {
         );
         assert_snapshot!("key_value_table", table.to_string());
     }
+
+    #[test]
+    fn right_aligned_column_pads_numbers_on_the_left() {
+        let table = Table::grid(
+            ["device", "rssi"],
+            vec![
+                vec!["kitchen".into(), "-43".into()],
+                vec!["hall".into(), "-7".into()],
+                vec!["garage".into(), "-101".into()],
+            ],
+        )
+        .with_column_alignment(1, ColumnAlignment::Right);
+        assert_snapshot!("right_aligned_column", table.to_string());
+    }
+
+    #[test]
+    fn key_value_aligned_right_aligns_numeric_values_only() {
+        let painter = Painter::new(false);
+        let table = Table::key_value_aligned(
+            &painter,
+            vec![
+                ("host", "example.com", "example.com".into()),
+                ("mtu", "509 bytes", "509 bytes".into()),
+                ("rssi", "-43", "-43".into()),
+            ],
+        );
+        assert_snapshot!("key_value_aligned_table", table.to_string());
+    }
+
+    #[rstest]
+    #[case::integer("42", ColumnAlignment::Right)]
+    #[case::negative("-43", ColumnAlignment::Right)]
+    #[case::byte_count("509 bytes", ColumnAlignment::Right)]
+    #[case::unknown("-", ColumnAlignment::Left)]
+    #[case::text("IDM-Clock", ColumnAlignment::Left)]
+    #[case::hex("05 00 01", ColumnAlignment::Left)]
+    fn for_value_detects_numeric_values(#[case] value: &str, #[case] expected: ColumnAlignment) {
+        assert_eq!(expected, ColumnAlignment::for_value(value));
+    }
 }
@@ -0,0 +1,95 @@
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+/// Renders a one-line upload summary with size, chunk count, duration, and throughput.
+///
+/// Sizes use decimal units, so 1 KB is 1000 bytes. The throughput is left out
+/// when the upload finished within the clock's resolution.
+pub(crate) struct UploadSummaryView {
+    bytes: usize,
+    chunks: usize,
+    elapsed: Duration,
+}
+
+impl UploadSummaryView {
+    pub(crate) fn new(bytes: usize, chunks: usize, elapsed: Duration) -> Self {
+        Self {
+            bytes,
+            chunks,
+            elapsed,
+        }
+    }
+}
+
+impl Display for UploadSummaryView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let chunk_label = if self.chunks == 1 { "chunk" } else { "chunks" };
+        let seconds = self.elapsed.as_secs_f64();
+        let bytes = f64::from(u32::try_from(self.bytes).unwrap_or(u32::MAX));
+        write!(
+            f,
+            "Uploaded {} in {} {chunk_label} over {seconds:.1}s",
+            DecimalSize(bytes),
+            self.chunks,
+        )?;
+        if seconds > 0.0 {
+            write!(f, " ({}/s)", DecimalSize(bytes / seconds))?;
+        }
+        Ok(())
+    }
+}
+
+struct DecimalSize(f64);
+
+impl Display for DecimalSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const KB: f64 = 1_000.0;
+        const MB: f64 = 1_000_000.0;
+
+        match self.0 {
+            bytes if bytes < KB => write!(f, "{bytes:.0} B"),
+            bytes if bytes < MB => write!(f, "{:.1} KB", bytes / KB),
+            bytes => write!(f, "{:.1} MB", bytes / MB),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::kilobytes(
+        12_300,
+        34,
+        Duration::from_millis(2_100),
+        "Uploaded 12.3 KB in 34 chunks over 2.1s (5.9 KB/s)"
+    )]
+    #[case::bytes(
+        70,
+        1,
+        Duration::from_millis(500),
+        "Uploaded 70 B in 1 chunk over 0.5s (140 B/s)"
+    )]
+    #[case::megabytes(
+        2_500_000,
+        612,
+        Duration::from_secs(4),
+        "Uploaded 2.5 MB in 612 chunks over 4.0s (625.0 KB/s)"
+    )]
+    #[case::instant(70, 1, Duration::ZERO, "Uploaded 70 B in 1 chunk over 0.0s")]
+    fn upload_summary_reports_size_chunks_and_throughput(
+        #[case] bytes: usize,
+        #[case] chunks: usize,
+        #[case] elapsed: Duration,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            expected,
+            UploadSummaryView::new(bytes, chunks, elapsed).to_string()
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Args;
+use tracing::instrument;
+
+use crate::cli::{CommandOutcome, GifValidationOutcome, OutputFormat};
+use crate::{GifAnimation, GifUploadRequest, GifValidationLimits, PanelDimensions};
+
+/// Arguments for the offline `validate-gif` command.
+#[derive(Debug, Args)]
+pub struct ValidateGifArgs {
+    /// Path to the GIF file to check.
+    gif_file: PathBuf,
+    /// Target panel dimensions, for example `32x32`.
+    #[arg(long, value_name = "WIDTHxHEIGHT", value_parser = parse_panel_dimensions)]
+    panel: PanelDimensions,
+    /// Maximum accepted frame count.
+    #[arg(long, value_name = "COUNT")]
+    max_frames: Option<usize>,
+    /// Maximum accepted payload size in bytes.
+    #[arg(long, value_name = "BYTES")]
+    max_bytes: Option<usize>,
+}
+
+impl ValidateGifArgs {
+    /// Creates validation arguments for one GIF and panel size.
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use idm::{PanelDimensions, ValidateGifArgs};
+    ///
+    /// let panel = PanelDimensions::new(32, 32).expect("32x32 should be valid dimensions");
+    /// let args = ValidateGifArgs::new("anim.gif", panel);
+    /// assert_eq!(Path::new("anim.gif"), args.path());
+    /// assert_eq!(panel, args.panel());
+    /// ```
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>, panel: PanelDimensions) -> Self {
+        Self {
+            gif_file: path.into(),
+            panel,
+            max_frames: None,
+            max_bytes: None,
+        }
+    }
+
+    /// Overrides the maximum accepted frame count.
+    ///
+    /// ```
+    /// use idm::{PanelDimensions, ValidateGifArgs};
+    ///
+    /// let panel = PanelDimensions::new(32, 32).expect("32x32 should be valid dimensions");
+    /// let args = ValidateGifArgs::new("anim.gif", panel).with_max_frames(8);
+    /// assert_eq!(8, args.limits().max_frames());
+    /// ```
+    #[must_use]
+    pub fn with_max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    /// Overrides the maximum accepted payload size in bytes.
+    ///
+    /// ```
+    /// use idm::{PanelDimensions, ValidateGifArgs};
+    ///
+    /// let panel = PanelDimensions::new(32, 32).expect("32x32 should be valid dimensions");
+    /// let args = ValidateGifArgs::new("anim.gif", panel).with_max_bytes(4096);
+    /// assert_eq!(4096, args.limits().max_payload_len());
+    /// ```
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Returns the GIF file path.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.gif_file
+    }
+
+    /// Returns the target panel dimensions.
+    #[must_use]
+    pub fn panel(&self) -> PanelDimensions {
+        self.panel
+    }
+
+    /// Returns the limits to check, falling back to the upload defaults.
+    #[must_use]
+    pub fn limits(&self) -> GifValidationLimits {
+        GifValidationLimits::builder()
+            .maybe_max_frames(self.max_frames)
+            .maybe_max_payload_len(self.max_bytes)
+            .build()
+    }
+}
+
+/// Executes the `validate-gif` command without connecting to a device.
+#[instrument(skip(args, out), level = "info", fields(path = %args.gif_file.display(), ?output_format))]
+pub(crate) fn run<W>(
+    args: &ValidateGifArgs,
+    out: &mut W,
+    output_format: OutputFormat,
+) -> Result<CommandOutcome>
+where
+    W: io::Write,
+{
+    let source_bytes = std::fs::read(args.path())
+        .with_context(|| format!("failed to read GIF file {}", args.path().display()))?;
+    let payload_len = source_bytes.len();
+
+    let (dimensions, frame_count, reasons) = match GifAnimation::try_from(source_bytes) {
+        Ok(gif) => {
+            let dimensions = gif.dimensions();
+            let frame_count = gif.frame_count().ok();
+            let reasons = GifUploadRequest::new(gif)
+                .validate(args.panel(), args.limits())
+                .into_iter()
+                .map(|failure| format!("{:#}", anyhow::Error::from(failure)))
+                .collect::<Vec<_>>();
+            (Some(dimensions), frame_count, reasons)
+        }
+        Err(error) => (
+            None,
+            None,
+            vec![format!("{:#}", anyhow::Error::from(error))],
+        ),
+    };
+    let passed = reasons.is_empty();
+
+    match output_format {
+        OutputFormat::Pretty | OutputFormat::Compact => {
+            let verdict = if passed { "PASS" } else { "FAIL" };
+            writeln!(out, "{verdict}: {}", args.path().display())?;
+            writeln!(out, "  Panel: {}", args.panel())?;
+            match dimensions {
+                Some(dimensions) => writeln!(out, "  Dimensions: {dimensions}")?,
+                None => writeln!(out, "  Dimensions: unknown")?,
+            }
+            match frame_count {
+                Some(frame_count) => writeln!(out, "  Frames: {frame_count}")?,
+                None => writeln!(out, "  Frames: unknown")?,
+            }
+            writeln!(out, "  Payload: {payload_len} bytes")?;
+            for reason in &reasons {
+                writeln!(out, "  - {reason}")?;
+            }
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {}
+    }
+
+    Ok(CommandOutcome::ValidateGif(GifValidationOutcome {
+        passed,
+        panel: args.panel(),
+        dimensions,
+        frame_count,
+        payload_len,
+        reasons,
+    }))
+}
+
+pub(crate) fn parse_panel_dimensions(value: &str) -> Result<PanelDimensions, String> {
+    let (width, height) = value
+        .split_once(['x', 'X'])
+        .ok_or_else(|| "expected WIDTHxHEIGHT, for example 32x32".to_string())?;
+    let width = width.parse::<u16>().map_err(|error| error.to_string())?;
+    let height = height.parse::<u16>().map_err(|error| error.to_string())?;
+    PanelDimensions::new(width, height)
+        .ok_or_else(|| "panel dimensions must be non-zero".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("32x32", Ok((32, 32)))]
+    #[case("64X16", Ok((64, 16)))]
+    #[case("32", Err(()))]
+    #[case("0x32", Err(()))]
+    #[case("axb", Err(()))]
+    fn parse_panel_dimensions_accepts_width_by_height(
+        #[case] value: &str,
+        #[case] expected: Result<(u16, u16), ()>,
+    ) {
+        let parsed = parse_panel_dimensions(value)
+            .map(|dimensions| (dimensions.width(), dimensions.height()))
+            .map_err(|_error| ());
+        assert_eq!(expected, parsed);
+    }
+}
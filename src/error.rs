@@ -1,10 +1,12 @@
-use derive_more::From;
+use std::path::PathBuf;
+
+use derive_more::{Display, From};
 use thiserror::Error;
 
 use crate::diy::Error as DiyError;
 use crate::handlers::{
-    BrightnessError, FrameCodecError, GifUploadError, ImageUploadError, TextUploadError,
-    UploadAckError,
+    BrightnessError, FrameCodecError, GifUploadError, ImageUploadError, MaterialSlotsError,
+    SceneError, ScheduleError, ScreenLightTimeoutError, TextUploadError, UploadAckError,
 };
 use crate::notification::NotificationDecodeError;
 use crate::protocol::{EndpointId, endpoint_metadata};
@@ -14,7 +16,9 @@ use crate::protocol::{EndpointId, endpoint_metadata};
 pub enum InteractionError {
     #[error("BLE operation failed")]
     Ble(#[from] btleplug::Error),
-    #[error("no BLE adapters were found")]
+    #[error(
+        "no Bluetooth adapter found; is the Bluetooth service running, or is rfkill blocking it?"
+    )]
     NoAdapters,
     #[error("no iDotMatrix device matching `{prefix}*` was found in the fake fixture")]
     NoMatchingFixtureDevice { prefix: String },
@@ -30,6 +34,10 @@ pub enum InteractionError {
     ModelOverrideIo { source: std::io::Error },
     #[error("invalid persisted model-override record: `{record}`")]
     InvalidModelOverrideRecord { record: String },
+    #[error("failed while reading or writing the device profile cache")]
+    ProfileCacheIo { source: std::io::Error },
+    #[error("invalid device profile cache record: `{record}`")]
+    InvalidProfileCacheRecord { record: String },
     #[error("invalid LED type override value `{value}`")]
     InvalidLedTypeOverride { value: u8 },
     #[error(
@@ -40,8 +48,27 @@ pub enum InteractionError {
         "notification stream has not completed yet; drain the stream before requesting a summary"
     )]
     NotificationStreamIncomplete,
-    #[error("session close timed out after {timeout_ms}ms")]
-    SessionCloseTimeout { timeout_ms: u64 },
+    #[error(
+        "a notification stream is already active on this session; drop it before subscribing to `{name}`",
+        name = endpoint_metadata(*endpoint).name()
+    )]
+    NotificationStreamBusy { endpoint: EndpointId },
+    #[error(
+        "endpoint `{name}` supports neither notify nor indicate, so it cannot be subscribed to",
+        name = endpoint_metadata(*endpoint).name()
+    )]
+    EndpointNotSubscribable { endpoint: EndpointId },
+    #[error(
+        "session close timed out after {timeout_ms}ms (peripheral still connected: {still_connected})"
+    )]
+    SessionCloseTimeout {
+        timeout_ms: u64,
+        still_connected: bool,
+    },
+    #[error("write of {len} bytes exceeds the device's {mtu}-byte write limit")]
+    WriteExceedsMtu { len: usize, mtu: usize },
+    #[error("BLE write did not complete within {timeout_ms}ms")]
+    WriteTimeout { timeout_ms: u64 },
     #[error(transparent)]
     Fixture(#[from] FixtureError),
 }
@@ -55,6 +82,14 @@ pub enum FixtureError {
     InvalidRecordFieldCount,
     #[error("fixture records cannot contain empty mandatory fields")]
     EmptyRecordField,
+    #[error("fixture record is missing a mandatory field")]
+    MissingRecordField,
+    #[error("named fixture entry `{entry}` must use `key=value` syntax")]
+    InvalidNamedField { entry: String },
+    #[error("unknown named fixture field `{key}`")]
+    UnknownNamedField { key: String },
+    #[error("named fixture field is given more than once")]
+    DuplicateNamedField,
     #[error("failed to parse RSSI value")]
     InvalidRssi(#[from] std::num::ParseIntError),
     #[error("hex payload length must be even")]
@@ -63,6 +98,44 @@ pub enum FixtureError {
     InvalidHexByte { value: String },
     #[error("scan model payload is not a valid iDotMatrix manufacturer payload")]
     InvalidScanModelPayload,
+    #[error(
+        "listen auto-advance needs a current-thread runtime with a paused clock, as in tests driving `run_with_clients`"
+    )]
+    AutoAdvanceUnavailable,
+    #[error("failed to read fixture file `{}`", path.display())]
+    ReadFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error(
+        "scan fixture record {record}{}: {source}",
+        field.map(|field| format!(", field `{field}`")).unwrap_or_default()
+    )]
+    InvalidScanRecord {
+        record: usize,
+        field: Option<ScanFixtureField>,
+        source: Box<FixtureError>,
+    },
+}
+
+/// Field of a fake scan fixture record, used to locate parse failures.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Display)]
+pub enum ScanFixtureField {
+    /// Adapter identifier (first positional field).
+    #[display("adapter")]
+    Adapter,
+    /// Device address (second positional field).
+    #[display("address")]
+    Address,
+    /// Advertised local name (third positional field).
+    #[display("name")]
+    Name,
+    /// Signal strength (fourth positional field).
+    #[display("rssi")]
+    Rssi,
+    /// Optional manufacturer scan payload (fifth positional field).
+    #[display("scan")]
+    Scan,
 }
 
 /// Errors returned when validating runtime backend options.
@@ -92,6 +165,9 @@ pub enum ProtocolError {
     #[from(BrightnessError, Box<BrightnessError>)]
     Brightness(Box<BrightnessError>),
     #[error(transparent)]
+    #[from(ScreenLightTimeoutError, Box<ScreenLightTimeoutError>)]
+    ScreenLightTimeout(Box<ScreenLightTimeoutError>),
+    #[error(transparent)]
     #[from(TextUploadError, Box<TextUploadError>)]
     TextUpload(Box<TextUploadError>),
     #[error(transparent)]
@@ -104,6 +180,15 @@ pub enum ProtocolError {
     #[from(DiyError, Box<DiyError>)]
     Diy(Box<DiyError>),
     #[error(transparent)]
+    #[from(MaterialSlotsError, Box<MaterialSlotsError>)]
+    MaterialSlots(Box<MaterialSlotsError>),
+    #[error(transparent)]
+    #[from(SceneError, Box<SceneError>)]
+    Scene(Box<SceneError>),
+    #[error(transparent)]
+    #[from(ScheduleError, Box<ScheduleError>)]
+    Schedule(Box<ScheduleError>),
+    #[error(transparent)]
     #[from(UploadAckError, Box<UploadAckError>)]
     UploadAck(Box<UploadAckError>),
     #[error(transparent)]
@@ -67,7 +67,7 @@ pub struct BrightnessHandler;
 
 impl BrightnessHandler {
     fn frame_for(brightness: Brightness) -> Result<Vec<u8>, FrameCodecError> {
-        FrameCodec::encode_short(0x04, 0x80, &[brightness.value()])
+        FrameCodec::encode_brightness(brightness)
     }
 
     /// Sends a brightness command.
@@ -0,0 +1,67 @@
+use crate::error::ProtocolError;
+use crate::hw::{Ack, DeviceSession, SessionWriter};
+use tracing::{instrument, warn};
+
+use super::{FrameCodec, FrameCodecError};
+
+const FACTORY_RESET_COMMAND_ID: u8 = 0x03;
+const FACTORY_RESET_COMMAND_NS: u8 = 0x80;
+
+/// Handler for the destructive reset-to-defaults command.
+pub struct FactoryResetHandler;
+
+impl FactoryResetHandler {
+    fn frame() -> Result<Vec<u8>, FrameCodecError> {
+        FrameCodec::encode_short(FACTORY_RESET_COMMAND_ID, FACTORY_RESET_COMMAND_NS, &[])
+    }
+
+    /// Restores the device to its factory defaults.
+    ///
+    /// The `04 00 03 80` frame is sent as a write-with-response, so this
+    /// returns once the device has acknowledged the write. Callers are
+    /// responsible for confirming the action with the user first.
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), idm::ProtocolError> {
+    /// use idm::FactoryResetHandler;
+    ///
+    /// FactoryResetHandler::reset(&session).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when frame encoding fails or the device does not
+    /// acknowledge the write.
+    #[instrument(skip(session), level = "debug")]
+    pub async fn reset(session: &DeviceSession) -> Result<(), ProtocolError> {
+        let frame = Self::frame()?;
+        warn!(
+            device_id = %session.device().device_id_display(),
+            "sending factory reset; device settings will be restored to defaults"
+        );
+        SessionWriter::builder()
+            .session(session)
+            .payload(&frame)
+            .ack(Ack::Transport)
+            .build()
+            .send()
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn frame_matches_protocol() {
+        let frame = FactoryResetHandler::frame().expect("reset frame should encode cleanly");
+
+        assert_eq!(vec![0x04, 0x00, 0x03, 0x80], frame);
+    }
+}
@@ -2,14 +2,27 @@ use std::fmt;
 
 use thiserror::Error;
 
-const SHORT_FRAME_HEADER_LEN: usize = 4;
+use super::{Brightness, Rgb, ScreenPower};
+use crate::protocol::{DiyPrefixLayout, MediaHeaderLayout, ShortFrameLayout};
+
+const SHORT_FRAME_HEADER_LEN: usize = ShortFrameLayout::HEADER_LEN;
 const SHORT_FRAME_MAX_PAYLOAD_LEN: usize = u16::MAX as usize - SHORT_FRAME_HEADER_LEN;
-const HEADER_LEN: u16 = 16;
+const NOTIFICATION_MIN_LEN: usize = SHORT_FRAME_HEADER_LEN + 1;
+const HEADER_LEN: u16 = MediaHeaderLayout::LEN;
 const HEADER_MAX_PAYLOAD_LEN: u16 = u16::MAX - HEADER_LEN;
-const DIY_PREFIX_LEN: u16 = 9;
+const DIY_PREFIX_LEN: u16 = DiyPrefixLayout::LEN;
 const DIY_PREFIX_MAX_PAYLOAD_LEN: u16 = u16::MAX - DIY_PREFIX_LEN;
+const MEDIA_TYPE_GIF: u8 = 0x01;
+const MEDIA_TYPE_IMAGE: u8 = 0x02;
+const MEDIA_TYPE_TEXT: u8 = 0x03;
 const MEDIA_SLOT_NO_TIME_SIGNATURE: u8 = 12;
 const MEDIA_SLOT_SHOW_NOW: u8 = 13;
+const FULLSCREEN_COLOUR_COMMAND_ID: u8 = 0x02;
+const FULLSCREEN_COLOUR_COMMAND_NS: u8 = 0x02;
+const BRIGHTNESS_COMMAND_ID: u8 = 0x04;
+const BRIGHTNESS_COMMAND_NS: u8 = 0x80;
+const POWER_COMMAND_ID: u8 = 0x07;
+const POWER_COMMAND_NS: u8 = 0x01;
 
 /// Errors returned by frame encoding and decoding.
 #[derive(Debug, Error, Clone, Eq, PartialEq)]
@@ -20,12 +33,21 @@ pub enum FrameCodecError {
     /// The declared frame length does not match the provided byte slice length.
     #[error("short frame length mismatch: declared {declared} bytes but frame has {actual} bytes")]
     ShortFrameLengthMismatch { declared: usize, actual: usize },
+    /// The notification frame has no status byte after its 4-byte header.
+    #[error("notification frame is too short: expected at least 5 bytes, got {actual}")]
+    NotificationTooShort { actual: usize },
     /// The payload is too large to fit in a 16-bit short-frame length field.
     #[error("short frame payload is too large: {payload_len} bytes exceeds max {max_payload_len}")]
     ShortFramePayloadTooLarge {
         payload_len: usize,
         max_payload_len: usize,
     },
+    /// The bytes are shorter than the 16-byte media header.
+    #[error("media header is too short: expected at least 16 bytes, got {actual}")]
+    MediaHeaderTooShort { actual: usize },
+    /// Media-header byte `2` does not name a known media type.
+    #[error("unknown media header type {value:#04x}")]
+    UnknownMediaType { value: u8 },
     /// The payload is too large to fit inside a 16-byte headered block.
     #[error("header payload is too large: {payload_len} bytes exceeds max {max_payload_len}")]
     HeaderPayloadTooLarge {
@@ -351,10 +373,12 @@ impl MediaHeaderTail {
     /// assert_eq!([60, 0, 13], [header[13], header[14], header[15]]);
     /// ```
     pub fn apply_to_header(self, header: &mut [u8; 16]) {
-        let tail = self.bytes();
-        header[13] = tail[0];
-        header[14] = tail[1];
-        header[15] = tail[2];
+        let (duration_seconds, slot) = match self {
+            Self::NoTimeSignature => (0, MEDIA_SLOT_NO_TIME_SIGNATURE),
+            Self::Timed { slot, time_sign } => (time_sign.duration_seconds(), slot.value()),
+        };
+        MediaHeaderLayout::DURATION_SECONDS.write(header, duration_seconds);
+        MediaHeaderLayout::SLOT.write(header, slot);
     }
 }
 
@@ -408,12 +432,20 @@ pub enum GifChunkFlag {
 }
 
 impl GifChunkFlag {
-    fn as_protocol_byte(self) -> u8 {
+    pub(crate) fn as_protocol_byte(self) -> u8 {
         match self {
             Self::First => 0x00,
             Self::Continuation => 0x02,
         }
     }
+
+    fn from_protocol_byte(value: u8) -> Option<Self> {
+        match value {
+            0x00 => Some(Self::First),
+            0x02 => Some(Self::Continuation),
+            _ => None,
+        }
+    }
 }
 
 /// Fields used when encoding a GIF upload header.
@@ -524,6 +556,165 @@ impl DiyPrefixFields {
     }
 }
 
+/// Short control frame split into its header fields and payload.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ShortFrame<'a> {
+    command_id: u8,
+    command_ns: u8,
+    payload: &'a [u8],
+}
+
+impl ShortFrame<'_> {
+    /// Returns the command id byte.
+    #[must_use]
+    pub fn command_id(&self) -> u8 {
+        self.command_id
+    }
+
+    /// Returns the command namespace byte.
+    #[must_use]
+    pub fn command_ns(&self) -> u8 {
+        self.command_ns
+    }
+
+    /// Returns the bytes following the 4-byte header.
+    #[must_use]
+    pub fn payload(&self) -> &[u8] {
+        self.payload
+    }
+
+    /// Returns the level when this is a valid brightness frame (`0x04`/`0x80`).
+    #[must_use]
+    pub fn brightness(&self) -> Option<Brightness> {
+        match (self.command_id, self.command_ns, self.payload) {
+            (BRIGHTNESS_COMMAND_ID, BRIGHTNESS_COMMAND_NS, [level]) => Brightness::new(*level).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the state when this is a screen power frame (`0x07`/`0x01`).
+    #[must_use]
+    pub fn power(&self) -> Option<ScreenPower> {
+        match (self.command_id, self.command_ns, self.payload) {
+            (POWER_COMMAND_ID, POWER_COMMAND_NS, [0x00]) => Some(ScreenPower::Off),
+            (POWER_COMMAND_ID, POWER_COMMAND_NS, [0x01]) => Some(ScreenPower::On),
+            _ => None,
+        }
+    }
+
+    /// Returns the colour when this is a full-screen colour frame (`0x02`/`0x02`).
+    #[must_use]
+    pub fn fullscreen_colour(&self) -> Option<Rgb> {
+        match (self.command_id, self.command_ns, self.payload) {
+            (FULLSCREEN_COLOUR_COMMAND_ID, FULLSCREEN_COLOUR_COMMAND_NS, [r, g, b]) => {
+                Some(Rgb::new(*r, *g, *b))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Media type carried in media-header byte `2`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MediaHeaderKind {
+    /// GIF upload header (`0x01`).
+    Gif,
+    /// Still-image upload header (`0x02`).
+    Image,
+    /// Text upload header (`0x03`).
+    Text,
+}
+
+impl MediaHeaderKind {
+    fn from_protocol_byte(value: u8) -> Option<Self> {
+        match value {
+            MEDIA_TYPE_GIF => Some(Self::Gif),
+            MEDIA_TYPE_IMAGE => Some(Self::Image),
+            MEDIA_TYPE_TEXT => Some(Self::Text),
+            _ => None,
+        }
+    }
+}
+
+/// Fields decoded from a 16-byte text, GIF, or image upload header.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MediaHeader {
+    kind: MediaHeaderKind,
+    block_len: u16,
+    chunk_flag_byte: u8,
+    payload_len: u32,
+    crc32: u32,
+    duration_seconds: u16,
+    slot: u8,
+}
+
+impl MediaHeader {
+    /// Returns the media type named by byte `2`.
+    #[must_use]
+    pub fn kind(&self) -> MediaHeaderKind {
+        self.kind
+    }
+
+    /// Returns the block length, header included, from bytes `0..2`.
+    #[must_use]
+    pub fn block_len(&self) -> u16 {
+        self.block_len
+    }
+
+    /// Returns the chunk payload length carried after the header.
+    #[must_use]
+    pub fn chunk_payload_len(&self) -> u16 {
+        self.block_len.saturating_sub(HEADER_LEN)
+    }
+
+    /// Returns the chunk flag from byte `4`, or `None` for an unknown value.
+    #[must_use]
+    pub fn chunk_flag(&self) -> Option<GifChunkFlag> {
+        GifChunkFlag::from_protocol_byte(self.chunk_flag_byte)
+    }
+
+    /// Returns the total payload length from bytes `5..9`.
+    #[must_use]
+    pub fn payload_len(&self) -> u32 {
+        self.payload_len
+    }
+
+    /// Returns the payload CRC32 from bytes `9..13`.
+    #[must_use]
+    pub fn crc32(&self) -> u32 {
+        self.crc32
+    }
+
+    /// Returns the material slot from byte `15`.
+    #[must_use]
+    pub fn slot(&self) -> MaterialSlot {
+        MaterialSlot::new(self.slot)
+    }
+
+    /// Returns the display duration in seconds from bytes `13..15`.
+    #[must_use]
+    pub fn duration_seconds(&self) -> u16 {
+        self.duration_seconds
+    }
+}
+
+/// Notification frame split into the command it answers and its body.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DecodedNotification<'a> {
+    /// Five-byte `05 00 id ns status` reply carrying one status byte.
+    Status {
+        command_id: u8,
+        command_ns: u8,
+        status: u8,
+    },
+    /// Longer reply carrying a multi-byte body, such as the LED-info response.
+    Payload {
+        command_id: u8,
+        command_ns: u8,
+        payload: &'a [u8],
+    },
+}
+
 /// Encodes and decodes iDotMatrix protocol frames.
 pub struct FrameCodec;
 
@@ -553,65 +744,197 @@ impl FrameCodec {
             }
         })?;
 
-        let mut frame = Vec::with_capacity(frame_len);
-        frame.extend_from_slice(&frame_len_u16.to_le_bytes());
-        frame.push(command_id);
-        frame.push(command_ns);
+        let mut frame = vec![0_u8; SHORT_FRAME_HEADER_LEN];
+        ShortFrameLayout::FRAME_LEN.write(&mut frame, frame_len_u16);
+        ShortFrameLayout::COMMAND_ID.write(&mut frame, command_id);
+        ShortFrameLayout::COMMAND_NS.write(&mut frame, command_ns);
         frame.extend_from_slice(payload);
         Ok(frame)
     }
 
+    /// Decodes a short control frame, checking its declared length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `frame` is shorter than the 4-byte header or its
+    /// declared length differs from its actual length.
+    pub fn decode_short(frame: &[u8]) -> Result<ShortFrame<'_>, FrameCodecError> {
+        let Some((header, payload)) = frame.split_at_checked(SHORT_FRAME_HEADER_LEN) else {
+            return Err(FrameCodecError::ShortFrameTooShort {
+                actual: frame.len(),
+            });
+        };
+        let declared = usize::from(ShortFrameLayout::FRAME_LEN.read(header));
+        if declared != frame.len() {
+            return Err(FrameCodecError::ShortFrameLengthMismatch {
+                declared,
+                actual: frame.len(),
+            });
+        }
+
+        Ok(ShortFrame {
+            command_id: ShortFrameLayout::COMMAND_ID.read(header),
+            command_ns: ShortFrameLayout::COMMAND_NS.read(header),
+            payload,
+        })
+    }
+
+    /// Decodes a device notification frame into its command and status.
+    ///
+    /// Notifications share the short-frame layout; a one-byte body is a
+    /// status reply and anything longer is kept as a payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `frame` is shorter than 5 bytes or its declared
+    /// length differs from its actual length.
+    pub fn decode_notification(frame: &[u8]) -> Result<DecodedNotification<'_>, FrameCodecError> {
+        if frame.len() < NOTIFICATION_MIN_LEN {
+            return Err(FrameCodecError::NotificationTooShort {
+                actual: frame.len(),
+            });
+        }
+        let ShortFrame {
+            command_id,
+            command_ns,
+            payload,
+        } = Self::decode_short(frame)?;
+
+        Ok(match payload {
+            [status] => DecodedNotification::Status {
+                command_id,
+                command_ns,
+                status: *status,
+            },
+            _ => DecodedNotification::Payload {
+                command_id,
+                command_ns,
+                payload,
+            },
+        })
+    }
+
+    /// Decodes the leading 16-byte text, GIF, or image upload header.
+    ///
+    /// Bytes after the header are ignored, so a header can be decoded on its
+    /// own or from the start of a full upload block.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `bytes` is shorter than 16 bytes or byte `2`
+    /// does not name a known media type.
+    pub fn decode_media_header(bytes: &[u8]) -> Result<MediaHeader, FrameCodecError> {
+        let Some(header) = bytes.first_chunk::<16>() else {
+            return Err(FrameCodecError::MediaHeaderTooShort {
+                actual: bytes.len(),
+            });
+        };
+        let media_type = MediaHeaderLayout::MEDIA_TYPE.read(header);
+        let kind = MediaHeaderKind::from_protocol_byte(media_type)
+            .ok_or(FrameCodecError::UnknownMediaType { value: media_type })?;
+
+        Ok(MediaHeader {
+            kind,
+            block_len: MediaHeaderLayout::BLOCK_LEN.read(header),
+            chunk_flag_byte: MediaHeaderLayout::CHUNK_FLAG.read(header),
+            payload_len: MediaHeaderLayout::PAYLOAD_LEN.read(header),
+            crc32: MediaHeaderLayout::CRC32.read(header),
+            duration_seconds: MediaHeaderLayout::DURATION_SECONDS.read(header),
+            slot: MediaHeaderLayout::SLOT.read(header),
+        })
+    }
+
+    /// Encodes a brightness control frame (`0x04`/`0x80`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the short frame cannot be encoded.
+    pub fn encode_brightness(brightness: Brightness) -> Result<Vec<u8>, FrameCodecError> {
+        Self::encode_short(
+            BRIGHTNESS_COMMAND_ID,
+            BRIGHTNESS_COMMAND_NS,
+            &[brightness.value()],
+        )
+    }
+
+    /// Encodes a screen power control frame (`0x07`/`0x01`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the short frame cannot be encoded.
+    pub fn encode_power(power: ScreenPower) -> Result<Vec<u8>, FrameCodecError> {
+        Self::encode_short(
+            POWER_COMMAND_ID,
+            POWER_COMMAND_NS,
+            &[power.as_payload_byte()],
+        )
+    }
+
+    /// Encodes a full-screen colour fill frame (`0x02`/`0x02`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the short frame cannot be encoded.
+    pub fn encode_fullscreen_colour(colour: Rgb) -> Result<Vec<u8>, FrameCodecError> {
+        Self::encode_short(
+            FULLSCREEN_COLOUR_COMMAND_ID,
+            FULLSCREEN_COLOUR_COMMAND_NS,
+            &[colour.r, colour.g, colour.b],
+        )
+    }
+
     /// Encodes a 16-byte text header.
     #[must_use]
     pub fn encode_text_header(fields: TextHeaderFields) -> [u8; 16] {
-        let mut header = [0u8; 16];
-        let block_len = HEADER_LEN + fields.chunk_payload_len;
-
-        header[0..2].copy_from_slice(&block_len.to_le_bytes());
-        header[2] = 0x03;
-        header[3] = 0x00;
-        header[4] = 0x00;
-        header[5..9].copy_from_slice(&fields.payload_len.to_le_bytes());
-        header[9..13].copy_from_slice(&fields.crc32.to_le_bytes());
-        header[13] = 0x00;
-        header[14] = 0x00;
-        header[15] = 0x0C;
-        header
+        Self::encode_media_header(
+            MEDIA_TYPE_TEXT,
+            GifChunkFlag::First,
+            fields.chunk_payload_len,
+            fields.payload_len,
+            fields.crc32,
+        )
     }
 
     /// Encodes a 16-byte GIF header.
     #[must_use]
     pub fn encode_gif_header(fields: GifHeaderFields) -> [u8; 16] {
-        let mut header = [0u8; 16];
-        let block_len = HEADER_LEN + fields.chunk_payload_len;
-
-        header[0..2].copy_from_slice(&block_len.to_le_bytes());
-        header[2] = 0x01;
-        header[3] = 0x00;
-        header[4] = fields.chunk_flag.as_protocol_byte();
-        header[5..9].copy_from_slice(&fields.payload_len.to_le_bytes());
-        header[9..13].copy_from_slice(&fields.crc32.to_le_bytes());
-        header[13] = 0x00;
-        header[14] = 0x00;
-        header[15] = MEDIA_SLOT_NO_TIME_SIGNATURE;
-        header
+        Self::encode_media_header(
+            MEDIA_TYPE_GIF,
+            fields.chunk_flag,
+            fields.chunk_payload_len,
+            fields.payload_len,
+            fields.crc32,
+        )
     }
 
     /// Encodes a 16-byte image header.
     #[must_use]
     pub fn encode_image_header(fields: ImageHeaderFields) -> [u8; 16] {
+        Self::encode_media_header(
+            MEDIA_TYPE_IMAGE,
+            fields.chunk_flag,
+            fields.chunk_payload_len,
+            fields.payload_len,
+            fields.crc32,
+        )
+    }
+
+    fn encode_media_header(
+        media_type: u8,
+        chunk_flag: GifChunkFlag,
+        chunk_payload_len: u16,
+        payload_len: u32,
+        crc32: u32,
+    ) -> [u8; 16] {
         let mut header = [0u8; 16];
-        let block_len = HEADER_LEN + fields.chunk_payload_len;
-
-        header[0..2].copy_from_slice(&block_len.to_le_bytes());
-        header[2] = 0x02;
-        header[3] = 0x00;
-        header[4] = fields.chunk_flag.as_protocol_byte();
-        header[5..9].copy_from_slice(&fields.payload_len.to_le_bytes());
-        header[9..13].copy_from_slice(&fields.crc32.to_le_bytes());
-        header[13] = 0x00;
-        header[14] = 0x00;
-        header[15] = MEDIA_SLOT_NO_TIME_SIGNATURE;
+
+        MediaHeaderLayout::BLOCK_LEN.write(&mut header, HEADER_LEN + chunk_payload_len);
+        MediaHeaderLayout::MEDIA_TYPE.write(&mut header, media_type);
+        MediaHeaderLayout::RESERVED.write(&mut header, 0x00);
+        MediaHeaderLayout::CHUNK_FLAG.write(&mut header, chunk_flag.as_protocol_byte());
+        MediaHeaderLayout::PAYLOAD_LEN.write(&mut header, payload_len);
+        MediaHeaderLayout::CRC32.write(&mut header, crc32);
+        MediaHeaderTail::NoTimeSignature.apply_to_header(&mut header);
         header
     }
 
@@ -619,13 +942,12 @@ impl FrameCodec {
     #[must_use]
     pub fn encode_diy_prefix(fields: DiyPrefixFields) -> [u8; 9] {
         let mut prefix = [0u8; 9];
-        let block_len = DIY_PREFIX_LEN + fields.chunk_payload_len;
 
-        prefix[0..2].copy_from_slice(&block_len.to_le_bytes());
-        prefix[2] = 0x00;
-        prefix[3] = 0x00;
-        prefix[4] = fields.chunk_flag.as_protocol_byte();
-        prefix[5..9].copy_from_slice(&fields.payload_len.to_le_bytes());
+        DiyPrefixLayout::BLOCK_LEN.write(&mut prefix, DIY_PREFIX_LEN + fields.chunk_payload_len);
+        DiyPrefixLayout::COMMAND_ID.write(&mut prefix, 0x00);
+        DiyPrefixLayout::COMMAND_NS.write(&mut prefix, 0x00);
+        DiyPrefixLayout::CHUNK_FLAG.write(&mut prefix, fields.chunk_flag.as_protocol_byte());
+        DiyPrefixLayout::PAYLOAD_LEN.write(&mut prefix, fields.payload_len);
         prefix
     }
 }
@@ -658,6 +980,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_brightness_matches_handler_frame() {
+        let brightness = Brightness::new(80).expect("test brightness should be valid");
+        let frame =
+            FrameCodec::encode_brightness(brightness).expect("brightness frame should encode");
+        assert_eq!(vec![0x05, 0x00, 0x04, 0x80, 0x50], frame);
+    }
+
+    #[rstest]
+    #[case(ScreenPower::Off, vec![0x05, 0x00, 0x07, 0x01, 0x00])]
+    #[case(ScreenPower::On, vec![0x05, 0x00, 0x07, 0x01, 0x01])]
+    fn encode_power_matches_handler_frame(#[case] power: ScreenPower, #[case] expected: Vec<u8>) {
+        let frame = FrameCodec::encode_power(power).expect("power frame should encode");
+        assert_eq!(expected, frame);
+    }
+
+    #[test]
+    fn encode_fullscreen_colour_matches_handler_frame() {
+        let frame = FrameCodec::encode_fullscreen_colour(Rgb::new(0x11, 0x22, 0x33))
+            .expect("colour frame should encode");
+        assert_eq!(vec![0x07, 0x00, 0x02, 0x02, 0x11, 0x22, 0x33], frame);
+    }
+
     #[test]
     fn encode_text_header_matches_expected_bytes() {
         let fields = TextHeaderFields::new(14, 14, 0x1122_3344)
@@ -712,6 +1057,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decode_short_round_trips_encoded_frame() {
+        let frame = FrameCodec::encode_fullscreen_colour(Rgb::new(0x11, 0x22, 0x33))
+            .expect("colour frame should encode");
+
+        let decoded = FrameCodec::decode_short(&frame).expect("encoded frame should decode");
+
+        assert_eq!(FULLSCREEN_COLOUR_COMMAND_ID, decoded.command_id());
+        assert_eq!(FULLSCREEN_COLOUR_COMMAND_NS, decoded.command_ns());
+        assert_eq!([0x11, 0x22, 0x33], decoded.payload());
+        assert_eq!(
+            Some(Rgb::new(0x11, 0x22, 0x33)),
+            decoded.fullscreen_colour()
+        );
+        assert_eq!(None, decoded.brightness());
+        assert_eq!(None, decoded.power());
+    }
+
+    #[rstest]
+    #[case(ScreenPower::Off)]
+    #[case(ScreenPower::On)]
+    fn decode_short_recognises_power_frames(#[case] power: ScreenPower) {
+        let frame = FrameCodec::encode_power(power).expect("power frame should encode");
+
+        let decoded = FrameCodec::decode_short(&frame).expect("encoded frame should decode");
+
+        assert_eq!(Some(power), decoded.power());
+    }
+
+    #[rstest]
+    #[case(&[0x05, 0x00, 0x04], FrameCodecError::ShortFrameTooShort { actual: 3 })]
+    #[case(
+        &[0x06, 0x00, 0x04, 0x80, 0x50],
+        FrameCodecError::ShortFrameLengthMismatch { declared: 6, actual: 5 }
+    )]
+    fn decode_short_rejects_malformed_frames(
+        #[case] frame: &[u8],
+        #[case] expected: FrameCodecError,
+    ) {
+        assert_eq!(Err(expected), FrameCodec::decode_short(frame));
+    }
+
+    #[rstest]
+    #[case::status(
+        &[0x05, 0x00, 0x01, 0x00, 0x03],
+        DecodedNotification::Status { command_id: 0x01, command_ns: 0x00, status: 0x03 }
+    )]
+    #[case::payload(
+        &[0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x04, 0x00],
+        DecodedNotification::Payload {
+            command_id: 0x01,
+            command_ns: 0x80,
+            payload: &[0x02, 0x0A, 0x01, 0x04, 0x00],
+        }
+    )]
+    fn decode_notification_splits_command_and_body(
+        #[case] frame: &[u8],
+        #[case] expected: DecodedNotification<'_>,
+    ) {
+        assert_eq!(Ok(expected), FrameCodec::decode_notification(frame));
+    }
+
+    #[rstest]
+    #[case(&[0x05, 0x00, 0x01, 0x00], FrameCodecError::NotificationTooShort { actual: 4 })]
+    #[case(&[0xAA, 0x55], FrameCodecError::NotificationTooShort { actual: 2 })]
+    #[case(
+        &[0x06, 0x00, 0x01, 0x00, 0x03],
+        FrameCodecError::ShortFrameLengthMismatch { declared: 6, actual: 5 }
+    )]
+    fn decode_notification_rejects_malformed_frames(
+        #[case] frame: &[u8],
+        #[case] expected: FrameCodecError,
+    ) {
+        assert_eq!(Err(expected), FrameCodec::decode_notification(frame));
+    }
+
+    #[test]
+    fn decode_media_header_round_trips_gif_header() {
+        let fields =
+            GifHeaderFields::new(0x08B9, GifChunkFlag::Continuation, 0x0000_18B9, 0x14CB_42DB)
+                .expect("captured values should construct");
+        let header = FrameCodec::encode_gif_header(fields);
+
+        let decoded =
+            FrameCodec::decode_media_header(&header).expect("encoded header should decode");
+
+        assert_eq!(MediaHeaderKind::Gif, decoded.kind());
+        assert_eq!(0x08C9, decoded.block_len());
+        assert_eq!(0x08B9, decoded.chunk_payload_len());
+        assert_eq!(Some(GifChunkFlag::Continuation), decoded.chunk_flag());
+        assert_eq!(0x0000_18B9, decoded.payload_len());
+        assert_eq!(0x14CB_42DB, decoded.crc32());
+        assert_eq!(MaterialSlot::NO_TIME_SIGNATURE, decoded.slot());
+        assert_eq!(0, decoded.duration_seconds());
+    }
+
+    #[rstest]
+    #[case(&[0x1E, 0x00, 0x03], FrameCodecError::MediaHeaderTooShort { actual: 3 })]
+    #[case(
+        &[
+            0x10, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x0C,
+        ],
+        FrameCodecError::UnknownMediaType { value: 0x07 }
+    )]
+    fn decode_media_header_rejects_malformed_headers(
+        #[case] bytes: &[u8],
+        #[case] expected: FrameCodecError,
+    ) {
+        assert_eq!(Err(expected), FrameCodec::decode_media_header(bytes));
+    }
+
     #[test]
     fn encode_diy_prefix_matches_expected_bytes() {
         let fields = DiyPrefixFields::new(0x1000, GifChunkFlag::Continuation, 0x0000_18B9)
@@ -722,4 +1179,28 @@ mod tests {
             prefix
         );
     }
+
+    #[test]
+    fn timed_tail_rewrites_only_the_captured_tail_bytes() {
+        let fields =
+            GifHeaderFields::new(0x08B9, GifChunkFlag::Continuation, 0x0000_18B9, 0x14CB_42DB)
+                .expect("captured values should construct");
+        let mut header = FrameCodec::encode_gif_header(fields);
+        MediaHeaderTail::timed(
+            TimedMaterialSlot::SHOW_NOW,
+            MaterialTimeSign::ThreeHundredSeconds,
+        )
+        .apply_to_header(&mut header);
+
+        assert_eq!(
+            [
+                0xC9, 0x08, 0x01, 0x00, 0x02, 0xB9, 0x18, 0x00, 0x00, 0xDB, 0x42, 0xCB, 0x14, 0x2C,
+                0x01, 0x0D,
+            ],
+            header
+        );
+        let decoded = FrameCodec::decode_media_header(&header).expect("header should decode");
+        assert_eq!(300, decoded.duration_seconds());
+        assert_eq!(MaterialSlot::new(13), decoded.slot());
+    }
 }
@@ -1,11 +1,30 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
 use crate::error::ProtocolError;
 use crate::hw::{Ack, DeviceSession, SessionWriter};
+use serde_with::SerializeDisplay;
+use thiserror::Error;
 use tracing::instrument;
 
 use super::{FrameCodec, FrameCodecError};
 
+/// Errors returned when parsing an [`Rgb`] colour from text.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+pub enum RgbParseError {
+    /// The text matched none of the accepted colour formats.
+    #[error("colour {value:?} is not in `#rrggbb`, `rrggbb`, or `r,g,b` form")]
+    UnrecognisedFormat { value: String },
+    /// A decimal channel in `r,g,b` form was not a byte.
+    #[error("colour channel {channel:?} is not a number in 0..=255")]
+    InvalidChannel { channel: String },
+}
+
 /// RGB colour value.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+///
+/// Parses from `#rrggbb`, `rrggbb`, or decimal `r,g,b`, and displays as
+/// lowercase `#rrggbb`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, SerializeDisplay)]
 pub struct Rgb {
     /// Red channel.
     pub r: u8,
@@ -28,6 +47,70 @@ impl Rgb {
     pub fn new(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b }
     }
+
+    fn from_hex(digits: &str) -> Option<Self> {
+        if digits.len() != 6 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |start: usize| u8::from_str_radix(&digits[start..start + 2], 16).ok();
+        Some(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    fn from_decimal(value: &str) -> Result<Option<Self>, RgbParseError> {
+        let channels: Vec<&str> = value.split(',').map(str::trim).collect();
+        let [r, g, b] = channels.as_slice() else {
+            return Ok(None);
+        };
+        let channel = |channel: &str| {
+            channel
+                .parse::<u8>()
+                .map_err(|_| RgbParseError::InvalidChannel {
+                    channel: channel.to_string(),
+                })
+        };
+        Ok(Some(Self::new(channel(r)?, channel(g)?, channel(b)?)))
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = RgbParseError;
+
+    /// Parses `#rrggbb`, `rrggbb`, or decimal `r,g,b`.
+    ///
+    /// ```
+    /// use idm::Rgb;
+    ///
+    /// assert_eq!(Rgb::new(0x11, 0x22, 0x33), "#112233".parse()?);
+    /// assert_eq!(Rgb::new(0xAA, 0xBB, 0xCC), "aabbcc".parse()?);
+    /// assert_eq!(Rgb::new(255, 127, 0), "255,127,0".parse()?);
+    /// # Ok::<(), idm::RgbParseError>(())
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        if trimmed.contains(',') {
+            if let Some(colour) = Self::from_decimal(trimmed)? {
+                return Ok(colour);
+            }
+        } else if let Some(colour) = Self::from_hex(trimmed.strip_prefix('#').unwrap_or(trimmed)) {
+            return Ok(colour);
+        }
+        Err(RgbParseError::UnrecognisedFormat {
+            value: value.to_string(),
+        })
+    }
+}
+
+impl Display for Rgb {
+    /// Formats the colour as lowercase `#rrggbb`.
+    ///
+    /// ```
+    /// use idm::Rgb;
+    ///
+    /// assert_eq!("#ff7f00", Rgb::new(255, 127, 0).to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
 }
 
 /// Handler for full-screen colour fill commands.
@@ -35,7 +118,7 @@ pub struct FullscreenColourHandler;
 
 impl FullscreenColourHandler {
     fn frame_for(colour: Rgb) -> Result<Vec<u8>, FrameCodecError> {
-        FrameCodec::encode_short(0x02, 0x02, &[colour.r, colour.g, colour.b])
+        FrameCodec::encode_fullscreen_colour(colour)
     }
 
     /// Fills the panel with a single colour.
@@ -73,9 +156,65 @@ impl FullscreenColourHandler {
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     use super::*;
 
+    #[rstest]
+    #[case::hash_hex("#112233", Rgb::new(0x11, 0x22, 0x33))]
+    #[case::bare_hex("112233", Rgb::new(0x11, 0x22, 0x33))]
+    #[case::uppercase_hex("#AABBCC", Rgb::new(0xAA, 0xBB, 0xCC))]
+    #[case::decimal("17,34,51", Rgb::new(0x11, 0x22, 0x33))]
+    #[case::decimal_with_spaces(" 255, 0 ,128 ", Rgb::new(255, 0, 128))]
+    fn rgb_parses_supported_formats(#[case] input: &str, #[case] expected: Rgb) {
+        assert_eq!(Ok(expected), input.parse::<Rgb>());
+    }
+
+    #[rstest]
+    #[case::short_hex("#123")]
+    #[case::non_hex_digit("#12345g")]
+    #[case::two_channels("1,2")]
+    #[case::four_channels("1,2,3,4")]
+    #[case::empty("")]
+    fn rgb_rejects_unrecognised_formats(#[case] input: &str) {
+        assert_eq!(
+            Err(RgbParseError::UnrecognisedFormat {
+                value: input.to_string(),
+            }),
+            input.parse::<Rgb>()
+        );
+    }
+
+    #[rstest]
+    #[case::out_of_range("256,0,0", "256")]
+    #[case::negative("0,-1,0", "-1")]
+    #[case::not_a_number("0,0,blue", "blue")]
+    fn rgb_rejects_invalid_decimal_channels(#[case] input: &str, #[case] channel: &str) {
+        assert_eq!(
+            Err(RgbParseError::InvalidChannel {
+                channel: channel.to_string(),
+            }),
+            input.parse::<Rgb>()
+        );
+    }
+
+    #[rstest]
+    #[case::black(Rgb::new(0x00, 0x00, 0x00), "#000000")]
+    #[case::mixed(Rgb::new(0x11, 0xAB, 0xFF), "#11abff")]
+    fn rgb_display_round_trips_through_from_str(#[case] colour: Rgb, #[case] expected: &str) {
+        let rendered = colour.to_string();
+
+        assert_eq!(expected, rendered);
+        assert_eq!(Ok(colour), rendered.parse::<Rgb>());
+    }
+
+    #[test]
+    fn rgb_serialises_as_hex_string() {
+        let json = serde_json::to_value(Rgb::new(0x11, 0x22, 0x33)).expect("colour serialises");
+
+        assert_eq!(serde_json::json!("#112233"), json);
+    }
+
     #[test]
     fn frame_for_colour_matches_protocol() {
         let frame = FullscreenColourHandler::frame_for(Rgb::new(0x11, 0x22, 0x33))
@@ -1,3 +1,4 @@
+use std::num::NonZeroU8;
 use std::time::Duration;
 
 use bon::Builder;
@@ -8,9 +9,15 @@ use super::upload_common::{UploadAckError, apply_fragment_delay};
 use crate::error::ProtocolError;
 use crate::hw::{Ack, DeviceSession, PanelDimensions, SessionWriter};
 use crate::{
-    FrameCodec, GifAnimation, GifChunkFlag, GifHeaderFields, MediaHeaderTail, TransferFamily,
+    FrameCodec, GifAnimation, GifAnimationError, GifChunkFlag, GifHeaderFields,
+    ImagePreparationError, ImagePreparationOptions, ImagePreprocessor, MediaHeaderTail,
+    TransferFamily,
 };
 const POST_FINISH_SETTLE_DELAY: Duration = Duration::from_millis(500);
+const REPEAT_TRIGGER_DELAY: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_GIF_FRAMES: usize = 64;
+/// The GIF header carries the total payload length as a `u32`.
+const DEFAULT_MAX_GIF_PAYLOAD_LEN: usize = u32::MAX as usize;
 
 /// Errors returned by GIF upload operations.
 #[derive(Debug, Error)]
@@ -36,6 +43,51 @@ pub enum GifUploadError {
     },
     #[error("gif upload chunk size cannot be zero")]
     InvalidChunkSize,
+    #[error("gif upload requires known panel dimensions for this device")]
+    PanelDimensionsUnknown,
+    #[error("failed to resize gif to panel dimensions {panel_dimensions}")]
+    AutoResize {
+        panel_dimensions: PanelDimensions,
+        source: ImagePreparationError,
+    },
+    #[error("gif has too many frames: {frame_count} exceeds max {max_frames}")]
+    TooManyFrames {
+        frame_count: usize,
+        max_frames: usize,
+    },
+    #[error("failed to read gif frames")]
+    FrameDecode { source: GifAnimationError },
+    #[error("failed to rewrite gif frame delays to {delay:?}")]
+    FrameDelayOverride {
+        delay: Duration,
+        source: ImagePreparationError,
+    },
+}
+
+/// How a GIF upload paces its transport fragments.
+///
+/// Every mode waits for the device's notify ack after each 4K logical chunk.
+/// The modes differ only in whether fragments within a chunk are also spaced
+/// out by a fixed delay.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum GifPacing {
+    /// Sleeps `20 ms` after every transport fragment, as the vendor app does.
+    #[default]
+    FragmentDelay,
+    /// Writes fragments back to back and relies on the per-chunk ack alone.
+    ///
+    /// Suits devices that ack promptly, where the fragment delay only adds
+    /// latency. Devices that drop fragments under load may need the default.
+    NotifyOnly,
+}
+
+impl GifPacing {
+    fn fragment_delay_override(self) -> Option<Duration> {
+        match self {
+            Self::FragmentDelay => None,
+            Self::NotifyOnly => Some(Duration::ZERO),
+        }
+    }
 }
 
 /// GIF upload request parameters.
@@ -44,6 +96,12 @@ pub struct GifUploadRequest {
     gif: GifAnimation,
     #[builder(default = MediaHeaderTail::default())]
     media_header_tail: MediaHeaderTail,
+    auto_resize: Option<PanelDimensions>,
+    #[builder(default)]
+    allow_unknown_dimensions: bool,
+    #[builder(default)]
+    pacing: GifPacing,
+    frame_delay_override: Option<Duration>,
 }
 
 impl GifUploadRequest {
@@ -69,6 +127,10 @@ impl GifUploadRequest {
         Self {
             gif,
             media_header_tail: MediaHeaderTail::default(),
+            auto_resize: None,
+            allow_unknown_dimensions: false,
+            pacing: GifPacing::default(),
+            frame_delay_override: None,
         }
     }
 
@@ -161,6 +223,340 @@ impl GifUploadRequest {
         self.media_header_tail = media_header_tail;
         self
     }
+
+    /// Returns a request that resizes the GIF to `panel_dimensions` on mismatch.
+    ///
+    /// Without this, [`GifUploadHandler::upload`] rejects GIFs whose logical
+    /// screen differs from the panel. With it, mismatched GIFs are run through
+    /// the same transformation as the `image` command before upload.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifUploadRequest, PanelDimensions};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+    /// let request = GifUploadRequest::new(gif).with_auto_resize(panel);
+    /// assert_eq!(Some(panel), request.auto_resize());
+    /// ```
+    #[must_use]
+    pub fn with_auto_resize(mut self, panel_dimensions: PanelDimensions) -> Self {
+        self.auto_resize = Some(panel_dimensions);
+        self
+    }
+
+    /// Returns the panel dimensions mismatched GIFs are resized to, if enabled.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// assert_eq!(None, GifUploadRequest::new(gif).auto_resize());
+    /// ```
+    #[must_use]
+    pub fn auto_resize(&self) -> Option<PanelDimensions> {
+        self.auto_resize
+    }
+
+    /// Returns a request that may upload when the device panel dimensions are unknown.
+    ///
+    /// By default [`GifUploadHandler::upload`] refuses to send a GIF it cannot
+    /// check against the panel. This opt-out is for experts who know the GIF
+    /// fits a device whose profile is unresolved.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// let request = GifUploadRequest::new(gif).with_allow_unknown_dimensions(true);
+    /// assert!(request.allows_unknown_dimensions());
+    /// ```
+    #[must_use]
+    pub fn with_allow_unknown_dimensions(mut self, allow_unknown_dimensions: bool) -> Self {
+        self.allow_unknown_dimensions = allow_unknown_dimensions;
+        self
+    }
+
+    /// Returns whether uploads may proceed without known panel dimensions.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// assert!(!GifUploadRequest::new(gif).allows_unknown_dimensions());
+    /// ```
+    #[must_use]
+    pub fn allows_unknown_dimensions(&self) -> bool {
+        self.allow_unknown_dimensions
+    }
+
+    /// Returns a request using `pacing` between transport fragments.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifPacing, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// let request = GifUploadRequest::new(gif).with_pacing(GifPacing::NotifyOnly);
+    /// assert_eq!(GifPacing::NotifyOnly, request.pacing());
+    /// ```
+    #[must_use]
+    pub fn with_pacing(mut self, pacing: GifPacing) -> Self {
+        self.pacing = pacing;
+        self
+    }
+
+    /// Returns how transport fragments are paced.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifPacing, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// assert_eq!(GifPacing::FragmentDelay, GifUploadRequest::new(gif).pacing());
+    /// ```
+    #[must_use]
+    pub fn pacing(&self) -> GifPacing {
+        self.pacing
+    }
+
+    /// Returns a request that shows every frame for `delay` on the panel.
+    ///
+    /// The panel plays frames with the delays encoded in the GIF, so the
+    /// override re-encodes the animation before upload rather than pacing
+    /// the transfer. Delays are rounded down to GIF centiseconds; an override
+    /// of [`Duration::ZERO`] falls back to the one-centisecond floor the image
+    /// preprocessor also applies.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{GifAnimation, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// let request =
+    ///     GifUploadRequest::new(gif).with_frame_delay_override(Duration::from_millis(250));
+    /// assert_eq!(Some(Duration::from_millis(250)), request.frame_delay_override());
+    /// ```
+    #[must_use]
+    pub fn with_frame_delay_override(mut self, delay: Duration) -> Self {
+        self.frame_delay_override = Some(delay);
+        self
+    }
+
+    /// Returns the per-frame delay applied before upload, if overridden.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// assert_eq!(None, GifUploadRequest::new(gif).frame_delay_override());
+    /// ```
+    #[must_use]
+    pub fn frame_delay_override(&self) -> Option<Duration> {
+        self.frame_delay_override
+    }
+
+    /// Checks this request against a panel and upload limits without connecting.
+    ///
+    /// Returns every reason the upload would be rejected; an empty list means
+    /// the GIF passes.
+    ///
+    /// ```
+    /// use idm::{GifAnimation, GifUploadRequest, GifValidationLimits, PanelDimensions};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// let request = GifUploadRequest::new(gif);
+    /// let panel = PanelDimensions::new(1, 1).expect("1x1 should be valid dimensions");
+    ///
+    /// assert!(request.validate(panel, GifValidationLimits::default()).is_empty());
+    /// ```
+    #[must_use]
+    pub fn validate(
+        &self,
+        panel_dimensions: PanelDimensions,
+        limits: GifValidationLimits,
+    ) -> Vec<GifUploadError> {
+        let mut failures = Vec::new();
+
+        let gif_dimensions = self.gif.dimensions();
+        if gif_dimensions != panel_dimensions {
+            failures.push(GifUploadError::PanelDimensionsMismatch {
+                gif_dimensions,
+                device_dimensions: panel_dimensions,
+            });
+        }
+
+        match self.gif.frame_count() {
+            Ok(frame_count) if frame_count > limits.max_frames() => {
+                failures.push(GifUploadError::TooManyFrames {
+                    frame_count,
+                    max_frames: limits.max_frames(),
+                });
+            }
+            Ok(_frame_count) => {}
+            Err(source) => failures.push(GifUploadError::FrameDecode { source }),
+        }
+
+        let payload_len = self.payload().len();
+        if payload_len > limits.max_payload_len() {
+            failures.push(GifUploadError::PayloadTooLarge {
+                payload_len,
+                max_payload_len: limits.max_payload_len(),
+            });
+        }
+
+        failures
+    }
+
+    /// Resizes the GIF when auto-resize is enabled and its dimensions differ.
+    fn conform_to_auto_resize(mut self) -> Result<Self, GifUploadError> {
+        let Some(panel_dimensions) = self.auto_resize else {
+            return Ok(self);
+        };
+        if self.gif.dimensions() == panel_dimensions {
+            return Ok(self);
+        }
+
+        self.gif = ImagePreprocessor::prepare_gif(
+            self.gif.payload(),
+            panel_dimensions,
+            ImagePreparationOptions::default(),
+        )
+        .map_err(|source| GifUploadError::AutoResize {
+            panel_dimensions,
+            source,
+        })?;
+        Ok(self)
+    }
+
+    /// Rewrites every frame delay when a frame-delay override is set.
+    fn apply_frame_delay_override(mut self) -> Result<Self, GifUploadError> {
+        let Some(delay) = self.frame_delay_override else {
+            return Ok(self);
+        };
+
+        self.gif = self
+            .gif
+            .retimed(delay)
+            .map_err(|source| GifUploadError::FrameDelayOverride { delay, source })?;
+        Ok(self)
+    }
+}
+
+/// Limits checked by [`GifUploadRequest::validate`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Builder)]
+pub struct GifValidationLimits {
+    #[builder(default = DEFAULT_MAX_GIF_FRAMES)]
+    max_frames: usize,
+    #[builder(default = DEFAULT_MAX_GIF_PAYLOAD_LEN)]
+    max_payload_len: usize,
+}
+
+impl Default for GifValidationLimits {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl GifValidationLimits {
+    /// Returns the maximum accepted frame count.
+    ///
+    /// ```
+    /// use idm::GifValidationLimits;
+    ///
+    /// let limits = GifValidationLimits::builder().max_frames(8).build();
+    /// assert_eq!(8, limits.max_frames());
+    /// assert_eq!(64, GifValidationLimits::default().max_frames());
+    /// ```
+    #[must_use]
+    pub fn max_frames(&self) -> usize {
+        self.max_frames
+    }
+
+    /// Returns the maximum accepted payload length in bytes.
+    ///
+    /// Defaults to the largest length the GIF chunk header can describe.
+    ///
+    /// ```
+    /// use idm::GifValidationLimits;
+    ///
+    /// let limits = GifValidationLimits::builder().max_payload_len(4096).build();
+    /// assert_eq!(4096, limits.max_payload_len());
+    /// ```
+    #[must_use]
+    pub fn max_payload_len(&self) -> usize {
+        self.max_payload_len
+    }
 }
 
 /// GIF upload metadata returned on success.
@@ -302,15 +698,24 @@ impl GifUploadHandler {
         session: &DeviceSession,
         request: GifUploadRequest,
     ) -> Result<GifUploadReceipt, ProtocolError> {
-        if let Some(device_dimensions) = session.device_profile().panel_dimensions() {
-            let gif_dimensions = request.gif().dimensions();
-            if gif_dimensions != device_dimensions {
-                return Err(GifUploadError::PanelDimensionsMismatch {
-                    gif_dimensions,
-                    device_dimensions,
+        let request = request
+            .conform_to_auto_resize()?
+            .apply_frame_delay_override()?;
+        match session.device_profile().panel_dimensions() {
+            Some(device_dimensions) => {
+                let gif_dimensions = request.gif().dimensions();
+                if gif_dimensions != device_dimensions {
+                    return Err(GifUploadError::PanelDimensionsMismatch {
+                        gif_dimensions,
+                        device_dimensions,
+                    }
+                    .into());
                 }
-                .into());
             }
+            None if request.allows_unknown_dimensions() => {
+                tracing::debug!("uploading gif without known panel dimensions");
+            }
+            None => return Err(GifUploadError::PanelDimensionsUnknown.into()),
         }
 
         let payload = request.payload();
@@ -340,6 +745,7 @@ impl GifUploadHandler {
             .ack(Ack::Transfer(TransferFamily::Gif))
             .header(&encoder)
             .allow_early_finish(true)
+            .maybe_fragment_delay(request.pacing().fragment_delay_override())
             .build()
             .send()
             .await?;
@@ -366,6 +772,53 @@ impl GifUploadHandler {
             cached,
         ))
     }
+
+    /// Uploads the same GIF `times` times, pausing between sends.
+    ///
+    /// The GIF header has no loop-count field on any known profile, so each
+    /// repetition re-sends the payload to re-trigger playback. Panels that
+    /// still hold the material usually finish repeats as cache hits.
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), idm::ProtocolError> {
+    /// use std::num::NonZeroU8;
+    ///
+    /// use idm::{GifAnimation, GifUploadHandler, GifUploadRequest};
+    ///
+    /// # fn tiny_gif() -> Vec<u8> {
+    /// #     vec![
+    /// #         0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00,
+    /// #         0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+    /// #         0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    /// #     ]
+    /// # }
+    /// let gif = GifAnimation::try_from(tiny_gif()).expect("test gif should decode");
+    /// let times = NonZeroU8::new(3).expect("3 is non-zero");
+    /// let receipts =
+    ///     GifUploadHandler::upload_repeated(&session, GifUploadRequest::new(gif), times).await?;
+    /// assert_eq!(3, receipts.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first upload error; later repetitions are not attempted.
+    pub async fn upload_repeated(
+        session: &DeviceSession,
+        request: GifUploadRequest,
+        times: NonZeroU8,
+    ) -> Result<Vec<GifUploadReceipt>, ProtocolError> {
+        let mut receipts = Vec::with_capacity(usize::from(times.get()));
+        for repetition in 0..times.get() {
+            if repetition > 0 {
+                apply_fragment_delay(REPEAT_TRIGGER_DELAY).await;
+            }
+            receipts.push(Self::upload(session, request.clone()).await?);
+        }
+        Ok(receipts)
+    }
 }
 
 #[cfg(test)]
@@ -393,6 +846,72 @@ mod tests {
         assert_eq!(MediaHeaderTail::default(), request.media_header_tail());
     }
 
+    #[test]
+    fn validate_passes_gif_within_limits() {
+        let panel = PanelDimensions::new(1, 1).expect("1x1 should be valid");
+        let failures =
+            GifUploadRequest::new(tiny_gif()).validate(panel, GifValidationLimits::default());
+
+        assert_eq!(0, failures.len());
+    }
+
+    #[test]
+    fn validate_reports_every_failed_limit() {
+        let panel = PanelDimensions::new(32, 32).expect("32x32 should be valid");
+        let limits = GifValidationLimits::builder()
+            .max_frames(0)
+            .max_payload_len(16)
+            .build();
+        let failures = GifUploadRequest::new(tiny_gif()).validate(panel, limits);
+
+        assert_eq!(
+            vec![
+                "gif upload dimensions 1x1 do not match device panel dimensions 32x32".to_string(),
+                "gif has too many frames: 1 exceeds max 0".to_string(),
+                "gif upload payload is too large: 43 bytes exceeds max 16".to_string(),
+            ],
+            failures.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn auto_resize_conforms_mismatched_gif_to_panel() {
+        let panel = PanelDimensions::new(4, 4).expect("4x4 should be valid");
+        let request = GifUploadRequest::new(tiny_gif())
+            .with_auto_resize(panel)
+            .conform_to_auto_resize()
+            .expect("1x1 gif should resize to 4x4");
+
+        assert_eq!(panel, request.gif().dimensions());
+    }
+
+    #[test]
+    fn conform_leaves_gif_untouched_without_auto_resize() {
+        let request = GifUploadRequest::new(tiny_gif())
+            .conform_to_auto_resize()
+            .expect("strict request should pass through");
+
+        assert_eq!(tiny_gif(), request.gif().clone());
+    }
+
+    #[test]
+    fn frame_delay_override_retimes_gif_before_upload() {
+        let request = GifUploadRequest::new(tiny_gif())
+            .with_frame_delay_override(Duration::from_millis(500))
+            .apply_frame_delay_override()
+            .expect("tiny gif should re-encode");
+
+        let mut reader = gif::DecodeOptions::new()
+            .read_info(request.payload())
+            .expect("retimed payload should decode");
+        let frame = reader
+            .next_frame_info()
+            .expect("retimed frame should decode")
+            .expect("retimed gif should keep its frame");
+        assert_eq!(50, frame.delay);
+        assert_eq!(tiny_gif().dimensions(), request.gif().dimensions());
+    }
+
     #[test]
     fn gif_upload_receipt_accessors_return_constructor_values() {
         let receipt = GifUploadReceipt::new(4112, 9, 1, true);
@@ -0,0 +1,103 @@
+use crate::error::ProtocolError;
+use crate::hw::{Ack, DeviceSession, SessionWriter};
+use tracing::instrument;
+
+const JOINT_MODE_FRAME_LEN: u8 = 0x05;
+const JOINT_MODE_COMMAND_ID: u8 = 0x0C;
+const JOINT_MODE_COMMAND_NS: u8 = 0x80;
+const JOINT_MODE_OFF: u8 = 0x00;
+const DEFAULT_JOINT_MODE_ON: u8 = 0x01;
+
+/// Builds the `05 00 0C 80 {mode}` joint-mode frame.
+///
+/// Shared by the connect path, which applies the profile's canonical mode,
+/// and [`JointModeHandler`], so both always write identical bytes.
+pub(crate) const fn joint_mode_frame(mode: u8) -> [u8; 5] {
+    [
+        JOINT_MODE_FRAME_LEN,
+        0x00,
+        JOINT_MODE_COMMAND_ID,
+        JOINT_MODE_COMMAND_NS,
+        mode,
+    ]
+}
+
+/// Requested multi-panel joint-mode state.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JointMode {
+    /// Clear joint mode (`0x00`).
+    Off,
+    /// Enable the device's canonical joint mode.
+    On,
+}
+
+impl JointMode {
+    /// Returns the mode byte, using `canonical` for [`JointMode::On`] when the
+    /// resolved profile provides one and `0x01` otherwise.
+    pub(crate) fn as_payload_byte(self, canonical: Option<u8>) -> u8 {
+        match self {
+            Self::Off => JOINT_MODE_OFF,
+            Self::On => canonical.unwrap_or(DEFAULT_JOINT_MODE_ON),
+        }
+    }
+}
+
+/// Handler for explicit multi-panel joint-mode toggling.
+pub struct JointModeHandler;
+
+impl JointModeHandler {
+    /// Writes the joint-mode frame for `mode`.
+    ///
+    /// Enabling uses the canonical joint mode resolved for the session's LED
+    /// type (`1/2/5/6`), falling back to `0x01` when the profile has none.
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), idm::ProtocolError> {
+    /// use idm::{JointMode, JointModeHandler};
+    ///
+    /// JointModeHandler::set_joint_mode(&session, JointMode::On).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the device does not acknowledge the write.
+    #[instrument(skip(session), level = "debug", fields(?mode))]
+    pub async fn set_joint_mode(
+        session: &DeviceSession,
+        mode: JointMode,
+    ) -> Result<(), ProtocolError> {
+        let mode_byte = mode.as_payload_byte(session.device_profile().joint_mode());
+        let frame = joint_mode_frame(mode_byte);
+        SessionWriter::builder()
+            .session(session)
+            .payload(&frame)
+            .ack(Ack::Transport)
+            .build()
+            .send()
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::off(JointMode::Off, None, [0x05, 0x00, 0x0C, 0x80, 0x00])]
+    #[case::off_ignores_profile(JointMode::Off, Some(5), [0x05, 0x00, 0x0C, 0x80, 0x00])]
+    #[case::on_default(JointMode::On, None, [0x05, 0x00, 0x0C, 0x80, 0x01])]
+    #[case::on_canonical(JointMode::On, Some(6), [0x05, 0x00, 0x0C, 0x80, 0x06])]
+    fn frame_matches_protocol(
+        #[case] mode: JointMode,
+        #[case] canonical: Option<u8>,
+        #[case] expected: [u8; 5],
+    ) {
+        assert_eq!(expected, joint_mode_frame(mode.as_payload_byte(canonical)));
+    }
+}
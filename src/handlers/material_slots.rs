@@ -0,0 +1,318 @@
+use std::fmt;
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+
+use crate::error::ProtocolError;
+use crate::hw::{Ack, DeviceSession, SessionWriter};
+use crate::notification::NotifyEvent;
+use crate::protocol::EndpointId;
+
+use super::{FrameCodec, FrameCodecError, MaterialSlot, MaterialTimeSign};
+
+const MATERIAL_SLOTS_COMMAND_ID: u8 = 0x10;
+const MATERIAL_SLOTS_NAMESPACE: u8 = 0x80;
+const MATERIAL_SLOTS_QUERY_TIMEOUT: Duration = Duration::from_millis(1_000);
+const RESPONSE_HEADER_LEN: usize = 5;
+const SLOT_RECORD_LEN: usize = 3;
+
+/// Errors returned when listing the device's stored material slots.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+pub enum MaterialSlotsError {
+    /// The device did not answer the slot query before the deadline.
+    #[error(
+        "device did not answer the material-slot query within {timeout_ms}ms; listing stored slots is not supported by this device"
+    )]
+    Unsupported { timeout_ms: u64 },
+    /// The response is shorter than its declared slot count requires.
+    #[error(
+        "material-slot response declares {count} slot(s) but carries {actual} byte(s), expected {expected}"
+    )]
+    Truncated {
+        count: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// A slot record carries a material type byte this crate does not know.
+    #[error("material slot {slot} has unknown material type {value:#04X}")]
+    UnknownKind { slot: MaterialSlot, value: u8 },
+    /// A slot record carries a time-sign outside the supported range.
+    #[error("material slot {slot} has an invalid time-sign")]
+    InvalidTimeSign {
+        slot: MaterialSlot,
+        source: FrameCodecError,
+    },
+}
+
+/// Kind of material stored in a device slot.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MaterialKind {
+    /// Animated GIF material.
+    Gif,
+    /// Still image material.
+    Image,
+    /// Text material.
+    Text,
+}
+
+impl MaterialKind {
+    fn from_raw(value: u8) -> Option<Self> {
+        match value {
+            0x01 => Some(Self::Gif),
+            0x02 => Some(Self::Image),
+            0x03 => Some(Self::Text),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MaterialKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gif => write!(f, "gif"),
+            Self::Image => write!(f, "image"),
+            Self::Text => write!(f, "text"),
+        }
+    }
+}
+
+/// One occupied material slot reported by the device.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct StoredMaterialSlot {
+    slot: MaterialSlot,
+    kind: MaterialKind,
+    time_sign: MaterialTimeSign,
+}
+
+impl StoredMaterialSlot {
+    /// Creates a stored-slot record.
+    ///
+    /// ```
+    /// use idm::{MaterialKind, MaterialSlot, MaterialTimeSign, StoredMaterialSlot};
+    ///
+    /// let stored = StoredMaterialSlot::new(
+    ///     MaterialSlot::new(2),
+    ///     MaterialKind::Gif,
+    ///     MaterialTimeSign::TenSeconds,
+    /// );
+    /// assert_eq!(2, stored.slot().value());
+    /// ```
+    #[must_use]
+    pub const fn new(slot: MaterialSlot, kind: MaterialKind, time_sign: MaterialTimeSign) -> Self {
+        Self {
+            slot,
+            kind,
+            time_sign,
+        }
+    }
+
+    /// Returns the slot index.
+    #[must_use]
+    pub const fn slot(self) -> MaterialSlot {
+        self.slot
+    }
+
+    /// Returns the kind of material stored in the slot.
+    #[must_use]
+    pub const fn kind(self) -> MaterialKind {
+        self.kind
+    }
+
+    /// Returns the slot's stored time-sign.
+    #[must_use]
+    pub const fn time_sign(self) -> MaterialTimeSign {
+        self.time_sign
+    }
+
+    /// Returns how long the material is shown during slideshow playback.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{MaterialKind, MaterialSlot, MaterialTimeSign, StoredMaterialSlot};
+    ///
+    /// let stored = StoredMaterialSlot::new(
+    ///     MaterialSlot::new(0),
+    ///     MaterialKind::Text,
+    ///     MaterialTimeSign::ThirtySeconds,
+    /// );
+    /// assert_eq!(Duration::from_secs(30), stored.duration());
+    /// ```
+    #[must_use]
+    pub fn duration(self) -> Duration {
+        Duration::from_secs(u64::from(self.time_sign.duration_seconds()))
+    }
+}
+
+/// Handler for the experimental stored-material-slot query.
+pub struct MaterialSlotsHandler;
+
+impl MaterialSlotsHandler {
+    fn query_frame() -> Result<Vec<u8>, FrameCodecError> {
+        FrameCodec::encode_short(MATERIAL_SLOTS_COMMAND_ID, MATERIAL_SLOTS_NAMESPACE, &[])
+    }
+
+    fn is_response(payload: &[u8]) -> bool {
+        payload.len() >= RESPONSE_HEADER_LEN
+            && payload[2] == MATERIAL_SLOTS_COMMAND_ID
+            && payload[3] == MATERIAL_SLOTS_NAMESPACE
+    }
+
+    fn parse_response(payload: &[u8]) -> Result<Vec<StoredMaterialSlot>, MaterialSlotsError> {
+        let count = usize::from(payload[4]);
+        let expected = RESPONSE_HEADER_LEN + count * SLOT_RECORD_LEN;
+        let records =
+            payload
+                .get(RESPONSE_HEADER_LEN..expected)
+                .ok_or(MaterialSlotsError::Truncated {
+                    count,
+                    expected,
+                    actual: payload.len(),
+                })?;
+
+        records
+            .chunks_exact(SLOT_RECORD_LEN)
+            .map(|record| {
+                let slot = MaterialSlot::new(record[0]);
+                let kind =
+                    MaterialKind::from_raw(record[1]).ok_or(MaterialSlotsError::UnknownKind {
+                        slot,
+                        value: record[1],
+                    })?;
+                let time_sign = MaterialTimeSign::try_from(record[2])
+                    .map_err(|source| MaterialSlotsError::InvalidTimeSign { slot, source })?;
+                Ok(StoredMaterialSlot::new(slot, kind, time_sign))
+            })
+            .collect()
+    }
+
+    /// Queries which material slots are occupied on the device.
+    ///
+    /// The query frame is not used by the vendor app, so many devices will
+    /// not answer it; those are reported as [`MaterialSlotsError::Unsupported`].
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), idm::ProtocolError> {
+    /// use idm::MaterialSlotsHandler;
+    ///
+    /// for stored in MaterialSlotsHandler::read_slots(&session).await? {
+    ///     println!("{} {} {:?}", stored.slot(), stored.kind(), stored.duration());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the write fails, when no response arrives in
+    /// time, or when the response cannot be parsed.
+    #[instrument(skip(session), level = "debug")]
+    pub async fn read_slots(
+        session: &DeviceSession,
+    ) -> Result<Vec<StoredMaterialSlot>, ProtocolError> {
+        let frame = Self::query_frame()?;
+        let cancel = CancellationToken::new();
+        let mut stream = session
+            .notification_stream(EndpointId::ReadNotifyCharacteristic, None, cancel)
+            .await?;
+        SessionWriter::builder()
+            .session(session)
+            .payload(&frame)
+            .ack(Ack::None)
+            .build()
+            .send()
+            .await?;
+
+        let deadline = tokio::time::Instant::now() + MATERIAL_SLOTS_QUERY_TIMEOUT;
+        loop {
+            match tokio::time::timeout_at(deadline, stream.next()).await {
+                Ok(Some(Ok(message))) => {
+                    if let Ok(NotifyEvent::Unknown(payload)) = message.event
+                        && Self::is_response(&payload)
+                    {
+                        return Ok(Self::parse_response(&payload)?);
+                    }
+                }
+                Ok(Some(Err(error))) => return Err(error.into()),
+                Ok(None) | Err(_) => {
+                    return Err(MaterialSlotsError::Unsupported {
+                        timeout_ms: u64::try_from(MATERIAL_SLOTS_QUERY_TIMEOUT.as_millis())
+                            .unwrap_or(u64::MAX),
+                    }
+                    .into());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn query_frame_matches_protocol_shape() {
+        let frame = MaterialSlotsHandler::query_frame().expect("query frame should encode");
+        assert_eq!(vec![0x04, 0x00, 0x10, 0x80], frame);
+    }
+
+    #[test]
+    fn parse_response_lists_each_slot() {
+        let payload = [
+            0x0B, 0x00, 0x10, 0x80, 0x02, 0x00, 0x01, 0x01, 0x03, 0x03, 0x04,
+        ];
+
+        let slots = MaterialSlotsHandler::parse_response(&payload).expect("response should parse");
+
+        assert_eq!(
+            vec![
+                StoredMaterialSlot::new(
+                    MaterialSlot::new(0),
+                    MaterialKind::Gif,
+                    MaterialTimeSign::TenSeconds,
+                ),
+                StoredMaterialSlot::new(
+                    MaterialSlot::new(3),
+                    MaterialKind::Text,
+                    MaterialTimeSign::ThreeHundredSeconds,
+                ),
+            ],
+            slots
+        );
+    }
+
+    #[rstest]
+    #[case::truncated(
+        &[0x08, 0x00, 0x10, 0x80, 0x02, 0x00, 0x01, 0x01],
+        MaterialSlotsError::Truncated { count: 2, expected: 11, actual: 8 },
+    )]
+    #[case::unknown_kind(
+        &[0x08, 0x00, 0x10, 0x80, 0x01, 0x05, 0x09, 0x00],
+        MaterialSlotsError::UnknownKind { slot: MaterialSlot::new(5), value: 0x09 },
+    )]
+    fn parse_response_rejects_malformed_payloads(
+        #[case] payload: &[u8],
+        #[case] expected: MaterialSlotsError,
+    ) {
+        assert_eq!(Err(expected), MaterialSlotsHandler::parse_response(payload));
+    }
+
+    #[test]
+    fn parse_response_rejects_invalid_time_sign() {
+        let payload = [0x08, 0x00, 0x10, 0x80, 0x01, 0x01, 0x02, 0x07];
+        assert_matches!(
+            MaterialSlotsHandler::parse_response(&payload),
+            Err(MaterialSlotsError::InvalidTimeSign {
+                source: FrameCodecError::InvalidMaterialTimeSign { value: 0x07 },
+                ..
+            })
+        );
+    }
+}
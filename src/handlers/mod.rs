@@ -1,32 +1,52 @@
 mod brightness;
+mod factory_reset;
 mod frame_codec;
 mod fullscreen_colour;
 mod gif_upload;
 mod image_upload;
+mod joint_mode;
+mod material_slots;
 mod power;
+mod scene;
+mod schedule;
 mod screen_light_timeout;
+mod text_preview;
 mod text_upload;
 mod time_sync;
 pub(crate) mod upload_common;
 
 pub use self::brightness::{Brightness, BrightnessError, BrightnessHandler};
+pub use self::factory_reset::FactoryResetHandler;
 pub(crate) use self::frame_codec::{
-    DiyPrefixFields, FrameCodec, GifChunkFlag, GifHeaderFields, ImageHeaderFields, TextHeaderFields,
+    DecodedNotification, DiyPrefixFields, FrameCodec, GifChunkFlag, GifHeaderFields,
+    ImageHeaderFields, MediaHeader, MediaHeaderKind, ShortFrame, TextHeaderFields,
 };
 pub use self::frame_codec::{
     FrameCodecError, MaterialSlot, MaterialTimeSign, MediaHeaderTail, TimedMaterialSlot,
 };
-pub use self::fullscreen_colour::{FullscreenColourHandler, Rgb};
-pub use self::gif_upload::{GifUploadError, GifUploadHandler, GifUploadReceipt, GifUploadRequest};
+pub use self::fullscreen_colour::{FullscreenColourHandler, Rgb, RgbParseError};
+pub use self::gif_upload::{
+    GifPacing, GifUploadError, GifUploadHandler, GifUploadReceipt, GifUploadRequest,
+    GifValidationLimits,
+};
 pub use self::image_upload::{
     ImageUploadError, ImageUploadHandler, ImageUploadReceipt, ImageUploadRequest,
 };
+pub(crate) use self::joint_mode::joint_mode_frame;
+pub use self::joint_mode::{JointMode, JointModeHandler};
+pub use self::material_slots::{
+    MaterialKind, MaterialSlotsError, MaterialSlotsHandler, StoredMaterialSlot,
+};
 pub use self::power::{PowerHandler, ScreenPower};
+pub use self::scene::{SceneError, SceneHandler, SceneRequest, SceneStep};
+pub use self::schedule::{ScheduleEntry, ScheduleError, ScheduleHandler};
 pub use self::screen_light_timeout::{
-    ScreenLightTimeoutHandler, ScreenLightTimeoutProbe, ScreenLightTimeoutProbeOutcome,
+    ScreenLightTimeout, ScreenLightTimeoutError, ScreenLightTimeoutHandler,
+    ScreenLightTimeoutProbe, ScreenLightTimeoutProbeOutcome,
 };
 pub use self::text_upload::{
-    TextOptions, TextUploadError, TextUploadHandler, TextUploadRequest, UploadReceipt,
+    ScrollDirection, TextDisplayMode, TextFileError, TextOptions, TextSpeed, TextSpeedParseError,
+    TextUploadError, TextUploadHandler, TextUploadRequest, UploadReceipt,
 };
 pub use self::time_sync::TimeSyncHandler;
 pub use self::upload_common::UploadAckError;
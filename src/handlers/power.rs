@@ -14,7 +14,7 @@ pub enum ScreenPower {
 }
 
 impl ScreenPower {
-    fn as_payload_byte(self) -> u8 {
+    pub(super) fn as_payload_byte(self) -> u8 {
         match self {
             Self::Off => 0x00,
             Self::On => 0x01,
@@ -27,7 +27,7 @@ pub struct PowerHandler;
 
 impl PowerHandler {
     fn frame_for(power: ScreenPower) -> Result<Vec<u8>, FrameCodecError> {
-        FrameCodec::encode_short(0x07, 0x01, &[power.as_payload_byte()])
+        FrameCodec::encode_power(power)
     }
 
     /// Sends a screen power command.
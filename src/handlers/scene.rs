@@ -0,0 +1,266 @@
+use std::fmt;
+
+use bon::Builder;
+use thiserror::Error;
+use tracing::instrument;
+
+use crate::error::ProtocolError;
+use crate::hw::DeviceSession;
+
+use super::{
+    Brightness, BrightnessHandler, FullscreenColourHandler, PowerHandler, Rgb, ScreenPower,
+};
+
+/// One step of a scene, listed in the order [`SceneHandler::apply`] sends them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SceneStep {
+    /// Screen power state.
+    Power,
+    /// Fullscreen colour fill.
+    Colour,
+    /// Panel brightness.
+    Brightness,
+}
+
+impl fmt::Display for SceneStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Power => write!(f, "power"),
+            Self::Colour => write!(f, "colour"),
+            Self::Brightness => write!(f, "brightness"),
+        }
+    }
+}
+
+/// Error returned when one scene step fails.
+///
+/// Steps before the failed one were already sent and are not rolled back;
+/// [`SceneError::applied`] lists them so callers can report or retry.
+#[derive(Debug, Error)]
+#[error(
+    "scene {failed} step failed; already applied: {}",
+    describe_steps(applied)
+)]
+pub struct SceneError {
+    applied: Vec<SceneStep>,
+    failed: SceneStep,
+    source: ProtocolError,
+}
+
+impl SceneError {
+    fn new(applied: &[SceneStep], failed: SceneStep, source: ProtocolError) -> Self {
+        Self {
+            applied: applied.to_vec(),
+            failed,
+            source,
+        }
+    }
+
+    /// Returns the steps that were sent before the failure, in order.
+    #[must_use]
+    pub fn applied(&self) -> &[SceneStep] {
+        &self.applied
+    }
+
+    /// Returns the step that failed.
+    #[must_use]
+    pub fn failed(&self) -> SceneStep {
+        self.failed
+    }
+}
+
+fn describe_steps(steps: &[SceneStep]) -> String {
+    if steps.is_empty() {
+        return "none".to_string();
+    }
+    steps
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Power, colour, and brightness settings sent together over one session.
+///
+/// Omitted settings are left as they are on the device.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Builder)]
+pub struct SceneRequest {
+    power: Option<ScreenPower>,
+    colour: Option<Rgb>,
+    brightness: Option<Brightness>,
+}
+
+impl SceneRequest {
+    /// Returns the requested screen power state.
+    ///
+    /// ```
+    /// use idm::{SceneRequest, ScreenPower};
+    ///
+    /// let request = SceneRequest::builder().power(ScreenPower::On).build();
+    /// assert_eq!(Some(ScreenPower::On), request.power());
+    /// ```
+    #[must_use]
+    pub fn power(&self) -> Option<ScreenPower> {
+        self.power
+    }
+
+    /// Returns the requested fullscreen colour.
+    ///
+    /// ```
+    /// use idm::{Rgb, SceneRequest};
+    ///
+    /// let request = SceneRequest::builder().colour(Rgb::new(0x11, 0x22, 0x33)).build();
+    /// assert_eq!(Some(Rgb::new(0x11, 0x22, 0x33)), request.colour());
+    /// ```
+    #[must_use]
+    pub fn colour(&self) -> Option<Rgb> {
+        self.colour
+    }
+
+    /// Returns the requested brightness.
+    ///
+    /// ```
+    /// use idm::{Brightness, SceneRequest};
+    ///
+    /// let request = SceneRequest::builder().brightness(Brightness::new(40)?).build();
+    /// assert_eq!(Some(40), request.brightness().map(Brightness::value));
+    /// # Ok::<(), idm::BrightnessError>(())
+    /// ```
+    #[must_use]
+    pub fn brightness(&self) -> Option<Brightness> {
+        self.brightness
+    }
+
+    /// Returns the steps this request sends, in order.
+    ///
+    /// ```
+    /// use idm::{Rgb, SceneRequest, SceneStep, ScreenPower};
+    ///
+    /// let request = SceneRequest::builder()
+    ///     .power(ScreenPower::On)
+    ///     .colour(Rgb::new(0, 0, 255))
+    ///     .build();
+    /// assert_eq!(vec![SceneStep::Power, SceneStep::Colour], request.steps());
+    /// ```
+    #[must_use]
+    pub fn steps(&self) -> Vec<SceneStep> {
+        [
+            self.power.map(|_power| SceneStep::Power),
+            self.colour.map(|_colour| SceneStep::Colour),
+            self.brightness.map(|_brightness| SceneStep::Brightness),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Handler that applies a [`SceneRequest`] step by step.
+pub struct SceneHandler;
+
+impl SceneHandler {
+    /// Sends power, then colour, then brightness, skipping omitted settings.
+    ///
+    /// Returns the steps that were applied. The device has no transaction
+    /// support, so a failure part-way leaves earlier steps applied.
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), idm::ProtocolError> {
+    /// use idm::{Brightness, Rgb, SceneHandler, SceneRequest, ScreenPower};
+    ///
+    /// let request = SceneRequest::builder()
+    ///     .power(ScreenPower::On)
+    ///     .colour(Rgb::new(255, 128, 0))
+    ///     .brightness(Brightness::new(60)?)
+    ///     .build();
+    /// let applied = SceneHandler::apply(&session, request).await?;
+    /// assert_eq!(3, applied.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SceneError`] naming the failed step and the steps applied
+    /// before it when frame encoding or a BLE write fails.
+    #[instrument(skip(session), level = "debug", fields(?request))]
+    pub async fn apply(
+        session: &DeviceSession,
+        request: SceneRequest,
+    ) -> Result<Vec<SceneStep>, SceneError> {
+        let mut applied = Vec::with_capacity(3);
+        if let Some(power) = request.power {
+            PowerHandler::set_power(session, power)
+                .await
+                .map_err(|source| SceneError::new(&applied, SceneStep::Power, source))?;
+            applied.push(SceneStep::Power);
+        }
+        if let Some(colour) = request.colour {
+            FullscreenColourHandler::set_colour(session, colour)
+                .await
+                .map_err(|source| SceneError::new(&applied, SceneStep::Colour, source))?;
+            applied.push(SceneStep::Colour);
+        }
+        if let Some(brightness) = request.brightness {
+            BrightnessHandler::set_brightness(session, brightness)
+                .await
+                .map_err(|source| SceneError::new(&applied, SceneStep::Brightness, source))?;
+            applied.push(SceneStep::Brightness);
+        }
+        Ok(applied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::BrightnessError;
+
+    #[rstest]
+    #[case::empty(SceneRequest::default(), vec![])]
+    #[case::brightness_only(
+        SceneRequest::builder()
+            .brightness(Brightness::new(10).expect("10 is a valid brightness"))
+            .build(),
+        vec![SceneStep::Brightness],
+    )]
+    #[case::all(
+        SceneRequest::builder()
+            .brightness(Brightness::new(10).expect("10 is a valid brightness"))
+            .colour(Rgb::new(1, 2, 3))
+            .power(ScreenPower::On)
+            .build(),
+        vec![SceneStep::Power, SceneStep::Colour, SceneStep::Brightness],
+    )]
+    fn steps_follow_power_colour_brightness_order(
+        #[case] request: SceneRequest,
+        #[case] expected: Vec<SceneStep>,
+    ) {
+        assert_eq!(expected, request.steps());
+    }
+
+    #[rstest]
+    #[case::nothing_applied(vec![], SceneStep::Power, "scene power step failed; already applied: none")]
+    #[case::partial(
+        vec![SceneStep::Power, SceneStep::Colour],
+        SceneStep::Brightness,
+        "scene brightness step failed; already applied: power, colour",
+    )]
+    fn scene_error_reports_applied_steps(
+        #[case] applied: Vec<SceneStep>,
+        #[case] failed: SceneStep,
+        #[case] expected: &str,
+    ) {
+        let source = ProtocolError::from(BrightnessError::OutOfRange {
+            value: 101,
+            min: 0,
+            max: 100,
+        });
+        let error = SceneError::new(&applied, failed, source);
+        assert_eq!(expected, error.to_string());
+        assert_eq!(applied, error.applied());
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use thiserror::Error;
+use time::Time;
+use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+
+use crate::error::ProtocolError;
+use crate::hw::{Ack, DeviceSession, SessionWriter};
+use crate::notification::{NotifyEvent, ScheduleSetupStatus};
+use crate::protocol::EndpointId;
+
+use super::{FrameCodec, FrameCodecError};
+
+const SCHEDULE_SETUP_COMMAND_ID: u8 = 0x05;
+const SCHEDULE_NAMESPACE: u8 = 0x80;
+const SCHEDULE_ACK_TIMEOUT: Duration = Duration::from_millis(1_000);
+const ALL_WEEKDAYS: u8 = 0x7F;
+
+/// Errors returned by schedule setup commands.
+#[derive(Debug, Error, Clone, Copy, Eq, PartialEq)]
+pub enum ScheduleError {
+    /// The weekday mask selects no day, or sets a bit above Sunday.
+    #[error("weekday mask {mask:#04X} must select at least one day within 0x7F")]
+    InvalidWeekdays { mask: u8 },
+    /// The device did not acknowledge the setup before the deadline.
+    #[error("device did not acknowledge the schedule setup within {timeout_ms}ms")]
+    Unacknowledged { timeout_ms: u64 },
+    /// The device answered setup with `Continue`, asking for a queued theme
+    /// resource this command does not send.
+    #[error("device requested the next queued schedule resource instead of accepting the entry")]
+    ResourceRequested,
+    /// The device rejected the schedule entry.
+    #[error("device rejected the schedule entry with status {status:#04X}")]
+    SetupRejected { status: u8 },
+}
+
+/// One schedule window: the weekdays it repeats on and its on/off times.
+///
+/// Bit `0` of the weekday mask is Monday through bit `6` for Sunday,
+/// following the device's Monday=1..Sunday=7 day numbering. Times are sent
+/// to minute precision.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScheduleEntry {
+    weekdays: u8,
+    on_time: Time,
+    off_time: Time,
+}
+
+impl ScheduleEntry {
+    /// Creates a schedule entry.
+    ///
+    /// ```
+    /// use idm::ScheduleEntry;
+    /// use time::Time;
+    ///
+    /// let weekdays = 0b001_1111;
+    /// let on_time = Time::from_hms(7, 30, 0)?;
+    /// let entry = ScheduleEntry::new(weekdays, on_time, Time::from_hms(22, 0, 0)?)?;
+    /// assert_eq!(weekdays, entry.weekdays());
+    /// assert!(ScheduleEntry::new(0, Time::MIDNIGHT, Time::MIDNIGHT).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScheduleError::InvalidWeekdays`] when `weekdays` is zero or
+    /// sets bit `7`.
+    pub fn new(weekdays: u8, on_time: Time, off_time: Time) -> Result<Self, ScheduleError> {
+        if weekdays == 0 || weekdays & !ALL_WEEKDAYS != 0 {
+            return Err(ScheduleError::InvalidWeekdays { mask: weekdays });
+        }
+        Ok(Self {
+            weekdays,
+            on_time,
+            off_time,
+        })
+    }
+
+    /// Returns the weekday mask, bit `0` for Monday.
+    #[must_use]
+    pub const fn weekdays(self) -> u8 {
+        self.weekdays
+    }
+
+    /// Returns the time the schedule switches on.
+    #[must_use]
+    pub const fn on_time(self) -> Time {
+        self.on_time
+    }
+
+    /// Returns the time the schedule switches off.
+    #[must_use]
+    pub const fn off_time(self) -> Time {
+        self.off_time
+    }
+}
+
+/// Handler for schedule setup commands.
+pub struct ScheduleHandler;
+
+impl ScheduleHandler {
+    fn setup_frame(entry: ScheduleEntry) -> Result<Vec<u8>, FrameCodecError> {
+        FrameCodec::encode_short(
+            SCHEDULE_SETUP_COMMAND_ID,
+            SCHEDULE_NAMESPACE,
+            &[
+                entry.weekdays,
+                entry.on_time.hour(),
+                entry.on_time.minute(),
+                entry.off_time.hour(),
+                entry.off_time.minute(),
+            ],
+        )
+    }
+
+    fn setup_result(status: ScheduleSetupStatus) -> Result<(), ScheduleError> {
+        match status {
+            ScheduleSetupStatus::Success => Ok(()),
+            ScheduleSetupStatus::Continue => Err(ScheduleError::ResourceRequested),
+            ScheduleSetupStatus::Failed(status) => Err(ScheduleError::SetupRejected { status }),
+        }
+    }
+
+    /// Programs a schedule entry and waits for the setup acknowledgement.
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), Box<dyn std::error::Error>> {
+    /// use idm::{ScheduleEntry, ScheduleHandler};
+    /// use time::Time;
+    ///
+    /// let on_time = Time::from_hms(8, 0, 0)?;
+    /// let entry = ScheduleEntry::new(0x7F, on_time, Time::from_hms(23, 0, 0)?)?;
+    /// ScheduleHandler::set_schedule(&session, entry).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the write fails, when no `0x05`/`0x80` status
+    /// arrives in time, or when the status is `Continue` or a failure.
+    #[instrument(skip(session), level = "debug")]
+    pub async fn set_schedule(
+        session: &DeviceSession,
+        entry: ScheduleEntry,
+    ) -> Result<(), ProtocolError> {
+        let frame = Self::setup_frame(entry)?;
+        let cancel = CancellationToken::new();
+        let mut stream = session
+            .notification_stream(EndpointId::ReadNotifyCharacteristic, None, cancel)
+            .await?;
+        SessionWriter::builder()
+            .session(session)
+            .payload(&frame)
+            .ack(Ack::None)
+            .build()
+            .send()
+            .await?;
+
+        let deadline = tokio::time::Instant::now() + SCHEDULE_ACK_TIMEOUT;
+        loop {
+            match tokio::time::timeout_at(deadline, stream.next()).await {
+                Ok(Some(Ok(message))) => {
+                    if let Ok(NotifyEvent::ScheduleSetup(status)) = message.event {
+                        return Ok(Self::setup_result(status)?);
+                    }
+                }
+                Ok(Some(Err(error))) => return Err(error.into()),
+                Ok(None) | Err(_) => {
+                    return Err(ScheduleError::Unacknowledged {
+                        timeout_ms: u64::try_from(SCHEDULE_ACK_TIMEOUT.as_millis())
+                            .unwrap_or(u64::MAX),
+                    }
+                    .into());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn time(hour: u8, minute: u8) -> Time {
+        Time::from_hms(hour, minute, 0).expect("time used in tests should be valid")
+    }
+
+    #[test]
+    fn setup_frame_matches_protocol_shape() {
+        let entry = ScheduleEntry::new(0b001_1111, time(7, 30), time(22, 5))
+            .expect("weekday mask should be valid");
+
+        let frame = ScheduleHandler::setup_frame(entry).expect("setup frame should encode");
+
+        assert_eq!(
+            vec![0x09, 0x00, 0x05, 0x80, 0x1F, 0x07, 0x1E, 0x16, 0x05],
+            frame
+        );
+    }
+
+    #[rstest]
+    #[case::no_days(0x00)]
+    #[case::high_bit(0x80)]
+    fn entry_rejects_invalid_weekday_masks(#[case] mask: u8) {
+        assert_eq!(
+            Err(ScheduleError::InvalidWeekdays { mask }),
+            ScheduleEntry::new(mask, time(0, 0), time(0, 0))
+        );
+    }
+
+    #[rstest]
+    #[case::success(ScheduleSetupStatus::Success, Ok(()))]
+    #[case::continue_(ScheduleSetupStatus::Continue, Err(ScheduleError::ResourceRequested))]
+    #[case::failed(
+        ScheduleSetupStatus::Failed(0x02),
+        Err(ScheduleError::SetupRejected { status: 0x02 })
+    )]
+    fn setup_result_maps_status(
+        #[case] status: ScheduleSetupStatus,
+        #[case] expected: Result<(), ScheduleError>,
+    ) {
+        assert_eq!(expected, ScheduleHandler::setup_result(status));
+    }
+}
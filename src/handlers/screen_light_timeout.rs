@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use derive_more::Display;
+use thiserror::Error;
 use tokio::time::timeout;
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
@@ -19,6 +20,104 @@ const SCREEN_LIGHT_COMMAND_ID: u8 = 0x0F;
 const SCREEN_LIGHT_NAMESPACE: u8 = 0x80;
 const SCREEN_LIGHT_READ_SENTINEL: u8 = 0xFF;
 const SCREEN_LIGHT_QUERY_TIMEOUT: Duration = Duration::from_millis(1_000);
+const SECONDS_PER_MINUTE: u64 = 60;
+const MIN_TIMEOUT_MINUTES: u8 = 1;
+const MAX_TIMEOUT_MINUTES: u8 = SCREEN_LIGHT_READ_SENTINEL - 1;
+
+/// Errors returned by screen-light timeout validation and confirmation.
+#[derive(Debug, Error, Clone, Copy, Eq, PartialEq)]
+pub enum ScreenLightTimeoutError {
+    /// The timeout rounds to a minute count the device cannot store.
+    #[error("screen-light timeout of {minutes} minute(s) is out of range ({min}..={max})")]
+    OutOfRange { minutes: u64, min: u8, max: u8 },
+    /// The device did not report the new timeout before the deadline.
+    #[error("device did not confirm the screen-light timeout within {timeout_ms}ms")]
+    Unconfirmed { timeout_ms: u64 },
+    /// The device reported a different timeout from the one written.
+    #[error("device reported a screen-light timeout of {actual} minute(s), expected {expected}")]
+    Mismatch { expected: u8, actual: u8 },
+}
+
+/// Screen-light timeout in whole minutes, the device's storage granularity.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Display)]
+#[display("{_0}m")]
+pub struct ScreenLightTimeout(u8);
+
+impl ScreenLightTimeout {
+    /// Creates a timeout from a whole number of minutes.
+    ///
+    /// ```
+    /// use idm::ScreenLightTimeout;
+    ///
+    /// let timeout = ScreenLightTimeout::from_minutes(5)?;
+    /// assert_eq!(5, timeout.minutes());
+    /// assert!(ScreenLightTimeout::from_minutes(0).is_err());
+    /// # Ok::<(), idm::ScreenLightTimeoutError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `minutes` is outside `1..=254`; `255` is the
+    /// read-back sentinel.
+    pub fn from_minutes(minutes: u8) -> Result<Self, ScreenLightTimeoutError> {
+        if !(MIN_TIMEOUT_MINUTES..=MAX_TIMEOUT_MINUTES).contains(&minutes) {
+            return Err(ScreenLightTimeoutError::OutOfRange {
+                minutes: u64::from(minutes),
+                min: MIN_TIMEOUT_MINUTES,
+                max: MAX_TIMEOUT_MINUTES,
+            });
+        }
+        Ok(Self(minutes))
+    }
+
+    /// Creates a timeout from a duration rounded to the nearest minute.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::ScreenLightTimeout;
+    ///
+    /// let timeout = ScreenLightTimeout::from_duration(Duration::from_secs(90))?;
+    /// assert_eq!(2, timeout.minutes());
+    /// # Ok::<(), idm::ScreenLightTimeoutError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the rounded minute count is outside `1..=254`.
+    pub fn from_duration(duration: Duration) -> Result<Self, ScreenLightTimeoutError> {
+        let minutes = (duration.as_secs() + SECONDS_PER_MINUTE / 2) / SECONDS_PER_MINUTE;
+        let out_of_range = ScreenLightTimeoutError::OutOfRange {
+            minutes,
+            min: MIN_TIMEOUT_MINUTES,
+            max: MAX_TIMEOUT_MINUTES,
+        };
+        let minutes = u8::try_from(minutes).map_err(|_overflow| out_of_range)?;
+        Self::from_minutes(minutes).map_err(|_error| out_of_range)
+    }
+
+    /// Returns the timeout in whole minutes.
+    #[must_use]
+    pub fn minutes(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the timeout as a duration.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::ScreenLightTimeout;
+    ///
+    /// let timeout = ScreenLightTimeout::from_minutes(5)?;
+    /// assert_eq!(Duration::from_secs(300), timeout.duration());
+    /// # Ok::<(), idm::ScreenLightTimeoutError>(())
+    /// ```
+    #[must_use]
+    pub fn duration(self) -> Duration {
+        Duration::from_secs(u64::from(self.0) * SECONDS_PER_MINUTE)
+    }
+}
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Display)]
 pub enum ScreenLightTimeoutProbeOutcome {
@@ -403,6 +502,64 @@ impl ScreenLightTimeoutHandler {
             None => Ok(()),
         }
     }
+
+    /// Sets the screen-light timeout and waits for the device to report it back.
+    ///
+    /// ```
+    /// # async fn demo(session: idm::DeviceSession) -> Result<(), idm::ProtocolError> {
+    /// use std::time::Duration;
+    ///
+    /// use idm::{ScreenLightTimeout, ScreenLightTimeoutHandler};
+    ///
+    /// let timeout = ScreenLightTimeout::from_duration(Duration::from_secs(300))?;
+    /// ScreenLightTimeoutHandler::set_timeout_confirmed(&session, timeout).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the write fails, when no `0x0F`/`0x80` status
+    /// arrives in time, or when the reported value differs from `timeout`.
+    #[instrument(skip(session), level = "debug")]
+    pub async fn set_timeout_confirmed(
+        session: &DeviceSession,
+        timeout: ScreenLightTimeout,
+    ) -> Result<ScreenLightTimeout, ProtocolError> {
+        let cancel = CancellationToken::new();
+        let mut stream = session
+            .notification_stream(EndpointId::ReadNotifyCharacteristic, None, cancel)
+            .await?;
+        Self::set_timeout(session, timeout.minutes()).await?;
+
+        let deadline = tokio::time::Instant::now() + SCREEN_LIGHT_QUERY_TIMEOUT;
+        let reported = loop {
+            match tokio::time::timeout_at(deadline, stream.next()).await {
+                Ok(Some(Ok(message))) => {
+                    if let Ok(NotifyEvent::ScreenLightTimeout(value)) = message.event {
+                        break value;
+                    }
+                }
+                Ok(Some(Err(error))) => return Err(error.into()),
+                Ok(None) | Err(_) => {
+                    return Err(ScreenLightTimeoutError::Unconfirmed {
+                        timeout_ms: u64::try_from(SCREEN_LIGHT_QUERY_TIMEOUT.as_millis())
+                            .unwrap_or(u64::MAX),
+                    }
+                    .into());
+                }
+            }
+        };
+
+        if reported != timeout.minutes() {
+            return Err(ScreenLightTimeoutError::Mismatch {
+                expected: timeout.minutes(),
+                actual: reported,
+            }
+            .into());
+        }
+        Ok(timeout)
+    }
 }
 
 #[cfg(test)]
@@ -426,6 +583,32 @@ mod tests {
         assert_eq!(vec![0x05, 0x00, 0x0F, 0x80, 0xFF], frame);
     }
 
+    #[rstest]
+    #[case::whole_minutes(Duration::from_secs(300), 5)]
+    #[case::rounds_down(Duration::from_secs(89), 1)]
+    #[case::rounds_up(Duration::from_secs(90), 2)]
+    #[case::largest(Duration::from_secs(254 * 60), 254)]
+    fn from_duration_rounds_to_nearest_minute(#[case] duration: Duration, #[case] minutes: u8) {
+        let timeout =
+            ScreenLightTimeout::from_duration(duration).expect("duration should be in range");
+        assert_eq!(minutes, timeout.minutes());
+    }
+
+    #[rstest]
+    #[case::rounds_to_zero(Duration::from_secs(29), 0)]
+    #[case::read_sentinel(Duration::from_secs(255 * 60), 255)]
+    #[case::beyond_u8(Duration::from_secs(1_000 * 60), 1_000)]
+    fn from_duration_rejects_out_of_range(#[case] duration: Duration, #[case] minutes: u64) {
+        assert_eq!(
+            Err(ScreenLightTimeoutError::OutOfRange {
+                minutes,
+                min: 1,
+                max: 254,
+            }),
+            ScreenLightTimeout::from_duration(duration)
+        );
+    }
+
     #[rstest]
     #[case::valid_screen_light_response(&[0x05, 0x00, 0x0F, 0x80, 0x1E], Some(0x1E))]
     #[case::wrong_command_id(&[0x05, 0x00, 0x01, 0x80, 0x1E], None)]
@@ -0,0 +1,159 @@
+use crate::hw::{PanelDimensions, TextPath};
+use crate::{Rgb, Rgb888Frame};
+
+use super::TextUploadError;
+
+const GLYPH_PREFIX_LEN: usize = 4;
+
+/// Returns the text encoder path a panel of `panel` dimensions uses.
+///
+/// Panels outside the known sizes fall back to the 16x16 class, as text
+/// upload does when the device profile leaves the path unresolved.
+pub(super) fn text_path_for_panel(panel: PanelDimensions) -> TextPath {
+    match (panel.width(), panel.height()) {
+        (32, 8) | (8, 32) => TextPath::Path832,
+        (32, 32) => TextPath::Path3232,
+        (64, 64) => TextPath::Path6464,
+        (64, 16) | (16, 64) => TextPath::Path1664,
+        _ => TextPath::Path1616,
+    }
+}
+
+/// Draws an encoded glyph stream onto a `panel`-sized frame.
+///
+/// Glyphs are read back the way the device reads them: each 4-byte prefix
+/// names a bitmap size by its type tag, and each bitmap is row-major with
+/// the least significant bit of each byte leftmost. Glyphs are laid left to
+/// right from the panel's left edge and centred vertically, so the frame
+/// shows the opening view of the text; glyphs past the right edge are cut.
+pub(super) fn render_glyph_stream(
+    stream: &[u8],
+    panel: PanelDimensions,
+    text_colour: Rgb,
+    background_colour: Rgb,
+) -> Result<Rgb888Frame, TextUploadError> {
+    let panel_width = usize::from(panel.width());
+    let panel_height = usize::from(panel.height());
+    let mut pixels = [
+        background_colour.r,
+        background_colour.g,
+        background_colour.b,
+    ]
+    .repeat(panel_width * panel_height);
+
+    let mut offset = 0;
+    let mut cursor_x = 0;
+    while offset < stream.len() {
+        let (width, height) = stream
+            .get(offset)
+            .and_then(|tag| glyph_size(*tag))
+            .ok_or(TextUploadError::MalformedGlyphStream { offset })?;
+        let bitmap_start = offset + GLYPH_PREFIX_LEN;
+        let bitmap = stream
+            .get(bitmap_start..bitmap_start + width * height / 8)
+            .ok_or(TextUploadError::MalformedGlyphStream { offset })?;
+
+        let top = panel_height.saturating_sub(height) / 2;
+        for y in 0..height.min(panel_height) {
+            for x in 0..width {
+                let bit_index = y * width + x;
+                let lit = (bitmap[bit_index / 8] >> (bit_index % 8)) & 0x01 == 0x01;
+                let panel_x = cursor_x + x;
+                if !lit || panel_x >= panel_width {
+                    continue;
+                }
+                let pixel = ((top + y) * panel_width + panel_x) * 3;
+                pixels[pixel..pixel + 3].copy_from_slice(&[
+                    text_colour.r,
+                    text_colour.g,
+                    text_colour.b,
+                ]);
+            }
+        }
+
+        cursor_x += width;
+        offset = bitmap_start + bitmap.len();
+    }
+
+    Ok(Rgb888Frame::try_from((panel, pixels))?)
+}
+
+/// Returns the bitmap `(width, height)` for a glyph type tag.
+fn glyph_size(tag: u8) -> Option<(usize, usize)> {
+    match tag {
+        0x00 | 0x04 => Some((8, 8)),
+        0x01 => Some((16, 12)),
+        0x02 => Some((8, 16)),
+        0x03 => Some((16, 16)),
+        0x05 => Some((16, 32)),
+        0x06 => Some((32, 32)),
+        0x07 => Some((32, 64)),
+        0x08 => Some((64, 64)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn panel(width: u16, height: u16) -> PanelDimensions {
+        PanelDimensions::new(width, height).expect("test panel should be valid")
+    }
+
+    #[rstest]
+    #[case::path_832(panel(32, 8), TextPath::Path832)]
+    #[case::path_1616(panel(16, 16), TextPath::Path1616)]
+    #[case::path_3232(panel(32, 32), TextPath::Path3232)]
+    #[case::path_6464(panel(64, 64), TextPath::Path6464)]
+    #[case::path_1664(panel(64, 16), TextPath::Path1664)]
+    #[case::unknown_size(panel(24, 48), TextPath::Path1616)]
+    fn text_path_for_panel_matches_known_sizes(
+        #[case] panel: PanelDimensions,
+        #[case] expected: TextPath,
+    ) {
+        assert_eq!(expected, text_path_for_panel(panel));
+    }
+
+    #[test]
+    fn render_glyph_stream_lights_bitmap_bits_in_text_colour() {
+        // One 8x8 glyph with only the top-left and bottom-right pixels set.
+        let stream = [0x00, 0x00, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0, 0x80];
+        let text = Rgb::new(0xFF, 0x00, 0x00);
+        let background = Rgb::new(0x00, 0x00, 0x10);
+
+        let frame = render_glyph_stream(&stream, panel(8, 8), text, background)
+            .expect("stream should render");
+
+        let lit = frame
+            .payload()
+            .chunks_exact(3)
+            .enumerate()
+            .filter(|(_index, rgb)| *rgb == [0xFF, 0x00, 0x00])
+            .map(|(index, _rgb)| index)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 63], lit);
+        assert_eq!(&[0x00, 0x00, 0x10], &frame.payload()[3..6]);
+    }
+
+    #[rstest]
+    #[case::unknown_tag(&[0x09, 0x00, 0x00, 0x00])]
+    #[case::truncated_bitmap(&[0x02, 0xFF, 0xFF, 0xFF, 0x00])]
+    fn render_glyph_stream_rejects_malformed_streams(#[case] stream: &[u8]) {
+        let rendered = render_glyph_stream(
+            stream,
+            panel(16, 16),
+            Rgb::new(0xFF, 0xFF, 0xFF),
+            Rgb::new(0x00, 0x00, 0x00),
+        );
+
+        assert_matches!(
+            rendered,
+            Err(TextUploadError::MalformedGlyphStream { offset: 0 })
+        );
+    }
+}
@@ -1,17 +1,29 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
 use bon::Builder;
+use clap::ValueEnum;
 use font8x8::UnicodeFonts;
 use idm_macros::progress;
 use thiserror::Error;
 
 use crate::error::ProtocolError;
-use crate::hw::{Ack, DeviceSession, SessionWriter, TextPath};
-use crate::{FrameCodec, Rgb, TextHeaderFields, TransferFamily};
+use crate::hw::{
+    Ack, DeviceSession, PanelDimensions, SessionWriter, TextCharCountEndian, TextPath,
+};
+use crate::protocol::MediaHeaderLayout;
+use crate::{
+    FrameCodec, GifChunkFlag, Rgb, Rgb888Frame, TextHeaderFields, TransferFamily, Warning,
+};
 
 use super::FrameCodecError;
+use super::text_preview::{render_glyph_stream, text_path_for_panel};
 
 const METADATA_LEN: usize = 14;
 const FONT_BITMAP_WIDTH: usize = 8;
 const FONT_BITMAP_HEIGHT: usize = 8;
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
 
 /// Errors returned by text upload operations.
 #[derive(Debug, Error)]
@@ -24,6 +36,186 @@ pub enum TextUploadError {
     InvalidChunkSize,
     #[error("text upload path is unresolved for this device routing profile")]
     UnresolvedTextPath,
+    #[error(
+        "text mode {mode:#04x} has no equivalent on LED type 2 panels, which number modes one higher"
+    )]
+    TextModeOutOfRange { mode: u8 },
+    #[error("glyph stream is malformed at byte offset {offset}")]
+    MalformedGlyphStream { offset: usize },
+    #[error(transparent)]
+    PreviewFrame(#[from] crate::Rgb888FrameError),
+}
+
+/// Errors returned when reading upload text from a file.
+#[derive(Debug, Error)]
+pub enum TextFileError {
+    #[error("failed to read text file `{}`", path.display())]
+    ReadFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("text file `{}` is not valid UTF-8: invalid bytes at offset {offset}", path.display())]
+    InvalidUtf8 { path: PathBuf, offset: usize },
+}
+
+/// How uploaded text moves across the panel.
+///
+/// Each direction maps to the text metadata `mode` byte; LED type 2 panels
+/// still get their `+1` adjustment when the metadata is encoded.
+///
+/// ```
+/// use idm::ScrollDirection;
+///
+/// assert_eq!(0x00, ScrollDirection::Static.text_mode());
+/// assert_eq!(0x01, ScrollDirection::Left.text_mode());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ScrollDirection {
+    /// Show the text in place without scrolling.
+    #[default]
+    Static,
+    /// Scroll from right to left.
+    Left,
+    /// Scroll from left to right.
+    Right,
+    /// Scroll from bottom to top.
+    Up,
+    /// Scroll from top to bottom.
+    Down,
+}
+
+impl ScrollDirection {
+    /// Returns the text metadata `mode` byte for this direction.
+    #[must_use]
+    pub fn text_mode(self) -> u8 {
+        match self {
+            Self::Static => 0x00,
+            Self::Left => 0x01,
+            Self::Right => 0x02,
+            Self::Up => 0x03,
+            Self::Down => 0x04,
+        }
+    }
+}
+
+/// How uploaded text is presented, as a named choice of text `mode` byte.
+///
+/// Each display mode is one of the [`ScrollDirection`] modes:
+///
+/// | Display mode | Direction | `mode` |
+/// | ------------ | --------- | ------ |
+/// | `Static`     | `Static`  | `0x00` |
+/// | `Marquee`    | `Left`    | `0x01` |
+/// | `Scroll`     | `Up`      | `0x03` |
+///
+/// LED type 2 panels still get their `+1` adjustment when the metadata is
+/// encoded.
+///
+/// ```
+/// use idm::TextDisplayMode;
+///
+/// assert_eq!(0x00, TextDisplayMode::Static.text_mode());
+/// assert_eq!(0x01, TextDisplayMode::Marquee.text_mode());
+/// assert_eq!(0x03, TextDisplayMode::Scroll.text_mode());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum TextDisplayMode {
+    /// Roll the text up from the bottom edge, like film credits.
+    Scroll,
+    /// Show the text in place; suits short messages that fit the panel.
+    #[default]
+    Static,
+    /// Run the text right to left across the panel, like a ticker.
+    Marquee,
+}
+
+impl TextDisplayMode {
+    /// Returns the scroll direction this display mode uses.
+    #[must_use]
+    pub fn scroll_direction(self) -> ScrollDirection {
+        match self {
+            Self::Scroll => ScrollDirection::Up,
+            Self::Static => ScrollDirection::Static,
+            Self::Marquee => ScrollDirection::Left,
+        }
+    }
+
+    /// Returns the text metadata `mode` byte for this display mode.
+    #[must_use]
+    pub fn text_mode(self) -> u8 {
+        self.scroll_direction().text_mode()
+    }
+}
+
+/// Errors returned when parsing a [`TextSpeed`].
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum TextSpeedParseError {
+    /// The text was neither a preset name nor a byte.
+    #[error("text speed {value:?} is not `slow`, `normal`, `fast`, or a number in 0..=255")]
+    UnrecognisedSpeed { value: String },
+}
+
+/// Scroll speed for uploaded text, as a named preset or a raw metadata byte.
+///
+/// Higher bytes scroll faster. The presets map to:
+///
+/// | Preset   | Byte   |
+/// | -------- | ------ |
+/// | `slow`   | `0x10` |
+/// | `normal` | `0x20` |
+/// | `fast`   | `0x40` |
+///
+/// `normal` matches the default [`TextOptions`] speed.
+///
+/// ```
+/// use idm::TextSpeed;
+///
+/// assert_eq!(0x10, "slow".parse::<TextSpeed>()?.byte());
+/// assert_eq!(0x7F, "127".parse::<TextSpeed>()?.byte());
+/// # Ok::<(), idm::TextSpeedParseError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum TextSpeed {
+    /// Slow scrolling, byte `0x10`.
+    Slow,
+    /// Default scrolling, byte `0x20`.
+    #[default]
+    Normal,
+    /// Fast scrolling, byte `0x40`.
+    Fast,
+    /// Raw metadata speed byte, for experimenting with firmware behaviour.
+    Raw(u8),
+}
+
+impl TextSpeed {
+    /// Returns the text metadata `speed` byte.
+    #[must_use]
+    pub fn byte(self) -> u8 {
+        match self {
+            Self::Slow => 0x10,
+            Self::Normal => 0x20,
+            Self::Fast => 0x40,
+            Self::Raw(byte) => byte,
+        }
+    }
+}
+
+impl FromStr for TextSpeed {
+    type Err = TextSpeedParseError;
+
+    /// Parses `slow`, `normal`, `fast`, or a decimal byte.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "slow" => Ok(Self::Slow),
+            "normal" => Ok(Self::Normal),
+            "fast" => Ok(Self::Fast),
+            other => other.parse::<u8>().map(Self::Raw).map_err(|_| {
+                TextSpeedParseError::UnrecognisedSpeed {
+                    value: value.to_string(),
+                }
+            }),
+        }
+    }
 }
 
 /// Text upload rendering options.
@@ -87,6 +279,47 @@ impl TextOptions {
             font_size: 16,
         }
     }
+
+    /// Sets the text mode to scroll in `direction`, or to show static text.
+    ///
+    /// ```
+    /// use idm::{ScrollDirection, TextOptions};
+    ///
+    /// let options = TextOptions::default().with_scroll_direction(ScrollDirection::Up);
+    /// assert_eq!(TextOptions::builder().text_mode(0x03).build(), options);
+    /// ```
+    #[must_use]
+    pub fn with_scroll_direction(mut self, direction: ScrollDirection) -> Self {
+        self.text_mode = direction.text_mode();
+        self
+    }
+
+    /// Sets the text mode for `mode`.
+    ///
+    /// ```
+    /// use idm::{TextDisplayMode, TextOptions};
+    ///
+    /// let options = TextOptions::default().with_display_mode(TextDisplayMode::Marquee);
+    /// assert_eq!(TextOptions::builder().text_mode(0x01).build(), options);
+    /// ```
+    #[must_use]
+    pub fn with_display_mode(self, mode: TextDisplayMode) -> Self {
+        self.with_scroll_direction(mode.scroll_direction())
+    }
+
+    /// Sets the scroll speed from a preset or raw byte.
+    ///
+    /// ```
+    /// use idm::{TextOptions, TextSpeed};
+    ///
+    /// let options = TextOptions::default().with_speed(TextSpeed::Fast);
+    /// assert_eq!(TextOptions::builder().speed(0x40).build(), options);
+    /// ```
+    #[must_use]
+    pub fn with_speed(mut self, speed: TextSpeed) -> Self {
+        self.speed = speed.byte();
+        self
+    }
 }
 
 /// Text upload request.
@@ -113,6 +346,153 @@ impl TextUploadRequest {
             options: TextOptions::default(),
         }
     }
+
+    /// Replaces the rendering options.
+    ///
+    /// ```
+    /// use idm::{TextOptions, TextSpeed, TextUploadRequest};
+    ///
+    /// let options = TextOptions::default().with_speed(TextSpeed::Slow);
+    /// let request = TextUploadRequest::new("Hello").with_options(options);
+    /// assert_eq!(
+    ///     TextUploadRequest::builder().text("Hello".to_string()).options(options).build(),
+    ///     request
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_options(mut self, options: TextOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Creates a text upload request with default options from a UTF-8 file.
+    ///
+    /// A leading UTF-8 byte order mark is stripped so it is not rendered as
+    /// a glyph.
+    ///
+    /// ```no_run
+    /// use idm::TextUploadRequest;
+    ///
+    /// let request = TextUploadRequest::from_file("message.txt")?;
+    /// assert_eq!(TextUploadRequest::new("Hello"), request);
+    /// # Ok::<(), idm::TextFileError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the file cannot be read or is not valid UTF-8.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TextFileError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|source| TextFileError::ReadFile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let (bom_len, body) = match bytes.strip_prefix(UTF8_BOM) {
+            Some(body) => (UTF8_BOM.len(), body),
+            None => (0, bytes.as_slice()),
+        };
+        let text = std::str::from_utf8(body).map_err(|error| TextFileError::InvalidUtf8 {
+            path: path.to_path_buf(),
+            offset: bom_len + error.valid_up_to(),
+        })?;
+
+        Ok(Self::new(text))
+    }
+
+    /// Estimates how long the text takes to scroll once across a panel.
+    ///
+    /// The text scrolls in from one edge until its last glyph leaves the
+    /// other, so the distance is the glyph columns plus the panel width for
+    /// `text_path`. The firmware's speed units are undocumented; one unit of
+    /// `speed` is taken as one pixel column per second, so treat the result
+    /// as a rough guide.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{TextPath, TextUploadRequest};
+    ///
+    /// // Five 8-column glyphs plus the 16-column panel at the default speed of 32.
+    /// let estimate = TextUploadRequest::new("Hello").estimated_scroll_duration(TextPath::Path1616);
+    /// assert_eq!(Duration::from_millis(1_750), estimate);
+    /// ```
+    #[must_use]
+    pub fn estimated_scroll_duration(&self, text_path: TextPath) -> Duration {
+        let glyph_height = glyph_height(text_path, self.options.font_size);
+        let glyph_columns: u64 = self
+            .text
+            .chars()
+            .map(|ch| {
+                if is_wide_char(ch) {
+                    glyph_height
+                } else {
+                    glyph_height / 2
+                }
+            })
+            .sum();
+        let columns = glyph_columns + panel_width(text_path);
+        let columns_per_second = u64::from(self.options.speed.max(1));
+        Duration::from_millis(columns.saturating_mul(1_000) / columns_per_second)
+    }
+
+    /// Returns a warning when scrolling the text once would take longer than
+    /// `threshold`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{TextPath, TextUploadRequest, Warning};
+    ///
+    /// let request = TextUploadRequest::new("A".repeat(500));
+    /// let warning = request.scroll_duration_warning(TextPath::Path1616, Duration::from_secs(60));
+    /// assert!(matches!(warning, Some(Warning::TextScrollTooLong { .. })));
+    /// ```
+    #[must_use]
+    pub fn scroll_duration_warning(
+        &self,
+        text_path: TextPath,
+        threshold: Duration,
+    ) -> Option<Warning> {
+        let estimated = self.estimated_scroll_duration(text_path);
+        (estimated > threshold).then_some(Warning::TextScrollTooLong {
+            estimated,
+            threshold,
+        })
+    }
+
+    /// Renders the glyph stream a `panel`-sized device would receive.
+    ///
+    /// The glyphs are encoded for the text path matching `panel`, then drawn
+    /// back from their bitmaps in the text colour over the background colour.
+    /// The frame shows the text's opening view from the left edge, so it
+    /// checks the encoding without a device connected.
+    ///
+    /// ```
+    /// use idm::{PanelDimensions, TextUploadRequest};
+    ///
+    /// let panel = PanelDimensions::new(16, 16).expect("panel should be valid");
+    /// let frame = TextUploadRequest::new("Hi").render_preview(panel)?;
+    /// assert_eq!(16 * 16 * 3, frame.payload().len());
+    /// # Ok::<(), idm::ProtocolError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the text is empty.
+    pub fn render_preview(&self, panel: PanelDimensions) -> Result<Rgb888Frame, ProtocolError> {
+        let context = TextEncodingContext {
+            text_path: text_path_for_panel(panel),
+            led_type: None,
+            char_count_endian: TextCharCountEndian::default(),
+        };
+        let glyph_stream = encode_glyph_stream(&self.text, self.options, context)?;
+        Ok(render_glyph_stream(
+            &glyph_stream,
+            panel,
+            guarded_text_colour(self.options.text_colour),
+            self.options.background_colour,
+        )?)
+    }
 }
 
 /// Upload result metadata.
@@ -212,7 +592,8 @@ impl TextUploadHandler {
             let fields = TextHeaderFields::new(chunk_len, total_len, crc)?;
             let mut header = FrameCodec::encode_text_header(fields);
             if index > 0 {
-                header[4] = 0x02;
+                MediaHeaderLayout::CHUNK_FLAG
+                    .write(&mut header, GifChunkFlag::Continuation.as_protocol_byte());
             }
             Ok(header.to_vec())
         };
@@ -246,6 +627,7 @@ fn ensure_text_path_is_resolved(session: &DeviceSession) -> Result<(), ProtocolE
 struct TextEncodingContext {
     text_path: TextPath,
     led_type: Option<u8>,
+    char_count_endian: TextCharCountEndian,
 }
 
 fn encoding_context(session: &DeviceSession) -> TextEncodingContext {
@@ -253,6 +635,7 @@ fn encoding_context(session: &DeviceSession) -> TextEncodingContext {
     TextEncodingContext {
         text_path: profile.text_path().unwrap_or(TextPath::Path1616),
         led_type: profile.led_type(),
+        char_count_endian: profile.text_char_count_endian(),
     }
 }
 
@@ -293,11 +676,15 @@ fn encode_metadata(
         })?;
 
     let mut metadata = [0u8; METADATA_LEN];
-    metadata[0..2].copy_from_slice(&char_count_u16.to_le_bytes());
+    let char_count_bytes = match context.char_count_endian {
+        TextCharCountEndian::Little => char_count_u16.to_le_bytes(),
+        TextCharCountEndian::Big => char_count_u16.to_be_bytes(),
+    };
+    metadata[0..2].copy_from_slice(&char_count_bytes);
     let (resolution_flag_1, resolution_flag_2) = text_path_resolution_flags(context.text_path);
     metadata[2] = resolution_flag_1;
     metadata[3] = resolution_flag_2;
-    metadata[4] = adjusted_text_mode(options.text_mode, context.led_type);
+    metadata[4] = adjusted_text_mode(options.text_mode, context.led_type)?;
     metadata[5] = options.speed;
     metadata[6] = options.text_colour_mode;
     let text_colour = guarded_text_colour(options.text_colour);
@@ -311,6 +698,23 @@ fn encode_metadata(
     Ok(metadata)
 }
 
+/// Height in pixels of one rendered glyph; narrow glyphs are half as wide.
+fn glyph_height(text_path: TextPath, font_size: u8) -> u64 {
+    match text_path {
+        TextPath::Path832 | TextPath::Path1616 | TextPath::Path1664 => 16,
+        TextPath::Path3232 => u64::from(normalised_font_size(font_size).min(32)),
+        TextPath::Path6464 => u64::from(normalised_font_size(font_size)),
+    }
+}
+
+fn panel_width(text_path: TextPath) -> u64 {
+    match text_path {
+        TextPath::Path1616 => 16,
+        TextPath::Path832 | TextPath::Path3232 => 32,
+        TextPath::Path6464 | TextPath::Path1664 => 64,
+    }
+}
+
 fn text_path_resolution_flags(text_path: TextPath) -> (u8, u8) {
     match text_path {
         TextPath::Path832 | TextPath::Path1664 => (0x00, 0x01),
@@ -318,12 +722,41 @@ fn text_path_resolution_flags(text_path: TextPath) -> (u8, u8) {
     }
 }
 
-fn adjusted_text_mode(text_mode: u8, led_type: Option<u8>) -> u8 {
-    if led_type == Some(2) {
-        text_mode.saturating_add(1)
-    } else {
-        text_mode
+/// How a panel numbers the text metadata `mode` values.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TextModeNumbering {
+    /// The base values from the protocol table, used by most LED types.
+    Base,
+    /// Every base value plus one, used by LED type 2 (8x32) panels.
+    OffsetByOne,
+}
+
+impl TextModeNumbering {
+    fn for_led_type(led_type: Option<u8>) -> Self {
+        match led_type {
+            Some(2) => Self::OffsetByOne,
+            Some(_) | None => Self::Base,
+        }
     }
+
+    /// Returns the wire value for base `text_mode`, or `None` when this
+    /// numbering has no slot for it.
+    fn wire_mode(self, text_mode: u8) -> Option<u8> {
+        match self {
+            Self::Base => Some(text_mode),
+            Self::OffsetByOne => text_mode.checked_add(1),
+        }
+    }
+}
+
+/// Maps base `text_mode` to the value the panel's LED type expects.
+///
+/// Base mode `0xFF` has no LED type 2 equivalent and is rejected rather than
+/// clamped onto `0xFE`'s slot or wrapped onto static text.
+fn adjusted_text_mode(text_mode: u8, led_type: Option<u8>) -> Result<u8, TextUploadError> {
+    TextModeNumbering::for_led_type(led_type)
+        .wire_mode(text_mode)
+        .ok_or(TextUploadError::TextModeOutOfRange { mode: text_mode })
 }
 
 fn guarded_text_colour(text_colour: Rgb) -> Rgb {
@@ -481,10 +914,80 @@ mod tests {
 
     use super::*;
 
+    fn temp_text_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("idm-text-{name}-{}.txt", std::process::id()));
+        std::fs::write(&path, contents).expect("temp text file should be writable");
+        path
+    }
+
+    #[rstest]
+    #[case::bom_prefixed("bom", b"\xEF\xBB\xBFHello")]
+    #[case::plain("plain", b"Hello")]
+    fn from_file_reads_utf8_without_a_leading_bom(#[case] name: &str, #[case] contents: &[u8]) {
+        let path = temp_text_file(name, contents);
+
+        let request = TextUploadRequest::from_file(&path);
+        std::fs::remove_file(&path).expect("temp text file should be removable");
+
+        assert_eq!(
+            TextUploadRequest::new("Hello"),
+            request.expect("text file should decode")
+        );
+    }
+
+    #[test]
+    fn from_file_rejects_invalid_utf8_with_the_byte_offset() {
+        let path = temp_text_file("invalid", b"\xEF\xBB\xBFHi\xFF!");
+
+        let result = TextUploadRequest::from_file(&path);
+        std::fs::remove_file(&path).expect("temp text file should be removable");
+
+        assert_matches!(
+            result,
+            Err(TextFileError::InvalidUtf8 { path: error_path, offset: 5 }) if error_path == path
+        );
+    }
+
+    #[test]
+    fn scroll_duration_warning_fires_for_over_long_text() {
+        let request = TextUploadRequest::new("A".repeat(1_000));
+        let threshold = Duration::from_secs(60);
+
+        // 1000 eight-column glyphs plus the 16-column panel at 32 columns/s.
+        assert_eq!(
+            Some(Warning::TextScrollTooLong {
+                estimated: Duration::from_millis(250_500),
+                threshold,
+            }),
+            request.scroll_duration_warning(TextPath::Path1616, threshold)
+        );
+    }
+
+    #[rstest]
+    #[case::path_832(TextPath::Path832, 2_250)]
+    #[case::path_1616(TextPath::Path1616, 1_750)]
+    #[case::path_6464(TextPath::Path6464, 3_250)]
+    fn scroll_duration_stays_within_threshold_for_short_text(
+        #[case] text_path: TextPath,
+        #[case] expected_ms: u64,
+    ) {
+        let request = TextUploadRequest::new("Hello");
+
+        assert_eq!(
+            Duration::from_millis(expected_ms),
+            request.estimated_scroll_duration(text_path)
+        );
+        assert_eq!(
+            None,
+            request.scroll_duration_warning(text_path, Duration::from_secs(60))
+        );
+    }
+
     fn context(text_path: TextPath, led_type: Option<u8>) -> TextEncodingContext {
         TextEncodingContext {
             text_path,
             led_type,
+            char_count_endian: TextCharCountEndian::Little,
         }
     }
 
@@ -504,6 +1007,24 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::little(TextCharCountEndian::Little, [0x2C, 0x01])]
+    #[case::big(TextCharCountEndian::Big, [0x01, 0x2C])]
+    fn metadata_char_count_follows_profile_endianness(
+        #[case] char_count_endian: TextCharCountEndian,
+        #[case] expected: [u8; 2],
+    ) {
+        let text = "A".repeat(300);
+        let context = TextEncodingContext {
+            char_count_endian,
+            ..context(TextPath::Path1616, None)
+        };
+        let metadata = encode_metadata(&text, TextOptions::default(), context)
+            .expect("metadata should encode");
+
+        assert_eq!(expected, [metadata[0], metadata[1]]);
+    }
+
     #[test]
     fn metadata_applies_led_type_mode_adjustment_and_colour_guard() {
         let options =
@@ -517,6 +1038,111 @@ mod tests {
         assert_eq!(0x01, metadata[9]);
     }
 
+    #[rstest]
+    #[case::static_text(ScrollDirection::Static, None, 0x00)]
+    #[case::left(ScrollDirection::Left, None, 0x01)]
+    #[case::right(ScrollDirection::Right, None, 0x02)]
+    #[case::up(ScrollDirection::Up, None, 0x03)]
+    #[case::down(ScrollDirection::Down, None, 0x04)]
+    #[case::static_led_type_2(ScrollDirection::Static, Some(2), 0x01)]
+    #[case::left_led_type_2(ScrollDirection::Left, Some(2), 0x02)]
+    #[case::right_led_type_2(ScrollDirection::Right, Some(2), 0x03)]
+    #[case::up_led_type_2(ScrollDirection::Up, Some(2), 0x04)]
+    #[case::down_led_type_2(ScrollDirection::Down, Some(2), 0x05)]
+    fn metadata_encodes_scroll_direction_mode(
+        #[case] direction: ScrollDirection,
+        #[case] led_type: Option<u8>,
+        #[case] expected_mode: u8,
+    ) {
+        let options = TextOptions::builder()
+            .text_colour(Rgb::new(0, 0, 0))
+            .build()
+            .with_scroll_direction(direction);
+        let metadata = encode_metadata("A", options, context(TextPath::Path832, led_type))
+            .expect("metadata should encode");
+
+        assert_eq!(expected_mode, metadata[4]);
+        assert_eq!([0x00, 0x00, 0x01], [metadata[7], metadata[8], metadata[9]]);
+    }
+
+    #[rstest]
+    #[case::unknown_led_type(None, 0x01, 0x01)]
+    #[case::led_type_0(Some(0), 0x01, 0x01)]
+    #[case::led_type_1(Some(1), 0x01, 0x01)]
+    #[case::led_type_2(Some(2), 0x01, 0x02)]
+    #[case::led_type_3(Some(3), 0x01, 0x01)]
+    #[case::led_type_1_highest_mode(Some(1), 0xFF, 0xFF)]
+    #[case::led_type_2_near_saturation(Some(2), 0xFE, 0xFF)]
+    fn adjusted_text_mode_offsets_only_led_type_2(
+        #[case] led_type: Option<u8>,
+        #[case] text_mode: u8,
+        #[case] expected: u8,
+    ) {
+        assert_matches!(adjusted_text_mode(text_mode, led_type), Ok(mode) if mode == expected);
+    }
+
+    #[test]
+    fn adjusted_text_mode_rejects_highest_mode_on_led_type_2() {
+        assert_matches!(
+            adjusted_text_mode(0xFF, Some(2)),
+            Err(TextUploadError::TextModeOutOfRange { mode: 0xFF })
+        );
+    }
+
+    #[rstest]
+    #[case::static_text(TextDisplayMode::Static, None, 0x00)]
+    #[case::marquee(TextDisplayMode::Marquee, None, 0x01)]
+    #[case::scroll(TextDisplayMode::Scroll, None, 0x03)]
+    #[case::static_led_type_2(TextDisplayMode::Static, Some(2), 0x01)]
+    #[case::marquee_led_type_2(TextDisplayMode::Marquee, Some(2), 0x02)]
+    #[case::scroll_led_type_2(TextDisplayMode::Scroll, Some(2), 0x04)]
+    fn metadata_encodes_display_mode(
+        #[case] mode: TextDisplayMode,
+        #[case] led_type: Option<u8>,
+        #[case] expected_mode: u8,
+    ) {
+        let options = TextOptions::default().with_display_mode(mode);
+        let metadata = encode_metadata("A", options, context(TextPath::Path832, led_type))
+            .expect("metadata should encode");
+
+        assert_eq!(expected_mode, metadata[4]);
+    }
+
+    #[rstest]
+    #[case::slow("slow", 0x10)]
+    #[case::normal("normal", 0x20)]
+    #[case::fast("fast", 0x40)]
+    #[case::preset_any_case(" FAST ", 0x40)]
+    #[case::raw_zero("0", 0x00)]
+    #[case::raw_mid("100", 0x64)]
+    #[case::raw_max("255", 0xFF)]
+    fn text_speed_parses_presets_and_raw_bytes(#[case] value: &str, #[case] expected: u8) {
+        let speed = value.parse::<TextSpeed>().expect("speed should parse");
+
+        assert_eq!(expected, speed.byte());
+    }
+
+    #[rstest]
+    #[case::unknown_preset("brisk")]
+    #[case::out_of_range("256")]
+    #[case::negative("-1")]
+    fn text_speed_rejects_unrecognised_values(#[case] value: &str) {
+        assert_eq!(
+            Err(TextSpeedParseError::UnrecognisedSpeed {
+                value: value.to_string(),
+            }),
+            value.parse::<TextSpeed>()
+        );
+    }
+
+    #[test]
+    fn normal_text_speed_matches_default_options() {
+        assert_eq!(
+            TextOptions::default(),
+            TextOptions::default().with_speed(TextSpeed::Normal)
+        );
+    }
+
     #[test]
     fn metadata_rejects_empty_text() {
         let result = encode_metadata(
@@ -553,6 +1179,63 @@ mod tests {
         assert_eq!(4 + 8, stream.len());
     }
 
+    #[rstest]
+    #[case::path_1616(16, 16, 8, 16)]
+    #[case::path_832(32, 8, 8, 8)]
+    #[case::path_3232(32, 32, 8, 16)]
+    fn render_preview_lights_the_font_bitmap_pixels(
+        #[case] panel_width: u16,
+        #[case] panel_height: u16,
+        #[case] glyph_width: usize,
+        #[case] glyph_height: usize,
+    ) {
+        let panel = PanelDimensions::new(panel_width, panel_height).expect("panel should be valid");
+        let options = TextOptions::builder()
+            .text_colour(Rgb::new(0xFF, 0x00, 0x00))
+            .background_colour(Rgb::new(0x00, 0x00, 0x20))
+            .build();
+        let font = font_bitmap_exact('A').expect("font should cover 'A'");
+        let top = usize::from(panel_height).saturating_sub(glyph_height) / 2;
+
+        let frame = TextUploadRequest::new("A")
+            .with_options(options)
+            .render_preview(panel)
+            .expect("preview should render");
+
+        let expected = (0..usize::from(panel_height))
+            .flat_map(|y| (0..usize::from(panel_width)).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let in_glyph = x < glyph_width && (top..top + glyph_height).contains(&y);
+                let lit = in_glyph
+                    && (font[(y - top) * FONT_BITMAP_HEIGHT / glyph_height]
+                        >> (x * FONT_BITMAP_WIDTH / glyph_width))
+                        & 0x01
+                        == 0x01;
+                if lit {
+                    [0xFF, 0x00, 0x00]
+                } else {
+                    [0x00, 0x00, 0x20]
+                }
+            })
+            .collect::<Vec<_>>();
+        let actual = frame
+            .payload()
+            .chunks_exact(3)
+            .map(|rgb| [rgb[0], rgb[1], rgb[2]])
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn render_preview_rejects_empty_text() {
+        let panel = PanelDimensions::new(16, 16).expect("panel should be valid");
+
+        assert_matches!(
+            TextUploadRequest::new("").render_preview(panel),
+            Err(ProtocolError::TextUpload(_))
+        );
+    }
+
     #[rstest]
     #[case('A', false)]
     #[case('?', false)]
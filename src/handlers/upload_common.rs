@@ -7,7 +7,7 @@ use tracing::{Span, instrument};
 
 use crate::error::{InteractionError, ProtocolError};
 use crate::hw::NotificationSubscription;
-use crate::{NotificationDecodeError, NotifyEvent, TransferFamily};
+use crate::{AckPolicy, NotificationDecodeError, NotifyEvent, TransferFamily};
 
 const DRAIN_NOTIFICATION_TIMEOUT: Duration = Duration::from_millis(25);
 const MAX_STALE_NOTIFICATION_DRAIN: usize = 8;
@@ -80,8 +80,11 @@ pub enum UploadAckError {
 }
 
 /// Waits for one acknowledgement event for the requested transfer family.
+///
+/// `ack_policy` reclassifies firmware-specific error statuses before the
+/// event is interpreted.
 #[instrument(
-    skip(stream),
+    skip(stream, ack_policy),
     level = "trace",
     fields(
         timeout_ms = timeout_duration.as_millis(),
@@ -94,6 +97,7 @@ pub(crate) async fn wait_for_transfer_ack(
     stream: &mut NotificationSubscription,
     timeout_duration: Duration,
     transfer_family: TransferFamily,
+    ack_policy: AckPolicy,
 ) -> Result<UploadAckOutcome, UploadAckError> {
     let span = Span::current();
     match timeout(timeout_duration, stream.next()).await {
@@ -112,7 +116,7 @@ pub(crate) async fn wait_for_transfer_ack(
         }
         Ok(Some(Ok(message))) => {
             let event = match message.event {
-                Ok(event) => event,
+                Ok(event) => ack_policy.classify(event),
                 Err(error) => {
                     span.record("notify_event_kind", "decode_error");
                     return Err(UploadAckError::from(error));
@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicU8, Ordering};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use async_trait::async_trait;
 use btleplug::api::{
@@ -17,10 +17,13 @@ use tokio_stream::StreamExt;
 use tracing::{debug, info, instrument, trace};
 
 use super::DeviceProfile;
-use super::hardware::{ConnectedBleSession, PayloadStream, WriteMode, missing_required_endpoints};
+use super::connection_observer::{ConnectionEvent, ConnectionObserver, NoopConnectionObserver};
+use super::hardware::{
+    ConnectAttempt, ConnectedBleSession, PayloadStream, WriteMode, missing_required_endpoints,
+};
 use super::model::{
-    CharacteristicInfo, EndpointPresence, FoundDevice, InspectReport, LedInfoQueryOutcome,
-    ServiceInfo, SessionMetadata,
+    CharacteristicInfo, EndpointPresence, EndpointProbe, FoundDevice, InspectReport,
+    LedInfoQueryOutcome, ServiceInfo, SessionMetadata,
 };
 use super::model_overrides::{ModelOverrideStore, ModelResolutionConfig, is_supported_led_type};
 use super::model_resolution_diagnostics::{
@@ -28,15 +31,20 @@ use super::model_resolution_diagnostics::{
     model_resolution_diagnostics,
 };
 use super::profile::{resolve_device_profile, resolve_device_routing_profile};
+use super::profile_cache::ProfileCache;
 use super::scan_model::{ScanIdentity, ScanModelHandler};
-use super::session::{NegotiatedSessionEndpoints, negotiate_session_endpoints};
+use super::session::{
+    NegotiatedSessionEndpoints, negotiate_session_endpoints, probe_session_endpoints,
+};
 use crate::error::InteractionError;
+use crate::handlers::joint_mode_frame;
 use crate::protocol::{self, EndpointId};
 
-const LED_INFO_QUERY_TIMEOUT_MS: u64 = 1_000;
 const GET_LED_INFO_QUERY: [u8; 4] = [0x04, 0x00, 0x01, 0x80];
+const SYNC_TIME_MODE_SUFFIX: &str = ":sync_time";
 const CONNECT_LOCAL_ABORT_MAX_ATTEMPTS: usize = 3;
 const CONNECT_LOCAL_ABORT_BASE_BACKOFF_MS: u64 = 150;
+const MIN_SCAN_POLL_INTERVAL: Duration = Duration::from_millis(10);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[repr(u8)]
@@ -75,7 +83,7 @@ impl ConnectionStateCell {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 struct LedInfoQueryResult {
     led_info: Option<super::LedInfoResponse>,
     outcome: LedInfoQueryOutcome,
@@ -160,10 +168,11 @@ impl BtleplugBackend {
     }
 
     /// Scans indefinitely until the first matching peripheral appears, then connects.
-    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix))]
+    #[instrument(skip(self, observer), level = "debug", fields(prefix = name_prefix))]
     async fn find_and_connect_first_matching(
         &self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
     ) -> Result<ConnectedPeripheral, InteractionError> {
         let adapters = self.adapters().await?;
         info!(
@@ -174,6 +183,9 @@ impl BtleplugBackend {
         for adapter in &adapters {
             adapter.adapter.start_scan(ScanFilter::default()).await?;
         }
+        observer.on_event(&ConnectionEvent::ScanStarted {
+            name_prefix: name_prefix.to_string(),
+        });
 
         loop {
             for adapter in &adapters {
@@ -182,14 +194,10 @@ impl BtleplugBackend {
                     let Some(properties) = peripheral.properties().await? else {
                         continue;
                     };
-                    let scan_identity = scan_identity_from_properties(&properties);
-                    let scan_properties_debug = scan_properties_debug_from_properties(&properties);
-
-                    let rssi = properties.rssi;
-                    let local_name = properties.local_name;
-                    if !matches_name_prefix(local_name.as_deref(), name_prefix) {
+                    if !matches_name_prefix(properties.local_name.as_deref(), name_prefix) {
                         continue;
                     }
+                    let scan_properties_debug = scan_properties_debug_from_properties(&properties);
 
                     for handle in &adapters {
                         if let Err(error) = handle.adapter.stop_scan().await {
@@ -199,23 +207,13 @@ impl BtleplugBackend {
 
                     connect_and_discover_services_with_retry(&peripheral).await?;
 
-                    let device = FoundDevice::new(
-                        adapter.name.clone(),
-                        peripheral.id().to_string(),
-                        local_name,
-                        rssi,
-                    );
-                    let device = match scan_identity {
-                        Some(scan_identity) => {
-                            let model_profile = ScanModelHandler::resolve_model(&scan_identity);
-                            device.with_scan_model(scan_identity, model_profile)
-                        }
-                        None => device,
-                    };
+                    let device =
+                        found_device_from_properties(&adapter.name, &peripheral, properties);
                     info!(
                         device_id = %device.device_id_display(),
                         "connected to matching peripheral"
                     );
+                    observer.on_event(&ConnectionEvent::DeviceFound(device.clone()));
                     return Ok(ConnectedPeripheral {
                         adapter: adapter.adapter.clone(),
                         peripheral,
@@ -225,10 +223,74 @@ impl BtleplugBackend {
                 }
             }
 
-            sleep(Duration::from_millis(250)).await;
+            sleep(scan_poll_delay(&self.model_resolution)).await;
         }
     }
 
+    /// Scans for `scan_window`, then connects to every matching peripheral
+    /// one after another, returning once all connections are attempted.
+    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix, ?scan_window))]
+    async fn find_and_connect_all_matching(
+        &self,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<ConnectAttempt<ConnectedPeripheral>>, InteractionError> {
+        let adapters = self.adapters().await?;
+        for adapter in &adapters {
+            adapter.adapter.start_scan(ScanFilter::default()).await?;
+        }
+        sleep(scan_window).await;
+
+        let mut seen = HashSet::new();
+        let mut candidates = Vec::new();
+        for adapter in &adapters {
+            for peripheral in adapter.adapter.peripherals().await? {
+                let Some(properties) = peripheral.properties().await? else {
+                    continue;
+                };
+                if !matches_name_prefix(properties.local_name.as_deref(), name_prefix)
+                    || !seen.insert(peripheral.id())
+                {
+                    continue;
+                }
+                let scan_properties_debug = scan_properties_debug_from_properties(&properties);
+                let device = found_device_from_properties(&adapter.name, &peripheral, properties);
+                candidates.push(ConnectedPeripheral {
+                    adapter: adapter.adapter.clone(),
+                    peripheral,
+                    device,
+                    scan_properties_debug,
+                });
+            }
+        }
+        for handle in &adapters {
+            if let Err(error) = handle.adapter.stop_scan().await {
+                debug!(?error, "failed to stop adapter scan cleanly");
+            }
+        }
+        info!(
+            matching_count = candidates.len(),
+            "finished bounded BLE scan"
+        );
+
+        let mut connected = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let device = candidate.device.clone();
+            let result = connect_and_discover_services_with_retry(&candidate.peripheral)
+                .await
+                .map(|()| candidate);
+            connected.push((device, result));
+        }
+        Ok(connected)
+    }
+
+    /// Lists the names of the adapters the manager can open, without scanning.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn adapter_names(&self) -> Result<Vec<String>, InteractionError> {
+        let adapters = self.adapters().await?;
+        Ok(adapters.into_iter().map(|handle| handle.name).collect())
+    }
+
     #[instrument(skip(self), level = "trace")]
     async fn adapters(&self) -> Result<Vec<AdapterHandle>, InteractionError> {
         let adapters = self.manager.adapters().await?;
@@ -245,12 +307,128 @@ impl BtleplugBackend {
     }
 
     /// Connects to the first matching peripheral and prepares a session object.
-    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix))]
+    ///
+    /// Transient failures are retried with backoff as the configured
+    /// [`RetryPolicy`] allows; missing endpoints and configuration errors
+    /// fail on the first attempt.
+    ///
+    /// [`RetryPolicy`]: super::RetryPolicy
+    #[instrument(skip(self, observer), level = "debug", fields(prefix = name_prefix))]
     pub(crate) async fn connect_first_matching_device(
         self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
+    ) -> Result<RealDeviceSession, InteractionError> {
+        let retry = self.model_resolution.connect_retry();
+        let mut attempt = 1;
+        loop {
+            trace!(
+                attempt,
+                max_attempts = retry.max_attempts().get(),
+                "connecting to first matching peripheral"
+            );
+            let error = match self
+                .connect_first_matching_once(name_prefix, observer)
+                .await
+            {
+                Ok(session) => return Ok(session),
+                Err(error) => error,
+            };
+            if !retry.should_retry(attempt, &error) {
+                return Err(error);
+            }
+
+            let backoff = retry.backoff(attempt);
+            trace!(
+                attempt,
+                ?backoff,
+                error = %error,
+                "connect attempt failed; retrying"
+            );
+            sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Makes one connect attempt, disconnecting again if session setup fails.
+    async fn connect_first_matching_once(
+        &self,
+        name_prefix: &str,
+        observer: &dyn ConnectionObserver,
+    ) -> Result<RealDeviceSession, InteractionError> {
+        let connected = self
+            .find_and_connect_first_matching(name_prefix, observer)
+            .await?;
+        let peripheral = connected.peripheral.clone();
+        let session = self.prepare_session(connected, observer).await;
+        if session.is_err()
+            && let Err(error) = peripheral.disconnect().await
+        {
+            debug!(?error, "failed to disconnect after session setup failed");
+        }
+        session
+    }
+
+    /// Connects to the first matching peripheral, reports endpoint presence, then disconnects.
+    ///
+    /// Skips the LED-info query and profile resolution entirely.
+    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix))]
+    pub(crate) async fn probe_first_matching_device(
+        self,
+        name_prefix: &str,
+    ) -> Result<EndpointProbe, InteractionError> {
+        let connected = self
+            .find_and_connect_first_matching(name_prefix, &NoopConnectionObserver)
+            .await?;
+        let (services, _characteristics_by_uuid) =
+            collect_services_and_characteristics(&connected.peripheral);
+        let (gatt_profile, endpoint_presence) = probe_session_endpoints(&services);
+        connected.peripheral.disconnect().await?;
+        Ok(EndpointProbe::new(
+            connected.device,
+            gatt_profile,
+            endpoint_presence,
+        ))
+    }
+
+    /// Connects to every peripheral matching within `scan_window` and prepares a session for each.
+    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix, ?scan_window))]
+    pub(crate) async fn connect_all_matching_devices(
+        self,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<ConnectAttempt<RealDeviceSession>>, InteractionError> {
+        let discovered = self
+            .find_and_connect_all_matching(name_prefix, scan_window)
+            .await?;
+
+        let mut sessions = Vec::with_capacity(discovered.len());
+        for (device, connected) in discovered {
+            let session = match connected {
+                Ok(connected) => {
+                    let peripheral = connected.peripheral.clone();
+                    let session = self
+                        .prepare_session(connected, &NoopConnectionObserver)
+                        .await;
+                    if session.is_err()
+                        && let Err(error) = peripheral.disconnect().await
+                    {
+                        debug!(?error, "failed to disconnect after session setup failed");
+                    }
+                    session
+                }
+                Err(error) => Err(error),
+            };
+            sessions.push((device, session));
+        }
+        Ok(sessions)
+    }
+
+    async fn prepare_session(
+        &self,
+        connected: ConnectedPeripheral,
+        observer: &dyn ConnectionObserver,
     ) -> Result<RealDeviceSession, InteractionError> {
-        let connected = self.find_and_connect_first_matching(name_prefix).await?;
         let connection_state = Arc::new(ConnectionStateCell::new(ConnectionState::Connected));
         let disconnect_watcher = spawn_disconnect_watcher(
             &connected.adapter,
@@ -259,38 +437,65 @@ impl BtleplugBackend {
         )
         .await;
         let gatt_layout = resolve_gatt_layout(&connected).await?;
+        observer.on_event(&ConnectionEvent::EndpointsNegotiated(
+            gatt_layout.endpoint_presence.clone(),
+        ));
 
         let selected_led_type =
             select_led_type_override(&connected.device, &self.model_resolution)?;
-        let led_info_query = query_led_info(
-            &connected.peripheral,
-            &gatt_layout.characteristics_by_endpoint,
-        )
-        .await;
+        let mut profile_cache = ProfileCache::load_if_enabled(&self.model_resolution);
+        let led_info_query = match skipped_led_info_query(&self.model_resolution) {
+            Some(skipped) => skipped,
+            None => match cached_led_info_query(profile_cache.as_ref(), &connected.device) {
+                Some(cached) => cached,
+                None => {
+                    let probed = query_led_info(
+                        &connected.peripheral,
+                        &gatt_layout.characteristics_by_endpoint,
+                        self.model_resolution.led_info_timeout(),
+                    )
+                    .await;
+                    remember_led_info(profile_cache.as_mut(), &connected.device, &probed);
+                    probed
+                }
+            },
+        };
         let led_info = led_info_query.led_info;
         let device_routing_profile =
             resolve_device_routing_profile(&connected.device, led_info, selected_led_type);
         ensure_ambiguous_shape_is_resolved(&connected.device, device_routing_profile)?;
-        maybe_apply_joint_mode(
-            &connected.peripheral,
-            &gatt_layout.characteristics_by_endpoint,
-            device_routing_profile,
-        )
-        .await?;
+        let joint_mode = if self.model_resolution.skip_joint_mode() {
+            debug!("skipping joint-mode write because it is disabled by configuration");
+            None
+        } else {
+            maybe_apply_joint_mode(
+                &connected.peripheral,
+                &gatt_layout.characteristics_by_endpoint,
+                device_routing_profile,
+            )
+            .await?
+        };
         persist_resolved_led_type(
             &connected.device,
             device_routing_profile,
             &self.model_resolution,
         )?;
 
-        let write_without_response_limit =
-            resolve_write_without_response_limit(&gatt_layout.characteristics_by_endpoint);
+        let negotiated_mtu = negotiated_att_mtu();
+        let write_without_response_limit = resolve_write_without_response_limit(
+            &gatt_layout.characteristics_by_endpoint,
+            negotiated_mtu,
+        );
         let device_profile = resolve_device_profile(
             &connected.device,
             &gatt_layout.services,
             write_without_response_limit,
             device_routing_profile,
-        );
+            led_info,
+        )
+        .with_text_char_count_endian_override(self.model_resolution.text_count_endian_override())
+        .with_ack_policy(self.model_resolution.ack_policy());
+        observer.on_event(&ConnectionEvent::ProfileResolved(device_profile));
         let connection_diagnostics = model_resolution_diagnostics(
             connected.device.scan_identity().copied(),
             Some(&connected.scan_properties_debug),
@@ -308,7 +513,9 @@ impl BtleplugBackend {
                 .with_endpoint_resolution(
                     gatt_layout.negotiated_endpoints.gatt_profile,
                     gatt_layout.negotiated_endpoints.endpoint_uuids.clone(),
-                );
+                )
+                .with_joint_mode(joint_mode)
+                .with_negotiated_mtu(negotiated_mtu.and_then(|mtu| u16::try_from(mtu).ok()));
         Ok(RealDeviceSession {
             device: connected.device,
             services: gatt_layout.services,
@@ -318,6 +525,7 @@ impl BtleplugBackend {
             peripheral: connected.peripheral,
             connection_state,
             disconnect_watcher: Mutex::new(disconnect_watcher),
+            write_timeout: self.model_resolution.write_timeout(),
         })
     }
 }
@@ -392,10 +600,17 @@ async fn resolve_gatt_layout(
     })
 }
 
+/// Selects the pause between scan polls, floored so a zero interval cannot spin.
+fn scan_poll_delay(model_resolution: &ModelResolutionConfig) -> Duration {
+    model_resolution
+        .scan_poll_interval()
+        .max(MIN_SCAN_POLL_INTERVAL)
+}
+
 fn resolve_write_without_response_limit(
     characteristics_by_endpoint: &HashMap<EndpointId, Characteristic>,
+    negotiated_att_mtu: Option<usize>,
 ) -> Option<usize> {
-    let negotiated_att_mtu = negotiated_att_mtu();
     let write_without_response_limit = characteristics_by_endpoint
         .get(&EndpointId::WriteCharacteristic)
         .and_then(|characteristic| {
@@ -548,34 +763,82 @@ fn ensure_ambiguous_shape_is_resolved(
     })
 }
 
+/// Writes the routing profile's joint mode and returns it once applied.
 async fn maybe_apply_joint_mode(
     peripheral: &Peripheral,
     characteristics_by_endpoint: &HashMap<EndpointId, Characteristic>,
     routing_profile: Option<super::DeviceRoutingProfile>,
-) -> Result<(), InteractionError> {
+) -> Result<Option<u8>, InteractionError> {
     let Some(joint_mode) = routing_profile.and_then(|profile| profile.joint_mode) else {
-        return Ok(());
+        return Ok(None);
     };
 
     let Some(write_characteristic) =
         characteristics_by_endpoint.get(&EndpointId::WriteCharacteristic)
     else {
-        return Ok(());
+        return Ok(None);
     };
 
-    let payload = [0x05, 0x00, 0x0C, 0x80, joint_mode];
+    let payload = joint_mode_frame(joint_mode);
     peripheral
         .write(write_characteristic, &payload, WriteType::WithResponse)
         .await?;
-    Ok(())
+    Ok(Some(joint_mode))
+}
+
+fn skipped_led_info_query(model_resolution: &ModelResolutionConfig) -> Option<LedInfoQueryResult> {
+    if !model_resolution.skip_led_info() {
+        return None;
+    }
+
+    debug!("skipping LED-info query because it is disabled by configuration");
+    Some(LedInfoQueryResult::skipped(
+        LedInfoQueryOutcome::SkippedByConfig,
+    ))
 }
 
-#[instrument(skip(peripheral, characteristics_by_endpoint), level = "debug")]
+fn cached_led_info_query(
+    profile_cache: Option<&ProfileCache>,
+    device: &FoundDevice,
+) -> Option<LedInfoQueryResult> {
+    let led_info = profile_cache?.fresh_led_info_for(device, SystemTime::now())?;
+    debug!("skipping LED-info query because a fresh cached result exists");
+    Some(LedInfoQueryResult {
+        led_info: Some(led_info),
+        outcome: LedInfoQueryOutcome::CachedProfile,
+        write_modes_attempted: Vec::new(),
+        sync_time_fallback_attempted: false,
+        last_payload: None,
+    })
+}
+
+fn remember_led_info(
+    profile_cache: Option<&mut ProfileCache>,
+    device: &FoundDevice,
+    query: &LedInfoQueryResult,
+) {
+    let (Some(profile_cache), Some(led_info)) = (profile_cache, query.led_info) else {
+        return;
+    };
+    if let Err(error) = profile_cache.record(device, led_info, SystemTime::now()) {
+        debug!(
+            ?error,
+            "failed to persist LED-info result to the profile cache"
+        );
+    }
+}
+
+#[instrument(
+    skip(peripheral, characteristics_by_endpoint),
+    level = "debug",
+    fields(timeout_ms = query_timeout.as_millis())
+)]
 async fn query_led_info(
     peripheral: &Peripheral,
     characteristics_by_endpoint: &HashMap<EndpointId, Characteristic>,
+    query_timeout: Duration,
 ) -> LedInfoQueryResult {
-    let plan = match build_led_info_query_plan(characteristics_by_endpoint) {
+    let plan = match build_led_info_query_plan(characteristics_by_endpoint, query_timeout) {
         Ok(plan) => plan,
         Err(skipped) => return skipped,
     };
@@ -588,26 +851,44 @@ async fn query_led_info(
         return result;
     }
 
-    let mut sync_time_fallback_attempted = false;
-    if plan.supports_notify {
-        sync_time_fallback_attempted = true;
-        if let Some(result) = attempt_led_info_sync_time_fallback(
-            peripheral,
-            &plan,
-            &mut attempted_modes,
-            &mut last_payload,
-        )
-        .await
-        {
-            return result;
-        }
+    if !plan.supports_notify {
+        return unresolved_led_info_result(attempted_modes, last_payload, false);
     }
 
-    unresolved_led_info_result(attempted_modes, last_payload, sync_time_fallback_attempted)
+    let result = match attempt_led_info_sync_time_fallback(
+        peripheral,
+        &plan,
+        &mut attempted_modes,
+        &mut last_payload,
+    )
+    .await
+    {
+        Some(result) => result,
+        None => unresolved_led_info_result(attempted_modes, last_payload, true),
+    };
+    trace_sync_time_fallback(&result);
+    result
+}
+
+/// Explains why a connect took longer: the direct LED-info query went
+/// unanswered, so the probe sent sync-time frames to coax a response.
+fn trace_sync_time_fallback(result: &LedInfoQueryResult) {
+    let fallback_write_modes = result
+        .write_modes_attempted
+        .iter()
+        .filter(|mode| mode.ends_with(SYNC_TIME_MODE_SUFFIX))
+        .count();
+    info!(
+        fallback_write_modes,
+        outcome = %result.outcome,
+        resolved = result.led_info.is_some(),
+        "LED-info probe fell back to sync-time frames"
+    );
 }
 
 fn build_led_info_query_plan(
     characteristics_by_endpoint: &HashMap<EndpointId, Characteristic>,
+    query_timeout: Duration,
 ) -> Result<LedInfoQueryPlan<'_>, LedInfoQueryResult> {
     let Some(write_characteristic) =
         characteristics_by_endpoint.get(&EndpointId::WriteCharacteristic)
@@ -649,6 +930,7 @@ fn build_led_info_query_plan(
         supports_read,
         supports_notify,
         write_types,
+        query_timeout,
     })
 }
 
@@ -668,6 +950,7 @@ async fn attempt_led_info_direct_queries(
                 write_type,
                 plan.read_characteristic,
                 &GET_LED_INFO_QUERY,
+                plan.query_timeout,
             )
             .await
             {
@@ -700,6 +983,7 @@ async fn attempt_led_info_direct_queries(
                 write_type,
                 plan.read_characteristic,
                 &GET_LED_INFO_QUERY,
+                plan.query_timeout,
             )
             .await
             {
@@ -730,13 +1014,17 @@ async fn attempt_led_info_sync_time_fallback(
 ) -> Option<LedInfoQueryResult> {
     let sync_time_query = sync_time_query_frame(OffsetDateTime::now_utc());
     for write_type in plan.write_types.iter().copied() {
-        attempted_modes.push(format!("{}:sync_time", write_type_label(write_type)));
+        attempted_modes.push(format!(
+            "{}{SYNC_TIME_MODE_SUFFIX}",
+            write_type_label(write_type)
+        ));
         match query_led_info_via_notify(
             peripheral,
             plan.write_characteristic,
             write_type,
             plan.read_characteristic,
             &sync_time_query,
+            plan.query_timeout,
         )
         .await
         {
@@ -790,6 +1078,7 @@ async fn query_led_info_via_notify(
     write_type: WriteType,
     read_characteristic: &Characteristic,
     query: &[u8],
+    query_timeout: Duration,
 ) -> LedInfoProbeResult {
     let mut notifications = match peripheral.notifications().await {
         Ok(stream) => stream,
@@ -819,7 +1108,7 @@ async fn query_led_info_via_notify(
         return LedInfoProbeResult::NoResponse;
     }
 
-    let deadline = tokio::time::Instant::now() + Duration::from_millis(LED_INFO_QUERY_TIMEOUT_MS);
+    let deadline = led_info_query_deadline(tokio::time::Instant::now(), query_timeout);
     let mut first_invalid_payload = None;
     loop {
         let now = tokio::time::Instant::now();
@@ -883,6 +1172,7 @@ async fn query_led_info_via_read(
     write_type: WriteType,
     read_characteristic: &Characteristic,
     query: &[u8],
+    query_timeout: Duration,
 ) -> LedInfoProbeResult {
     if let Err(error) = peripheral
         .write(write_characteristic, query, write_type)
@@ -892,12 +1182,7 @@ async fn query_led_info_via_read(
         return LedInfoProbeResult::NoResponse;
     }
 
-    match timeout(
-        Duration::from_millis(LED_INFO_QUERY_TIMEOUT_MS),
-        peripheral.read(read_characteristic),
-    )
-    .await
-    {
+    match timeout(query_timeout, peripheral.read(read_characteristic)).await {
         Ok(Ok(payload)) => {
             if let Some(parsed) = super::LedInfoResponse::parse(&payload) {
                 LedInfoProbeResult::Parsed {
@@ -919,6 +1204,13 @@ async fn query_led_info_via_read(
     }
 }
 
+fn led_info_query_deadline(
+    now: tokio::time::Instant,
+    query_timeout: Duration,
+) -> tokio::time::Instant {
+    now + query_timeout
+}
+
 fn write_type_label(write_type: WriteType) -> &'static str {
     match write_type {
         WriteType::WithResponse => "with_response",
@@ -1052,6 +1344,27 @@ fn negotiated_transport_write_limit(
     None
 }
 
+fn found_device_from_properties(
+    adapter_name: &str,
+    peripheral: &Peripheral,
+    properties: PeripheralProperties,
+) -> FoundDevice {
+    let scan_identity = scan_identity_from_properties(&properties);
+    let device = FoundDevice::new(
+        adapter_name.to_string(),
+        peripheral.id().to_string(),
+        properties.local_name,
+        properties.rssi,
+    );
+    match scan_identity {
+        Some(scan_identity) => {
+            let model_profile = ScanModelHandler::resolve_model(&scan_identity);
+            device.with_scan_model(scan_identity, model_profile)
+        }
+        None => device,
+    }
+}
+
 fn matches_name_prefix(local_name: Option<&str>, name_prefix: &str) -> bool {
     if name_prefix.is_empty() {
         return true;
@@ -1071,6 +1384,7 @@ pub(crate) struct RealDeviceSession {
     peripheral: Peripheral,
     connection_state: Arc<ConnectionStateCell>,
     disconnect_watcher: Mutex<Option<JoinHandle<()>>>,
+    write_timeout: Duration,
 }
 
 impl RealDeviceSession {
@@ -1111,10 +1425,32 @@ impl ConnectedBleSession for RealDeviceSession {
         self.session_metadata.write_without_response_limit()
     }
 
+    fn negotiated_mtu(&self) -> Option<u16> {
+        self.session_metadata.negotiated_mtu()
+    }
+
     fn device_profile(&self) -> DeviceProfile {
         self.session_metadata.device_profile()
     }
 
+    #[instrument(skip(self), level = "trace")]
+    async fn is_alive(&self) -> bool {
+        if self.connection_state.get() == ConnectionState::Disconnected {
+            return false;
+        }
+
+        match self.peripheral.is_connected().await {
+            Ok(connected) => connected,
+            Err(error) => {
+                trace!(
+                    ?error,
+                    "treating failed connection-state query as disconnected"
+                );
+                false
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "trace", fields(?endpoint))]
     async fn read_endpoint(&self, endpoint: EndpointId) -> Result<Vec<u8>, InteractionError> {
         let characteristic = self.characteristic_for(endpoint)?;
@@ -1142,15 +1478,24 @@ impl ConnectedBleSession for RealDeviceSession {
             WriteMode::WithResponse => WriteType::WithResponse,
             WriteMode::WithoutResponse => WriteType::WithoutResponse,
         };
-        self.peripheral
-            .write(characteristic, payload, write_type)
-            .await?;
-        Ok(())
+        write_with_timeout(
+            self.write_timeout,
+            self.peripheral.write(characteristic, payload, write_type),
+        )
+        .await
     }
 
     #[instrument(skip(self), level = "trace", fields(?endpoint))]
     async fn subscribe_endpoint(&self, endpoint: EndpointId) -> Result<(), InteractionError> {
         let characteristic = self.characteristic_for(endpoint)?;
+        // btleplug enables indications when a characteristic lacks NOTIFY, so
+        // indicate-only endpoints subscribe through the same call.
+        if !characteristic
+            .properties
+            .intersects(CharPropFlags::NOTIFY | CharPropFlags::INDICATE)
+        {
+            return Err(InteractionError::EndpointNotSubscribable { endpoint });
+        }
         self.peripheral.subscribe(characteristic).await?;
         Ok(())
     }
@@ -1202,6 +1547,22 @@ impl ConnectedBleSession for RealDeviceSession {
     }
 }
 
+/// Awaits one characteristic write, giving up once `write_timeout` elapses.
+///
+/// A stalled adapter can otherwise leave the write pending forever and hang
+/// the upload that issued it.
+async fn write_with_timeout<F>(write_timeout: Duration, write: F) -> Result<(), InteractionError>
+where
+    F: Future<Output = Result<(), btleplug::Error>>,
+{
+    match timeout(write_timeout, write).await {
+        Ok(result) => Ok(result?),
+        Err(_elapsed) => Err(InteractionError::WriteTimeout {
+            timeout_ms: u64::try_from(write_timeout.as_millis()).unwrap_or(u64::MAX),
+        }),
+    }
+}
+
 impl Drop for RealDeviceSession {
     fn drop(&mut self) {
         if let Ok(mut disconnect_watcher) = self.disconnect_watcher.lock()
@@ -1241,6 +1602,7 @@ struct LedInfoQueryPlan<'a> {
     supports_read: bool,
     supports_notify: bool,
     write_types: Vec<WriteType>,
+    query_timeout: Duration,
 }
 
 fn collect_services_and_characteristics(
@@ -1328,6 +1690,7 @@ fn format_missing_endpoints(endpoints: &[EndpointId]) -> String {
 mod tests {
     use std::collections::HashMap;
 
+    use assert_matches::assert_matches;
     use btleplug::api::{PeripheralProperties, bleuuid::uuid_from_u16};
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -1335,6 +1698,33 @@ mod tests {
 
     use super::*;
 
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            let bytes = self
+                .0
+                .lock()
+                .expect("captured logs lock should not be poisoned");
+            String::from_utf8_lossy(&bytes).into_owned()
+        }
+    }
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0
+                .lock()
+                .expect("captured logs lock should not be poisoned")
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
     fn manufacturer_properties(company_id: u16, payload: &[u8]) -> PeripheralProperties {
         let mut manufacturer_data = HashMap::new();
         manufacturer_data.insert(company_id, payload.to_vec());
@@ -1487,6 +1877,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skipped_led_info_query_is_none_by_default() {
+        let skipped = skipped_led_info_query(&ModelResolutionConfig::default());
+        assert_eq!(true, skipped.is_none());
+    }
+
+    #[test]
+    fn skipped_led_info_query_records_config_outcome_when_disabled() {
+        let model_resolution = ModelResolutionConfig::default().with_skip_led_info(true);
+
+        let skipped = skipped_led_info_query(&model_resolution)
+            .expect("LED-info query should be skipped when disabled by configuration");
+        assert_eq!(
+            LedInfoQueryResult {
+                led_info: None,
+                outcome: LedInfoQueryOutcome::SkippedByConfig,
+                write_modes_attempted: Vec::new(),
+                sync_time_fallback_attempted: false,
+                last_payload: None,
+            },
+            skipped
+        );
+    }
+
+    #[test]
+    fn cached_led_info_query_uses_fresh_cache_entry() {
+        let path = std::env::temp_dir().join(format!(
+            "idm-btleplug-profile-cache-{}.tsv",
+            SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("system time should be after unix epoch")
+                .as_nanos()
+        ));
+        let device = FoundDevice::new(
+            "hci0".to_string(),
+            "AA:BB:CC".to_string(),
+            Some("IDM-Clock".to_string()),
+            Some(-43),
+        );
+        let led_info = crate::hw::LedInfoResponse::parse(&[
+            0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x04, 0x00,
+        ])
+        .expect("fixture payload should parse");
+        let mut cache = ProfileCache::load_from_path(path.clone(), Duration::from_secs(60))
+            .expect("new cache should load");
+
+        assert_eq!(true, cached_led_info_query(Some(&cache), &device).is_none());
+        cache
+            .record(&device, led_info, SystemTime::now())
+            .expect("recording LED info should succeed");
+
+        let cached = cached_led_info_query(Some(&cache), &device)
+            .expect("fresh cache entry should replace the LED-info query");
+        assert_eq!(LedInfoQueryOutcome::CachedProfile, cached.outcome);
+        assert_eq!(Some(led_info), cached.led_info);
+        assert_eq!(None, cached.last_payload);
+
+        std::fs::remove_file(&path).expect("temporary cache file should be removable");
+    }
+
+    #[rstest]
+    #[case(Duration::from_millis(1_000))]
+    #[case(Duration::from_millis(250))]
+    #[case(Duration::from_secs(3))]
+    fn led_info_query_deadline_uses_configured_timeout(#[case] query_timeout: Duration) {
+        let now = tokio::time::Instant::now();
+        let deadline = led_info_query_deadline(now, query_timeout);
+        assert_eq!(query_timeout, deadline - now);
+    }
+
     #[rstest]
     #[case("org.bluez.Error.Failed le-connection-abort-by-local", true)]
     #[case("org.bluez.Error.Failed LE-CONNECTION-ABORT-BY-LOCAL", true)]
@@ -1506,4 +1966,100 @@ mod tests {
         let delay = local_abort_backoff(attempt);
         assert_eq!(Duration::from_millis(expected_millis), delay);
     }
+
+    #[rstest]
+    #[case::unresolved(
+        unresolved_led_info_result(
+            vec![
+                "without_response:get_led_type".to_string(),
+                "with_response:get_led_type".to_string(),
+                "without_response:sync_time".to_string(),
+                "with_response:sync_time".to_string(),
+            ],
+            None,
+            true,
+        ),
+        "fallback_write_modes=2 outcome=no_response resolved=false",
+    )]
+    #[case::resolved(
+        LedInfoQueryResult::resolved(
+            crate::hw::LedInfoResponse::parse(&[0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x03, 0x00])
+                .expect("fixture payload should parse"),
+            LedInfoQueryOutcome::ParsedNotifyAfterSyncTime,
+            vec![
+                "without_response:get_led_type".to_string(),
+                "without_response:sync_time".to_string(),
+            ],
+            vec![0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x03, 0x00],
+        )
+        .mark_sync_time_fallback_attempted(),
+        "fallback_write_modes=1 outcome=parsed_notify_after_sync_time resolved=true",
+    )]
+    fn sync_time_fallback_emits_info_event(
+        #[case] result: LedInfoQueryResult,
+        #[case] expected_fields: &str,
+    ) {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || trace_sync_time_fallback(&result));
+
+        let contents = logs.contents();
+        assert_eq!(
+            true,
+            contents.contains("LED-info probe fell back to sync-time frames"),
+            "captured logs: {contents}"
+        );
+        assert_eq!(
+            true,
+            contents.contains(expected_fields),
+            "captured logs: {contents}"
+        );
+    }
+
+    #[rstest]
+    #[case::default(ModelResolutionConfig::default(), Duration::from_millis(250))]
+    #[case::custom(
+        ModelResolutionConfig::default().with_scan_poll_interval(Duration::from_millis(50)),
+        Duration::from_millis(50)
+    )]
+    #[case::zero_is_floored(
+        ModelResolutionConfig::default().with_scan_poll_interval(Duration::ZERO),
+        MIN_SCAN_POLL_INTERVAL
+    )]
+    fn scan_poll_delay_follows_config(
+        #[case] model_resolution: ModelResolutionConfig,
+        #[case] expected: Duration,
+    ) {
+        assert_eq!(expected, scan_poll_delay(&model_resolution));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn write_with_timeout_fails_when_write_never_resolves() {
+        let write_timeout = Duration::from_millis(1_500);
+        let started = tokio::time::Instant::now();
+
+        let result = write_with_timeout(
+            write_timeout,
+            std::future::pending::<Result<(), btleplug::Error>>(),
+        )
+        .await;
+
+        assert_matches!(
+            result,
+            Err(InteractionError::WriteTimeout { timeout_ms: 1_500 })
+        );
+        assert_eq!(write_timeout, started.elapsed());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn write_with_timeout_passes_through_completed_write() {
+        let result = write_with_timeout(Duration::from_secs(5), async { Ok(()) }).await;
+
+        assert_matches!(result, Ok(()));
+    }
 }
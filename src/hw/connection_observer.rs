@@ -0,0 +1,57 @@
+use super::model::{EndpointPresence, FoundDevice};
+use super::profile::DeviceProfile;
+
+/// One milestone reached while connecting to a device.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ConnectionEvent {
+    /// Discovery started for peripherals whose name starts with `name_prefix`.
+    ScanStarted {
+        /// Local-name prefix being scanned for.
+        name_prefix: String,
+    },
+    /// A matching peripheral was found and connected.
+    DeviceFound(FoundDevice),
+    /// The vendor GATT endpoints were negotiated.
+    EndpointsNegotiated(EndpointPresence),
+    /// The device behaviour profile was resolved and the session is ready.
+    ProfileResolved(DeviceProfile),
+}
+
+/// Receives connection milestones as they happen.
+///
+/// Events arrive in the order scanning, discovery, endpoint negotiation and
+/// profile resolution happen. A failed connection stops reporting at the
+/// stage that failed.
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use idm::{ConnectionEvent, ConnectionObserver};
+///
+/// #[derive(Default)]
+/// struct Recorder(Mutex<Vec<ConnectionEvent>>);
+///
+/// impl ConnectionObserver for Recorder {
+///     fn on_event(&self, event: &ConnectionEvent) {
+///         self.0.lock().expect("recorder lock").push(event.clone());
+///     }
+/// }
+///
+/// let recorder = Recorder::default();
+/// recorder.on_event(&ConnectionEvent::ScanStarted {
+///     name_prefix: "IDM-".to_string(),
+/// });
+/// assert_eq!(1, recorder.0.lock().expect("recorder lock").len());
+/// ```
+pub trait ConnectionObserver: Send + Sync {
+    /// Called once for each milestone.
+    fn on_event(&self, event: &ConnectionEvent);
+}
+
+/// Observer that ignores every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopConnectionObserver;
+
+impl ConnectionObserver for NoopConnectionObserver {
+    fn on_event(&self, _event: &ConnectionEvent) {}
+}
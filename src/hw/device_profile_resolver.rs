@@ -2,6 +2,7 @@ use derive_more::Display;
 use serde::Serialize;
 use serde_with::SerializeDisplay;
 
+use super::profile::PanelDimensions;
 use super::scan_capabilities::ScanCapabilityTable;
 use super::scan_model::{ScanIdentity, ScanModelHandler};
 
@@ -77,6 +78,18 @@ impl LedInfoResponse {
             password_enabled: payload[8] != 0,
         })
     }
+
+    /// Returns the panel dimensions implied by the reported screen type, when known.
+    ///
+    /// ```
+    /// let response = idm::LedInfoResponse::parse(&[0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x06, 0x00])
+    ///     .expect("fixture payload should parse");
+    /// assert_eq!(idm::PanelDimensions::new(24, 48), response.panel_dimensions());
+    /// ```
+    #[must_use]
+    pub fn panel_dimensions(self) -> Option<PanelDimensions> {
+        panel_dimensions_for_screen_type(self.screen_type)
+    }
 }
 
 /// Resolves routing decisions from discovery identity and LED-info response data.
@@ -181,19 +194,37 @@ fn is_known_led_type(led_type: u8) -> bool {
     matches!(led_type, 1 | 2 | 3 | 4 | 6 | 7 | 11)
 }
 
-fn panel_size_for_led_type(led_type: u8) -> Option<(u16, u16)> {
-    match led_type {
-        1 => Some((16, 16)),
-        2 => Some((8, 32)),
-        3 => Some((32, 32)),
-        4 => Some((64, 64)),
-        6 => Some((24, 48)),
-        7 => Some((16, 32)),
-        11 => Some((16, 64)),
+/// Maps an LED-info `screen_type` byte to panel geometry.
+///
+/// | `screen_type` | Panel   |
+/// |---------------|---------|
+/// | `1`           | `16x16` |
+/// | `2`           | `8x32`  |
+/// | `3`           | `32x32` |
+/// | `4`           | `64x64` |
+/// | `6`           | `24x48` |
+/// | `7`           | `16x32` |
+/// | `11`          | `16x64` |
+///
+/// Any other value is unknown and yields `None`.
+fn panel_dimensions_for_screen_type(screen_type: u8) -> Option<PanelDimensions> {
+    match screen_type {
+        1 => PanelDimensions::new(16, 16),
+        2 => PanelDimensions::new(8, 32),
+        3 => PanelDimensions::new(32, 32),
+        4 => PanelDimensions::new(64, 64),
+        6 => PanelDimensions::new(24, 48),
+        7 => PanelDimensions::new(16, 32),
+        11 => PanelDimensions::new(16, 64),
         _ => None,
     }
 }
 
+fn panel_size_for_led_type(led_type: u8) -> Option<(u16, u16)> {
+    panel_dimensions_for_screen_type(led_type)
+        .map(|dimensions| (dimensions.width(), dimensions.height()))
+}
+
 fn text_path_for_led_type(led_type: u8) -> Option<TextPath> {
     match led_type {
         2 => Some(TextPath::Path832),
@@ -294,6 +325,24 @@ mod tests {
         assert_eq!(expected, parsed);
     }
 
+    #[rstest]
+    #[case(1, PanelDimensions::new(16, 16))]
+    #[case(2, PanelDimensions::new(8, 32))]
+    #[case(3, PanelDimensions::new(32, 32))]
+    #[case(4, PanelDimensions::new(64, 64))]
+    #[case(6, PanelDimensions::new(24, 48))]
+    #[case(7, PanelDimensions::new(16, 32))]
+    #[case(11, PanelDimensions::new(16, 64))]
+    #[case(0, None)]
+    #[case(5, None)]
+    #[case(99, None)]
+    fn panel_dimensions_for_screen_type_maps_known_types(
+        #[case] screen_type: u8,
+        #[case] expected: Option<PanelDimensions>,
+    ) {
+        assert_eq!(expected, led_info(screen_type).panel_dimensions());
+    }
+
     #[test]
     fn resolve_with_selected_led_type_uses_selected_type_when_led_query_is_missing() {
         let resolved =
@@ -1,38 +1,54 @@
 use std::collections::VecDeque;
+use std::path::Path;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use async_trait::async_trait;
 use bon::Builder;
 use strum_macros::EnumString;
+use tokio::runtime::{Handle, RuntimeFlavor};
 use tokio::time::sleep;
-use tracing::instrument;
+use tracing::{debug, instrument, trace};
 
 use super::DeviceProfile;
-use super::hardware::{ConnectedBleSession, PayloadStream, WriteMode, missing_required_endpoints};
+use super::connection_observer::{ConnectionEvent, ConnectionObserver, NoopConnectionObserver};
+use super::hardware::{
+    ConnectAttempt, ConnectedBleSession, PayloadStream, WriteMode, missing_required_endpoints,
+};
 use super::model::{
-    CharacteristicInfo, EndpointPresence, FoundDevice, InspectReport, ServiceInfo, SessionMetadata,
+    CharacteristicInfo, EndpointPresence, EndpointProbe, FoundDevice, InspectReport,
+    LedInfoQueryOutcome, NotifyDelivery, ServiceInfo, SessionMetadata, endpoint_characteristic,
 };
 use super::model_overrides::{ModelResolutionConfig, is_supported_led_type};
+use super::model_resolution_diagnostics::{LedInfoDiagnosticParams, model_resolution_diagnostics};
 use super::profile::{resolve_device_profile, resolve_device_routing_profile};
+use super::profile_cache::ProfileCache;
 use super::scan_model::ScanModelHandler;
-use super::session::{FA_SERVICE_UUID, FA_WRITE_UUID, negotiate_session_endpoints};
-use crate::error::{FixtureError, InteractionError};
-use crate::notification::{NotifyEvent, TransferFamily};
+use super::session::{
+    FA_SERVICE_UUID, FA_WRITE_UUID, negotiate_session_endpoints, probe_session_endpoints,
+};
+use crate::error::{FixtureError, InteractionError, ScanFixtureField};
+use crate::notification::{NotificationHandler, NotifyEvent, ScheduleSetupStatus, TransferFamily};
 use crate::protocol::{self, EndpointId};
 
 const DEFAULT_INITIAL_READ: [u8; 5] = [0x05, 0x00, 0x01, 0x00, 0x01];
 const DEFAULT_WRITE_WITHOUT_RESPONSE_LIMIT: Option<usize> =
     Some(protocol::TRANSPORT_CHUNK_MTU_READY);
+/// ATT MTU every fake connection reports as negotiated.
+const FAKE_NEGOTIATED_MTU: u16 = 512;
 const NOTIFY_PREFIX_LEN: u8 = 0x05;
 const NOTIFY_PREFIX_NS: u8 = 0x00;
-const STATUS_NEXT_PACKAGE: u8 = 0x01;
-const STATUS_FINISHED: u8 = 0x03;
-const SCHEDULE_SETUP_ID: u8 = 0x05;
-const SCHEDULE_MASTER_SWITCH_ID: u8 = 0x07;
+const STATUS_TRANSFER_ERROR: u8 = 0x02;
 const SCHEDULE_NS: u8 = 0x80;
 const SCREEN_LIGHT_TIMEOUT_ID: u8 = 0x0F;
+const SCREEN_LIGHT_READ_SENTINEL: u8 = 0xFF;
+const JOINT_MODE_ID: u8 = 0x0C;
+const SCHEDULE_SETUP_ID: u8 = 0x05;
+const SCHEDULE_SETUP_FRAME_LEN: u8 = 0x09;
+const FACTORY_RESET_FRAME: [u8; 4] = [0x04, 0x00, 0x03, SCHEDULE_NS];
+const MATERIAL_SLOTS_QUERY_FRAME: [u8; 4] = [0x04, 0x00, 0x10, SCHEDULE_NS];
 const GIF_COMMAND_ID: u8 = 0x01;
 const IMAGE_COMMAND_ID: u8 = 0x02;
 const TEXT_COMMAND_ID: u8 = 0x03;
@@ -41,30 +57,6 @@ const DIY_PREFIX_HEADER_LEN: usize = 9;
 const MEDIA_HEADER_LEN: usize = 16;
 const DIY_LOGICAL_CHUNK_MAX_PAYLOAD_LEN: usize = 4096;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-struct NotificationCode {
-    id: u8,
-    ns: u8,
-}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-struct NotificationFrame {
-    code: NotificationCode,
-    status: u8,
-}
-
-impl NotificationFrame {
-    fn into_payload(self) -> Vec<u8> {
-        vec![
-            NOTIFY_PREFIX_LEN,
-            NOTIFY_PREFIX_NS,
-            self.code.id,
-            self.code.ns,
-            self.status,
-        ]
-    }
-}
-
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 enum UploadCommand {
     Gif,
@@ -119,6 +111,13 @@ pub(crate) struct ScanFixture {
     devices: Vec<FoundDevice>,
 }
 
+impl ScanFixture {
+    /// Reads and parses a scan fixture file.
+    pub(crate) fn from_file(path: &Path) -> Result<Self, FixtureError> {
+        read_fixture_file(path, ";")?.parse()
+    }
+}
+
 impl FromStr for ScanFixture {
     type Err = FixtureError;
 
@@ -161,9 +160,16 @@ pub struct ScanScenario {
 impl ScanScenario {
     /// Parses a semicolon-delimited fake scan fixture into a scan scenario.
     ///
+    /// Each record is either positional (`adapter|address|name|rssi[|scan]`,
+    /// with `-` for an absent name, RSSI or scan payload) or named
+    /// (`key=value` pairs separated by commas, in any order). Named records
+    /// require only `addr`; `adapter` defaults to `hci0` and `name`, `rssi`
+    /// and `scan` may be omitted.
+    ///
     /// ```
     /// let scan = idm::ScanScenario::from_fixture("hci0|AA:BB:CC|IDM-Clock|-43")?;
-    /// let _ = scan;
+    /// let named = idm::ScanScenario::from_fixture("name=IDM-Clock,addr=AA:BB:CC,rssi=-43")?;
+    /// let _ = (scan, named);
     /// # Ok::<(), idm::FixtureError>(())
     /// ```
     pub fn from_fixture(raw_fixture: &str) -> Result<Self, FixtureError> {
@@ -172,6 +178,27 @@ impl ScanScenario {
             discovery_delay: Duration::ZERO,
         })
     }
+
+    /// Reads a scan fixture from a file, one or more records per line.
+    ///
+    /// Lines are joined as if separated by `;`, and blank lines are ignored.
+    ///
+    /// ```no_run
+    /// let scan = idm::ScanScenario::from_file("fixtures/scan.txt")?;
+    /// let _ = scan;
+    /// # Ok::<(), idm::FixtureError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the file cannot be read or its records do not
+    /// parse.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, FixtureError> {
+        Ok(Self {
+            fixture: ScanFixture::from_file(path.as_ref())?,
+            discovery_delay: Duration::ZERO,
+        })
+    }
 }
 
 impl TryFrom<&str> for ScanScenario {
@@ -209,7 +236,7 @@ impl ListenNotification {
 impl From<ListenNotification> for Vec<u8> {
     fn from(value: ListenNotification) -> Self {
         match value {
-            ListenNotification::Event(event) => encode_notify_event(event),
+            ListenNotification::Event(event) => NotificationHandler::encode(&event),
             ListenNotification::Raw(payload) => payload,
         }
     }
@@ -236,6 +263,48 @@ impl ListenScenario {
     pub fn from_payloads(raw_value: &str) -> Result<Self, FixtureError> {
         raw_value.parse()
     }
+
+    /// Reads notification payloads from a file, one or more per line.
+    ///
+    /// Lines are joined as if separated by `,`, and blank lines are ignored.
+    /// A file containing only a named [`ListenFixture`] selects that fixture.
+    ///
+    /// ```no_run
+    /// let listen = idm::ListenScenario::from_file("fixtures/notifications.txt")?;
+    /// let _ = listen;
+    /// # Ok::<(), idm::FixtureError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the file cannot be read or a payload is not valid
+    /// hexadecimal.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, FixtureError> {
+        read_fixture_file(path.as_ref(), ",")?.parse()
+    }
+
+    /// Returns how far the paused Tokio clock advances per tick while the
+    /// notification stream is open, if auto-advance is enabled.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let listen = idm::ListenScenario::builder()
+    ///     .auto_advance_interval(Duration::from_millis(10))
+    ///     .build();
+    /// assert_eq!(Some(Duration::from_millis(10)), listen.auto_advance_interval());
+    /// ```
+    #[must_use]
+    pub fn auto_advance_interval(&self) -> Option<Duration> {
+        self.auto_advance_interval
+    }
+
+    /// Replaces the auto-advance interval.
+    #[must_use]
+    pub(crate) fn with_auto_advance_interval(mut self, interval: Duration) -> Self {
+        self.auto_advance_interval = Some(interval);
+        self
+    }
 }
 
 /// Behaviour of the fake listen stream once subscribed.
@@ -256,6 +325,16 @@ pub enum ListenFixture {
     GifTransferHappyPath,
     /// A text transfer that ACKs `next package`, then `finished`.
     TextTransferHappyPath,
+    /// A four-chunk GIF transfer that ACKs `next package` three times, then
+    /// `finished`.
+    ///
+    /// Uploads drain these events as stale before the first chunk, so pair
+    /// the fixture with a [`GifScenario`] to drive the same sequence of
+    /// per-chunk acknowledgements.
+    GifMultiChunkHappyPath,
+    /// A GIF transfer rejected with status `0x02`, the documented
+    /// transfer-error status (for example insufficient space).
+    GifTransferError,
 }
 
 impl ListenFixture {
@@ -263,23 +342,47 @@ impl ListenFixture {
     pub const GIF_TRANSFER_HAPPY_PATH: Self = Self::GifTransferHappyPath;
     /// Constant-style alias for [`ListenFixture::TextTransferHappyPath`].
     pub const TEXT_TRANSFER_HAPPY_PATH: Self = Self::TextTransferHappyPath;
+    /// Constant-style alias for [`ListenFixture::GifMultiChunkHappyPath`].
+    pub const GIF_MULTI_CHUNK_HAPPY_PATH: Self = Self::GifMultiChunkHappyPath;
+    /// Constant-style alias for [`ListenFixture::GifTransferError`].
+    pub const GIF_TRANSFER_ERROR: Self = Self::GifTransferError;
+
+    const MULTI_CHUNK_NEXT_PACKAGE_ACKS: usize = 3;
 
     fn into_scenario(self) -> ListenScenario {
-        let family = match self {
-            Self::GifTransferHappyPath => TransferFamily::Gif,
-            Self::TextTransferHappyPath => TransferFamily::Text,
+        let notifications = match self {
+            Self::GifTransferHappyPath => happy_path_notifications(TransferFamily::Gif, 1),
+            Self::TextTransferHappyPath => happy_path_notifications(TransferFamily::Text, 1),
+            Self::GifMultiChunkHappyPath => {
+                happy_path_notifications(TransferFamily::Gif, Self::MULTI_CHUNK_NEXT_PACKAGE_ACKS)
+            }
+            Self::GifTransferError => vec![ListenNotification::Event(NotifyEvent::Error(
+                TransferFamily::Gif,
+                STATUS_TRANSFER_ERROR,
+            ))],
         };
         ListenScenario {
-            notifications: vec![
-                ListenNotification::Event(NotifyEvent::NextPackage(family)),
-                ListenNotification::Event(NotifyEvent::Finished(family)),
-            ],
+            notifications,
             stream_behaviour: ListenStreamBehaviour::KeepOpen,
             auto_advance_interval: None,
         }
     }
 }
 
+fn happy_path_notifications(
+    family: TransferFamily,
+    next_package_acks: usize,
+) -> Vec<ListenNotification> {
+    std::iter::repeat_n(
+        ListenNotification::Event(NotifyEvent::NextPackage(family)),
+        next_package_acks,
+    )
+    .chain(std::iter::once(ListenNotification::Event(
+        NotifyEvent::Finished(family),
+    )))
+    .collect()
+}
+
 impl From<ListenFixture> for ListenScenario {
     fn from(value: ListenFixture) -> Self {
         value.into_scenario()
@@ -424,7 +527,7 @@ impl From<NotificationPayloads> for ListenScenario {
 }
 
 /// Settings for constructing a fake hardware backend.
-#[derive(Debug, Builder)]
+#[derive(Debug, Clone, Builder)]
 pub(crate) struct FakeBackendConfig {
     scan: ScanScenario,
     initial_read: Option<HexPayload>,
@@ -438,6 +541,26 @@ pub(crate) struct FakeBackendConfig {
     text: TextScenario,
     #[builder(default)]
     model_resolution: ModelResolutionConfig,
+    /// Largest write the fake device accepts, in bytes.
+    ///
+    /// When set, sessions report it as the write-without-response limit and
+    /// reject longer writes, as a device with a short ATT MTU would.
+    write_mtu: Option<usize>,
+    /// Delivery mode advertised by the read/notify characteristic.
+    ///
+    /// Defaults to [`NotifyDelivery::Notify`]; use
+    /// [`NotifyDelivery::Indicate`] to model an indicate-only device.
+    notify_delivery: Option<NotifyDelivery>,
+    /// Delivery mode advertised by the write characteristic, if any.
+    ///
+    /// Unset by default, so the write characteristic cannot be subscribed
+    /// to; set it to model a device that also pushes notifications there.
+    write_notify_delivery: Option<NotifyDelivery>,
+    /// Notification sent in answer to the material-slot query.
+    ///
+    /// When unset the fake device ignores the query, as devices without slot
+    /// readback do.
+    material_slots: Option<HexPayload>,
 }
 
 /// Fake backend used in tests and non-hardware environments.
@@ -452,7 +575,34 @@ pub(crate) struct FakeBackend {
     image: ImageScenario,
     text: TextScenario,
     write_without_response_limit: Option<usize>,
+    write_mtu: Option<usize>,
     model_resolution: ModelResolutionConfig,
+    material_slots: Option<Vec<u8>>,
+}
+
+impl FakeBackendConfig {
+    /// Returns how long session teardown may take before timing out.
+    pub(crate) fn close_timeout(&self) -> Duration {
+        self.model_resolution.close_timeout()
+    }
+
+    /// Returns the model-resolution settings fake sessions connect with.
+    pub(crate) fn model_resolution(&self) -> &ModelResolutionConfig {
+        &self.model_resolution
+    }
+
+    /// Returns each adapter named by the scan fixture, in first-seen order.
+    pub(crate) fn adapter_names(&self) -> Vec<String> {
+        let devices: Vec<FoundDevice> = self.scan.fixture.clone().into();
+        let mut names = Vec::new();
+        for device in devices {
+            let name = device.adapter_name().to_string();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
 }
 
 impl FakeBackend {
@@ -469,38 +619,93 @@ impl FakeBackend {
 
         Self {
             devices: fixture.into(),
-            services: default_services(),
+            services: default_services(
+                config.notify_delivery.unwrap_or(NotifyDelivery::Notify),
+                config.write_notify_delivery,
+            ),
             initial_read,
             discovery_delay,
             listen: config.listen,
             gif: config.gif,
             image: config.image,
             text: config.text,
-            write_without_response_limit: DEFAULT_WRITE_WITHOUT_RESPONSE_LIMIT,
+            write_without_response_limit: config.write_mtu.or(DEFAULT_WRITE_WITHOUT_RESPONSE_LIMIT),
+            write_mtu: config.write_mtu,
             model_resolution: config.model_resolution,
+            material_slots: config.material_slots.map(Into::into),
         }
     }
 
     /// Connects to the first matching fake peripheral and returns a session.
-    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix))]
+    #[instrument(skip(self, observer), level = "debug", fields(prefix = name_prefix))]
     pub(crate) async fn connect_first_matching_device(
         self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
     ) -> Result<FakeDeviceSession, InteractionError> {
-        let Self {
-            devices,
-            services,
-            initial_read,
-            discovery_delay,
-            listen,
-            gif,
-            image,
-            text,
-            write_without_response_limit,
-            model_resolution,
-        } = self;
+        observer.on_event(&ConnectionEvent::ScanStarted {
+            name_prefix: name_prefix.to_string(),
+        });
+        let device =
+            first_matching_device(&self.devices, self.discovery_delay, name_prefix).await?;
+        observer.on_event(&ConnectionEvent::DeviceFound(device.clone()));
+        self.session_for_device(device, observer)
+    }
+
+    /// Finds the first matching fake peripheral and reports endpoint presence only.
+    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix))]
+    pub(crate) async fn probe_first_matching_device(
+        self,
+        name_prefix: &str,
+    ) -> Result<EndpointProbe, InteractionError> {
+        let device =
+            first_matching_device(&self.devices, self.discovery_delay, name_prefix).await?;
+        let (gatt_profile, endpoint_presence) = probe_session_endpoints(&self.services);
+        Ok(EndpointProbe::new(device, gatt_profile, endpoint_presence))
+    }
+
+    /// Connects to every matching fake peripheral before returning.
+    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix))]
+    pub(crate) async fn connect_all_matching_devices(
+        self,
+        name_prefix: &str,
+    ) -> Result<Vec<ConnectAttempt<FakeDeviceSession>>, InteractionError> {
+        if !self.discovery_delay.is_zero() {
+            sleep(self.discovery_delay).await;
+        }
+
+        let matching = self
+            .devices
+            .iter()
+            .filter(|device| device.local_name_starts_with(name_prefix))
+            .cloned()
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            return Err(InteractionError::NoMatchingFixtureDevice {
+                prefix: name_prefix.to_string(),
+            });
+        }
+
+        Ok(matching
+            .into_iter()
+            .map(|device| {
+                let session = self.session_for_device(device.clone(), &NoopConnectionObserver);
+                (device, session)
+            })
+            .collect())
+    }
+
+    fn session_for_device(
+        &self,
+        device: FoundDevice,
+        observer: &dyn ConnectionObserver,
+    ) -> Result<FakeDeviceSession, InteractionError> {
+        let services = self.services.clone();
+        let initial_read = self.initial_read.clone();
+        let listen = self.listen.clone();
+        let write_without_response_limit = self.write_without_response_limit;
+        let model_resolution = &self.model_resolution;
 
-        let device = first_matching_device(devices, discovery_delay, name_prefix).await?;
         let negotiated_endpoints = negotiate_session_endpoints(&services)?;
         let endpoint_presence = negotiated_endpoints.endpoint_presence();
         let missing = missing_required_endpoints(&endpoint_presence);
@@ -509,27 +714,76 @@ impl FakeBackend {
                 missing: format_missing_endpoints(&missing),
             });
         }
-
-        let selected_led_type = select_led_type_override(&device, &model_resolution)?;
-        let led_info = initial_read
-            .as_deref()
-            .and_then(super::LedInfoResponse::parse);
+        observer.on_event(&ConnectionEvent::EndpointsNegotiated(
+            endpoint_presence.clone(),
+        ));
+
+        let selected_led_type = select_led_type_override(&device, model_resolution)?;
+        let mut profile_cache = ProfileCache::load_if_enabled(model_resolution)
+            .filter(|_cache| !model_resolution.skip_led_info());
+        let cached_led_info = profile_cache
+            .as_ref()
+            .and_then(|cache| cache.fresh_led_info_for(&device, SystemTime::now()));
+        let led_info = match cached_led_info {
+            Some(cached) => Some(cached),
+            None => {
+                let probed = initial_read
+                    .as_deref()
+                    .filter(|_payload| !model_resolution.skip_led_info())
+                    .and_then(super::LedInfoResponse::parse);
+                if let (Some(cache), Some(probed)) = (profile_cache.as_mut(), probed)
+                    && let Err(error) = cache.record(&device, probed, SystemTime::now())
+                {
+                    debug!(
+                        ?error,
+                        "failed to persist LED-info result to the profile cache"
+                    );
+                }
+                probed
+            }
+        };
         let device_routing_profile =
             resolve_device_routing_profile(&device, led_info, selected_led_type);
         ensure_ambiguous_shape_is_resolved(&device, device_routing_profile)?;
+        let joint_mode = device_routing_profile
+            .and_then(|profile| profile.joint_mode)
+            .filter(|_joint_mode| !model_resolution.skip_joint_mode());
+        let mut protocol_state =
+            FakeProtocolState::new(self.gif.clone(), self.image.clone(), self.text.clone());
+        if let Some(joint_mode) = joint_mode {
+            protocol_state.joint_mode = Some(joint_mode);
+            debug!(joint_mode, "fake device applied joint mode during connect");
+        }
 
         let device_profile = resolve_device_profile(
             &device,
             &services,
             write_without_response_limit,
             device_routing_profile,
+            led_info,
+        )
+        .with_text_char_count_endian_override(model_resolution.text_count_endian_override())
+        .with_ack_policy(model_resolution.ack_policy());
+        observer.on_event(&ConnectionEvent::ProfileResolved(device_profile));
+        let connection_diagnostics = model_resolution_diagnostics(
+            device.scan_identity().copied(),
+            None,
+            led_info_diagnostic_params(
+                initial_read.as_deref(),
+                led_info,
+                cached_led_info.is_some(),
+                model_resolution,
+            ),
         );
         let session_metadata =
             SessionMetadata::new(true, write_without_response_limit, device_profile)
+                .with_connection_diagnostics(connection_diagnostics)
                 .with_endpoint_resolution(
                     negotiated_endpoints.gatt_profile,
                     negotiated_endpoints.endpoint_uuids.clone(),
-                );
+                )
+                .with_joint_mode(joint_mode)
+                .with_negotiated_mtu(Some(FAKE_NEGOTIATED_MTU));
 
         Ok(FakeDeviceSession {
             device,
@@ -547,7 +801,10 @@ impl FakeBackend {
             ),
             listen_stream_behaviour: listen.stream_behaviour,
             listen_auto_advance_interval: listen.auto_advance_interval,
-            protocol_state: Mutex::new(FakeProtocolState::new(gif, image, text)),
+            write_mtu: self.write_mtu,
+            material_slots: self.material_slots.clone(),
+            protocol_state: Mutex::new(protocol_state),
+            closed: AtomicBool::new(false),
         })
     }
 }
@@ -563,7 +820,10 @@ pub(crate) struct FakeDeviceSession {
     pending_notifications: Mutex<VecDeque<Vec<u8>>>,
     listen_stream_behaviour: ListenStreamBehaviour,
     listen_auto_advance_interval: Option<Duration>,
+    write_mtu: Option<usize>,
+    material_slots: Option<Vec<u8>>,
     protocol_state: Mutex<FakeProtocolState>,
+    closed: AtomicBool,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -624,6 +884,9 @@ struct FakeProtocolState {
     image_progress: TransferProgress,
     text_progress: TransferProgress,
     diy_progress: TransferProgress,
+    joint_mode: Option<u8>,
+    /// Every accepted write-characteristic payload, in the order received.
+    written_frames: Vec<Vec<u8>>,
 }
 
 impl FakeProtocolState {
@@ -636,9 +899,19 @@ impl FakeProtocolState {
             image_progress: TransferProgress::default(),
             text_progress: TransferProgress::default(),
             diy_progress: TransferProgress::default(),
+            joint_mode: None,
+            written_frames: Vec::new(),
         }
     }
 
+    /// Forgets any partially observed transfers, as a device does after a reset.
+    fn reset_progress(&mut self) {
+        self.gif_progress = TransferProgress::default();
+        self.image_progress = TransferProgress::default();
+        self.text_progress = TransferProgress::default();
+        self.diy_progress = TransferProgress::default();
+    }
+
     fn action_for_header(&mut self, header: ParsedTransferHeader) -> AckAction {
         match header.family {
             TransferFamily::Gif => self.gif.action_for(header.phase),
@@ -669,10 +942,18 @@ impl ConnectedBleSession for FakeDeviceSession {
         self.session_metadata.write_without_response_limit()
     }
 
+    fn negotiated_mtu(&self) -> Option<u16> {
+        self.session_metadata.negotiated_mtu()
+    }
+
     fn device_profile(&self) -> DeviceProfile {
         self.session_metadata.device_profile()
     }
 
+    async fn is_alive(&self) -> bool {
+        !self.closed.load(Ordering::Acquire)
+    }
+
     #[instrument(skip(self), level = "trace", fields(?endpoint))]
     async fn read_endpoint(&self, endpoint: EndpointId) -> Result<Vec<u8>, InteractionError> {
         self.read_endpoint_optional(endpoint)
@@ -699,10 +980,20 @@ impl ConnectedBleSession for FakeDeviceSession {
         payload: &[u8],
         mode: WriteMode,
     ) -> Result<(), InteractionError> {
-        let _ = (payload, mode);
         if endpoint != EndpointId::WriteCharacteristic {
             return Err(InteractionError::MissingEndpoint { endpoint });
         }
+        if let Some(mtu) = self.write_mtu.filter(|mtu| payload.len() > *mtu) {
+            return Err(InteractionError::WriteExceedsMtu {
+                len: payload.len(),
+                mtu,
+            });
+        }
+        self.protocol_state
+            .lock()
+            .expect("protocol mutex poisoned")
+            .written_frames
+            .push(payload.to_vec());
 
         if let Some(header) = self.parse_transfer_header(payload) {
             let action = {
@@ -711,8 +1002,26 @@ impl ConnectedBleSession for FakeDeviceSession {
                 protocol_state.action_for_header(header)
             };
             if let Some(event) = action.into_event(header.family) {
-                self.emit_notification(encode_notify_event(event));
+                self.emit_notification(NotificationHandler::encode(&event));
             }
+        } else if let Some(minutes) = parse_screen_light_timeout_set(payload) {
+            self.emit_notification(NotificationHandler::encode(
+                &NotifyEvent::ScreenLightTimeout(minutes),
+            ));
+        } else if let Some(event) = schedule_acknowledgement(payload) {
+            self.emit_notification(NotificationHandler::encode(&event));
+        } else if let Some(joint_mode) = parse_joint_mode_set(payload) {
+            let mut protocol_state = self.protocol_state.lock().expect("protocol mutex poisoned");
+            protocol_state.joint_mode = Some(joint_mode);
+            debug!(joint_mode, "fake device applied joint mode");
+        } else if payload == FACTORY_RESET_FRAME {
+            let mut protocol_state = self.protocol_state.lock().expect("protocol mutex poisoned");
+            protocol_state.reset_progress();
+            debug!(?mode, "fake device acknowledged factory reset");
+        } else if payload == MATERIAL_SLOTS_QUERY_FRAME
+            && let Some(response) = &self.material_slots
+        {
+            self.emit_notification(response.clone());
         }
 
         Ok(())
@@ -720,18 +1029,15 @@ impl ConnectedBleSession for FakeDeviceSession {
 
     #[instrument(skip(self), level = "trace", fields(?endpoint))]
     async fn subscribe_endpoint(&self, endpoint: EndpointId) -> Result<(), InteractionError> {
-        if endpoint != EndpointId::ReadNotifyCharacteristic {
-            return Err(InteractionError::MissingEndpoint { endpoint });
-        }
+        let delivery = self.endpoint_notify_delivery(endpoint)?;
+        trace!(%delivery, "fake device subscribed");
 
         Ok(())
     }
 
     #[instrument(skip(self), level = "trace", fields(?endpoint))]
     async fn unsubscribe_endpoint(&self, endpoint: EndpointId) -> Result<(), InteractionError> {
-        if endpoint != EndpointId::ReadNotifyCharacteristic {
-            return Err(InteractionError::MissingEndpoint { endpoint });
-        }
+        self.endpoint_notify_delivery(endpoint)?;
 
         Ok(())
     }
@@ -740,15 +1046,18 @@ impl ConnectedBleSession for FakeDeviceSession {
         &self,
         endpoint: EndpointId,
     ) -> Result<PayloadStream, InteractionError> {
-        if endpoint != EndpointId::ReadNotifyCharacteristic {
-            return Err(InteractionError::MissingEndpoint { endpoint });
-        }
+        self.endpoint_notify_delivery(endpoint)?;
 
         let (sender, rx) = tokio::sync::mpsc::unbounded_channel();
-        if let Some(interval) = self
+        let auto_advance_interval = self
             .listen_auto_advance_interval
-            .filter(|value| !value.is_zero())
+            .filter(|value| !value.is_zero());
+        if auto_advance_interval.is_some()
+            && Handle::current().runtime_flavor() != RuntimeFlavor::CurrentThread
         {
+            return Err(FixtureError::AutoAdvanceUnavailable.into());
+        }
+        if let Some(interval) = auto_advance_interval {
             let sender_for_clock = sender.clone();
             tokio::spawn(async move {
                 while !sender_for_clock.is_closed() {
@@ -803,12 +1112,25 @@ impl ConnectedBleSession for FakeDeviceSession {
 
     #[instrument(skip(self), level = "debug")]
     async fn close(self: Arc<Self>) -> Result<(), InteractionError> {
-        let _ = self;
+        self.closed.store(true, Ordering::Release);
         Ok(())
     }
 }
 
 impl FakeDeviceSession {
+    /// Returns how `endpoint` delivers notifications, rejecting endpoints
+    /// whose characteristic advertises neither notify nor indicate.
+    ///
+    /// Every subscribable endpoint shares the scenario's notification queue.
+    fn endpoint_notify_delivery(
+        &self,
+        endpoint: EndpointId,
+    ) -> Result<NotifyDelivery, InteractionError> {
+        endpoint_characteristic(&self.services, &self.session_metadata, endpoint)
+            .and_then(CharacteristicInfo::notify_delivery)
+            .ok_or(InteractionError::EndpointNotSubscribable { endpoint })
+    }
+
     fn emit_notification(&self, payload: Vec<u8>) {
         if let Some(sender) = self
             .notification_tx
@@ -894,6 +1216,96 @@ impl FakeDeviceSession {
     }
 }
 
+/// Returns the stored value when `payload` is a screen-light timeout set frame.
+///
+/// Read queries carry the `0xFF` sentinel and are left unanswered, matching
+/// devices that never report the timeout unprompted.
+fn parse_screen_light_timeout_set(payload: &[u8]) -> Option<u8> {
+    match payload {
+        [
+            NOTIFY_PREFIX_LEN,
+            NOTIFY_PREFIX_NS,
+            SCREEN_LIGHT_TIMEOUT_ID,
+            SCHEDULE_NS,
+            value,
+        ] if *value != SCREEN_LIGHT_READ_SENTINEL => Some(*value),
+        _ => None,
+    }
+}
+
+/// Returns the success status the fake device answers schedule setup
+/// frames with.
+fn schedule_acknowledgement(payload: &[u8]) -> Option<NotifyEvent> {
+    match payload {
+        [
+            SCHEDULE_SETUP_FRAME_LEN,
+            NOTIFY_PREFIX_NS,
+            SCHEDULE_SETUP_ID,
+            SCHEDULE_NS,
+            _weekdays,
+            _on_hour,
+            _on_minute,
+            _off_hour,
+            _off_minute,
+        ] => Some(NotifyEvent::ScheduleSetup(ScheduleSetupStatus::Success)),
+        _ => None,
+    }
+}
+
+/// Returns the requested mode when `payload` is a joint-mode frame.
+fn parse_joint_mode_set(payload: &[u8]) -> Option<u8> {
+    match payload {
+        [
+            NOTIFY_PREFIX_LEN,
+            NOTIFY_PREFIX_NS,
+            JOINT_MODE_ID,
+            SCHEDULE_NS,
+            mode,
+        ] => Some(*mode),
+        _ => None,
+    }
+}
+
+/// Describes the fake initial read as if it were the LED-info probe response.
+fn led_info_diagnostic_params(
+    initial_read: Option<&[u8]>,
+    led_info: Option<super::LedInfoResponse>,
+    from_profile_cache: bool,
+    model_resolution: &ModelResolutionConfig,
+) -> LedInfoDiagnosticParams {
+    if model_resolution.skip_led_info() {
+        return LedInfoDiagnosticParams {
+            response: None,
+            query_outcome: LedInfoQueryOutcome::SkippedByConfig,
+            write_modes_attempted: Vec::new(),
+            sync_time_fallback_attempted: false,
+            last_payload: None,
+        };
+    }
+    if from_profile_cache {
+        return LedInfoDiagnosticParams {
+            response: led_info,
+            query_outcome: LedInfoQueryOutcome::CachedProfile,
+            write_modes_attempted: Vec::new(),
+            sync_time_fallback_attempted: false,
+            last_payload: None,
+        };
+    }
+
+    let query_outcome = match (led_info, initial_read) {
+        (Some(_response), _) => LedInfoQueryOutcome::ParsedRead,
+        (None, Some(_payload)) => LedInfoQueryOutcome::InvalidResponse,
+        (None, None) => LedInfoQueryOutcome::NoResponse,
+    };
+    LedInfoDiagnosticParams {
+        response: led_info,
+        query_outcome,
+        write_modes_attempted: Vec::new(),
+        sync_time_fallback_attempted: false,
+        last_payload: initial_read.map(<[u8]>::to_vec),
+    }
+}
+
 fn select_led_type_override(
     device: &FoundDevice,
     model_resolution: &ModelResolutionConfig,
@@ -949,13 +1361,20 @@ fn parse_scan_fixture(raw_fixture: &str) -> Result<Vec<FoundDevice>, FixtureErro
 
     raw_fixture
         .split(';')
-        .map(parse_scan_record)
+        .enumerate()
+        .map(|(index, raw_record)| {
+            parse_scan_record(raw_record).map_err(|failure| FixtureError::InvalidScanRecord {
+                record: index + 1,
+                field: failure.field,
+                source: Box::new(failure.source),
+            })
+        })
         .collect::<Result<Vec<_>, _>>()
 }
 
 #[instrument(skip(devices), level = "trace", fields(prefix = name_prefix))]
 async fn first_matching_device(
-    devices: Vec<FoundDevice>,
+    devices: &[FoundDevice],
     discovery_delay: Duration,
     name_prefix: &str,
 ) -> Result<FoundDevice, InteractionError> {
@@ -964,46 +1383,183 @@ async fn first_matching_device(
     }
 
     devices
-        .into_iter()
+        .iter()
         .find(|device| device.local_name_starts_with(name_prefix))
+        .cloned()
         .ok_or_else(|| InteractionError::NoMatchingFixtureDevice {
             prefix: name_prefix.to_string(),
         })
 }
 
-fn parse_scan_record(raw_record: &str) -> Result<FoundDevice, FixtureError> {
+/// Parse failure within one scan record, before its position is known.
+#[derive(Debug)]
+struct ScanRecordFailure {
+    field: Option<ScanFixtureField>,
+    source: FixtureError,
+}
+
+impl ScanRecordFailure {
+    fn in_field(field: ScanFixtureField, source: FixtureError) -> Self {
+        Self {
+            field: Some(field),
+            source,
+        }
+    }
+}
+
+impl From<FixtureError> for ScanRecordFailure {
+    fn from(source: FixtureError) -> Self {
+        Self {
+            field: None,
+            source,
+        }
+    }
+}
+
+const POSITIONAL_SCAN_FIELDS: [ScanFixtureField; 4] = [
+    ScanFixtureField::Adapter,
+    ScanFixtureField::Address,
+    ScanFixtureField::Name,
+    ScanFixtureField::Rssi,
+];
+const DEFAULT_FIXTURE_ADAPTER: &str = "hci0";
+
+/// Raw field values of one scan record; `None` marks an omitted field.
+#[derive(Debug, Default)]
+struct ScanRecordFields<'a> {
+    adapter: Option<&'a str>,
+    address: Option<&'a str>,
+    name: Option<&'a str>,
+    rssi: Option<&'a str>,
+    scan: Option<&'a str>,
+}
+
+impl<'a> ScanRecordFields<'a> {
+    fn slot(&mut self, field: ScanFixtureField) -> &mut Option<&'a str> {
+        match field {
+            ScanFixtureField::Adapter => &mut self.adapter,
+            ScanFixtureField::Address => &mut self.address,
+            ScanFixtureField::Name => &mut self.name,
+            ScanFixtureField::Rssi => &mut self.rssi,
+            ScanFixtureField::Scan => &mut self.scan,
+        }
+    }
+}
+
+/// Parses one record in either the positional (`hci|addr|name|rssi[|scan]`) or
+/// named (`addr=AA:BB,name=IDM-Cube`) form.
+fn parse_scan_record(raw_record: &str) -> Result<FoundDevice, ScanRecordFailure> {
+    let fields = if raw_record.contains('=') && !raw_record.contains('|') {
+        named_scan_record_fields(raw_record)?
+    } else {
+        positional_scan_record_fields(raw_record)?
+    };
+    found_device_from_fields(&fields)
+}
+
+fn positional_scan_record_fields(
+    raw_record: &str,
+) -> Result<ScanRecordFields<'_>, ScanRecordFailure> {
     let fields: Vec<&str> = raw_record.split('|').map(str::trim).collect();
     if fields.len() != 4 && fields.len() != 5 {
-        return Err(FixtureError::InvalidRecordFieldCount);
+        return Err(FixtureError::InvalidRecordFieldCount.into());
     }
-    if fields[0].is_empty() || fields[1].is_empty() || fields[2].is_empty() || fields[3].is_empty()
+    if let Some(field) = POSITIONAL_SCAN_FIELDS
+        .iter()
+        .zip(&fields)
+        .find_map(|(field, value)| value.is_empty().then_some(*field))
     {
-        return Err(FixtureError::EmptyRecordField);
+        return Err(ScanRecordFailure::in_field(
+            field,
+            FixtureError::EmptyRecordField,
+        ));
     }
 
-    let local_name = if fields[2] == "-" {
-        None
-    } else {
-        Some(fields[2].to_string())
-    };
-    let rssi = if fields[3] == "-" {
-        None
-    } else {
-        Some(fields[3].parse::<i16>()?)
-    };
+    let unless_placeholder = |value: &&str| *value != "-";
+    Ok(ScanRecordFields {
+        adapter: Some(fields[0]),
+        address: Some(fields[1]),
+        name: Some(fields[2]).filter(unless_placeholder),
+        rssi: Some(fields[3]).filter(unless_placeholder),
+        scan: fields.get(4).copied().filter(unless_placeholder),
+    })
+}
+
+fn named_scan_record_fields(raw_record: &str) -> Result<ScanRecordFields<'_>, ScanRecordFailure> {
+    let mut fields = ScanRecordFields::default();
+    for entry in raw_record.split(',').map(str::trim) {
+        let Some((key, value)) = entry.split_once('=') else {
+            return Err(FixtureError::InvalidNamedField {
+                entry: entry.to_string(),
+            }
+            .into());
+        };
+        let field = named_scan_field(key.trim())?;
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ScanRecordFailure::in_field(
+                field,
+                FixtureError::EmptyRecordField,
+            ));
+        }
+        if fields.slot(field).replace(value).is_some() {
+            return Err(ScanRecordFailure::in_field(
+                field,
+                FixtureError::DuplicateNamedField,
+            ));
+        }
+    }
+    Ok(fields)
+}
+
+fn named_scan_field(key: &str) -> Result<ScanFixtureField, FixtureError> {
+    match key {
+        "adapter" | "hci" => Ok(ScanFixtureField::Adapter),
+        "addr" | "address" => Ok(ScanFixtureField::Address),
+        "name" => Ok(ScanFixtureField::Name),
+        "rssi" => Ok(ScanFixtureField::Rssi),
+        "scan" => Ok(ScanFixtureField::Scan),
+        _ => Err(FixtureError::UnknownNamedField {
+            key: key.to_string(),
+        }),
+    }
+}
+
+fn found_device_from_fields(
+    fields: &ScanRecordFields<'_>,
+) -> Result<FoundDevice, ScanRecordFailure> {
+    let address = fields.address.ok_or_else(|| {
+        ScanRecordFailure::in_field(ScanFixtureField::Address, FixtureError::MissingRecordField)
+    })?;
+    let rssi =
+        match fields.rssi {
+            Some(value) => Some(value.parse::<i16>().map_err(|error| {
+                ScanRecordFailure::in_field(ScanFixtureField::Rssi, error.into())
+            })?),
+            None => None,
+        };
 
     let device = FoundDevice::new(
-        fields[0].to_string(),
-        fields[1].to_string(),
-        local_name,
+        fields
+            .adapter
+            .unwrap_or(DEFAULT_FIXTURE_ADAPTER)
+            .to_string(),
+        address.to_string(),
+        fields.name.map(str::to_string),
         rssi,
     );
 
-    let scan_model = match fields.get(4).copied().filter(|value| *value != "-") {
+    let scan_model = match fields.scan {
         Some(value) => {
-            let scan_payload = parse_hex(value)?;
-            let scan_identity = ScanModelHandler::parse_identity(&scan_payload)
-                .ok_or(FixtureError::InvalidScanModelPayload)?;
+            let scan_payload = parse_hex(value)
+                .map_err(|error| ScanRecordFailure::in_field(ScanFixtureField::Scan, error))?;
+            let scan_identity =
+                ScanModelHandler::parse_identity(&scan_payload).ok_or_else(|| {
+                    ScanRecordFailure::in_field(
+                        ScanFixtureField::Scan,
+                        FixtureError::InvalidScanModelPayload,
+                    )
+                })?;
             let model_profile = ScanModelHandler::resolve_model(&scan_identity);
             Some((scan_identity, model_profile))
         }
@@ -1018,6 +1574,20 @@ fn parse_scan_record(raw_record: &str) -> Result<FoundDevice, FixtureError> {
     })
 }
 
+/// Reads a fixture file and joins its non-blank lines with `separator`.
+fn read_fixture_file(path: &Path, separator: &str) -> Result<String, FixtureError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| FixtureError::ReadFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator))
+}
+
 fn parse_notifications(raw_value: &str) -> Result<Vec<Vec<u8>>, FixtureError> {
     if raw_value.trim().is_empty() {
         return Ok(Vec::new());
@@ -1025,167 +1595,6 @@ fn parse_notifications(raw_value: &str) -> Result<Vec<Vec<u8>>, FixtureError> {
     raw_value.split(',').map(parse_hex).collect()
 }
 
-fn encode_notify_event(event: NotifyEvent) -> Vec<u8> {
-    let frame = match event {
-        NotifyEvent::NextPackage(TransferFamily::Text) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: TEXT_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status: STATUS_NEXT_PACKAGE,
-        }),
-        NotifyEvent::Finished(TransferFamily::Text) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: TEXT_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status: STATUS_FINISHED,
-        }),
-        NotifyEvent::Error(TransferFamily::Text, status) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: TEXT_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status,
-        }),
-        NotifyEvent::NextPackage(TransferFamily::Gif) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: GIF_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status: STATUS_NEXT_PACKAGE,
-        }),
-        NotifyEvent::Finished(TransferFamily::Gif) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: GIF_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status: STATUS_FINISHED,
-        }),
-        NotifyEvent::Error(TransferFamily::Gif, status) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: GIF_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status,
-        }),
-        NotifyEvent::NextPackage(TransferFamily::Image) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: IMAGE_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status: STATUS_NEXT_PACKAGE,
-        }),
-        NotifyEvent::Finished(TransferFamily::Image) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: IMAGE_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status: STATUS_FINISHED,
-        }),
-        NotifyEvent::Error(TransferFamily::Image, status) => Some(NotificationFrame {
-            code: NotificationCode {
-                id: IMAGE_COMMAND_ID,
-                ns: COMMAND_NS,
-            },
-            status,
-        }),
-        NotifyEvent::NextPackage(TransferFamily::Diy) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x00, ns: 0x00 },
-            status: 0x02,
-        }),
-        NotifyEvent::Finished(TransferFamily::Diy) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x00, ns: 0x00 },
-            status: 0x00,
-        }),
-        NotifyEvent::Error(TransferFamily::Diy, status) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x00, ns: 0x00 },
-            status,
-        }),
-        NotifyEvent::NextPackage(TransferFamily::Timer) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x00, ns: 0x80 },
-            status: STATUS_NEXT_PACKAGE,
-        }),
-        NotifyEvent::Finished(TransferFamily::Timer) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x00, ns: 0x80 },
-            status: STATUS_FINISHED,
-        }),
-        NotifyEvent::Error(TransferFamily::Timer, status) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x00, ns: 0x80 },
-            status,
-        }),
-        NotifyEvent::NextPackage(TransferFamily::Ota) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x01, ns: 0xC0 },
-            status: STATUS_NEXT_PACKAGE,
-        }),
-        NotifyEvent::Finished(TransferFamily::Ota) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x01, ns: 0xC0 },
-            status: STATUS_FINISHED,
-        }),
-        NotifyEvent::Error(TransferFamily::Ota, status) => Some(NotificationFrame {
-            code: NotificationCode { id: 0x01, ns: 0xC0 },
-            status,
-        }),
-        NotifyEvent::ScheduleSetup(status) => {
-            let value = match status {
-                crate::ScheduleSetupStatus::Success => 0x01,
-                crate::ScheduleSetupStatus::Continue => 0x03,
-                crate::ScheduleSetupStatus::Failed(other) => other,
-            };
-            return NotificationFrame {
-                code: NotificationCode {
-                    id: SCHEDULE_SETUP_ID,
-                    ns: SCHEDULE_NS,
-                },
-                status: value,
-            }
-            .into_payload();
-        }
-        NotifyEvent::ScheduleMasterSwitch(status) => {
-            let value = match status {
-                crate::ScheduleMasterSwitchStatus::Success => 0x01,
-                crate::ScheduleMasterSwitchStatus::Failed(other) => other,
-            };
-            return NotificationFrame {
-                code: NotificationCode {
-                    id: SCHEDULE_MASTER_SWITCH_ID,
-                    ns: SCHEDULE_NS,
-                },
-                status: value,
-            }
-            .into_payload();
-        }
-        NotifyEvent::LedInfo(response) => {
-            return vec![
-                0x09,
-                0x00,
-                0x01,
-                0x80,
-                response.mcu_major_version,
-                response.mcu_minor_version,
-                response.status,
-                response.screen_type,
-                u8::from(response.password_enabled),
-            ];
-        }
-        NotifyEvent::ScreenLightTimeout(value) => {
-            return NotificationFrame {
-                code: NotificationCode {
-                    id: SCREEN_LIGHT_TIMEOUT_ID,
-                    ns: SCHEDULE_NS,
-                },
-                status: value,
-            }
-            .into_payload();
-        }
-        NotifyEvent::Unknown(payload) => return payload,
-    };
-
-    frame
-        .expect("all transfer notifications map to frames")
-        .into_payload()
-}
-
 fn parse_hex(raw_value: &str) -> Result<Vec<u8>, FixtureError> {
     let cleaned: String = raw_value.chars().filter(|c| !c.is_whitespace()).collect();
     if !cleaned.len().is_multiple_of(2) {
@@ -1209,15 +1618,40 @@ fn parse_hex(raw_value: &str) -> Result<Vec<u8>, FixtureError> {
     Ok(payload)
 }
 
-fn default_services() -> Vec<ServiceInfo> {
+fn delivery_labels(delivery: NotifyDelivery) -> &'static [&'static str] {
+    match delivery {
+        NotifyDelivery::Notify => &["notify"],
+        NotifyDelivery::Indicate => &["indicate"],
+        NotifyDelivery::NotifyAndIndicate => &["notify", "indicate"],
+    }
+}
+
+fn default_services(
+    notify_delivery: NotifyDelivery,
+    write_notify_delivery: Option<NotifyDelivery>,
+) -> Vec<ServiceInfo> {
+    let read_notify_properties = std::iter::once("read")
+        .chain(delivery_labels(notify_delivery).iter().copied())
+        .map(str::to_string)
+        .collect();
+    let write_properties = std::iter::once("write")
+        .chain(
+            write_notify_delivery
+                .map_or(&[][..], delivery_labels)
+                .iter()
+                .copied(),
+        )
+        .map(str::to_string)
+        .collect();
+
     vec![ServiceInfo::new(
         FA_SERVICE_UUID.to_string(),
         true,
         vec![
-            CharacteristicInfo::new(FA_WRITE_UUID.to_string(), vec!["write".to_string()]),
+            CharacteristicInfo::new(FA_WRITE_UUID.to_string(), write_properties),
             CharacteristicInfo::new(
                 "0000fa03-0000-1000-8000-00805f9b34fb".to_string(),
-                vec!["read".to_string(), "notify".to_string()],
+                read_notify_properties,
             ),
         ],
     )]
@@ -1256,7 +1690,117 @@ mod tests {
     #[test]
     fn parse_scan_fixture_rejects_invalid_field_count() {
         let result = parse_scan_fixture("hci0|AA:BB|IDM-Cube");
-        assert_matches!(result, Err(FixtureError::InvalidRecordFieldCount));
+        assert_matches!(
+            result,
+            Err(FixtureError::InvalidScanRecord { record: 1, field: None, source })
+                if matches!(*source, FixtureError::InvalidRecordFieldCount)
+        );
+    }
+
+    #[rstest]
+    #[case::field_count(
+        "hci0|AA:BB|IDM-Cube|-43;hci0|CC:DD|Speaker;hci1|EE:FF|IDM-Clock|-50",
+        None
+    )]
+    #[case::empty_address(
+        "hci0|AA:BB|IDM-Cube|-43;hci0||Speaker|-55;hci1|EE:FF|IDM-Clock|-50",
+        Some(ScanFixtureField::Address)
+    )]
+    #[case::rssi(
+        "hci0|AA:BB|IDM-Cube|-43;hci0|CC:DD|Speaker|loud;hci1|EE:FF|IDM-Clock|-50",
+        Some(ScanFixtureField::Rssi)
+    )]
+    #[case::scan_payload(
+        "hci0|AA:BB|IDM-Cube|-43;hci0|CC:DD|Speaker|-55|ABC;hci1|EE:FF|IDM-Clock|-50",
+        Some(ScanFixtureField::Scan)
+    )]
+    fn parse_scan_fixture_identifies_malformed_record(
+        #[case] fixture: &str,
+        #[case] expected_field: Option<ScanFixtureField>,
+    ) {
+        let result = parse_scan_fixture(fixture);
+
+        let Err(FixtureError::InvalidScanRecord { record, field, .. }) = result else {
+            panic!("expected a positional scan record error, got {result:?}");
+        };
+        assert_eq!(2, record);
+        assert_eq!(expected_field, field);
+    }
+
+    #[test]
+    fn parse_scan_fixture_accepts_named_records_in_any_order() {
+        let devices = parse_scan_fixture(
+            "scan=5452007004010200010520002000,rssi=-43,name=IDM-Cube,addr=AA:BB,adapter=hci1",
+        )
+        .expect("named fixture should parse");
+
+        let [device] = devices.as_slice() else {
+            panic!("expected one device, got {devices:?}");
+        };
+        assert_eq!("hci1", device.adapter_name());
+        assert_eq!("AA:BB", device.device_id());
+        assert_eq!(Some("IDM-Cube"), device.local_name());
+        assert_eq!(Some(-43), device.rssi());
+        assert_eq!(
+            Some(4),
+            device.scan_identity().map(|identity| identity.shape)
+        );
+    }
+
+    #[test]
+    fn parse_scan_fixture_defaults_omitted_named_fields() {
+        let devices = parse_scan_fixture("addr=AA:BB").expect("named fixture should parse");
+
+        let [device] = devices.as_slice() else {
+            panic!("expected one device, got {devices:?}");
+        };
+        assert_eq!("hci0", device.adapter_name());
+        assert_eq!("AA:BB", device.device_id());
+        assert_eq!(None, device.local_name());
+        assert_eq!(None, device.rssi());
+        assert_eq!(None, device.scan_identity());
+    }
+
+    #[test]
+    fn parse_scan_fixture_mixes_positional_and_named_records() {
+        let devices = parse_scan_fixture("hci0|AA:BB|IDM-Cube|-43;name=IDM-Clock,addr=CC:DD")
+            .expect("mixed fixture should parse");
+
+        let names = devices
+            .iter()
+            .map(FoundDevice::local_name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some("IDM-Cube"), Some("IDM-Clock")], names);
+    }
+
+    #[rstest]
+    #[case::missing_address("name=IDM-Cube,rssi=-43", Some(ScanFixtureField::Address))]
+    #[case::duplicate_field("addr=AA:BB,name=A,name=B", Some(ScanFixtureField::Name))]
+    #[case::empty_value("addr=AA:BB,rssi=", Some(ScanFixtureField::Rssi))]
+    #[case::unknown_key("addr=AA:BB,colour=red", None)]
+    #[case::bare_entry("addr=AA:BB,IDM-Cube", None)]
+    fn parse_scan_fixture_rejects_malformed_named_records(
+        #[case] fixture: &str,
+        #[case] expected_field: Option<ScanFixtureField>,
+    ) {
+        let result = parse_scan_fixture(fixture);
+
+        let Err(FixtureError::InvalidScanRecord { record, field, .. }) = result else {
+            panic!("expected a named scan record error, got {result:?}");
+        };
+        assert_eq!(1, record);
+        assert_eq!(expected_field, field);
+    }
+
+    #[test]
+    fn parse_scan_fixture_error_message_names_record_and_field() {
+        let error = parse_scan_fixture("hci0|AA:BB|IDM-Cube|-43;hci0|CC:DD|Speaker|loud")
+            .expect_err("second record should fail");
+
+        assert_eq!(
+            "scan fixture record 2, field `rssi`: failed to parse RSSI value",
+            error.to_string()
+        );
     }
 
     #[test]
@@ -1265,9 +1809,201 @@ mod tests {
         assert_matches!(result, Err(FixtureError::InvalidHexLength));
     }
 
+    #[test]
+    fn default_initial_read_decodes_as_ready_status() {
+        let status = crate::InitialReadStatus::decode(&DEFAULT_INITIAL_READ)
+            .expect("default initial read should be a status frame");
+
+        assert_eq!(0x01, status.command_id());
+        assert_eq!(0x00, status.command_ns());
+        assert_eq!(0x01, status.status());
+        assert_eq!(true, status.is_ready());
+    }
+
+    #[test]
+    fn gif_multi_chunk_happy_path_acks_each_chunk_then_finishes() {
+        let scenario: ListenScenario = "gif_multi_chunk_happy_path"
+            .parse()
+            .expect("fixture name should parse");
+
+        let next_package = ListenNotification::Event(NotifyEvent::NextPackage(TransferFamily::Gif));
+        let finished = ListenNotification::Event(NotifyEvent::Finished(TransferFamily::Gif));
+        assert_eq!(
+            vec![
+                next_package.clone(),
+                next_package.clone(),
+                next_package,
+                finished
+            ],
+            scenario.notifications
+        );
+    }
+
+    #[rstest]
+    #[case::set_five_minutes(&[0x05, 0x00, 0x0F, 0x80, 0x05], Some(5))]
+    #[case::read_sentinel(&[0x05, 0x00, 0x0F, 0x80, 0xFF], None)]
+    #[case::brightness(&[0x05, 0x00, 0x04, 0x80, 0x05], None)]
+    fn parse_screen_light_timeout_set_matches_set_frames_only(
+        #[case] payload: &[u8],
+        #[case] expected: Option<u8>,
+    ) {
+        assert_eq!(expected, parse_screen_light_timeout_set(payload));
+    }
+
+    #[rstest]
+    #[case::setup(
+        &[0x09, 0x00, 0x05, 0x80, 0x7F, 0x08, 0x00, 0x17, 0x00],
+        Some(NotifyEvent::ScheduleSetup(ScheduleSetupStatus::Success)),
+    )]
+    #[case::time_indicator(&[0x05, 0x00, 0x07, 0x80, 0x01], None)]
+    #[case::truncated_setup(&[0x09, 0x00, 0x05, 0x80, 0x7F], None)]
+    #[case::joint_mode(&[0x05, 0x00, 0x0C, 0x80, 0x01], None)]
+    fn schedule_acknowledgement_answers_schedule_frames_only(
+        #[case] payload: &[u8],
+        #[case] expected: Option<NotifyEvent>,
+    ) {
+        assert_eq!(expected, schedule_acknowledgement(payload));
+    }
+
+    #[rstest]
+    #[case::off(crate::JointMode::Off, 0x00)]
+    #[case::on(crate::JointMode::On, 0x01)]
+    #[tokio::test]
+    async fn fake_session_records_joint_mode_byte(
+        #[case] mode: crate::JointMode,
+        #[case] expected: u8,
+    ) {
+        let scan = ScanScenario::from_fixture("hci0|AA:BB|IDM-Cube|-43")
+            .expect("scan fixture should parse");
+        let backend = FakeBackend::new(
+            FakeBackendConfig::builder()
+                .scan(scan)
+                .maybe_initial_read(None)
+                .build(),
+        );
+        let session = backend
+            .connect_first_matching_device("IDM-", &NoopConnectionObserver)
+            .await
+            .expect("fake session should connect");
+        let frame = crate::handlers::joint_mode_frame(
+            mode.as_payload_byte(session.device_profile().joint_mode()),
+        );
+
+        session
+            .write_endpoint(
+                EndpointId::WriteCharacteristic,
+                &frame,
+                WriteMode::WithResponse,
+            )
+            .await
+            .expect("joint-mode write should succeed");
+
+        let protocol_state = session
+            .protocol_state
+            .lock()
+            .expect("protocol mutex poisoned");
+        assert_eq!(Some(expected), protocol_state.joint_mode);
+    }
+
+    #[tokio::test]
+    async fn scene_handler_writes_power_colour_then_brightness() {
+        use crate::hw::DeviceSession;
+        use crate::hw::session::chunk_sizer::AdaptiveChunkSizer;
+
+        let scan = ScanScenario::from_fixture("hci0|AA:BB|IDM-Cube|-43")
+            .expect("scan fixture should parse");
+        let backend = FakeBackend::new(FakeBackendConfig::builder().scan(scan).build());
+        let fake_session = Arc::new(
+            backend
+                .connect_first_matching_device("IDM-", &NoopConnectionObserver)
+                .await
+                .expect("fake session should connect"),
+        );
+        let session = DeviceSession {
+            session: fake_session.clone(),
+            chunk_sizer: Arc::new(AdaptiveChunkSizer::from_baseline(512)),
+            close_timeout: Duration::from_secs(1),
+            notification_slot: Arc::default(),
+        };
+        let request = crate::SceneRequest::builder()
+            .brightness(crate::Brightness::new(40).expect("40 is a valid brightness"))
+            .colour(crate::Rgb::new(0x11, 0x22, 0x33))
+            .power(crate::ScreenPower::On)
+            .build();
+
+        let applied = crate::SceneHandler::apply(&session, request)
+            .await
+            .expect("scene should apply");
+
+        assert_eq!(
+            vec![
+                crate::SceneStep::Power,
+                crate::SceneStep::Colour,
+                crate::SceneStep::Brightness,
+            ],
+            applied
+        );
+        let protocol_state = fake_session
+            .protocol_state
+            .lock()
+            .expect("protocol mutex poisoned");
+        assert_eq!(
+            vec![
+                vec![0x05, 0x00, 0x07, 0x01, 0x01],
+                vec![0x07, 0x00, 0x02, 0x02, 0x11, 0x22, 0x33],
+                vec![0x05, 0x00, 0x04, 0x80, 0x28],
+            ],
+            protocol_state.written_frames
+        );
+    }
+
+    #[rstest]
+    #[case::applied_by_default(false, Some(2))]
+    #[case::suppressed(true, None)]
+    #[tokio::test]
+    async fn fake_session_applies_joint_mode_unless_suppressed(
+        #[case] skip_joint_mode: bool,
+        #[case] expected: Option<u8>,
+    ) {
+        let scan =
+            ScanScenario::from_fixture("hci0|AAMBIG:02|IDM-1+3|-43|5452007081010200010720002000")
+                .expect("scan fixture should parse");
+        let initial_read = "090001800100000200"
+            .parse()
+            .expect("initial read should parse");
+        let backend = FakeBackend::new(
+            FakeBackendConfig::builder()
+                .scan(scan)
+                .initial_read(initial_read)
+                .model_resolution(
+                    ModelResolutionConfig::default().with_skip_joint_mode(skip_joint_mode),
+                )
+                .build(),
+        );
+
+        let session = backend
+            .connect_first_matching_device("IDM-", &NoopConnectionObserver)
+            .await
+            .expect("fake session should connect");
+
+        let protocol_state = session
+            .protocol_state
+            .lock()
+            .expect("protocol mutex poisoned");
+        assert_eq!(expected, protocol_state.joint_mode);
+        assert_eq!(expected, session.session_metadata.joint_mode());
+    }
+
     #[test]
     fn parse_scan_fixture_rejects_invalid_scan_model_payload() {
         let result = parse_scan_fixture("hci0|AA:BB|IDM-Cube|-43|DEADBEEF");
-        assert_matches!(result, Err(FixtureError::InvalidScanModelPayload));
+        assert_matches!(
+            result,
+            Err(FixtureError::InvalidScanRecord {
+                record: 1,
+                field: Some(ScanFixtureField::Scan),
+                source,
+            }) if matches!(*source, FixtureError::InvalidScanModelPayload)
+        );
     }
 }
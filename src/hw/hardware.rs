@@ -1,17 +1,24 @@
+use std::future::Future;
+use std::num::NonZeroUsize;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use async_trait::async_trait;
-use tokio::time::timeout;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tokio::time::{Instant, timeout};
 use tokio_stream::Stream;
 use tokio_util::sync::CancellationToken;
-use tracing::{Span, info, instrument, trace};
+use tracing::{Span, info, instrument, trace, warn};
 
 use super::btleplug_backend::BtleplugBackend;
+use super::connection_observer::{ConnectionObserver, NoopConnectionObserver};
 use super::fake_backend::{FakeBackend, FakeBackendConfig};
 use super::model::{
-    EndpointPresence, FoundDevice, InspectReport, ListenStopReason, NotificationRunSummary,
+    EndpointPresence, EndpointProbe, FoundDevice, InspectReport, ListenStopReason,
+    NotificationRunSummary,
 };
 use super::model_overrides::ModelResolutionConfig;
 use super::profile::DeviceProfile;
@@ -19,8 +26,9 @@ use super::session::chunk_sizer::AdaptiveChunkSizer;
 use crate::error::InteractionError;
 use crate::notification::{NotificationDecodeError, NotificationHandler, NotifyEvent};
 use crate::protocol::EndpointId;
+use crate::warning::Warning;
 
-const SESSION_CLOSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+const SESSION_CLOSE_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
 
 /// Creates a hardware client backed by the real BLE transport.
 pub(crate) fn real_hardware_client() -> Box<dyn HardwareClient> {
@@ -64,9 +72,15 @@ pub(crate) trait ConnectedBleSession: Send + Sync {
     /// Returns the negotiated write-without-response payload limit, if known.
     fn write_without_response_limit(&self) -> Option<usize>;
 
+    /// Returns the negotiated ATT MTU, if known.
+    fn negotiated_mtu(&self) -> Option<u16>;
+
     /// Returns the resolved device profile for this session.
     fn device_profile(&self) -> DeviceProfile;
 
+    /// Returns whether the underlying peripheral is still connected.
+    async fn is_alive(&self) -> bool;
+
     /// Reads one endpoint value.
     async fn read_endpoint(&self, endpoint: EndpointId) -> Result<Vec<u8>, InteractionError>;
 
@@ -108,28 +122,49 @@ pub(crate) trait ConnectedBleSession: Send + Sync {
 /// Low-level transport capable of establishing iDotMatrix sessions.
 #[async_trait]
 pub(crate) trait BleTransport: Send {
-    /// Connects to the first peripheral matching `name_prefix`.
+    /// Connects to the first peripheral matching `name_prefix`, reporting
+    /// milestones to `observer`.
     async fn connect_first_matching(
         self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
     ) -> Result<Arc<dyn ConnectedBleSession>, InteractionError>;
+
+    /// Connects to every peripheral matching `name_prefix` seen within `scan_window`.
+    ///
+    /// The outer error covers discovery; each device carries its own connection result.
+    async fn connect_all_matching(
+        self,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<TransportConnectAttempt>, InteractionError>;
 }
 
+/// One discovered peripheral paired with the outcome of connecting to it.
+pub(crate) type ConnectAttempt<S> = (FoundDevice, Result<S, InteractionError>);
+
+/// A [`ConnectAttempt`] holding a transport-erased session.
+pub(crate) type TransportConnectAttempt = ConnectAttempt<Arc<dyn ConnectedBleSession>>;
+
 /// Session builder over a selected BLE transport.
 #[derive(Debug)]
 pub(crate) struct SessionHandler<T: BleTransport> {
     transport: T,
+    close_timeout: Duration,
 }
 
 impl<T: BleTransport> SessionHandler<T> {
     /// Creates a new session handler.
-    pub(crate) fn new(transport: T) -> Self {
-        Self { transport }
+    pub(crate) fn new(transport: T, close_timeout: Duration) -> Self {
+        Self {
+            transport,
+            close_timeout,
+        }
     }
 
     /// Connects to the first matching device and returns a session.
     #[instrument(
-        skip(self),
+        skip(self, observer),
         level = "debug",
         fields(
             prefix = name_prefix,
@@ -146,8 +181,12 @@ impl<T: BleTransport> SessionHandler<T> {
     pub(crate) async fn connect_first(
         self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
     ) -> Result<DeviceSession, InteractionError> {
-        let session = self.transport.connect_first_matching(name_prefix).await?;
+        let session = self
+            .transport
+            .connect_first_matching(name_prefix, observer)
+            .await?;
         let resolved_chunk_sizer = super::session::resolve_chunk_sizer(&*session);
         let profile = session.device_profile();
         let span = Span::current();
@@ -174,8 +213,42 @@ impl<T: BleTransport> SessionHandler<T> {
         Ok(DeviceSession {
             session,
             chunk_sizer: resolved_chunk_sizer.chunk_sizer,
+            close_timeout: self.close_timeout,
+            notification_slot: Arc::default(),
         })
     }
+
+    /// Connects to every device matching within `scan_window`.
+    ///
+    /// Every connection is made before this returns, so all sessions are open
+    /// at once.
+    #[instrument(skip(self), level = "debug", fields(prefix = name_prefix, ?scan_window))]
+    pub(crate) async fn connect_all(
+        self,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<DeviceConnectAttempt>, InteractionError> {
+        let close_timeout = self.close_timeout;
+        let attempts = self
+            .transport
+            .connect_all_matching(name_prefix, scan_window)
+            .await?;
+        Ok(attempts
+            .into_iter()
+            .map(|(device, session)| DeviceConnectAttempt {
+                device,
+                session: session.map(|session| {
+                    let chunk_sizer = super::session::resolve_chunk_sizer(&*session).chunk_sizer;
+                    DeviceSession {
+                        session,
+                        chunk_sizer,
+                        close_timeout,
+                        notification_slot: Arc::default(),
+                    }
+                }),
+            })
+            .collect())
+    }
 }
 
 pub(crate) fn missing_required_endpoints(presence: &EndpointPresence) -> Vec<EndpointId> {
@@ -196,10 +269,31 @@ impl BleTransport for BtleplugBackend {
     async fn connect_first_matching(
         self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
     ) -> Result<Arc<dyn ConnectedBleSession>, InteractionError> {
-        let session = self.connect_first_matching_device(name_prefix).await?;
+        let session = self
+            .connect_first_matching_device(name_prefix, observer)
+            .await?;
         Ok(Arc::new(session))
     }
+
+    async fn connect_all_matching(
+        self,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<TransportConnectAttempt>, InteractionError> {
+        let attempts = self
+            .connect_all_matching_devices(name_prefix, scan_window)
+            .await?;
+        Ok(attempts
+            .into_iter()
+            .map(|(device, session)| {
+                let session =
+                    session.map(|session| Arc::new(session) as Arc<dyn ConnectedBleSession>);
+                (device, session)
+            })
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -207,10 +301,30 @@ impl BleTransport for FakeBackend {
     async fn connect_first_matching(
         self,
         name_prefix: &str,
+        observer: &dyn ConnectionObserver,
     ) -> Result<Arc<dyn ConnectedBleSession>, InteractionError> {
-        let session = self.connect_first_matching_device(name_prefix).await?;
+        let session = self
+            .connect_first_matching_device(name_prefix, observer)
+            .await?;
         Ok(Arc::new(session))
     }
+
+    /// Fixture devices are all visible immediately, so `scan_window` is unused.
+    async fn connect_all_matching(
+        self,
+        name_prefix: &str,
+        _scan_window: Duration,
+    ) -> Result<Vec<TransportConnectAttempt>, InteractionError> {
+        let attempts = self.connect_all_matching_devices(name_prefix).await?;
+        Ok(attempts
+            .into_iter()
+            .map(|(device, session)| {
+                let session =
+                    session.map(|session| Arc::new(session) as Arc<dyn ConnectedBleSession>);
+                (device, session)
+            })
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -220,6 +334,155 @@ pub trait HardwareClient: Send + Sync {
         self: Box<Self>,
         name_prefix: &str,
     ) -> Result<DeviceSession, InteractionError>;
+
+    /// Connects to the first matching iDotMatrix peripheral, reporting each
+    /// milestone to `observer`.
+    ///
+    /// The default implementation reports nothing and connects exactly as
+    /// [`connect_first_device`](Self::connect_first_device) does.
+    async fn connect_first_device_observed(
+        self: Box<Self>,
+        name_prefix: &str,
+        _observer: Arc<dyn ConnectionObserver>,
+    ) -> Result<DeviceSession, InteractionError> {
+        self.connect_first_device(name_prefix).await
+    }
+
+    /// Connects to every iDotMatrix peripheral matching within `scan_window`.
+    ///
+    /// Devices are connected one after another and every session is open
+    /// before this returns. The default implementation only reaches the first
+    /// matching device.
+    async fn connect_all_devices(
+        self: Box<Self>,
+        name_prefix: &str,
+        _scan_window: Duration,
+    ) -> Result<Vec<DeviceConnectAttempt>, InteractionError> {
+        let session = self.connect_first_device(name_prefix).await?;
+        Ok(vec![DeviceConnectAttempt {
+            device: session.device().clone(),
+            session: Ok(session),
+        }])
+    }
+
+    /// Connects to the first matching peripheral, reports endpoint presence, then disconnects.
+    ///
+    /// Backends skip the LED-info query and profile resolution. The default
+    /// implementation connects a full session and closes it again.
+    async fn probe_first_device(
+        self: Box<Self>,
+        name_prefix: &str,
+    ) -> Result<EndpointProbe, InteractionError> {
+        let session = self.connect_first_device(name_prefix).await?;
+        let report = session.inspect_report();
+        session.close().await?;
+        Ok(EndpointProbe::new(
+            report.device().clone(),
+            report.session_metadata().gatt_profile(),
+            report.endpoint_presence().clone(),
+        ))
+    }
+
+    /// Lists the Bluetooth adapters this client can open, without scanning.
+    ///
+    /// The default implementation reports none.
+    async fn adapter_names(&self) -> Result<Vec<String>, InteractionError> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the model-resolution settings this client connects with.
+    ///
+    /// The default implementation returns [`ModelResolutionConfig::default`].
+    fn model_resolution(&self) -> ModelResolutionConfig {
+        ModelResolutionConfig::default()
+    }
+
+    /// Connects to and closes the first matching peripheral `cycles` times,
+    /// timing each cycle.
+    ///
+    /// Backends start every cycle from a fresh transport, so adapter setup,
+    /// scanning, connect and teardown all run again. A failed cycle is
+    /// recorded and the loop carries on. The default implementation can only
+    /// run one cycle, because connecting consumes the client.
+    async fn reconnect_cycles(
+        self: Box<Self>,
+        name_prefix: &str,
+        _cycles: NonZeroUsize,
+    ) -> Vec<ReconnectCycle> {
+        let started = Instant::now();
+        let result = match self.connect_first_device(name_prefix).await {
+            Ok(session) => session.close().await,
+            Err(error) => Err(error),
+        };
+        vec![ReconnectCycle::new(1, started.elapsed(), result)]
+    }
+}
+
+/// One matched device and the outcome of connecting to it.
+pub struct DeviceConnectAttempt {
+    /// Device as seen during discovery.
+    pub device: FoundDevice,
+    /// Connected session, or the error that prevented it.
+    pub session: Result<DeviceSession, InteractionError>,
+}
+
+/// Timing and outcome of one connect-then-close cycle.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReconnectCycle {
+    /// One-based cycle number.
+    pub cycle: usize,
+    /// Time from starting the scan until the session closed or failed.
+    pub elapsed: Duration,
+    /// Rendered error when the cycle failed to connect or close.
+    pub error: Option<String>,
+}
+
+impl ReconnectCycle {
+    fn new(cycle: usize, elapsed: Duration, result: Result<(), InteractionError>) -> Self {
+        Self {
+            cycle,
+            elapsed,
+            error: result.err().map(|error| error.to_string()),
+        }
+    }
+
+    /// Returns whether the cycle connected and closed cleanly.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let cycle = idm::ReconnectCycle {
+    ///     cycle: 1,
+    ///     elapsed: Duration::from_millis(120),
+    ///     error: None,
+    /// };
+    /// assert!(cycle.succeeded());
+    /// ```
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Runs `cycles` connect-then-close cycles, opening each session with `connect`.
+async fn run_reconnect_cycles<F, Fut>(cycles: NonZeroUsize, mut connect: F) -> Vec<ReconnectCycle>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<DeviceSession, InteractionError>>,
+{
+    let mut results = Vec::with_capacity(cycles.get());
+    for cycle in 1..=cycles.get() {
+        let started = Instant::now();
+        let result = match connect().await {
+            Ok(session) => session.close().await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = &result {
+            warn!(cycle, %error, "reconnect cycle failed");
+        }
+        results.push(ReconnectCycle::new(cycle, started.elapsed(), result));
+    }
+    results
 }
 
 #[derive(Debug)]
@@ -235,15 +498,71 @@ impl RealHardwareClient {
 
 #[async_trait]
 impl HardwareClient for RealHardwareClient {
-    #[instrument(skip(self), level = "info", fields(prefix = name_prefix))]
+    async fn adapter_names(&self) -> Result<Vec<String>, InteractionError> {
+        let backend = BtleplugBackend::new(self.model_resolution.clone()).await?;
+        backend.adapter_names().await
+    }
+
+    fn model_resolution(&self) -> ModelResolutionConfig {
+        self.model_resolution.clone()
+    }
+
     async fn connect_first_device(
         self: Box<Self>,
         name_prefix: &str,
     ) -> Result<DeviceSession, InteractionError> {
+        self.connect_first_device_observed(name_prefix, Arc::new(NoopConnectionObserver))
+            .await
+    }
+
+    #[instrument(skip(self, observer), level = "info", fields(prefix = name_prefix))]
+    async fn connect_first_device_observed(
+        self: Box<Self>,
+        name_prefix: &str,
+        observer: Arc<dyn ConnectionObserver>,
+    ) -> Result<DeviceSession, InteractionError> {
+        let Self { model_resolution } = *self;
+        let close_timeout = model_resolution.close_timeout();
+        let backend = BtleplugBackend::new(model_resolution).await?;
+        let handler = SessionHandler::new(backend, close_timeout);
+        handler.connect_first(name_prefix, &*observer).await
+    }
+
+    #[instrument(skip(self), level = "info", fields(prefix = name_prefix, ?scan_window))]
+    async fn connect_all_devices(
+        self: Box<Self>,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<DeviceConnectAttempt>, InteractionError> {
         let Self { model_resolution } = *self;
+        let close_timeout = model_resolution.close_timeout();
         let backend = BtleplugBackend::new(model_resolution).await?;
-        let handler = SessionHandler::new(backend);
-        handler.connect_first(name_prefix).await
+        let handler = SessionHandler::new(backend, close_timeout);
+        handler.connect_all(name_prefix, scan_window).await
+    }
+
+    #[instrument(skip(self), level = "info", fields(prefix = name_prefix))]
+    async fn probe_first_device(
+        self: Box<Self>,
+        name_prefix: &str,
+    ) -> Result<EndpointProbe, InteractionError> {
+        let Self { model_resolution } = *self;
+        let backend = BtleplugBackend::new(model_resolution).await?;
+        backend.probe_first_matching_device(name_prefix).await
+    }
+
+    #[instrument(skip(self), level = "info", fields(prefix = name_prefix))]
+    async fn reconnect_cycles(
+        self: Box<Self>,
+        name_prefix: &str,
+        cycles: NonZeroUsize,
+    ) -> Vec<ReconnectCycle> {
+        let Self { model_resolution } = *self;
+        run_reconnect_cycles(cycles, || {
+            let client = Box::new(Self::new(model_resolution.clone()));
+            client.connect_first_device(name_prefix)
+        })
+        .await
     }
 }
 
@@ -260,15 +579,70 @@ impl FakeHardwareClient {
 
 #[async_trait]
 impl HardwareClient for FakeHardwareClient {
-    #[instrument(skip(self), level = "info", fields(prefix = name_prefix))]
+    async fn adapter_names(&self) -> Result<Vec<String>, InteractionError> {
+        Ok(self.config.adapter_names())
+    }
+
+    fn model_resolution(&self) -> ModelResolutionConfig {
+        self.config.model_resolution().clone()
+    }
+
     async fn connect_first_device(
         self: Box<Self>,
         name_prefix: &str,
     ) -> Result<DeviceSession, InteractionError> {
+        self.connect_first_device_observed(name_prefix, Arc::new(NoopConnectionObserver))
+            .await
+    }
+
+    #[instrument(skip(self, observer), level = "info", fields(prefix = name_prefix))]
+    async fn connect_first_device_observed(
+        self: Box<Self>,
+        name_prefix: &str,
+        observer: Arc<dyn ConnectionObserver>,
+    ) -> Result<DeviceSession, InteractionError> {
+        let Self { config } = *self;
+        let close_timeout = config.close_timeout();
+        let backend = FakeBackend::new(config);
+        let handler = SessionHandler::new(backend, close_timeout);
+        handler.connect_first(name_prefix, &*observer).await
+    }
+
+    #[instrument(skip(self), level = "info", fields(prefix = name_prefix, ?scan_window))]
+    async fn connect_all_devices(
+        self: Box<Self>,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<DeviceConnectAttempt>, InteractionError> {
+        let Self { config } = *self;
+        let close_timeout = config.close_timeout();
+        let backend = FakeBackend::new(config);
+        let handler = SessionHandler::new(backend, close_timeout);
+        handler.connect_all(name_prefix, scan_window).await
+    }
+
+    #[instrument(skip(self), level = "info", fields(prefix = name_prefix))]
+    async fn probe_first_device(
+        self: Box<Self>,
+        name_prefix: &str,
+    ) -> Result<EndpointProbe, InteractionError> {
         let Self { config } = *self;
         let backend = FakeBackend::new(config);
-        let handler = SessionHandler::new(backend);
-        handler.connect_first(name_prefix).await
+        backend.probe_first_matching_device(name_prefix).await
+    }
+
+    #[instrument(skip(self), level = "info", fields(prefix = name_prefix))]
+    async fn reconnect_cycles(
+        self: Box<Self>,
+        name_prefix: &str,
+        cycles: NonZeroUsize,
+    ) -> Vec<ReconnectCycle> {
+        let Self { config } = *self;
+        run_reconnect_cycles(cycles, || {
+            let client = Box::new(Self::new(config.clone()));
+            client.connect_first_device(name_prefix)
+        })
+        .await
     }
 }
 
@@ -277,6 +651,19 @@ impl HardwareClient for FakeHardwareClient {
 pub struct DeviceSession {
     pub(super) session: Arc<dyn ConnectedBleSession>,
     pub(super) chunk_sizer: Arc<AdaptiveChunkSizer>,
+    pub(super) close_timeout: Duration,
+    pub(super) notification_slot: Arc<NotificationSlot>,
+}
+
+/// The single notification stream slot shared by clones of a session.
+///
+/// `live` is set while a subscription is held by a caller. `released` stays
+/// locked until that subscription's unsubscribe has finished, so a new
+/// stream waits for it instead of being unsubscribed by the old one.
+#[derive(Default)]
+pub(super) struct NotificationSlot {
+    live: AtomicBool,
+    released: Arc<Mutex<()>>,
 }
 
 /// One typed notification item emitted by [`DeviceSession::notification_stream`].
@@ -286,12 +673,19 @@ pub struct NotificationMessage {
     pub index: usize,
     /// Parsed notification event or decode error for the payload.
     pub event: Result<NotifyEvent, NotificationDecodeError>,
+    /// Payload bytes exactly as received from the device.
+    pub raw: Vec<u8>,
 }
 
 /// Single-consumer notification stream tied to one endpoint subscription.
+///
+/// Only one subscription may be live per session; dropping it frees the slot
+/// once the background unsubscribe has finished.
 pub struct NotificationSubscription {
     payloads: Option<PayloadStream>,
     session: Arc<dyn ConnectedBleSession>,
+    slot: Arc<NotificationSlot>,
+    slot_guard: Option<OwnedMutexGuard<()>>,
     endpoint: EndpointId,
     max_notifications: Option<usize>,
     cancel: CancellationToken,
@@ -336,6 +730,7 @@ impl Stream for NotificationSubscription {
                 let message = NotificationMessage {
                     index: this.received,
                     event: NotificationHandler::decode(&payload),
+                    raw: payload,
                 };
 
                 if let Some(limit) = this.max_notifications
@@ -400,8 +795,10 @@ impl TryFrom<NotificationSubscription> for NotificationRunSummary {
 
 impl Drop for NotificationSubscription {
     fn drop(&mut self) {
+        self.slot.live.store(false, Ordering::Release);
         let endpoint = self.endpoint;
         let session = Arc::clone(&self.session);
+        let slot_guard = self.slot_guard.take();
         if let Ok(handle) = tokio::runtime::Handle::try_current() {
             handle.spawn(async move {
                 if let Err(error) = session.unsubscribe_endpoint(endpoint).await {
@@ -411,6 +808,7 @@ impl Drop for NotificationSubscription {
                         "failed to unsubscribe notification stream cleanly"
                     );
                 }
+                drop(slot_guard);
             });
         }
     }
@@ -435,6 +833,23 @@ impl DeviceSession {
         self.session.write_without_response_limit()
     }
 
+    /// Returns the ATT MTU negotiated for this connection, if the backend
+    /// reports it.
+    ///
+    /// ```
+    /// # async fn demo(client: Box<dyn idm::HardwareClient>) -> Result<(), idm::InteractionError> {
+    /// let session = client.connect_first_device("IDM-").await?;
+    /// if let Some(mtu) = session.negotiated_mtu() {
+    ///     println!("negotiated ATT MTU: {mtu} bytes");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn negotiated_mtu(&self) -> Option<u16> {
+        self.session.negotiated_mtu()
+    }
+
     /// Returns the resolved device profile for this session.
     ///
     /// ```
@@ -449,6 +864,48 @@ impl DeviceSession {
         self.session.device_profile()
     }
 
+    /// Returns warnings about the session's transport health so far.
+    ///
+    /// A write failure that forced the adaptive chunk size below the
+    /// connection's baseline is reported as [`Warning::TransportDegraded`].
+    ///
+    /// ```
+    /// # async fn demo(client: Box<dyn idm::HardwareClient>) -> Result<(), idm::InteractionError> {
+    /// let session = client.connect_first_device("IDM-").await?;
+    /// for warning in session.warnings() {
+    ///     eprintln!("{warning}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.chunk_sizer
+            .degraded_size()
+            .map(|chunk_size| Warning::TransportDegraded { chunk_size })
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns whether the underlying peripheral is still connected.
+    ///
+    /// This is a cheap liveness check for long-running consumers: it asks the
+    /// transport for its connection state without any device round-trip.
+    ///
+    /// ```
+    /// # async fn demo(client: Box<dyn idm::HardwareClient>) -> Result<(), idm::InteractionError> {
+    /// let session = client.connect_first_device("IDM-").await?;
+    /// if !session.is_alive().await {
+    ///     return Ok(());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "trace")]
+    pub async fn is_alive(&self) -> bool {
+        self.session.is_alive().await
+    }
+
     /// Reads one endpoint value.
     ///
     /// # Errors
@@ -501,9 +958,16 @@ impl DeviceSession {
     /// When the token is cancelled, the stream terminates with
     /// [`ListenStopReason::Interrupted`].
     ///
+    /// A session delivers notifications to a single consumer, so only one
+    /// stream may be live at a time across all clones of this session. Callers
+    /// that need acknowledgements while listening should share one stream
+    /// rather than opening a second.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the initial endpoint subscription fails.
+    /// Returns [`InteractionError::NotificationStreamBusy`] while another
+    /// stream on this session is still live, or an error if the initial
+    /// endpoint subscription fails.
     #[instrument(skip(self, cancel), level = "trace", fields(?endpoint, ?max_notifications))]
     pub async fn notification_stream(
         &self,
@@ -511,12 +975,25 @@ impl DeviceSession {
         max_notifications: Option<usize>,
         cancel: CancellationToken,
     ) -> Result<NotificationSubscription, InteractionError> {
-        self.session.subscribe_endpoint(endpoint).await?;
+        if self.notification_slot.live.load(Ordering::Acquire) {
+            return Err(InteractionError::NotificationStreamBusy { endpoint });
+        }
+        // Waits for a dropped stream's unsubscribe before taking the slot.
+        let slot_guard = Arc::clone(&self.notification_slot.released)
+            .lock_owned()
+            .await;
+        self.notification_slot.live.store(true, Ordering::Release);
+
+        if let Err(error) = self.session.subscribe_endpoint(endpoint).await {
+            self.notification_slot.live.store(false, Ordering::Release);
+            return Err(error);
+        }
 
         let payloads = match self.session.notification_payloads(endpoint).await {
             Ok(payloads) => payloads,
             Err(error) => {
                 let _ = self.session.unsubscribe_endpoint(endpoint).await;
+                self.notification_slot.live.store(false, Ordering::Release);
                 return Err(error);
             }
         };
@@ -524,6 +1001,8 @@ impl DeviceSession {
         Ok(NotificationSubscription {
             payloads: Some(payloads),
             session: Arc::clone(&self.session),
+            slot: Arc::clone(&self.notification_slot),
+            slot_guard: Some(slot_guard),
             endpoint,
             max_notifications,
             cancel,
@@ -536,15 +1015,33 @@ impl DeviceSession {
     ///
     /// # Errors
     ///
-    /// Returns an error if teardown fails.
+    /// Returns an error if teardown fails. Teardown is bounded by the
+    /// configured close timeout (three seconds by default). When it times out, the
+    /// returned [`InteractionError::SessionCloseTimeout`] reports whether the
+    /// peripheral still appeared connected afterwards.
     #[instrument(skip(self), level = "debug")]
     pub async fn close(self) -> Result<(), InteractionError> {
-        match timeout(SESSION_CLOSE_TIMEOUT, self.session.close()).await {
+        match timeout(self.close_timeout, Arc::clone(&self.session).close()).await {
             Ok(result) => result,
             Err(_elapsed) => {
-                let timeout_ms =
-                    u64::try_from(SESSION_CLOSE_TIMEOUT.as_millis()).unwrap_or(u64::MAX);
-                Err(InteractionError::SessionCloseTimeout { timeout_ms })
+                let timeout_ms = u64::try_from(self.close_timeout.as_millis()).unwrap_or(u64::MAX);
+                let still_connected = self.still_connected_after_close_timeout().await;
+                Err(InteractionError::SessionCloseTimeout {
+                    timeout_ms,
+                    still_connected,
+                })
+            }
+        }
+    }
+
+    async fn still_connected_after_close_timeout(&self) -> bool {
+        match timeout(SESSION_CLOSE_PROBE_TIMEOUT, self.session.is_alive()).await {
+            Ok(alive) => alive,
+            Err(_elapsed) => {
+                // A stalled probe says nothing about the link, so assume the
+                // worst and let the caller decide whether to reset the adapter.
+                trace!("connection-state probe stalled after close timeout");
+                true
             }
         }
     }
@@ -602,6 +1099,7 @@ mod tests {
 
     struct HangingCloseSession {
         device: FoundDevice,
+        connected_after_timeout: bool,
     }
 
     #[async_trait]
@@ -618,10 +1116,18 @@ mod tests {
             None
         }
 
+        fn negotiated_mtu(&self) -> Option<u16> {
+            None
+        }
+
         fn device_profile(&self) -> DeviceProfile {
             panic!("device_profile should not be called in this test");
         }
 
+        async fn is_alive(&self) -> bool {
+            self.connected_after_timeout
+        }
+
         async fn read_endpoint(&self, _endpoint: EndpointId) -> Result<Vec<u8>, InteractionError> {
             panic!("read_endpoint should not be called in this test");
         }
@@ -665,9 +1171,11 @@ mod tests {
         }
     }
 
-    #[tokio::test(flavor = "current_thread", start_paused = true)]
-    async fn close_times_out_when_backend_close_stalls() {
-        let session = DeviceSession {
+    fn hanging_close_session(
+        connected_after_timeout: bool,
+        close_timeout: Duration,
+    ) -> DeviceSession {
+        DeviceSession {
             session: Arc::new(HangingCloseSession {
                 device: FoundDevice::new(
                     "hci0".to_string(),
@@ -675,14 +1183,48 @@ mod tests {
                     Some("IDM-Test".to_string()),
                     Some(-42),
                 ),
+                connected_after_timeout,
             }),
             chunk_sizer: Arc::new(AdaptiveChunkSizer::from_baseline(512)),
-        };
+            close_timeout,
+            notification_slot: Arc::default(),
+        }
+    }
+
+    #[rstest]
+    #[case::still_connected(true)]
+    #[case::disconnected(false)]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn close_times_out_when_backend_close_stalls(#[case] connected_after_timeout: bool) {
+        let session = hanging_close_session(connected_after_timeout, Duration::from_secs(3));
 
         let result = session.close().await;
         match result {
-            Err(InteractionError::SessionCloseTimeout { timeout_ms }) => {
+            Err(InteractionError::SessionCloseTimeout {
+                timeout_ms,
+                still_connected,
+            }) => {
                 assert_eq!(3_000, timeout_ms);
+                assert_eq!(connected_after_timeout, still_connected);
+            }
+            other => panic!("expected SessionCloseTimeout, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(Duration::from_millis(250))]
+    #[case(Duration::from_secs(10))]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn close_timeout_follows_configured_duration(#[case] close_timeout: Duration) {
+        let session = hanging_close_session(false, close_timeout);
+        let started = tokio::time::Instant::now();
+
+        let result = session.close().await;
+
+        assert_eq!(close_timeout, started.elapsed());
+        match result {
+            Err(InteractionError::SessionCloseTimeout { timeout_ms, .. }) => {
+                assert_eq!(close_timeout.as_millis(), u128::from(timeout_ms));
             }
             other => panic!("expected SessionCloseTimeout, got {other:?}"),
         }
@@ -1,4 +1,5 @@
 mod btleplug_backend;
+mod connection_observer;
 mod device_profile_resolver;
 pub(crate) mod diagnostic_value;
 pub(crate) mod diagnostics;
@@ -8,10 +9,13 @@ mod model;
 mod model_overrides;
 mod model_resolution_diagnostics;
 mod profile;
+mod profile_cache;
+mod retry_policy;
 mod scan_capabilities;
 mod scan_model;
 mod session;
 
+pub use self::connection_observer::{ConnectionEvent, ConnectionObserver, NoopConnectionObserver};
 pub(crate) use self::device_profile_resolver::{DeviceProfileResolver, DeviceRoutingProfile};
 pub use self::device_profile_resolver::{LedInfoResponse, TextPath};
 pub use self::fake_backend::{
@@ -22,19 +26,25 @@ pub(crate) use self::fake_backend::{
     FakeBackendConfig, HexPayload, NotificationPayloads, ScanFixture,
 };
 pub use self::hardware::{
-    DeviceSession, HardwareClient, NotificationMessage, NotificationSubscription, WriteMode,
+    DeviceConnectAttempt, DeviceSession, HardwareClient, NotificationMessage,
+    NotificationSubscription, ReconnectCycle, WriteMode,
 };
 pub(crate) use self::hardware::{
     fake_hardware_client, real_hardware_client, real_hardware_client_with_model_resolution,
 };
 pub use self::model::{
-    CharacteristicInfo, EndpointPresence, FoundDevice, InspectReport, ListenStopReason,
-    ListenSummary, NotificationRunSummary, ServiceInfo, SessionMetadata,
+    CharacteristicInfo, EndpointPresence, EndpointProbe, FoundDevice, InitialReadStatus,
+    InspectReport, ListenStopReason, ListenSummary, NotificationRunSummary, NotifyDelivery,
+    NotifyEventCounts, ServiceInfo, SessionMetadata,
 };
 pub use self::model_overrides::ModelResolutionConfig;
+pub(crate) use self::model_overrides::model_overrides_path;
 pub use self::profile::{
     DeviceProfile, GifHeaderProfile, ImageUploadMode, PanelDimensions, PanelSize,
+    TextCharCountEndian,
 };
+pub(crate) use self::profile_cache::profile_cache_path;
+pub use self::retry_policy::RetryPolicy;
 pub use self::scan_model::{AmbiguousShape, ModelProfile, ScanIdentity, ScanModelHandler};
 pub use self::session::GattProfile;
 pub(crate) use self::session::{Ack, SessionWriter};
@@ -2,9 +2,10 @@ use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::Serialize;
-use serde_with::{hex::Hex, serde_as};
+use serde_with::{SerializeDisplay, hex::Hex, serde_as};
 
-use crate::protocol::EndpointId;
+use crate::notification::{NotificationDecodeError, NotifyEvent};
+use crate::protocol::{EndpointId, endpoint_metadata};
 
 use super::DeviceProfile;
 use super::diagnostics::ConnectionDiagnostics;
@@ -137,6 +138,40 @@ impl CharacteristicInfo {
     pub fn properties(&self) -> &[String] {
         &self.properties
     }
+
+    /// Returns how this characteristic pushes value changes, if it can.
+    #[must_use]
+    pub fn notify_delivery(&self) -> Option<NotifyDelivery> {
+        let has = |label: &str| {
+            self.properties
+                .iter()
+                .any(|property| property.eq_ignore_ascii_case(label))
+        };
+        match (has("notify"), has("indicate")) {
+            (true, true) => Some(NotifyDelivery::NotifyAndIndicate),
+            (true, false) => Some(NotifyDelivery::Notify),
+            (false, true) => Some(NotifyDelivery::Indicate),
+            (false, false) => None,
+        }
+    }
+}
+
+/// How a characteristic delivers value changes to subscribers.
+///
+/// Subscribing works the same way for either mode: the BLE stack writes the
+/// matching client configuration descriptor, and indications are acknowledged
+/// by the stack rather than by this crate.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, derive_more::Display, SerializeDisplay)]
+pub enum NotifyDelivery {
+    /// Unacknowledged notifications.
+    #[display("notify")]
+    Notify,
+    /// Indications, acknowledged by the central.
+    #[display("indicate")]
+    Indicate,
+    /// Both; BLE stacks subscribe with notifications in this case.
+    #[display("notify+indicate")]
+    NotifyAndIndicate,
 }
 
 /// A GATT service with discovered characteristics.
@@ -206,6 +241,10 @@ pub(crate) enum LedInfoQueryOutcome {
     SkippedNoNotifyOrRead,
     #[display("skipped_no_write_characteristic")]
     SkippedNoWriteCharacteristic,
+    #[display("skipped_by_config")]
+    SkippedByConfig,
+    #[display("cached_profile")]
+    CachedProfile,
     #[display("no_response")]
     NoResponse,
     #[display("invalid_response")]
@@ -223,10 +262,12 @@ pub(crate) enum LedInfoQueryOutcome {
 pub struct SessionMetadata {
     required_endpoints_verified: bool,
     write_without_response_limit: Option<usize>,
+    negotiated_mtu: Option<u16>,
     device_profile: DeviceProfile,
     connection_diagnostics: ConnectionDiagnostics,
     gatt_profile: Option<GattProfile>,
     resolved_endpoint_uuids: HashMap<EndpointId, String>,
+    joint_mode: Option<u8>,
 }
 
 impl SessionMetadata {
@@ -239,10 +280,12 @@ impl SessionMetadata {
         Self {
             required_endpoints_verified,
             write_without_response_limit,
+            negotiated_mtu: None,
             device_profile,
             connection_diagnostics: ConnectionDiagnostics::default(),
             gatt_profile: None,
             resolved_endpoint_uuids: HashMap::new(),
+            joint_mode: None,
         }
     }
 
@@ -264,6 +307,16 @@ impl SessionMetadata {
         self
     }
 
+    pub(crate) fn with_joint_mode(mut self, joint_mode: Option<u8>) -> Self {
+        self.joint_mode = joint_mode;
+        self
+    }
+
+    pub(crate) fn with_negotiated_mtu(mut self, negotiated_mtu: Option<u16>) -> Self {
+        self.negotiated_mtu = negotiated_mtu;
+        self
+    }
+
     /// Returns whether required iDotMatrix endpoints were verified at connect time.
     #[must_use]
     pub fn required_endpoints_verified(&self) -> bool {
@@ -276,6 +329,16 @@ impl SessionMetadata {
         self.write_without_response_limit
     }
 
+    /// Returns the ATT MTU negotiated for this connection, when the backend
+    /// reports it.
+    ///
+    /// Transport chunk sizes fall back to conservative defaults when this is
+    /// unknown, so it is the first thing to check when uploads are slow.
+    #[must_use]
+    pub fn negotiated_mtu(&self) -> Option<u16> {
+        self.negotiated_mtu
+    }
+
     /// Returns the resolved device profile used for handler behaviour.
     #[must_use]
     pub fn device_profile(&self) -> DeviceProfile {
@@ -292,6 +355,15 @@ impl SessionMetadata {
         self.gatt_profile
     }
 
+    /// Returns the joint mode written during connect, if any.
+    ///
+    /// Devices do not report their current joint mode, so this reflects the
+    /// routing profile applied by this session rather than a device readback.
+    #[must_use]
+    pub fn joint_mode(&self) -> Option<u8> {
+        self.joint_mode
+    }
+
     /// Returns the concrete UUID bound to an endpoint role for this session.
     #[must_use]
     pub fn resolved_endpoint_uuid(&self, endpoint: EndpointId) -> Option<&str> {
@@ -308,6 +380,7 @@ pub struct InspectReport {
     services: Vec<ServiceInfo>,
     endpoint_presence: EndpointPresence,
     session_metadata: SessionMetadata,
+    notify_delivery: Option<NotifyDelivery>,
 }
 
 impl InspectReport {
@@ -318,14 +391,36 @@ impl InspectReport {
         endpoint_presence: EndpointPresence,
         session_metadata: SessionMetadata,
     ) -> Self {
+        let notify_delivery = endpoint_characteristic(
+            &services,
+            &session_metadata,
+            EndpointId::ReadNotifyCharacteristic,
+        )
+        .and_then(CharacteristicInfo::notify_delivery);
         Self {
             device,
             services,
             endpoint_presence,
             session_metadata,
+            notify_delivery,
         }
     }
 
+    /// Returns the discovered characteristic bound to an endpoint role.
+    ///
+    /// Uses the UUID resolved during connect, falling back to the endpoint's
+    /// canonical UUID.
+    #[must_use]
+    pub fn endpoint_characteristic(&self, endpoint: EndpointId) -> Option<&CharacteristicInfo> {
+        endpoint_characteristic(&self.services, &self.session_metadata, endpoint)
+    }
+
+    /// Returns whether the read/notify endpoint uses notifications or indications.
+    #[must_use]
+    pub fn notify_delivery(&self) -> Option<NotifyDelivery> {
+        self.notify_delivery
+    }
+
     /// Returns the connected device details.
     #[must_use]
     pub fn device(&self) -> &FoundDevice {
@@ -351,6 +446,72 @@ impl InspectReport {
     }
 }
 
+/// Finds the discovered characteristic bound to an endpoint role.
+pub(crate) fn endpoint_characteristic<'a>(
+    services: &'a [ServiceInfo],
+    session_metadata: &SessionMetadata,
+    endpoint: EndpointId,
+) -> Option<&'a CharacteristicInfo> {
+    let expected_uuid = session_metadata
+        .resolved_endpoint_uuid(endpoint)
+        .unwrap_or_else(|| endpoint_metadata(endpoint).uuid());
+
+    services
+        .iter()
+        .flat_map(ServiceInfo::characteristics)
+        .find(|characteristic| characteristic.uuid().eq_ignore_ascii_case(expected_uuid))
+}
+
+/// Endpoint negotiation result from a connect-and-disconnect probe.
+///
+/// Unlike [`InspectReport`], a probe skips the LED-info query and profile
+/// resolution, and it reports missing endpoints instead of failing on them.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct EndpointProbe {
+    device: FoundDevice,
+    gatt_profile: Option<GattProfile>,
+    endpoint_presence: EndpointPresence,
+}
+
+impl EndpointProbe {
+    /// Creates an endpoint probe result.
+    pub(crate) fn new(
+        device: FoundDevice,
+        gatt_profile: Option<GattProfile>,
+        endpoint_presence: EndpointPresence,
+    ) -> Self {
+        Self {
+            device,
+            gatt_profile,
+            endpoint_presence,
+        }
+    }
+
+    /// Returns the probed device details.
+    #[must_use]
+    pub fn device(&self) -> &FoundDevice {
+        &self.device
+    }
+
+    /// Returns the negotiated GATT profile, or `None` when negotiation failed.
+    #[must_use]
+    pub fn gatt_profile(&self) -> Option<GattProfile> {
+        self.gatt_profile
+    }
+
+    /// Returns expected iDotMatrix endpoint presence.
+    #[must_use]
+    pub fn endpoint_presence(&self) -> &EndpointPresence {
+        &self.endpoint_presence
+    }
+
+    /// Returns whether every required endpoint was found.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        EndpointId::all().all(|endpoint| self.endpoint_presence.is_present(endpoint))
+    }
+}
+
 /// Why a listening session ended.
 #[derive(Debug, Clone, Eq, PartialEq, derive_more::Display, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -395,6 +556,189 @@ impl NotificationRunSummary {
     }
 }
 
+/// Per-type tally of notification events seen during a listen session.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize)]
+pub struct NotifyEventCounts {
+    next_package: usize,
+    finished: usize,
+    error: usize,
+    schedule_setup: usize,
+    schedule_master_switch: usize,
+    led_info: usize,
+    screen_light_timeout: usize,
+    unknown: usize,
+    decode_error: usize,
+}
+
+impl NotifyEventCounts {
+    /// Records one decoded notification.
+    pub(crate) fn record(&mut self, event: &Result<NotifyEvent, NotificationDecodeError>) {
+        let count = match event {
+            Ok(NotifyEvent::NextPackage(_)) => &mut self.next_package,
+            Ok(NotifyEvent::Finished(_)) => &mut self.finished,
+            Ok(NotifyEvent::Error(_, _)) => &mut self.error,
+            Ok(NotifyEvent::ScheduleSetup(_)) => &mut self.schedule_setup,
+            Ok(NotifyEvent::ScheduleMasterSwitch(_)) => &mut self.schedule_master_switch,
+            Ok(NotifyEvent::LedInfo(_)) => &mut self.led_info,
+            Ok(NotifyEvent::ScreenLightTimeout(_)) => &mut self.screen_light_timeout,
+            Ok(NotifyEvent::Unknown(_)) => &mut self.unknown,
+            Err(_) => &mut self.decode_error,
+        };
+        *count += 1;
+    }
+
+    /// Returns labelled counts in a stable order, skipping event types never seen.
+    pub(crate) fn non_zero(&self) -> impl Iterator<Item = (&'static str, usize)> {
+        [
+            ("next package", self.next_package),
+            ("finished", self.finished),
+            ("error", self.error),
+            ("schedule setup", self.schedule_setup),
+            ("schedule master switch", self.schedule_master_switch),
+            ("LED info", self.led_info),
+            ("screen-light timeout", self.screen_light_timeout),
+            ("unknown", self.unknown),
+            ("decode error", self.decode_error),
+        ]
+        .into_iter()
+        .filter(|(_label, count)| *count > 0)
+    }
+
+    /// Returns the number of `next package` acknowledgements.
+    #[must_use]
+    pub fn next_package(&self) -> usize {
+        self.next_package
+    }
+
+    /// Returns the number of transfer `finished` acknowledgements.
+    #[must_use]
+    pub fn finished(&self) -> usize {
+        self.finished
+    }
+
+    /// Returns the number of family-specific error statuses.
+    #[must_use]
+    pub fn error(&self) -> usize {
+        self.error
+    }
+
+    /// Returns the number of schedule setup responses.
+    #[must_use]
+    pub fn schedule_setup(&self) -> usize {
+        self.schedule_setup
+    }
+
+    /// Returns the number of schedule master-switch responses.
+    #[must_use]
+    pub fn schedule_master_switch(&self) -> usize {
+        self.schedule_master_switch
+    }
+
+    /// Returns the number of LED info responses.
+    #[must_use]
+    pub fn led_info(&self) -> usize {
+        self.led_info
+    }
+
+    /// Returns the number of screen-light timeout readbacks.
+    #[must_use]
+    pub fn screen_light_timeout(&self) -> usize {
+        self.screen_light_timeout
+    }
+
+    /// Returns the number of unrecognised payloads.
+    #[must_use]
+    pub fn unknown(&self) -> usize {
+        self.unknown
+    }
+
+    /// Returns the number of payloads that failed to decode.
+    #[must_use]
+    pub fn decode_error(&self) -> usize {
+        self.decode_error
+    }
+}
+
+const INITIAL_READ_STATUS_FRAME_LEN: u16 = 5;
+const INITIAL_READ_STATUS_READY: u8 = 0x01;
+
+/// Short status frame returned by a plain `fa03` read before listening.
+///
+/// The frame uses the short control layout: bytes `[0..2]` hold the frame
+/// length (`5`, little-endian), `[2]` the command id, `[3]` the command
+/// namespace and `[4]` a status byte. Devices observed so far answer
+/// `05 00 01 00 01`; status `0x01` is read as "ready", matching its
+/// next-package meaning in transfer ACKs. Other status values have not been
+/// seen.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub struct InitialReadStatus {
+    command_id: u8,
+    command_ns: u8,
+    status: u8,
+}
+
+impl InitialReadStatus {
+    /// Decodes an initial-read payload, returning `None` when it is not a
+    /// five-byte status frame.
+    ///
+    /// ```
+    /// use idm::InitialReadStatus;
+    ///
+    /// let status = InitialReadStatus::decode(&[0x05, 0x00, 0x01, 0x00, 0x01])
+    ///     .expect("default initial read should decode");
+    /// assert!(status.is_ready());
+    /// assert_eq!(None, InitialReadStatus::decode(&[0xDE, 0xAD, 0xBE, 0xEF]));
+    /// ```
+    #[must_use]
+    pub fn decode(payload: &[u8]) -> Option<Self> {
+        let [len_lo, len_hi, command_id, command_ns, status] = *payload else {
+            return None;
+        };
+        if u16::from_le_bytes([len_lo, len_hi]) != INITIAL_READ_STATUS_FRAME_LEN {
+            return None;
+        }
+        Some(Self {
+            command_id,
+            command_ns,
+            status,
+        })
+    }
+
+    /// Returns the command id at byte `[2]`.
+    #[must_use]
+    pub fn command_id(&self) -> u8 {
+        self.command_id
+    }
+
+    /// Returns the command namespace at byte `[3]`.
+    #[must_use]
+    pub fn command_ns(&self) -> u8 {
+        self.command_ns
+    }
+
+    /// Returns the raw status byte at byte `[4]`.
+    #[must_use]
+    pub fn status(&self) -> u8 {
+        self.status
+    }
+
+    /// Returns whether the status byte reports the device as ready.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.status == INITIAL_READ_STATUS_READY
+    }
+}
+
+impl Display for InitialReadStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_ready() {
+            write!(f, "ready")
+        } else {
+            write!(f, "status 0x{:02X}", self.status)
+        }
+    }
+}
+
 /// Summary returned when a listen session exits.
 #[serde_as]
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -402,8 +746,10 @@ pub struct ListenSummary {
     device: FoundDevice,
     #[serde_as(as = "Option<Hex>")]
     initial_read: Option<Vec<u8>>,
+    initial_read_status: Option<InitialReadStatus>,
     received_notifications: usize,
     stop_reason: ListenStopReason,
+    event_counts: NotifyEventCounts,
 }
 
 impl ListenSummary {
@@ -413,12 +759,16 @@ impl ListenSummary {
         initial_read: Option<Vec<u8>>,
         received_notifications: usize,
         stop_reason: ListenStopReason,
+        event_counts: NotifyEventCounts,
     ) -> Self {
+        let initial_read_status = initial_read.as_deref().and_then(InitialReadStatus::decode);
         Self {
             device,
             initial_read,
+            initial_read_status,
             received_notifications,
             stop_reason,
+            event_counts,
         }
     }
 
@@ -434,6 +784,12 @@ impl ListenSummary {
         self.initial_read.as_deref()
     }
 
+    /// Returns the decoded initial-read status frame, if the read returned one.
+    #[must_use]
+    pub fn initial_read_status(&self) -> Option<InitialReadStatus> {
+        self.initial_read_status
+    }
+
     /// Returns the number of notifications received.
     #[must_use]
     pub fn received_notifications(&self) -> usize {
@@ -445,6 +801,12 @@ impl ListenSummary {
     pub fn stop_reason(&self) -> &ListenStopReason {
         &self.stop_reason
     }
+
+    /// Returns the per-type tally of events received.
+    #[must_use]
+    pub fn event_counts(&self) -> &NotifyEventCounts {
+        &self.event_counts
+    }
 }
 
 #[cfg(test)]
@@ -452,7 +814,44 @@ mod tests {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
-    use super::FoundDevice;
+    use super::{
+        CharacteristicInfo, FoundDevice, InitialReadStatus, NotifyDelivery, NotifyEventCounts,
+    };
+    use crate::notification::{NotificationDecodeError, NotifyEvent, TransferFamily};
+
+    #[rstest]
+    #[case::led_info(&[0x09, 0x00, 0x01, 0x80, 0x01, 0x00, 0x00, 0x02, 0x00])]
+    #[case::too_short(&[0xDE, 0xAD, 0xBE, 0xEF])]
+    #[case::length_mismatch(&[0x06, 0x00, 0x01, 0x00, 0x01])]
+    fn initial_read_status_rejects_non_status_frames(#[case] payload: &[u8]) {
+        assert_eq!(None, InitialReadStatus::decode(payload));
+    }
+
+    #[rstest]
+    #[case::notify(&["read", "notify"], Some(NotifyDelivery::Notify))]
+    #[case::indicate_only(&["indicate"], Some(NotifyDelivery::Indicate))]
+    #[case::both(&["notify", "indicate"], Some(NotifyDelivery::NotifyAndIndicate))]
+    #[case::read_only(&["read"], None)]
+    fn characteristic_notify_delivery_follows_properties(
+        #[case] properties: &[&str],
+        #[case] expected: Option<NotifyDelivery>,
+    ) {
+        let characteristic = CharacteristicInfo::new(
+            "0000fa03-0000-1000-8000-00805f9b34fb".to_string(),
+            properties.iter().map(ToString::to_string).collect(),
+        );
+
+        assert_eq!(expected, characteristic.notify_delivery());
+    }
+
+    #[test]
+    fn initial_read_status_displays_unknown_status_byte() {
+        let status = InitialReadStatus::decode(&[0x05, 0x00, 0x01, 0x00, 0x02])
+            .expect("five-byte frame should decode");
+
+        assert_eq!(false, status.is_ready());
+        assert_eq!("status 0x02", status.to_string());
+    }
 
     #[rstest]
     #[case(
@@ -474,4 +873,31 @@ mod tests {
         assert_eq!(raw_device_id, device.device_id());
         assert_eq!(expected_display, device.device_id_display().to_string());
     }
+
+    #[test]
+    fn notify_event_counts_tally_each_event_type() {
+        let mut counts = NotifyEventCounts::default();
+        for event in [
+            Ok(NotifyEvent::NextPackage(TransferFamily::Gif)),
+            Ok(NotifyEvent::NextPackage(TransferFamily::Gif)),
+            Ok(NotifyEvent::Error(TransferFamily::Gif, 0x02)),
+            Ok(NotifyEvent::Unknown(vec![0xAA])),
+            Err(NotificationDecodeError::EmptyPayload),
+            Ok(NotifyEvent::Finished(TransferFamily::Gif)),
+        ] {
+            counts.record(&event);
+        }
+
+        assert_eq!(
+            vec![
+                ("next package", 2),
+                ("finished", 1),
+                ("error", 1),
+                ("unknown", 1),
+                ("decode error", 1),
+            ],
+            counts.non_zero().collect::<Vec<_>>()
+        );
+        assert_eq!(0, counts.led_info());
+    }
 }
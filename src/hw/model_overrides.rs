@@ -1,20 +1,47 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use directories::ProjectDirs;
 
 use super::model::FoundDevice;
+use super::profile::TextCharCountEndian;
+use super::retry_policy::RetryPolicy;
 use super::scan_model::ScanIdentity;
 use crate::error::InteractionError;
+use crate::notification::AckPolicy;
 
 const OVERRIDES_FILE_NAME: &str = "model-overrides.tsv";
+const DEFAULT_LED_INFO_TIMEOUT: Duration = Duration::from_millis(1_000);
+const DEFAULT_PROFILE_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const DEFAULT_SESSION_CLOSE_TIMEOUT: Duration = Duration::from_secs(3);
+const DEFAULT_SCAN_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const DEFAULT_WRITE_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Runtime model-resolution options supplied by CLI arguments.
-#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ModelResolutionConfig {
     led_type_override: Option<u8>,
     overrides_path: Option<PathBuf>,
+    skip_led_info: bool,
+    skip_joint_mode: bool,
+    led_info_timeout: Duration,
+    profile_cache: bool,
+    profile_cache_ttl: Duration,
+    profile_cache_path: Option<PathBuf>,
+    text_count_endian_override: Option<TextCharCountEndian>,
+    ack_policy: AckPolicy,
+    close_timeout: Duration,
+    scan_poll_interval: Duration,
+    write_timeout: Duration,
+    connect_retry: RetryPolicy,
+}
+
+impl Default for ModelResolutionConfig {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
 }
 
 impl ModelResolutionConfig {
@@ -24,9 +51,189 @@ impl ModelResolutionConfig {
         Self {
             led_type_override,
             overrides_path,
+            skip_led_info: false,
+            skip_joint_mode: false,
+            led_info_timeout: DEFAULT_LED_INFO_TIMEOUT,
+            profile_cache: false,
+            profile_cache_ttl: DEFAULT_PROFILE_CACHE_TTL,
+            profile_cache_path: None,
+            text_count_endian_override: None,
+            ack_policy: AckPolicy::default(),
+            close_timeout: DEFAULT_SESSION_CLOSE_TIMEOUT,
+            scan_poll_interval: DEFAULT_SCAN_POLL_INTERVAL,
+            write_timeout: DEFAULT_WRITE_TIMEOUT,
+            connect_retry: RetryPolicy::default(),
         }
     }
 
+    /// Skips the connect-time LED-info probe when set.
+    #[must_use]
+    pub fn with_skip_led_info(mut self, skip_led_info: bool) -> Self {
+        self.skip_led_info = skip_led_info;
+        self
+    }
+
+    /// Leaves the device's joint mode untouched during connect when set.
+    #[must_use]
+    pub fn with_skip_joint_mode(mut self, skip_joint_mode: bool) -> Self {
+        self.skip_joint_mode = skip_joint_mode;
+        self
+    }
+
+    /// Sets how long each LED-info probe attempt waits for a response.
+    #[must_use]
+    pub fn with_led_info_timeout(mut self, led_info_timeout: Duration) -> Self {
+        self.led_info_timeout = led_info_timeout;
+        self
+    }
+
+    /// Reuses LED-info probe results cached from earlier connects when set.
+    ///
+    /// The cache is keyed by device address and lives next to the
+    /// model-overrides file unless [`Self::with_profile_cache_path`] says
+    /// otherwise.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let config = idm::ModelResolutionConfig::default()
+    ///     .with_profile_cache(true)
+    ///     .with_profile_cache_ttl(Duration::from_secs(3_600));
+    /// assert!(config.profile_cache());
+    /// assert_eq!(Duration::from_secs(3_600), config.profile_cache_ttl());
+    /// ```
+    #[must_use]
+    pub fn with_profile_cache(mut self, profile_cache: bool) -> Self {
+        self.profile_cache = profile_cache;
+        self
+    }
+
+    /// Sets how long a cached LED-info result stays fresh.
+    #[must_use]
+    pub fn with_profile_cache_ttl(mut self, profile_cache_ttl: Duration) -> Self {
+        self.profile_cache_ttl = profile_cache_ttl;
+        self
+    }
+
+    /// Stores the profile cache at `profile_cache_path` instead of the default location.
+    #[must_use]
+    pub fn with_profile_cache_path(mut self, profile_cache_path: PathBuf) -> Self {
+        self.profile_cache_path = Some(profile_cache_path);
+        self
+    }
+
+    /// Forces the text-metadata character-count byte order for debugging firmware variants.
+    ///
+    /// ```
+    /// use idm::{ModelResolutionConfig, TextCharCountEndian};
+    ///
+    /// let config = ModelResolutionConfig::default()
+    ///     .with_text_count_endian_override(TextCharCountEndian::Big);
+    /// assert_eq!(
+    ///     Some(TextCharCountEndian::Big),
+    ///     config.text_count_endian_override()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_text_count_endian_override(mut self, endian: TextCharCountEndian) -> Self {
+        self.text_count_endian_override = Some(endian);
+        self
+    }
+
+    /// Sets the transfer-acknowledgement policy applied to resolved device profiles.
+    ///
+    /// ```
+    /// use idm::{AckPolicy, ModelResolutionConfig};
+    ///
+    /// let policy = AckPolicy::default().with_continue_status(0x07);
+    /// let config = ModelResolutionConfig::default().with_ack_policy(policy);
+    /// assert_eq!(policy, config.ack_policy());
+    /// ```
+    #[must_use]
+    pub fn with_ack_policy(mut self, ack_policy: AckPolicy) -> Self {
+        self.ack_policy = ack_policy;
+        self
+    }
+
+    /// Sets how long session teardown may take before it is reported as timed out.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let config = idm::ModelResolutionConfig::default();
+    /// assert_eq!(Duration::from_secs(3), config.close_timeout());
+    ///
+    /// let config = config.with_close_timeout(Duration::from_secs(10));
+    /// assert_eq!(Duration::from_secs(10), config.close_timeout());
+    /// ```
+    #[must_use]
+    pub fn with_close_timeout(mut self, close_timeout: Duration) -> Self {
+        self.close_timeout = close_timeout;
+        self
+    }
+
+    /// Sets how long the real scan loop waits between peripheral polls.
+    ///
+    /// Shorter intervals find devices sooner at the cost of more CPU.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let config = idm::ModelResolutionConfig::default();
+    /// assert_eq!(Duration::from_millis(250), config.scan_poll_interval());
+    ///
+    /// let config = config.with_scan_poll_interval(Duration::from_millis(50));
+    /// assert_eq!(Duration::from_millis(50), config.scan_poll_interval());
+    /// ```
+    #[must_use]
+    pub fn with_scan_poll_interval(mut self, scan_poll_interval: Duration) -> Self {
+        self.scan_poll_interval = scan_poll_interval;
+        self
+    }
+
+    /// Sets how long a single characteristic write may take on real hardware.
+    ///
+    /// The fake backend completes writes immediately and ignores this.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let config = idm::ModelResolutionConfig::default();
+    /// assert_eq!(Duration::from_secs(5), config.write_timeout());
+    ///
+    /// let config = config.with_write_timeout(Duration::from_millis(750));
+    /// assert_eq!(Duration::from_millis(750), config.write_timeout());
+    /// ```
+    #[must_use]
+    pub fn with_write_timeout(mut self, write_timeout: Duration) -> Self {
+        self.write_timeout = write_timeout;
+        self
+    }
+
+    /// Sets how the real backend retries a failed connect.
+    ///
+    /// The fake backend connects on the first attempt and ignores this.
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use idm::RetryPolicy;
+    ///
+    /// let config = idm::ModelResolutionConfig::default();
+    /// assert_eq!(RetryPolicy::default(), config.connect_retry());
+    ///
+    /// let retry = RetryPolicy::builder()
+    ///     .max_attempts(NonZeroUsize::new(3).expect("3 is non-zero"))
+    ///     .build();
+    /// let config = config.with_connect_retry(retry);
+    /// assert_eq!(3, config.connect_retry().max_attempts().get());
+    /// ```
+    #[must_use]
+    pub fn with_connect_retry(mut self, connect_retry: RetryPolicy) -> Self {
+        self.connect_retry = connect_retry;
+        self
+    }
+
     /// Returns the optional explicit LED-type override.
     #[must_use]
     pub fn led_type_override(&self) -> Option<u8> {
@@ -38,6 +245,78 @@ impl ModelResolutionConfig {
     pub fn overrides_path(&self) -> Option<&Path> {
         self.overrides_path.as_deref()
     }
+
+    /// Returns whether the connect-time LED-info probe should be skipped.
+    #[must_use]
+    pub fn skip_led_info(&self) -> bool {
+        self.skip_led_info
+    }
+
+    /// Returns whether the connect-time joint-mode write should be skipped.
+    #[must_use]
+    pub fn skip_joint_mode(&self) -> bool {
+        self.skip_joint_mode
+    }
+
+    /// Returns the per-attempt LED-info probe timeout.
+    #[must_use]
+    pub fn led_info_timeout(&self) -> Duration {
+        self.led_info_timeout
+    }
+
+    /// Returns whether cached LED-info results may replace the connect-time probe.
+    #[must_use]
+    pub fn profile_cache(&self) -> bool {
+        self.profile_cache
+    }
+
+    /// Returns how long a cached LED-info result stays fresh.
+    #[must_use]
+    pub fn profile_cache_ttl(&self) -> Duration {
+        self.profile_cache_ttl
+    }
+
+    /// Returns the optional custom profile cache file path.
+    #[must_use]
+    pub fn profile_cache_path(&self) -> Option<&Path> {
+        self.profile_cache_path.as_deref()
+    }
+
+    /// Returns the optional text char-count byte-order override.
+    #[must_use]
+    pub fn text_count_endian_override(&self) -> Option<TextCharCountEndian> {
+        self.text_count_endian_override
+    }
+
+    /// Returns the transfer-acknowledgement policy applied to resolved device profiles.
+    #[must_use]
+    pub fn ack_policy(&self) -> AckPolicy {
+        self.ack_policy
+    }
+
+    /// Returns how long session teardown may take before timing out.
+    #[must_use]
+    pub fn close_timeout(&self) -> Duration {
+        self.close_timeout
+    }
+
+    /// Returns how long the real scan loop waits between peripheral polls.
+    #[must_use]
+    pub fn scan_poll_interval(&self) -> Duration {
+        self.scan_poll_interval
+    }
+
+    /// Returns how long a single characteristic write may take on real hardware.
+    #[must_use]
+    pub fn write_timeout(&self) -> Duration {
+        self.write_timeout
+    }
+
+    /// Returns how the real backend retries a failed connect.
+    #[must_use]
+    pub fn connect_retry(&self) -> RetryPolicy {
+        self.connect_retry
+    }
 }
 
 /// Persistent store for per-device ambiguous-shape LED-type choices.
@@ -49,11 +328,7 @@ pub(crate) struct ModelOverrideStore {
 
 impl ModelOverrideStore {
     pub(crate) fn load(config: &ModelResolutionConfig) -> Result<Self, InteractionError> {
-        let path = config
-            .overrides_path()
-            .map(Path::to_path_buf)
-            .unwrap_or_else(default_override_path);
-        Self::load_from_path(path)
+        Self::load_from_path(model_overrides_path(config))
     }
 
     pub(crate) fn load_from_path(path: PathBuf) -> Result<Self, InteractionError> {
@@ -152,17 +427,27 @@ fn entry_key(device: &FoundDevice, identity: &ScanIdentity) -> String {
     )
 }
 
-fn default_override_path() -> PathBuf {
+/// Resolves the overrides file: the explicit path, else the per-user state
+/// directory.
+pub(crate) fn model_overrides_path(config: &ModelResolutionConfig) -> PathBuf {
+    config
+        .overrides_path()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| default_state_path(OVERRIDES_FILE_NAME))
+}
+
+/// Returns the path of `file_name` inside the per-user idm state directory.
+pub(crate) fn default_state_path(file_name: &str) -> PathBuf {
     let project_dirs = ProjectDirs::from("uk.co", "OrangeSquash", "idm");
     let Some(project_dirs) = project_dirs else {
-        return std::env::temp_dir().join("idm").join(OVERRIDES_FILE_NAME);
+        return std::env::temp_dir().join("idm").join(file_name);
     };
 
     let root = project_dirs
         .state_dir()
         .map(Path::to_path_buf)
         .unwrap_or_else(|| project_dirs.data_local_dir().to_path_buf());
-    root.join(OVERRIDES_FILE_NAME)
+    root.join(file_name)
 }
 
 #[cfg(test)]
@@ -226,6 +511,12 @@ mod tests {
         remove_if_exists(&path);
     }
 
+    #[test]
+    fn model_resolution_defaults_led_info_timeout_to_one_second() {
+        let config = ModelResolutionConfig::default();
+        assert_eq!(Duration::from_millis(1_000), config.led_info_timeout());
+    }
+
     #[test]
     fn store_rejects_invalid_record() {
         let path = unique_temp_path("model-override-invalid");
@@ -1,3 +1,4 @@
+use clap::ValueEnum;
 use derive_more::Display;
 use serde::Serialize;
 use serde_with::SerializeDisplay;
@@ -7,6 +8,7 @@ use super::device_profile_resolver::{
     DeviceProfileResolver, DeviceRoutingProfile, LedInfoResponse, TextPath,
 };
 use super::model::{FoundDevice, ServiceInfo};
+use crate::notification::AckPolicy;
 use crate::protocol;
 
 const ALTERNATE_VENDOR_SERVICE_UUID: &str = "0000ae00-0000-1000-8000-00805f9b34fb";
@@ -114,6 +116,18 @@ pub enum GifHeaderProfile {
     NoTimeSignature,
 }
 
+/// Byte order of the character count in text-upload metadata bytes `0..2`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Display, SerializeDisplay, ValueEnum)]
+pub enum TextCharCountEndian {
+    /// `[low, high]`, as sent by the vendor app and every known firmware.
+    #[default]
+    #[display("little")]
+    Little,
+    /// `[high, low]`, for firmware that garbles text under the default order.
+    #[display("big")]
+    Big,
+}
+
 /// Image upload mode to use for DIY/media handlers.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Display, SerializeDisplay)]
 pub enum ImageUploadMode {
@@ -135,6 +149,9 @@ pub struct DeviceProfile {
     joint_mode: Option<u8>,
     gif_header_profile: GifHeaderProfile,
     image_upload_mode: ImageUploadMode,
+    text_char_count_endian: TextCharCountEndian,
+    #[serde(skip)]
+    ack_policy: AckPolicy,
     write_without_response_fallback: usize,
 }
 
@@ -167,6 +184,8 @@ impl DeviceProfile {
             joint_mode: None,
             gif_header_profile,
             image_upload_mode,
+            text_char_count_endian: TextCharCountEndian::default(),
+            ack_policy: AckPolicy::default(),
             write_without_response_fallback,
         }
     }
@@ -182,6 +201,22 @@ impl DeviceProfile {
         self
     }
 
+    /// Replaces the resolved text char-count byte order when an override is set.
+    pub(crate) fn with_text_char_count_endian_override(
+        mut self,
+        endian_override: Option<TextCharCountEndian>,
+    ) -> Self {
+        if let Some(endian) = endian_override {
+            self.text_char_count_endian = endian;
+        }
+        self
+    }
+
+    pub(crate) fn with_ack_policy(mut self, ack_policy: AckPolicy) -> Self {
+        self.ack_policy = ack_policy;
+        self
+    }
+
     #[must_use]
     pub(crate) fn routing_profile_present(&self) -> bool {
         self.routing_profile_present
@@ -302,6 +337,34 @@ impl DeviceProfile {
         self.image_upload_mode
     }
 
+    /// Returns the byte order used for the text-metadata character count.
+    ///
+    /// ```
+    /// use idm::{DeviceProfile, GifHeaderProfile, ImageUploadMode, TextCharCountEndian};
+    ///
+    /// let profile =
+    ///     DeviceProfile::new(None, GifHeaderProfile::Timed, ImageUploadMode::PngFile, 512);
+    /// assert_eq!(TextCharCountEndian::Little, profile.text_char_count_endian());
+    /// ```
+    #[must_use]
+    pub fn text_char_count_endian(&self) -> TextCharCountEndian {
+        self.text_char_count_endian
+    }
+
+    /// Returns the transfer-acknowledgement status policy.
+    ///
+    /// ```
+    /// use idm::{AckPolicy, DeviceProfile, GifHeaderProfile, ImageUploadMode};
+    ///
+    /// let profile =
+    ///     DeviceProfile::new(None, GifHeaderProfile::Timed, ImageUploadMode::PngFile, 512);
+    /// assert_eq!(AckPolicy::default(), profile.ack_policy());
+    /// ```
+    #[must_use]
+    pub fn ack_policy(&self) -> AckPolicy {
+        self.ack_policy
+    }
+
     /// Returns the write-without-response fallback chunk size.
     ///
     /// ```
@@ -341,6 +404,7 @@ pub(crate) fn resolve_device_profile(
     services: &[ServiceInfo],
     write_without_response_limit: Option<usize>,
     routing_profile: Option<DeviceRoutingProfile>,
+    led_info: Option<LedInfoResponse>,
 ) -> DeviceProfile {
     let panel_dimensions = routing_profile
         .and_then(|profile| profile.panel_size)
@@ -351,6 +415,7 @@ pub(crate) fn resolve_device_profile(
                 .and_then(|model_profile| model_profile.panel_size)
                 .and_then(panel_dimensions_from_tuple)
         })
+        .or_else(|| led_info.and_then(LedInfoResponse::panel_dimensions))
         .or_else(|| infer_panel_dimensions(device.local_name()));
     let has_alternate_vendor_service = services.iter().any(|service| {
         service
@@ -481,6 +546,7 @@ mod tests {
             &[primary_fa_service()],
             None,
             None,
+            None,
         );
 
         assert_eq!(
@@ -501,6 +567,7 @@ mod tests {
             &[primary_fa_service()],
             None,
             None,
+            None,
         );
 
         assert_eq!(
@@ -521,6 +588,7 @@ mod tests {
             &[primary_fa_service()],
             Some(UNUSABLE_WRITE_WITHOUT_RESPONSE_LIMIT),
             None,
+            None,
         );
 
         assert_eq!(
@@ -541,6 +609,7 @@ mod tests {
             &[primary_fa_service()],
             None,
             None,
+            None,
         );
 
         assert_eq!(
@@ -554,6 +623,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolver_derives_panel_from_screen_type_without_scan_model() {
+        let led_info = LedInfoResponse {
+            mcu_major_version: 1,
+            mcu_minor_version: 0,
+            status: 0,
+            screen_type: 3,
+            password_enabled: false,
+        };
+        let resolved = resolve_device_profile(
+            &device(Some("IDM-Clock")),
+            &[primary_fa_service()],
+            None,
+            None,
+            Some(led_info),
+        );
+
+        assert_eq!(
+            DeviceProfile::new(
+                PanelDimensions::new(32, 32),
+                GifHeaderProfile::Timed,
+                ImageUploadMode::PngFile,
+                protocol::TRANSPORT_CHUNK_FALLBACK,
+            ),
+            resolved
+        );
+    }
+
     #[test]
     fn resolve_device_routing_profile_uses_scan_identity() {
         let device = device_with_model(Some("IDM-Clock"), Some((64, 64)));
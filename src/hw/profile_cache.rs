@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tracing::{debug, warn};
+
+use super::device_profile_resolver::LedInfoResponse;
+use super::model::FoundDevice;
+use super::model_overrides::{ModelResolutionConfig, default_state_path};
+use crate::error::InteractionError;
+
+const PROFILE_CACHE_FILE_NAME: &str = "profile-cache.tsv";
+
+/// One cached LED-info probe result.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct CachedLedInfo {
+    resolved_at_unix_secs: u64,
+    led_info: LedInfoResponse,
+}
+
+/// Persistent cache of the last LED-info probe result per device address.
+///
+/// The device profile is derived from scan identity, GATT layout, and the
+/// LED-info response. Only the LED-info response needs a device round-trip,
+/// so caching it lets reconnects rebuild the same profile without probing.
+#[derive(Debug)]
+pub(crate) struct ProfileCache {
+    path: PathBuf,
+    ttl: Duration,
+    entries: HashMap<String, CachedLedInfo>,
+}
+
+impl ProfileCache {
+    /// Loads the cache when enabled by `config`.
+    ///
+    /// An unreadable or corrupt cache is logged and replaced by an empty one,
+    /// so a bad cache file never blocks a connect.
+    pub(crate) fn load_if_enabled(config: &ModelResolutionConfig) -> Option<Self> {
+        if !config.profile_cache() {
+            return None;
+        }
+
+        let path = profile_cache_path(config);
+        match Self::load_from_path(path.clone(), config.profile_cache_ttl()) {
+            Ok(cache) => Some(cache),
+            Err(error) => {
+                warn!(?error, path = %path.display(), "ignoring unreadable device profile cache");
+                Some(Self {
+                    path,
+                    ttl: config.profile_cache_ttl(),
+                    entries: HashMap::new(),
+                })
+            }
+        }
+    }
+
+    pub(crate) fn load_from_path(path: PathBuf, ttl: Duration) -> Result<Self, InteractionError> {
+        let entries = if path.exists() {
+            let raw = fs::read_to_string(&path)
+                .map_err(|source| InteractionError::ProfileCacheIo { source })?;
+            parse_entries(&raw)?
+        } else {
+            HashMap::new()
+        };
+        Ok(Self { path, ttl, entries })
+    }
+
+    /// Returns the cached LED-info response for `device` if it is younger than the TTL.
+    #[must_use]
+    pub(crate) fn fresh_led_info_for(
+        &self,
+        device: &FoundDevice,
+        now: SystemTime,
+    ) -> Option<LedInfoResponse> {
+        let cached = self.entries.get(&entry_key(device))?;
+        let age = Duration::from_secs(unix_secs(now).saturating_sub(cached.resolved_at_unix_secs));
+        if age >= self.ttl {
+            debug!(?age, ttl = ?self.ttl, "cached LED-info result has expired");
+            return None;
+        }
+
+        Some(cached.led_info)
+    }
+
+    /// Records a freshly probed LED-info response for `device` and saves the cache.
+    pub(crate) fn record(
+        &mut self,
+        device: &FoundDevice,
+        led_info: LedInfoResponse,
+        now: SystemTime,
+    ) -> Result<(), InteractionError> {
+        self.entries.insert(
+            entry_key(device),
+            CachedLedInfo {
+                resolved_at_unix_secs: unix_secs(now),
+                led_info,
+            },
+        );
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), InteractionError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|source| InteractionError::ProfileCacheIo { source })?;
+        }
+
+        let mut rows = self.entries.iter().collect::<Vec<_>>();
+        rows.sort_by_key(|(key, _)| *key);
+        let serialised = rows
+            .into_iter()
+            .map(|(key, cached)| format_entry(key, cached))
+            .collect::<String>();
+
+        fs::write(&self.path, serialised)
+            .map_err(|source| InteractionError::ProfileCacheIo { source })?;
+        Ok(())
+    }
+}
+
+/// Resolves the cache location: the explicit path, else next to a custom
+/// overrides file, else the per-user state directory.
+pub(crate) fn profile_cache_path(config: &ModelResolutionConfig) -> PathBuf {
+    if let Some(path) = config.profile_cache_path() {
+        return path.to_path_buf();
+    }
+
+    config
+        .overrides_path()
+        .and_then(Path::parent)
+        .map(|parent| parent.join(PROFILE_CACHE_FILE_NAME))
+        .unwrap_or_else(|| default_state_path(PROFILE_CACHE_FILE_NAME))
+}
+
+fn format_entry(key: &str, cached: &CachedLedInfo) -> String {
+    let led_info = cached.led_info;
+    format!(
+        "{key}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+        cached.resolved_at_unix_secs,
+        led_info.mcu_major_version,
+        led_info.mcu_minor_version,
+        led_info.status,
+        led_info.screen_type,
+        u8::from(led_info.password_enabled),
+    )
+}
+
+fn parse_entries(contents: &str) -> Result<HashMap<String, CachedLedInfo>, InteractionError> {
+    let mut entries = HashMap::new();
+    for raw_line in contents.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || InteractionError::InvalidProfileCacheRecord {
+            record: line.to_string(),
+        };
+        let fields = line.split('\t').collect::<Vec<_>>();
+        let &[
+            key,
+            resolved_at,
+            mcu_major,
+            mcu_minor,
+            status,
+            screen_type,
+            password,
+        ] = fields.as_slice()
+        else {
+            return Err(invalid());
+        };
+        let parse_byte = |value: &str| value.parse::<u8>().map_err(|_error| invalid());
+
+        let cached = CachedLedInfo {
+            resolved_at_unix_secs: resolved_at.parse::<u64>().map_err(|_error| invalid())?,
+            led_info: LedInfoResponse {
+                mcu_major_version: parse_byte(mcu_major)?,
+                mcu_minor_version: parse_byte(mcu_minor)?,
+                status: parse_byte(status)?,
+                screen_type: parse_byte(screen_type)?,
+                password_enabled: parse_byte(password)? != 0,
+            },
+        };
+        entries.insert(key.to_string(), cached);
+    }
+
+    Ok(entries)
+}
+
+fn entry_key(device: &FoundDevice) -> String {
+    device.device_id().to_ascii_lowercase()
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn unique_temp_path(file_name: &str) -> PathBuf {
+        let suffix = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time should be after unix epoch")
+            .as_nanos();
+        std::env::temp_dir().join(format!("idm-{file_name}-{suffix}.tsv"))
+    }
+
+    fn remove_if_exists(path: &Path) {
+        if path.exists() {
+            fs::remove_file(path).expect("temporary fixture file should be removable");
+        }
+    }
+
+    fn device() -> FoundDevice {
+        FoundDevice::new(
+            "hci0".to_string(),
+            "AA:BB:CC:DD".to_string(),
+            Some("IDM-Clock".to_string()),
+            Some(-40),
+        )
+    }
+
+    fn led_info() -> LedInfoResponse {
+        LedInfoResponse::parse(&[0x09, 0x00, 0x01, 0x80, 0x02, 0x0A, 0x01, 0x03, 0x00])
+            .expect("fixture payload should parse")
+    }
+
+    #[test]
+    fn cache_round_trips_fresh_led_info() {
+        let path = unique_temp_path("profile-cache");
+        let now = SystemTime::now();
+        let mut cache = ProfileCache::load_from_path(path.clone(), Duration::from_secs(60))
+            .expect("new cache should load");
+
+        assert_eq!(None, cache.fresh_led_info_for(&device(), now));
+        cache
+            .record(&device(), led_info(), now)
+            .expect("recording LED info should succeed");
+
+        let reloaded = ProfileCache::load_from_path(path.clone(), Duration::from_secs(60))
+            .expect("stored cache should reload");
+        assert_eq!(
+            Some(led_info()),
+            reloaded.fresh_led_info_for(&device(), now)
+        );
+
+        remove_if_exists(&path);
+    }
+
+    #[test]
+    fn cache_ignores_entries_older_than_ttl() {
+        let path = unique_temp_path("profile-cache-expired");
+        let recorded_at = SystemTime::now();
+        let mut cache = ProfileCache::load_from_path(path.clone(), Duration::from_secs(60))
+            .expect("new cache should load");
+        cache
+            .record(&device(), led_info(), recorded_at)
+            .expect("recording LED info should succeed");
+
+        let later = recorded_at + Duration::from_secs(61);
+        assert_eq!(None, cache.fresh_led_info_for(&device(), later));
+
+        remove_if_exists(&path);
+    }
+
+    #[test]
+    fn cache_rejects_invalid_record() {
+        let path = unique_temp_path("profile-cache-invalid");
+        fs::write(&path, "aa:bb\tnot-a-timestamp\n").expect("invalid fixture should write");
+
+        let loaded = ProfileCache::load_from_path(path.clone(), Duration::from_secs(60));
+        assert_matches!(
+            loaded,
+            Err(InteractionError::InvalidProfileCacheRecord { .. })
+        );
+
+        remove_if_exists(&path);
+    }
+
+    #[test]
+    fn disabled_cache_does_not_load() {
+        assert_eq!(
+            true,
+            ProfileCache::load_if_enabled(&ModelResolutionConfig::default()).is_none()
+        );
+    }
+}
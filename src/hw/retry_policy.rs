@@ -0,0 +1,140 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use bon::Builder;
+
+use crate::error::InteractionError;
+
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// How the real backend retries a failed connect to the first matching device.
+///
+/// The wait before retry `n` starts at `initial_backoff` and doubles on every
+/// retry, capped at `max_backoff`. Only transient BLE failures are retried;
+/// a device missing its required endpoints or a configuration error fails on
+/// the first attempt. The default makes a single attempt.
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use std::time::Duration;
+///
+/// use idm::RetryPolicy;
+///
+/// let policy = RetryPolicy::builder()
+///     .max_attempts(NonZeroUsize::new(4).expect("4 is non-zero"))
+///     .max_backoff(Duration::from_secs(2))
+///     .build();
+/// assert_eq!(4, policy.max_attempts().get());
+/// assert_eq!(Duration::from_millis(250), policy.initial_backoff());
+/// assert_eq!(1, RetryPolicy::default().max_attempts().get());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Builder)]
+pub struct RetryPolicy {
+    /// Connect attempts made in total, the first one included.
+    #[builder(default = NonZeroUsize::MIN)]
+    max_attempts: NonZeroUsize,
+    /// Wait before the first retry.
+    #[builder(default = DEFAULT_INITIAL_BACKOFF)]
+    initial_backoff: Duration,
+    /// Ceiling on the wait before any retry.
+    #[builder(default = DEFAULT_MAX_BACKOFF)]
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the total number of connect attempts.
+    #[must_use]
+    pub fn max_attempts(&self) -> NonZeroUsize {
+        self.max_attempts
+    }
+
+    /// Returns the wait before the first retry.
+    #[must_use]
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the ceiling on the wait before any retry.
+    #[must_use]
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Returns how long to wait after failed attempt number `attempt` (1-based).
+    pub(crate) fn backoff(&self, attempt: usize) -> Duration {
+        let doublings = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let factor = 2u32.checked_pow(doublings).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_backoff)
+    }
+
+    /// Returns whether `error` from attempt number `attempt` (1-based) is retried.
+    pub(crate) fn should_retry(&self, attempt: usize, error: &InteractionError) -> bool {
+        attempt < self.max_attempts.get() && is_transient(error)
+    }
+}
+
+/// Transient failures come from the BLE stack itself; everything else
+/// describes the device or configuration and would fail again.
+fn is_transient(error: &InteractionError) -> bool {
+    matches!(
+        error,
+        InteractionError::Ble(_) | InteractionError::WriteTimeout { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn policy(max_attempts: usize) -> RetryPolicy {
+        RetryPolicy::builder()
+            .max_attempts(NonZeroUsize::new(max_attempts).expect("attempts should be non-zero"))
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(500))
+            .build()
+    }
+
+    #[rstest]
+    #[case::first_retry(1, 100)]
+    #[case::doubles(2, 200)]
+    #[case::doubles_again(3, 400)]
+    #[case::capped(4, 500)]
+    #[case::stays_capped(64, 500)]
+    fn backoff_doubles_up_to_the_ceiling(#[case] attempt: usize, #[case] expected_ms: u64) {
+        assert_eq!(
+            Duration::from_millis(expected_ms),
+            policy(3).backoff(attempt)
+        );
+    }
+
+    #[rstest]
+    #[case::transient_ble(InteractionError::Ble(btleplug::Error::NotConnected), 1, true)]
+    #[case::transient_write(InteractionError::WriteTimeout { timeout_ms: 5_000 }, 2, true)]
+    #[case::attempts_exhausted(InteractionError::Ble(btleplug::Error::NotConnected), 3, false)]
+    #[case::missing_endpoints(
+        InteractionError::MissingRequiredEndpoints { missing: "write".to_string() },
+        1,
+        false
+    )]
+    #[case::no_adapters(InteractionError::NoAdapters, 1, false)]
+    fn should_retry_only_transient_failures_within_budget(
+        #[case] error: InteractionError,
+        #[case] attempt: usize,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, policy(3).should_retry(attempt, &error));
+    }
+}
@@ -100,6 +100,42 @@ impl ScanModelHandler {
         parse_with_ad_type(payload).or_else(|| parse_without_ad_type(payload))
     }
 
+    /// Builds a manufacturer payload that [`Self::parse_identity`] reads back as `identity`.
+    ///
+    /// The payload omits the AD type byte, so it can be used directly as the
+    /// fifth field of a fake scan fixture once hex-encoded.
+    ///
+    /// ```
+    /// let identity = idm::ScanIdentity {
+    ///     cid: 1,
+    ///     pid: 5,
+    ///     shape: 3,
+    ///     reverse: false,
+    ///     group_id: 1,
+    ///     device_id: 2,
+    ///     lamp_count: 32,
+    ///     lamp_num: 32,
+    /// };
+    /// let payload = idm::ScanModelHandler::build_identity_payload(&identity);
+    /// assert_eq!(Some(identity), idm::ScanModelHandler::parse_identity(&payload));
+    /// ```
+    #[must_use]
+    pub fn build_identity_payload(identity: &ScanIdentity) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(14);
+        payload.extend_from_slice(&TR_SIGNATURE_P);
+        payload.extend_from_slice(&[
+            identity.shape as u8,
+            identity.group_id,
+            identity.device_id,
+            u8::from(identity.reverse),
+            identity.cid,
+            identity.pid,
+        ]);
+        payload.extend_from_slice(&identity.lamp_count.to_le_bytes());
+        payload.extend_from_slice(&identity.lamp_num.to_le_bytes());
+        payload
+    }
+
     /// Resolves a provisional model profile from parsed identity fields.
     ///
     /// ```
@@ -280,6 +316,32 @@ mod tests {
         assert_eq!(expected, parsed);
     }
 
+    #[rstest]
+    #[case::square(3, 1, 4, false)]
+    #[case::reversed(4, 2, 0x20, true)]
+    #[case::ambiguous(-127, 1, 7, false)]
+    fn build_identity_payload_round_trips_through_parse_identity(
+        #[case] shape: i8,
+        #[case] cid: u8,
+        #[case] pid: u8,
+        #[case] reverse: bool,
+    ) {
+        let identity = ScanIdentity {
+            cid,
+            pid,
+            shape,
+            reverse,
+            group_id: 1,
+            device_id: 2,
+            lamp_count: 0x0120,
+            lamp_num: 48,
+        };
+
+        let payload = ScanModelHandler::build_identity_payload(&identity);
+
+        assert_eq!(Some(identity), ScanModelHandler::parse_identity(&payload));
+    }
+
     #[rstest]
     #[case(1, Some(1), Some((16, 16)), None)]
     #[case(2, Some(2), Some((8, 32)), None)]
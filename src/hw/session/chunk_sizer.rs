@@ -9,6 +9,9 @@ use crate::protocol;
 #[derive(Debug)]
 pub(crate) struct AdaptiveChunkSizer {
     current: AtomicUsize,
+    /// Smallest size that still counts as healthy; probing down from the
+    /// MTU-ready size to the fallback is expected and not a degradation.
+    healthy_floor: usize,
 }
 
 impl AdaptiveChunkSizer {
@@ -18,13 +21,15 @@ impl AdaptiveChunkSizer {
     /// MTU-ready size and back off on failures.
     pub(crate) fn from_baseline(baseline: usize) -> Self {
         let baseline = baseline.max(protocol::TRANSPORT_CHUNK_FALLBACK);
-        let current = if baseline <= protocol::TRANSPORT_CHUNK_FALLBACK {
-            protocol::TRANSPORT_CHUNK_MTU_READY
+        let (current, healthy_floor) = if baseline <= protocol::TRANSPORT_CHUNK_FALLBACK {
+            (protocol::TRANSPORT_CHUNK_MTU_READY, baseline)
         } else {
-            baseline.min(protocol::TRANSPORT_CHUNK_MTU_READY)
+            let start = baseline.min(protocol::TRANSPORT_CHUNK_MTU_READY);
+            (start, start)
         };
         Self {
             current: AtomicUsize::new(current),
+            healthy_floor,
         }
     }
 
@@ -34,6 +39,14 @@ impl AdaptiveChunkSizer {
         self.current.load(Ordering::Relaxed)
     }
 
+    /// Returns the current chunk size when write failures have pushed it
+    /// below what the connection's baseline promised.
+    #[must_use]
+    pub(crate) fn degraded_size(&self) -> Option<usize> {
+        let current = self.current();
+        (current < self.healthy_floor).then_some(current)
+    }
+
     /// Halves current chunk size, saturating at protocol fallback.
     ///
     /// Returns `true` when chunk size was reduced, or `false` when already at
@@ -89,4 +102,18 @@ mod tests {
         assert_eq!(vec![509, 254, 127, 63, 31, 18], observed);
         assert!(!sizer.reduce_on_failure());
     }
+
+    #[rstest]
+    #[case::fallback_baseline(18, None)]
+    #[case::reported_baseline(509, Some(254))]
+    fn degraded_size_reports_only_drops_below_baseline(
+        #[case] baseline: usize,
+        #[case] expected: Option<usize>,
+    ) {
+        let sizer = AdaptiveChunkSizer::from_baseline(baseline);
+
+        assert_eq!(None, sizer.degraded_size());
+        assert!(sizer.reduce_on_failure());
+        assert_eq!(expected, sizer.degraded_size());
+    }
 }
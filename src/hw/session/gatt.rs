@@ -55,6 +55,19 @@ pub(crate) fn negotiate_session_endpoints(
     negotiate_session_endpoints_inner(services)
 }
 
+/// Resolves endpoint presence without failing when required endpoints are missing.
+pub(crate) fn probe_session_endpoints(
+    services: &[ServiceInfo],
+) -> (Option<GattProfile>, EndpointPresence) {
+    match negotiate_session_endpoints_inner(services) {
+        Ok(negotiated) => (
+            Some(negotiated.gatt_profile),
+            negotiated.endpoint_presence(),
+        ),
+        Err(_error) => (None, infer_required_endpoint_presence(services)),
+    }
+}
+
 #[instrument(skip(services), level = "debug", fields(service_count = services.len()))]
 fn negotiate_session_endpoints_inner(
     services: &[ServiceInfo],
@@ -375,4 +388,22 @@ mod tests {
             if missing.contains(expected_fragment)
         );
     }
+
+    #[test]
+    fn probe_reports_missing_read_notify_without_failing() {
+        let services = vec![service(
+            FA_SERVICE_UUID,
+            vec![characteristic(FA_WRITE_UUID, &["write"])],
+        )];
+
+        let (gatt_profile, presence) = probe_session_endpoints(&services);
+
+        assert_eq!(None, gatt_profile);
+        assert_eq!(true, presence.is_present(EndpointId::ControlService));
+        assert_eq!(true, presence.is_present(EndpointId::WriteCharacteristic));
+        assert_eq!(
+            false,
+            presence.is_present(EndpointId::ReadNotifyCharacteristic)
+        );
+    }
 }
@@ -5,6 +5,7 @@ mod write;
 pub use gatt::GattProfile;
 pub(super) use gatt::{
     FA_SERVICE_UUID, FA_WRITE_UUID, NegotiatedSessionEndpoints, negotiate_session_endpoints,
+    probe_session_endpoints,
 };
 pub(super) use write::resolve_chunk_sizer;
 pub(crate) use write::{Ack, SessionWriter};
@@ -166,6 +166,11 @@ pub(crate) struct SessionWriter<'a> {
     /// the GIF handler for cache-hit detection.
     #[builder(default = false)]
     allow_early_finish: bool,
+
+    /// Pause after each transport fragment. Zero writes fragments back to
+    /// back and leaves pacing to the transfer acks alone.
+    #[builder(default = DEFAULT_FRAGMENT_DELAY)]
+    fragment_delay: Duration,
 }
 
 impl<'a> SessionWriter<'a> {
@@ -201,6 +206,7 @@ impl<'a> SessionWriter<'a> {
             header,
             mut stream,
             allow_early_finish,
+            fragment_delay,
         } = self;
         span.record("payload_len", payload.len());
 
@@ -211,7 +217,7 @@ impl<'a> SessionWriter<'a> {
 
         let encoder = match header {
             None => {
-                let frag_stats = session.write(payload, write_mode).await?;
+                let frag_stats = session.write(payload, write_mode, fragment_delay).await?;
                 let stats = WriteStats {
                     bytes_written: frag_stats.bytes_written,
                     chunks_written: frag_stats.chunks_written,
@@ -256,6 +262,7 @@ impl<'a> SessionWriter<'a> {
             None
         };
 
+        let ack_policy = session.device_profile().ack_policy();
         let mut bytes_written = 0usize;
         let mut chunks_written = 0usize;
         let mut logical_chunks_sent = 0usize;
@@ -266,7 +273,9 @@ impl<'a> SessionWriter<'a> {
             frame_block.extend_from_slice(&header_bytes);
             frame_block.extend_from_slice(logical_chunk);
 
-            let frag_stats = session.write(&frame_block, write_mode).await?;
+            let frag_stats = session
+                .write(&frame_block, write_mode, fragment_delay)
+                .await?;
             bytes_written += frag_stats.bytes_written;
             chunks_written += frag_stats.chunks_written;
             logical_chunks_sent += 1;
@@ -280,7 +289,9 @@ impl<'a> SessionWriter<'a> {
                         .expect("internal stream must exist for Transfer ack")
                 };
                 let ack_started = Instant::now();
-                match wait_for_transfer_ack(ack_stream, DEFAULT_ACK_TIMEOUT, family).await {
+                match wait_for_transfer_ack(ack_stream, DEFAULT_ACK_TIMEOUT, family, ack_policy)
+                    .await
+                {
                     Ok(UploadAckOutcome::Continue) => {
                         trace!(
                             logical_chunk_index = index,
@@ -354,13 +365,14 @@ impl DeviceSession {
     /// Writes a payload to the device.
     ///
     /// The payload is transparently split into transport-sized chunks with
-    /// a default 20 ms pacing delay between successive writes. On write
-    /// failure the chunk size is reduced and the failing chunk is retried.
+    /// `fragment_delay` between successive writes. On write failure the
+    /// chunk size is reduced and the failing chunk is retried.
     #[instrument(
         skip(self, frame),
         level = "trace",
         fields(
             ?write_mode,
+            ?fragment_delay,
             frame_len = frame.len(),
             adaptive_transport_chunk_limit_start = tracing::field::Empty,
             adaptive_transport_chunk_limit_end = tracing::field::Empty,
@@ -372,9 +384,9 @@ impl DeviceSession {
         &self,
         frame: &[u8],
         write_mode: WriteMode,
+        fragment_delay: Duration,
     ) -> Result<WriteStats, ProtocolError> {
         let span = tracing::Span::current();
-        let fragment_delay = DEFAULT_FRAGMENT_DELAY;
         let mut bytes_written = 0usize;
         let mut chunks_written = 0usize;
         let mut chunk_index = 0usize;
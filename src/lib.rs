@@ -7,52 +7,74 @@ mod hw;
 mod media;
 mod notification;
 mod protocol;
+mod spinner;
 mod telemetry;
 mod terminal;
 mod utils;
+mod warning;
 
 // ── Public API ───────────────────────────────────────────────────────
 
 pub use app::{
     SessionHandler, fake_hardware_client, real_hardware_client,
     real_hardware_client_with_model_resolution, run, run_with_clients,
-    run_with_clients_and_log_level, run_with_log_level,
+    run_with_clients_and_log_level, run_with_log_level, run_with_result,
 };
+#[cfg(unix)]
+pub use cli::DaemonArgs;
 pub use cli::{
-    Args, BrightnessArgs, ColourArgs, Command, ControlAction, ControlArgs, FakeArgs, ImageArgs,
-    ListenArgs, LogLevel, OutputFormat, PowerArgs, PowerState, SyncTimeArgs, TextArgs,
+    AnimateArgs, AnimateOutcome, Args, BrightnessArgs, ColourArgs, Command, CommandExit,
+    CommandOutcome, ControlAction, ControlArgs, CrcArgs, CrcOutcome, DescribeArgs, DeviceOutcome,
+    DoctorCheck, DoctorOutcome, DoctorStatus, FactoryResetArgs, FakeArgs, FrameDescription,
+    GifValidationOutcome, ImageArgs, ImageMediaType, ImageOutcome, ImagePreviewOutcome,
+    InspectArgs, JointModeArgs, JointModeState, ListenArgs, LogLevel, OutputFormat, PowerArgs,
+    PowerState, ReconnectLoopArgs, ReconnectLoopOutcome, SceneArgs, ScreenTimeoutArgs,
+    SelfTestCheck, SelfTestOutcome, SyncTimeArgs, TextArgs, TextOutcome, TextPreviewOutcome,
+    ValidateGifArgs,
 };
-pub use error::{FixtureError, InteractionError, ProtocolError};
+pub use error::{FixtureError, InteractionError, ProtocolError, ScanFixtureField};
 pub use handlers::{
-    Brightness, BrightnessError, BrightnessHandler, FrameCodecError, FullscreenColourHandler,
-    GifUploadError, GifUploadHandler, GifUploadReceipt, GifUploadRequest, ImageUploadError,
-    ImageUploadHandler, ImageUploadReceipt, ImageUploadRequest, MaterialSlot, MaterialTimeSign,
-    MediaHeaderTail, PowerHandler, Rgb, ScreenLightTimeoutHandler, ScreenLightTimeoutProbe,
-    ScreenLightTimeoutProbeOutcome, ScreenPower, TextOptions, TextUploadError, TextUploadHandler,
-    TextUploadRequest, TimeSyncHandler, TimedMaterialSlot, UploadAckError, UploadReceipt,
+    Brightness, BrightnessError, BrightnessHandler, FactoryResetHandler, FrameCodecError,
+    FullscreenColourHandler, GifPacing, GifUploadError, GifUploadHandler, GifUploadReceipt,
+    GifUploadRequest, GifValidationLimits, ImageUploadError, ImageUploadHandler,
+    ImageUploadReceipt, ImageUploadRequest, JointMode, JointModeHandler, MaterialKind,
+    MaterialSlot, MaterialSlotsError, MaterialSlotsHandler, MaterialTimeSign, MediaHeaderTail,
+    PowerHandler, Rgb, RgbParseError, SceneError, SceneHandler, SceneRequest, SceneStep,
+    ScheduleEntry, ScheduleError, ScheduleHandler, ScreenLightTimeout, ScreenLightTimeoutError,
+    ScreenLightTimeoutHandler, ScreenLightTimeoutProbe, ScreenLightTimeoutProbeOutcome,
+    ScreenPower, ScrollDirection, StoredMaterialSlot, TextDisplayMode, TextFileError, TextOptions,
+    TextSpeed, TextSpeedParseError, TextUploadError, TextUploadHandler, TextUploadRequest,
+    TimeSyncHandler, TimedMaterialSlot, UploadAckError, UploadReceipt,
 };
 pub use hw::{
-    AckAction, AmbiguousShape, CharacteristicInfo, DeviceProfile, DeviceSession, EndpointPresence,
+    AckAction, AmbiguousShape, CharacteristicInfo, ConnectionEvent, ConnectionObserver,
+    DeviceConnectAttempt, DeviceProfile, DeviceSession, EndpointPresence, EndpointProbe,
     FoundDevice, GattProfile, GifHeaderProfile, GifScenario, HardwareClient, ImageScenario,
-    ImageUploadMode, InspectReport, LedInfoResponse, ListenFixture, ListenNotification,
-    ListenScenario, ListenStopReason, ListenStreamBehaviour, ListenSummary, ModelProfile,
-    ModelResolutionConfig, NotificationMessage, NotificationRunSummary, NotificationSubscription,
-    PanelDimensions, PanelSize, ScanIdentity, ScanModelHandler, ScanScenario, ServiceInfo,
-    SessionMetadata, TextPath, TextScenario, WriteMode,
+    ImageUploadMode, InitialReadStatus, InspectReport, LedInfoResponse, ListenFixture,
+    ListenNotification, ListenScenario, ListenStopReason, ListenStreamBehaviour, ListenSummary,
+    ModelProfile, ModelResolutionConfig, NoopConnectionObserver, NotificationMessage,
+    NotificationRunSummary, NotificationSubscription, NotifyDelivery, NotifyEventCounts,
+    PanelDimensions, PanelSize, ReconnectCycle, RetryPolicy, ScanIdentity, ScanModelHandler,
+    ScanScenario, ServiceInfo, SessionMetadata, TextCharCountEndian, TextPath, TextScenario,
+    WriteMode,
 };
 pub use media::{
-    GifAnimation, GifAnimationError, ImagePreparationError, ImagePreprocessor, PreparedImageUpload,
-    PreparedStillImage, Rgb888Frame, Rgb888FrameError,
+    DEFAULT_MAX_SOURCE_PIXELS, DitherMode, GifAnimation, GifAnimationError, ImagePreparationError,
+    ImagePreparationOptions, ImagePreprocessor, PreparedGifFrame, PreparedImageUpload,
+    PreparedStillImage, Rgb888Frame, Rgb888FrameError, ScalingMode, TransparencyMode,
 };
 pub use notification::{
-    NotificationDecodeError, NotifyEvent, ScheduleMasterSwitchStatus, ScheduleSetupStatus,
-    TransferFamily,
+    AckPolicy, NotificationDecodeError, NotificationHandler, NotifyEvent,
+    ScheduleMasterSwitchStatus, ScheduleSetupStatus, TransferFamily, decode_notifications,
 };
 pub use protocol::EndpointId;
-pub use terminal::TerminalClient;
+pub use spinner::{SpinnerCharset, SpinnerStyle};
+pub use terminal::{SystemTerminalClient, TerminalClient};
+pub use warning::Warning;
 
 // ── Crate-internal re-exports ────────────────────────────────────────
 
 pub(crate) use handlers::{
-    DiyPrefixFields, FrameCodec, GifChunkFlag, GifHeaderFields, ImageHeaderFields, TextHeaderFields,
+    DecodedNotification, DiyPrefixFields, FrameCodec, GifChunkFlag, GifHeaderFields,
+    ImageHeaderFields, MediaHeader, MediaHeaderKind, ShortFrame, TextHeaderFields,
 };
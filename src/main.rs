@@ -4,8 +4,8 @@ use std::process::ExitCode;
 use clap::Parser;
 
 use idm::{
-    Args, OutputFormat, fake_hardware_client, real_hardware_client_with_model_resolution,
-    run_with_log_level,
+    Args, CommandExit, SystemTerminalClient, fake_hardware_client,
+    real_hardware_client_with_model_resolution, run_with_clients_and_log_level,
 };
 
 #[tokio::main]
@@ -15,21 +15,21 @@ async fn main() -> ExitCode {
 
     let run_result = async {
         let log_level = args.log_level();
-        let output_format = args.output_format().unwrap_or(if stdout.is_terminal() {
-            OutputFormat::Pretty
-        } else {
-            OutputFormat::Json
-        });
+        let output_format = args.resolve_output_format(stdout.is_terminal());
         let model_resolution = args.model_resolution();
+        let terminal_client = SystemTerminalClient::new(args.no_colour())
+            .with_force_colour(args.force_colour())
+            .with_spinner_style(args.spinner_style());
         let (command, maybe_fake_args) = args.into_command_and_fake_args()?;
         let hardware_client = match maybe_fake_args {
             Some(fake_args) => fake_hardware_client(fake_args),
             None => real_hardware_client_with_model_resolution(model_resolution),
         };
 
-        run_with_log_level(
+        run_with_clients_and_log_level(
             command,
             &mut stdout,
+            &terminal_client,
             hardware_client,
             log_level,
             output_format,
@@ -38,15 +38,16 @@ async fn main() -> ExitCode {
     }
     .await;
 
+    let exit = CommandExit::from_result(&run_result);
     match run_result {
-        Ok(()) => ExitCode::SUCCESS,
+        Ok(()) => exit.into(),
         Err(error) => {
             if tracing::dispatcher::has_been_set() {
                 tracing::error!(error = %format_args!("{error:#}"), "command failed");
             } else {
                 eprintln!("error: {error:#}");
             }
-            ExitCode::from(1)
+            exit.into()
         }
     }
 }
@@ -0,0 +1,228 @@
+use clap::ValueEnum;
+
+/// Spread of the ordered-dither offsets when reducing stills to RGB565 levels.
+pub(crate) const RGB565_ORDERED_SPREAD: i32 = 8;
+/// Spread of the ordered-dither offsets when mapping onto a quantised GIF palette.
+pub(crate) const GIF_PALETTE_ORDERED_SPREAD: i32 = 32;
+
+/// 4x4 Bayer threshold matrix, values `0..16`.
+const BAYER_4X4: [[i32; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// How prepared images approximate colours the output cannot represent.
+///
+/// Stills are reduced to RGB565 levels when dithering is on, so smooth
+/// gradients turn into fine patterns instead of visible bands. GIF frames are
+/// dithered against their shared palette; the default leaves output unchanged.
+///
+/// ```
+/// use idm::{DitherMode, ImagePreparationOptions};
+///
+/// let options = ImagePreparationOptions::builder()
+///     .dither(DitherMode::FloydSteinberg)
+///     .build();
+/// assert_eq!(DitherMode::FloydSteinberg, options.dither());
+/// assert_eq!(DitherMode::None, ImagePreparationOptions::default().dither());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum DitherMode {
+    /// Map every pixel to its nearest colour.
+    #[default]
+    None,
+    /// Diffuse each pixel's rounding error onto the neighbours not yet visited.
+    FloydSteinberg,
+    /// Offset each pixel by a fixed 4x4 Bayer pattern before rounding.
+    Ordered,
+}
+
+/// Maps every pixel through `quantise`, dithering per `mode`.
+///
+/// `pixels` holds `channels` bytes per pixel with RGB first, `width` pixels
+/// per row. `quantise` receives the pixel index and the colour to round, and
+/// returns its choice together with the colour that choice displays as; the
+/// difference is the error Floyd-Steinberg diffuses.
+pub(crate) fn dither<T>(
+    mode: DitherMode,
+    pixels: &[u8],
+    channels: usize,
+    width: usize,
+    ordered_spread: i32,
+    mut quantise: impl FnMut(usize, [u8; 3]) -> (T, [u8; 3]),
+) -> Vec<T> {
+    let pixel_count = pixels.len() / channels;
+    let colour_at = |index: usize| {
+        let offset = index * channels;
+        [
+            i32::from(pixels[offset]),
+            i32::from(pixels[offset + 1]),
+            i32::from(pixels[offset + 2]),
+        ]
+    };
+    let mut errors = vec![[0_i32; 3]; pixel_count];
+    let mut chosen = Vec::with_capacity(pixel_count);
+
+    for index in 0..pixel_count {
+        let (x, y) = (index % width, index / width);
+        let colour = colour_at(index);
+        let target = match mode {
+            DitherMode::None => colour,
+            DitherMode::FloydSteinberg => {
+                let error = errors[index];
+                [
+                    colour[0] + error[0],
+                    colour[1] + error[1],
+                    colour[2] + error[2],
+                ]
+            }
+            DitherMode::Ordered => {
+                let offset = (BAYER_4X4[y % 4][x % 4] * 2 - 15) * ordered_spread / 32;
+                colour.map(|channel| channel + offset)
+            }
+        };
+        let target = target.map(clamp_channel);
+        let (choice, displayed) = quantise(index, target);
+        chosen.push(choice);
+
+        if mode == DitherMode::FloydSteinberg {
+            let error: [i32; 3] = std::array::from_fn(|channel| {
+                i32::from(target[channel]) - i32::from(displayed[channel])
+            });
+            let mut spread = |dx: isize, dy: usize, weight: i32| {
+                let Some(nx) = x.checked_add_signed(dx).filter(|nx| *nx < width) else {
+                    return;
+                };
+                let neighbour = (y + dy) * width + nx;
+                if let Some(slot) = errors.get_mut(neighbour) {
+                    for (slot_channel, error_channel) in slot.iter_mut().zip(error) {
+                        *slot_channel += error_channel * weight / 16;
+                    }
+                }
+            };
+            spread(1, 0, 7);
+            spread(-1, 1, 3);
+            spread(0, 1, 5);
+            spread(1, 1, 1);
+        }
+    }
+    chosen
+}
+
+/// Reduces RGB888 pixels to RGB565 levels, dithering per `mode`.
+///
+/// [`DitherMode::None`] returns the pixels untouched.
+pub(crate) fn dither_rgb888_to_rgb565(
+    rgb_pixels: Vec<u8>,
+    width: usize,
+    mode: DitherMode,
+) -> Vec<u8> {
+    if mode == DitherMode::None {
+        return rgb_pixels;
+    }
+    dither(
+        mode,
+        &rgb_pixels,
+        3,
+        width,
+        RGB565_ORDERED_SPREAD,
+        |_index, [r, g, b]| {
+            let rounded = [
+                round_to_bits(r, 5),
+                round_to_bits(g, 6),
+                round_to_bits(b, 5),
+            ];
+            (rounded, rounded)
+        },
+    )
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn clamp_channel(value: i32) -> u8 {
+    u8::try_from(value.clamp(0, 255)).unwrap_or(u8::MAX)
+}
+
+/// Rounds `value` to the nearest of the `2^bits` evenly spaced levels.
+fn round_to_bits(value: u8, bits: u32) -> u8 {
+    let max_level = (1_u32 << bits) - 1;
+    let level = (u32::from(value) * max_level + 127) / 255;
+    u8::try_from((level * 255 + max_level / 2) / max_level).unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const WIDTH: usize = 16;
+
+    fn flat_rgb(colour: [u8; 3]) -> Vec<u8> {
+        colour.repeat(WIDTH * WIDTH)
+    }
+
+    fn mean_red(rgb_pixels: &[u8]) -> usize {
+        let reds = rgb_pixels
+            .chunks_exact(3)
+            .map(|pixel| usize::from(pixel[0]));
+        reds.sum::<usize>() / (rgb_pixels.len() / 3)
+    }
+
+    #[test]
+    fn no_dither_leaves_pixels_untouched() {
+        let pixels = flat_rgb([4, 4, 4]);
+
+        assert_eq!(
+            pixels.clone(),
+            dither_rgb888_to_rgb565(pixels, WIDTH, DitherMode::None)
+        );
+    }
+
+    #[rstest]
+    #[case::floyd_steinberg(DitherMode::FloydSteinberg)]
+    #[case::ordered(DitherMode::Ordered)]
+    fn dithering_mixes_neighbouring_levels_to_keep_the_mean(#[case] mode: DitherMode) {
+        // Red 4 sits between the RGB565 levels 0 and 8.
+        let dithered = dither_rgb888_to_rgb565(flat_rgb([4, 4, 4]), WIDTH, mode);
+
+        let mut reds = dithered
+            .chunks_exact(3)
+            .map(|pixel| pixel[0])
+            .collect::<Vec<_>>();
+        reds.sort_unstable();
+        reds.dedup();
+        assert_eq!(vec![0, 8], reds);
+        assert!((3..=5).contains(&mean_red(&dithered)), "{dithered:?}");
+    }
+
+    #[test]
+    fn ordered_dither_depends_only_on_pixel_position() {
+        let gradient = (0..WIDTH * WIDTH)
+            .flat_map(|index| {
+                let level = u8::try_from(index % 256).unwrap_or(u8::MAX);
+                [level, level, level]
+            })
+            .collect::<Vec<_>>();
+        let mut changed = gradient.clone();
+        changed[..3].copy_from_slice(&[0xFF, 0x00, 0x00]);
+
+        let first = dither_rgb888_to_rgb565(gradient, WIDTH, DitherMode::Ordered);
+        let second = dither_rgb888_to_rgb565(changed, WIDTH, DitherMode::Ordered);
+
+        assert_eq!(first[3..], second[3..]);
+    }
+
+    #[rstest]
+    #[case::black(0, 5, 0)]
+    #[case::white(255, 5, 255)]
+    #[case::rounds_down(4, 5, 0)]
+    #[case::rounds_up(5, 5, 8)]
+    #[case::six_bit(3, 6, 4)]
+    fn round_to_bits_picks_nearest_level(
+        #[case] value: u8,
+        #[case] bits: u32,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(expected, round_to_bits(value, bits));
+    }
+}
@@ -1,7 +1,10 @@
 use std::io::Cursor;
+use std::time::Duration;
 
 use thiserror::Error;
 
+use super::image_preprocessor::{encode_animation, retime_gif};
+use super::{ImagePreparationError, Rgb888Frame};
 use crate::hw::PanelDimensions;
 
 /// Errors returned when validating GIF upload payloads.
@@ -46,6 +49,66 @@ impl GifAnimation {
         self.dimensions
     }
 
+    /// Assembles panel-sized frames into one looping GIF animation.
+    ///
+    /// Every frame is shown for `delay`, rounded down to GIF centiseconds and
+    /// clamped to at least one. All frames must share the same dimensions.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{GifAnimation, PanelDimensions, Rgb888Frame};
+    ///
+    /// let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid dimensions");
+    /// let red = Rgb888Frame::try_from((panel, vec![0xFF, 0x00, 0x00].repeat(4)))?;
+    /// let blue = Rgb888Frame::try_from((panel, vec![0x00, 0x00, 0xFF].repeat(4)))?;
+    ///
+    /// let gif = GifAnimation::from_frames(&[red, blue], Duration::from_millis(200))?;
+    /// assert_eq!(panel, gif.dimensions());
+    /// assert_eq!(2, gif.frame_count()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `frames` is empty, frame dimensions differ, or
+    /// GIF encoding fails.
+    pub fn from_frames(
+        frames: &[Rgb888Frame],
+        delay: Duration,
+    ) -> Result<Self, ImagePreparationError> {
+        encode_animation(frames, delay, None)
+    }
+
+    /// Re-encodes the animation with every frame shown for `delay`.
+    ///
+    /// Frame pixels and palettes are kept as they are. `delay` is rounded down
+    /// to GIF centiseconds and clamped to at least one, so `Duration::ZERO`
+    /// plays as fast as the format allows rather than stalling.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{GifAnimation, PanelDimensions, Rgb888Frame};
+    ///
+    /// let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid dimensions");
+    /// let red = Rgb888Frame::try_from((panel, vec![0xFF, 0x00, 0x00].repeat(4)))?;
+    /// let blue = Rgb888Frame::try_from((panel, vec![0x00, 0x00, 0xFF].repeat(4)))?;
+    /// let gif = GifAnimation::from_frames(&[red, blue], Duration::from_millis(200))?;
+    ///
+    /// let retimed = gif.retimed(Duration::from_millis(50))?;
+    /// assert_eq!(panel, retimed.dimensions());
+    /// assert_eq!(2, retimed.frame_count()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the frames cannot be decoded or re-encoded.
+    pub fn retimed(&self, delay: Duration) -> Result<Self, ImagePreparationError> {
+        retime_gif(self, delay)
+    }
+
     /// Returns the validated GIF bytes.
     ///
     /// ```
@@ -86,10 +149,48 @@ impl GifAnimation {
         self.payload
     }
 
-    fn parse_dimensions(payload: &[u8]) -> Result<PanelDimensions, GifAnimationError> {
+    /// Counts the frames in the GIF stream without decoding pixel data.
+    ///
+    /// ```
+    /// use idm::GifAnimation;
+    ///
+    /// let bytes = vec![
+    ///     0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00, 0x00, 0x00,
+    ///     0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02,
+    ///     0x44, 0x01, 0x00, 0x3B,
+    /// ];
+    /// let gif = GifAnimation::try_from(bytes)?;
+    /// assert_eq!(1, gif.frame_count()?);
+    /// # Ok::<(), idm::GifAnimationError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a frame descriptor in the stream fails to decode.
+    pub fn frame_count(&self) -> Result<usize, GifAnimationError> {
+        let mut reader = Self::decode_options()
+            .read_info(Cursor::new(self.payload.as_slice()))
+            .map_err(|source| GifAnimationError::InvalidGif { source })?;
+        let mut frame_count = 0;
+        while reader
+            .next_frame_info()
+            .map_err(|source| GifAnimationError::InvalidGif { source })?
+            .is_some()
+        {
+            frame_count += 1;
+        }
+        Ok(frame_count)
+    }
+
+    fn decode_options() -> gif::DecodeOptions {
         let mut options = gif::DecodeOptions::new();
         options.check_frame_consistency(true);
-        let reader = options
+        options
+    }
+
+    fn parse_dimensions(payload: &[u8]) -> Result<PanelDimensions, GifAnimationError> {
+        let reader = Self::decode_options()
             .read_info(Cursor::new(payload))
             .map_err(|source| GifAnimationError::InvalidGif { source })?;
         let width = reader.width();
@@ -126,6 +227,7 @@ impl TryFrom<&[u8]> for GifAnimation {
 mod tests {
     use assert_matches::assert_matches;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     use super::*;
 
@@ -145,6 +247,13 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn frame_count_counts_single_frame() -> Result<(), GifAnimationError> {
+        let gif = GifAnimation::try_from(MINIMAL_GIF_1X1.to_vec())?;
+        assert_eq!(1, gif.frame_count()?);
+        Ok(())
+    }
+
     #[test]
     fn try_from_rejects_empty_payload() {
         let result = GifAnimation::try_from(Vec::new());
@@ -156,4 +265,37 @@ mod tests {
         let result = GifAnimation::try_from(vec![0x47, 0x49, 0x46]);
         assert_matches!(result, Err(GifAnimationError::InvalidGif { .. }));
     }
+
+    fn frame_delays(gif: &GifAnimation) -> Result<Vec<u16>, gif::DecodingError> {
+        let mut reader = GifAnimation::decode_options().read_info(Cursor::new(gif.payload()))?;
+        let mut delays = Vec::new();
+        while let Some(frame) = reader.next_frame_info()? {
+            delays.push(frame.delay);
+        }
+        Ok(delays)
+    }
+
+    #[rstest]
+    #[case::faster(Duration::from_millis(50), 5)]
+    #[case::rounds_down(Duration::from_millis(1239), 123)]
+    #[case::zero_uses_floor(Duration::ZERO, 1)]
+    fn retimed_rewrites_every_frame_delay(
+        #[case] delay: Duration,
+        #[case] expected_centiseconds: u16,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let red = Rgb888Frame::try_from((panel, [0xFF, 0x00, 0x00].repeat(4)))?;
+        let blue = Rgb888Frame::try_from((panel, [0x00, 0x00, 0xFF].repeat(4)))?;
+        let gif = GifAnimation::from_frames(&[red, blue], Duration::from_millis(200))?;
+
+        let retimed = gif.retimed(delay)?;
+
+        assert_eq!(vec![20, 20], frame_delays(&gif)?);
+        assert_eq!(
+            vec![expected_centiseconds, expected_centiseconds],
+            frame_delays(&retimed)?
+        );
+        assert_eq!(panel, retimed.dimensions());
+        Ok(())
+    }
 }
@@ -1,18 +1,153 @@
 use std::collections::{HashMap, HashSet};
 use std::io::Cursor;
+use std::num::NonZeroU16;
+use std::time::Duration;
 
+use bon::Builder;
+use clap::ValueEnum;
 use image::imageops::FilterType;
 use image::{DynamicImage, GenericImageView};
 use thiserror::Error;
 
 use crate::hw::PanelDimensions;
+use crate::{Rgb, Warning};
 
-use super::{GifAnimation, Rgb888Frame};
+use super::dither::{GIF_PALETTE_ORDERED_SPREAD, dither, dither_rgb888_to_rgb565};
+use super::{DitherMode, GifAnimation, Rgb888Frame};
 
 const MAX_GIF_FRAMES: usize = 64;
 const MIN_GIF_DELAY_CENTISECONDS: u16 = 1;
 const GIF_QUANTISATION_SPEED: i32 = 1;
 const MAX_GIF_PALETTE_COLOURS: usize = 256;
+const REDUCED_PALETTE_COLOURS: [usize; 4] = [128, 64, 32, 16];
+
+/// Default cap on the number of pixels a source image may declare.
+///
+/// One hundred megapixels comfortably covers camera photos while rejecting
+/// inputs whose decoded framebuffer alone would run to gigabytes.
+pub const DEFAULT_MAX_SOURCE_PIXELS: u64 = 100_000_000;
+
+/// How transparent source pixels are rendered on the opaque panel.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum TransparencyMode {
+    /// Blends each pixel over the padding colour by its alpha.
+    #[default]
+    FlattenToPadding,
+    /// Renders fully transparent pixels as black, the panel's "off" colour,
+    /// and shows every other pixel at full colour regardless of its alpha.
+    OffAsBlack,
+}
+
+/// How a source image is fitted to a panel of a different aspect ratio.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum ScalingMode {
+    /// Scales the long edge to the panel and pads the margins.
+    #[default]
+    Contain,
+    /// Scales the short edge to the panel and centre-crops the overflow.
+    Cover,
+    /// Scales each edge to the panel independently, ignoring aspect ratio.
+    Stretch,
+}
+
+/// Options controlling how source images are normalised for the panel.
+///
+/// ```
+/// use idm::{
+///     DEFAULT_MAX_SOURCE_PIXELS, DitherMode, ImagePreparationOptions, Rgb, ScalingMode,
+///     TransparencyMode,
+/// };
+///
+/// let options = ImagePreparationOptions::builder()
+///     .transparency(TransparencyMode::OffAsBlack)
+///     .scaling(ScalingMode::Cover)
+///     .build();
+/// assert_eq!(DEFAULT_MAX_SOURCE_PIXELS, options.max_source_pixels());
+/// assert!(!options.dedupe_frames());
+/// assert_eq!(TransparencyMode::OffAsBlack, options.transparency());
+/// assert_eq!(None, options.target_max_bytes());
+/// assert_eq!(DitherMode::None, options.dither());
+/// assert_eq!(ScalingMode::Cover, options.scaling());
+/// assert_eq!(Rgb::new(0, 0, 0), options.pad_colour());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Builder)]
+pub struct ImagePreparationOptions {
+    /// Sources whose header declares more pixels than this are rejected
+    /// before any pixel data is decoded.
+    #[builder(default = DEFAULT_MAX_SOURCE_PIXELS)]
+    max_source_pixels: u64,
+    /// Merges runs of pixel-identical consecutive GIF frames into one frame
+    /// whose delay is the sum of the run.
+    #[builder(default)]
+    dedupe_frames: bool,
+    /// How transparent pixels in still sources are rendered.
+    #[builder(default)]
+    transparency: TransparencyMode,
+    /// Largest accepted GIF payload, in bytes. Larger encodes are reduced,
+    /// see [`ImagePreprocessor::prepare_for_upload`].
+    target_max_bytes: Option<usize>,
+    /// How colours are approximated after resizing, see [`DitherMode`].
+    #[builder(default)]
+    dither: DitherMode,
+    /// How sources are fitted to the panel's aspect ratio.
+    #[builder(default)]
+    scaling: ScalingMode,
+    /// Colour of the margins left by [`ScalingMode::Contain`], which
+    /// transparent pixels are also flattened against. GIF sources start
+    /// from, and dispose frames to, this colour as well.
+    #[builder(default = Rgb::new(0x00, 0x00, 0x00))]
+    pad_colour: Rgb,
+}
+
+impl Default for ImagePreparationOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl ImagePreparationOptions {
+    /// Returns the largest source image, in pixels, accepted before decoding.
+    #[must_use]
+    pub fn max_source_pixels(&self) -> u64 {
+        self.max_source_pixels
+    }
+
+    /// Returns whether identical consecutive GIF frames are merged.
+    #[must_use]
+    pub fn dedupe_frames(&self) -> bool {
+        self.dedupe_frames
+    }
+
+    /// Returns how transparent pixels in still sources are rendered.
+    #[must_use]
+    pub fn transparency(&self) -> TransparencyMode {
+        self.transparency
+    }
+
+    /// Returns the largest accepted GIF payload, if one is set.
+    #[must_use]
+    pub fn target_max_bytes(&self) -> Option<usize> {
+        self.target_max_bytes
+    }
+
+    /// Returns how colours are approximated after resizing.
+    #[must_use]
+    pub fn dither(&self) -> DitherMode {
+        self.dither
+    }
+
+    /// Returns how sources are fitted to the panel's aspect ratio.
+    #[must_use]
+    pub fn scaling(&self) -> ScalingMode {
+        self.scaling
+    }
+
+    /// Returns the colour of the padding margins.
+    #[must_use]
+    pub fn pad_colour(&self) -> Rgb {
+        self.pad_colour
+    }
+}
 
 /// Errors returned when preparing an image for panel upload.
 #[derive(Debug, Error)]
@@ -20,6 +155,16 @@ pub enum ImagePreparationError {
     /// The source bytes are not a supported image format.
     #[error("failed to detect image format from source bytes")]
     UnknownFormat(#[source] image::ImageError),
+    /// Reading the source image header failed.
+    #[error("failed to read source image header")]
+    SourceRead(#[from] std::io::Error),
+    /// The source image declares more pixels than the configured cap.
+    #[error("source image is {width}x{height}, exceeding the limit of {max_pixels} pixels")]
+    SourceTooLarge {
+        width: u32,
+        height: u32,
+        max_pixels: u64,
+    },
     /// The source image failed to decode.
     #[error("failed to decode source image")]
     Decode(#[source] image::ImageError),
@@ -32,6 +177,24 @@ pub enum ImagePreparationError {
     /// The GIF stream does not contain any frames.
     #[error("gif payload contains no frames")]
     GifHasNoFrames,
+    /// No reduction brought the GIF payload within the byte target.
+    #[error(
+        "gif payload cannot be reduced to {target_max_bytes} bytes; the smallest encoding is {smallest_len} bytes"
+    )]
+    TargetSizeUnreachable {
+        target_max_bytes: usize,
+        smallest_len: usize,
+    },
+    /// An animation was requested without any frames.
+    #[error("animation requires at least one frame")]
+    NoAnimationFrames,
+    /// Animation frames do not share the same panel dimensions.
+    #[error("animation frame {index} is {actual}, expected {expected}")]
+    AnimationFrameDimensionsMismatch {
+        index: usize,
+        expected: PanelDimensions,
+        actual: PanelDimensions,
+    },
     /// Transformed GIF payload failed GIF validation.
     #[error(transparent)]
     GifPayload(#[from] crate::GifAnimationError),
@@ -91,119 +254,634 @@ impl PreparedImageUpload {
 pub struct ImagePreprocessor;
 
 impl ImagePreprocessor {
-    /// Decodes, orients, resizes, and pads source bytes to panel geometry.
+    /// Decodes, orients, and scales source bytes to panel geometry.
     ///
     /// Static images are transformed into one RGB888 frame. GIF files are
     /// transformed frame-by-frame and re-encoded as GIF before upload.
+    /// See [`ImagePreparationOptions`] for the source size cap, GIF frame
+    /// deduplication, transparency handling, dithering, scaling, and padding.
+    ///
+    /// When a byte target is set and the encoded GIF exceeds it, the GIF is
+    /// reduced in this order until it fits:
+    ///
+    /// 1. The shared palette is shrunk from 256 colours to 128, 64, 32, then
+    ///    16 colours, keeping every frame.
+    /// 2. Every other remaining frame is dropped, folding the dropped frames'
+    ///    delays into the kept ones so playback length is unchanged, and the
+    ///    palette steps are tried again from 256 colours.
+    ///
+    /// A single frame with 16 colours is the floor; if that still exceeds
+    /// the target, preparation fails.
     ///
     /// # Errors
     ///
-    /// Returns an error when format detection, decode, transformation, encode,
-    /// or payload validation fails.
+    /// Returns an error when the source is too large, when format
+    /// detection, decode, transformation, encode, or payload validation
+    /// fails, or when the GIF cannot be reduced to the byte target.
     pub fn prepare_for_upload(
         source_bytes: &[u8],
         panel_dimensions: PanelDimensions,
+        options: ImagePreparationOptions,
     ) -> Result<PreparedImageUpload, ImagePreparationError> {
+        Self::prepare_for_upload_with_warnings(source_bytes, panel_dimensions, options)
+            .map(|(prepared, _warnings)| prepared)
+    }
+
+    /// Prepares source bytes like [`prepare_for_upload`](Self::prepare_for_upload)
+    /// and also returns the warnings raised along the way.
+    ///
+    /// A source GIF with more frames than the upload limit is truncated to
+    /// its leading frames and reported as [`Warning::GifFramesTruncated`].
+    ///
+    /// ```
+    /// use idm::{ImagePreparationOptions, ImagePreprocessor, PanelDimensions};
+    ///
+    /// # fn demo(source: &[u8]) -> Result<(), idm::ImagePreparationError> {
+    /// let panel = PanelDimensions::new(32, 32).expect("32x32 is valid");
+    /// let (_prepared, warnings) = ImagePreprocessor::prepare_for_upload_with_warnings(
+    ///     source,
+    ///     panel,
+    ///     ImagePreparationOptions::default(),
+    /// )?;
+    /// for warning in warnings {
+    ///     eprintln!("{warning}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`prepare_for_upload`](Self::prepare_for_upload).
+    pub fn prepare_for_upload_with_warnings(
+        source_bytes: &[u8],
+        panel_dimensions: PanelDimensions,
+        options: ImagePreparationOptions,
+    ) -> Result<(PreparedImageUpload, Vec<Warning>), ImagePreparationError> {
         let source_format =
             image::guess_format(source_bytes).map_err(ImagePreparationError::UnknownFormat)?;
+        check_source_dimensions(source_bytes, options.max_source_pixels())?;
         match source_format {
             image::ImageFormat::Gif => {
-                let gif = Self::prepare_gif(source_bytes, panel_dimensions)?;
-                Ok(PreparedImageUpload::Gif(gif))
+                let (gif, warnings) =
+                    Self::prepare_gif_with_warnings(source_bytes, panel_dimensions, options)?;
+                Ok((PreparedImageUpload::Gif(gif), warnings))
             }
             _other => {
-                let still = Self::prepare_still(source_bytes, panel_dimensions, source_format)?;
-                Ok(PreparedImageUpload::Still(still))
+                let still =
+                    Self::prepare_still(source_bytes, panel_dimensions, source_format, options)?;
+                Ok((PreparedImageUpload::Still(still), Vec::new()))
             }
         }
     }
 
+    /// Decodes only the first frame of a GIF and prepares it as a still image.
+    ///
+    /// The frame is composited, oriented, resized, and padded exactly as it
+    /// would be for the animated upload, so the preview matches the opening
+    /// frame of the animation. Non-GIF sources are prepared as ordinary stills.
+    /// Sources declaring more than the options' pixel cap are rejected before
+    /// decoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the source is too large, when format detection,
+    /// decode, transformation, or framebuffer validation fails, or when the
+    /// GIF has no frames.
+    pub fn prepare_first_frame(
+        source_bytes: &[u8],
+        panel_dimensions: PanelDimensions,
+        options: ImagePreparationOptions,
+    ) -> Result<PreparedStillImage, ImagePreparationError> {
+        let source_format =
+            image::guess_format(source_bytes).map_err(ImagePreparationError::UnknownFormat)?;
+        check_source_dimensions(source_bytes, options.max_source_pixels())?;
+        if source_format != image::ImageFormat::Gif {
+            return Self::prepare_still(source_bytes, panel_dimensions, source_format, options);
+        }
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::Indexed);
+        let mut reader = decoder
+            .read_info(Cursor::new(source_bytes))
+            .map_err(|source| ImagePreparationError::GifDecode { source })?;
+        let global_palette = reader.global_palette().map(ToOwned::to_owned);
+        let mut canvas = image::RgbaImage::from_pixel(
+            u32::from(reader.width()),
+            u32::from(reader.height()),
+            opaque(options.pad_colour()),
+        );
+        let frame = reader
+            .read_next_frame()
+            .map_err(|source| ImagePreparationError::GifDecode { source })?
+            .ok_or(ImagePreparationError::GifHasNoFrames)?;
+        composite_indexed_frame(&mut canvas, frame, global_palette.as_deref());
+
+        let oriented = apply_orientation(
+            DynamicImage::ImageRgba8(canvas),
+            exif_orientation(source_bytes),
+        );
+        let padded = DynamicImage::ImageRgba8(resize_and_pad_rgba(
+            oriented,
+            panel_dimensions,
+            options.scaling(),
+            options.pad_colour(),
+        ))
+        .to_rgb8();
+        let dithered = dither_rgb888_to_rgb565(
+            padded.into_raw(),
+            usize::from(panel_dimensions.width()),
+            options.dither(),
+        );
+        let frame = Rgb888Frame::try_from((panel_dimensions, dithered))?;
+        Ok(PreparedStillImage {
+            source_format,
+            frame,
+        })
+    }
+
+    /// Prepares a wide image as a horizontally scrolling GIF.
+    ///
+    /// The source is scaled so its height fits the panel while keeping its
+    /// aspect ratio, then a panel-wide window pans from the left edge to the
+    /// right edge in `step`-pixel increments, one frame per position. A pan
+    /// distance of `d` pixels yields `d / step + 1` frames. When that would
+    /// exceed the 64-frame GIF limit, the step grows to `ceil(d / 63)` so the
+    /// pan still reaches the right edge. Sources that are no wider than the
+    /// panel produce a single padded frame. GIF sources
+    /// contribute their first frame. Sources declaring more than the options'
+    /// pixel cap are rejected before decoding.
+    ///
+    /// The options' byte target is applied as described for
+    /// [`prepare_for_upload`](Self::prepare_for_upload).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the source is too large, when format
+    /// detection, decode, framebuffer validation, or GIF encoding fails, or
+    /// when the animation cannot be reduced to the byte target.
+    pub fn prepare_scroll(
+        source_bytes: &[u8],
+        panel_dimensions: PanelDimensions,
+        step: NonZeroU16,
+        delay: Duration,
+        options: ImagePreparationOptions,
+    ) -> Result<GifAnimation, ImagePreparationError> {
+        check_source_dimensions(source_bytes, options.max_source_pixels())?;
+        let decoded =
+            image::load_from_memory(source_bytes).map_err(ImagePreparationError::Decode)?;
+        let oriented = apply_transparency(
+            apply_orientation(decoded, exif_orientation(source_bytes)),
+            options.transparency(),
+        );
+        let panel_width = u32::from(panel_dimensions.width());
+        let panel_height = u32::from(panel_dimensions.height());
+        let (source_width, source_height) = oriented.dimensions();
+        let scaled_width = u32::try_from(
+            u64::from(source_width) * u64::from(panel_height) / u64::from(source_height),
+        )
+        .unwrap_or(u32::MAX)
+        .max(1);
+
+        if scaled_width <= panel_width {
+            let padded = DynamicImage::ImageRgba8(resize_and_pad_rgba(
+                oriented,
+                panel_dimensions,
+                options.scaling(),
+                options.pad_colour(),
+            ))
+            .to_rgb8();
+            let frame = Rgb888Frame::try_from((panel_dimensions, padded.into_raw()))?;
+            return encode_animation(&[frame], delay, options.target_max_bytes());
+        }
+
+        let strip = oriented
+            .resize_exact(scaled_width, panel_height, FilterType::Lanczos3)
+            .to_rgb8();
+        let pan_distance = scaled_width - panel_width;
+        let max_pan_steps = u32::try_from(MAX_GIF_FRAMES - 1).unwrap_or(u32::MAX);
+        let step = u32::from(step.get()).max(pan_distance.div_ceil(max_pan_steps));
+        let frames = (0..=pan_distance)
+            .step_by(usize::try_from(step).unwrap_or(usize::MAX))
+            .map(|offset| {
+                let window =
+                    image::imageops::crop_imm(&strip, offset, 0, panel_width, panel_height)
+                        .to_image();
+                Rgb888Frame::try_from((panel_dimensions, window.into_raw()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        encode_animation(&frames, delay, options.target_max_bytes())
+    }
+
     fn prepare_still(
         source_bytes: &[u8],
         panel_dimensions: PanelDimensions,
         source_format: image::ImageFormat,
+        options: ImagePreparationOptions,
     ) -> Result<PreparedStillImage, ImagePreparationError> {
         let decoded = image::load_from_memory_with_format(source_bytes, source_format)
             .map_err(ImagePreparationError::Decode)?;
-        let oriented = apply_orientation(decoded, exif_orientation(source_bytes));
-        let padded =
-            DynamicImage::ImageRgba8(resize_and_pad_rgba(oriented, panel_dimensions)).to_rgb8();
-        let frame = Rgb888Frame::try_from((panel_dimensions, padded.into_raw()))?;
+        let oriented = apply_transparency(
+            apply_orientation(decoded, exif_orientation(source_bytes)),
+            options.transparency(),
+        );
+        let padded = DynamicImage::ImageRgba8(resize_and_pad_rgba(
+            oriented,
+            panel_dimensions,
+            options.scaling(),
+            options.pad_colour(),
+        ))
+        .to_rgb8();
+        let dithered = dither_rgb888_to_rgb565(
+            padded.into_raw(),
+            usize::from(panel_dimensions.width()),
+            options.dither(),
+        );
+        let frame = Rgb888Frame::try_from((panel_dimensions, dithered))?;
         Ok(PreparedStillImage {
             source_format,
             frame,
         })
     }
 
-    fn prepare_gif(
+    pub(crate) fn prepare_gif(
         source_bytes: &[u8],
         panel_dimensions: PanelDimensions,
+        options: ImagePreparationOptions,
     ) -> Result<GifAnimation, ImagePreparationError> {
+        Self::prepare_gif_with_warnings(source_bytes, panel_dimensions, options)
+            .map(|(gif, _warnings)| gif)
+    }
+
+    /// Composites, orients, and fits each GIF frame to the panel without
+    /// encoding the result.
+    ///
+    /// The frames are the ones [`prepare_for_upload`](Self::prepare_for_upload)
+    /// would encode for a resized GIF, before palette reduction, so external
+    /// encoders and previews see full-colour pixels. Deduplication and the
+    /// frame cap apply as they do for the upload.
+    ///
+    /// ```
+    /// use idm::{ImagePreparationOptions, ImagePreprocessor, PanelDimensions};
+    ///
+    /// # fn demo(source: &[u8]) -> Result<(), idm::ImagePreparationError> {
+    /// let panel = PanelDimensions::new(32, 32).expect("32x32 is valid");
+    /// let frames = ImagePreprocessor::prepare_gif_frames(
+    ///     source,
+    ///     panel,
+    ///     ImagePreparationOptions::default(),
+    /// )?;
+    /// for frame in &frames {
+    ///     assert_eq!(32 * 32 * 4, frame.rgba_pixels().len());
+    ///     println!("{} cs", frame.delay_centiseconds());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the source is too large, is not a decodable GIF,
+    /// or a frame cannot be transformed.
+    pub fn prepare_gif_frames(
+        source_bytes: &[u8],
+        panel_dimensions: PanelDimensions,
+        options: ImagePreparationOptions,
+    ) -> Result<Vec<PreparedGifFrame>, ImagePreparationError> {
+        check_source_dimensions(source_bytes, options.max_source_pixels())?;
+        transform_gif_frames(source_bytes, panel_dimensions, options)
+            .map(|(frames, _warnings)| frames)
+    }
+
+    fn prepare_gif_with_warnings(
+        source_bytes: &[u8],
+        panel_dimensions: PanelDimensions,
+        options: ImagePreparationOptions,
+    ) -> Result<(GifAnimation, Vec<Warning>), ImagePreparationError> {
         let source_gif = GifAnimation::try_from(source_bytes)?;
-        if source_gif.dimensions() == panel_dimensions {
-            return Ok(source_gif);
+        let within_target = options
+            .target_max_bytes()
+            .is_none_or(|target| source_gif.payload().len() <= target);
+        if source_gif.dimensions() == panel_dimensions && !options.dedupe_frames() && within_target
+        {
+            return Ok((source_gif, Vec::new()));
         }
 
-        let mut decoder = gif::DecodeOptions::new();
-        decoder.set_color_output(gif::ColorOutput::Indexed);
-        let mut reader = decoder
-            .read_info(Cursor::new(source_bytes))
-            .map_err(|source| ImagePreparationError::GifDecode { source })?;
-        let global_palette = reader.global_palette().map(ToOwned::to_owned);
-        let source_width = u32::from(reader.width());
-        let source_height = u32::from(reader.height());
-        let mut composite_canvas = image::RgbaImage::from_pixel(
-            source_width,
-            source_height,
-            image::Rgba([0x00, 0x00, 0x00, 0xFF]),
+        let (transformed_frames, warnings) =
+            transform_gif_frames(source_bytes, panel_dimensions, options)?;
+        let gif = encode_within_target(
+            panel_dimensions.width(),
+            panel_dimensions.height(),
+            &transformed_frames,
+            options.target_max_bytes(),
+            options.dither(),
+        )?;
+        Ok((gif, warnings))
+    }
+}
+
+/// Composites, orients, and fits each GIF frame to the panel.
+///
+/// Frames past [`MAX_GIF_FRAMES`] are dropped and reported as
+/// [`Warning::GifFramesTruncated`].
+fn transform_gif_frames(
+    source_bytes: &[u8],
+    panel_dimensions: PanelDimensions,
+    options: ImagePreparationOptions,
+) -> Result<(Vec<PreparedGifFrame>, Vec<Warning>), ImagePreparationError> {
+    let dedupe_frames = options.dedupe_frames();
+    let mut decoder = gif::DecodeOptions::new();
+    decoder.set_color_output(gif::ColorOutput::Indexed);
+    let mut reader = decoder
+        .read_info(Cursor::new(source_bytes))
+        .map_err(|source| ImagePreparationError::GifDecode { source })?;
+    let global_palette = reader.global_palette().map(ToOwned::to_owned);
+    let source_width = u32::from(reader.width());
+    let source_height = u32::from(reader.height());
+    let pad_colour = opaque(options.pad_colour());
+    let mut composite_canvas =
+        image::RgbaImage::from_pixel(source_width, source_height, pad_colour);
+    let orientation = exif_orientation(source_bytes);
+    let mut transformed_frames: Vec<PreparedGifFrame> = Vec::new();
+
+    while transformed_frames.len() < MAX_GIF_FRAMES {
+        let Some(frame) = reader
+            .read_next_frame()
+            .map_err(|source| ImagePreparationError::GifDecode { source })?
+        else {
+            break;
+        };
+        composite_indexed_frame(&mut composite_canvas, frame, global_palette.as_deref());
+        let dynamic = DynamicImage::ImageRgba8(composite_canvas.clone());
+        let oriented = apply_orientation(dynamic, orientation);
+        let padded = resize_and_pad_rgba(
+            oriented,
+            panel_dimensions,
+            options.scaling(),
+            options.pad_colour(),
         );
-        let orientation = exif_orientation(source_bytes);
-        let panel_width = panel_dimensions.width();
-        let panel_height = panel_dimensions.height();
-        let mut transformed_frames = Vec::new();
-
-        while transformed_frames.len() < MAX_GIF_FRAMES {
-            let Some(frame) = reader
-                .read_next_frame()
-                .map_err(|source| ImagePreparationError::GifDecode { source })?
-            else {
-                break;
-            };
-            composite_indexed_frame(&mut composite_canvas, frame, global_palette.as_deref());
-            let dynamic = DynamicImage::ImageRgba8(composite_canvas.clone());
-            let oriented = apply_orientation(dynamic, orientation);
-            let padded = resize_and_pad_rgba(oriented, panel_dimensions);
-            transformed_frames.push(PreparedGifFrame {
-                rgba_pixels: padded.into_raw(),
-                delay_centiseconds: frame.delay.max(MIN_GIF_DELAY_CENTISECONDS),
-            });
+        let prepared = PreparedGifFrame {
+            rgba_pixels: padded.into_raw(),
+            delay_centiseconds: frame.delay.max(MIN_GIF_DELAY_CENTISECONDS),
+        };
+        match transformed_frames.last_mut() {
+            Some(previous) if dedupe_frames && previous.rgba_pixels == prepared.rgba_pixels => {
+                previous.delay_centiseconds = previous
+                    .delay_centiseconds
+                    .saturating_add(prepared.delay_centiseconds);
+            }
+            _ => transformed_frames.push(prepared),
+        }
 
-            if frame.dispose == gif::DisposalMethod::Background {
-                clear_rect(
-                    &mut composite_canvas,
-                    u32::from(frame.left),
-                    u32::from(frame.top),
-                    u32::from(frame.width),
-                    u32::from(frame.height),
+        if frame.dispose == gif::DisposalMethod::Background {
+            clear_rect(
+                &mut composite_canvas,
+                u32::from(frame.left),
+                u32::from(frame.top),
+                u32::from(frame.width),
+                u32::from(frame.height),
+                pad_colour,
+            );
+        }
+    }
+    if transformed_frames.is_empty() {
+        return Err(ImagePreparationError::GifHasNoFrames);
+    }
+
+    let mut dropped = 0usize;
+    while reader
+        .read_next_frame()
+        .map_err(|source| ImagePreparationError::GifDecode { source })?
+        .is_some()
+    {
+        dropped += 1;
+    }
+    let mut warnings = Vec::new();
+    if dropped > 0 {
+        warnings.push(Warning::GifFramesTruncated { dropped });
+    }
+    Ok((transformed_frames, warnings))
+}
+
+/// Rejects sources whose header declares more than `max_source_pixels` pixels.
+///
+/// Only the format header is read, so oversized inputs fail before their
+/// pixel data is decoded.
+fn check_source_dimensions(
+    source_bytes: &[u8],
+    max_source_pixels: u64,
+) -> Result<(), ImagePreparationError> {
+    let mut reader = image::ImageReader::new(Cursor::new(source_bytes)).with_guessed_format()?;
+    reader.no_limits();
+    let (width, height) = reader
+        .into_dimensions()
+        .map_err(ImagePreparationError::Decode)?;
+    if u64::from(width) * u64::from(height) > max_source_pixels {
+        return Err(ImagePreparationError::SourceTooLarge {
+            width,
+            height,
+            max_pixels: max_source_pixels,
+        });
+    }
+    Ok(())
+}
+
+/// Encodes panel-sized RGB888 frames into one looping GIF with a fixed delay.
+pub(crate) fn encode_animation(
+    frames: &[Rgb888Frame],
+    delay: Duration,
+    target_max_bytes: Option<usize>,
+) -> Result<GifAnimation, ImagePreparationError> {
+    let Some(first) = frames.first() else {
+        return Err(ImagePreparationError::NoAnimationFrames);
+    };
+    let expected = first.dimensions();
+    if let Some((index, frame)) = frames
+        .iter()
+        .enumerate()
+        .find(|(_index, frame)| frame.dimensions() != expected)
+    {
+        return Err(ImagePreparationError::AnimationFrameDimensionsMismatch {
+            index,
+            expected,
+            actual: frame.dimensions(),
+        });
+    }
+
+    let delay_centiseconds = gif_delay_centiseconds(delay);
+    let prepared_frames = frames
+        .iter()
+        .map(|frame| PreparedGifFrame {
+            rgba_pixels: frame
+                .payload()
+                .chunks_exact(3)
+                .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 0xFF])
+                .collect(),
+            delay_centiseconds,
+        })
+        .collect::<Vec<_>>();
+
+    encode_within_target(
+        expected.width(),
+        expected.height(),
+        &prepared_frames,
+        target_max_bytes,
+        DitherMode::None,
+    )
+}
+
+/// Re-encodes `gif` with every frame shown for `delay`.
+///
+/// Frames are copied as decoded, palette indices included, so only their
+/// timing changes. `delay` is rounded down to GIF centiseconds and clamped to
+/// at least one.
+pub(crate) fn retime_gif(
+    gif: &GifAnimation,
+    delay: Duration,
+) -> Result<GifAnimation, ImagePreparationError> {
+    let delay_centiseconds = gif_delay_centiseconds(delay);
+    let mut decoder = gif::DecodeOptions::new();
+    decoder.set_color_output(gif::ColorOutput::Indexed);
+    let mut reader = decoder
+        .read_info(Cursor::new(gif.payload()))
+        .map_err(|source| ImagePreparationError::GifDecode { source })?;
+    let global_palette = reader.global_palette().unwrap_or_default().to_vec();
+    let mut retimed_payload = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(
+            &mut retimed_payload,
+            reader.width(),
+            reader.height(),
+            &global_palette,
+        )
+        .map_err(|source| ImagePreparationError::GifEncode { source })?;
+        encoder
+            .set_repeat(reader.repeat())
+            .map_err(|source| ImagePreparationError::GifEncode { source })?;
+
+        while let Some(frame) = reader
+            .read_next_frame()
+            .map_err(|source| ImagePreparationError::GifDecode { source })?
+        {
+            let mut retimed_frame = frame.clone();
+            retimed_frame.delay = delay_centiseconds;
+            encoder
+                .write_frame(&retimed_frame)
+                .map_err(|source| ImagePreparationError::GifEncode { source })?;
+        }
+    }
+    Ok(GifAnimation::try_from(strip_empty_global_palette(
+        retimed_payload,
+    ))?)
+}
+
+/// Converts `delay` to GIF centiseconds, rounding down and clamping to
+/// [`MIN_GIF_DELAY_CENTISECONDS`].
+fn gif_delay_centiseconds(delay: Duration) -> u16 {
+    u16::try_from(delay.as_millis() / 10)
+        .unwrap_or(u16::MAX)
+        .max(MIN_GIF_DELAY_CENTISECONDS)
+}
+
+/// Encodes `frames`, shrinking the palette and then the frame count until the
+/// payload fits `target_max_bytes`.
+///
+/// Palette reduction always comes first: every palette step from 256 down to
+/// 16 colours is tried with all frames kept, and frames are only dropped once
+/// even the 16-colour encoding exceeds the target. Each halving of the frame
+/// count restarts the palette steps from 256 colours. The public contract is
+/// on [`ImagePreprocessor::prepare_for_upload`].
+fn encode_within_target(
+    panel_width: u16,
+    panel_height: u16,
+    frames: &[PreparedGifFrame],
+    target_max_bytes: Option<usize>,
+    dither: DitherMode,
+) -> Result<GifAnimation, ImagePreparationError> {
+    let encode = |frames: &[PreparedGifFrame], max_colours: usize| {
+        encode_gif_frames_with_shared_palette(
+            panel_width,
+            panel_height,
+            frames,
+            max_colours,
+            dither,
+        )
+        .map(strip_empty_global_palette)
+    };
+    let Some(target_max_bytes) = target_max_bytes else {
+        return Ok(GifAnimation::try_from(encode(
+            frames,
+            MAX_GIF_PALETTE_COLOURS,
+        )?)?);
+    };
+
+    let mut smallest_len = usize::MAX;
+    let mut stride = 1;
+    loop {
+        let subsampled = subsample_frames(frames, stride);
+        for max_colours in std::iter::once(MAX_GIF_PALETTE_COLOURS).chain(REDUCED_PALETTE_COLOURS) {
+            let payload = encode(&subsampled, max_colours)?;
+            if payload.len() <= target_max_bytes {
+                tracing::debug!(
+                    frames = subsampled.len(),
+                    source_frames = frames.len(),
+                    max_colours,
+                    payload_len = payload.len(),
+                    "reduced gif payload to fit byte target"
                 );
+                return Ok(GifAnimation::try_from(payload)?);
             }
+            smallest_len = smallest_len.min(payload.len());
         }
-        if transformed_frames.is_empty() {
-            return Err(ImagePreparationError::GifHasNoFrames);
+        if subsampled.len() <= 1 {
+            return Err(ImagePreparationError::TargetSizeUnreachable {
+                target_max_bytes,
+                smallest_len,
+            });
         }
-
-        let transformed_payload =
-            encode_gif_frames_with_shared_palette(panel_width, panel_height, &transformed_frames)?;
-        let transformed_payload = strip_empty_global_palette(transformed_payload);
-        Ok(GifAnimation::try_from(transformed_payload)?)
+        stride *= 2;
     }
 }
 
-struct PreparedGifFrame {
+/// Keeps every `stride`-th frame, adding each dropped frame's delay to the
+/// kept frame before it.
+fn subsample_frames(frames: &[PreparedGifFrame], stride: usize) -> Vec<PreparedGifFrame> {
+    frames
+        .chunks(stride)
+        .map(|group| PreparedGifFrame {
+            rgba_pixels: group[0].rgba_pixels.clone(),
+            delay_centiseconds: group.iter().fold(0_u16, |total, frame| {
+                total.saturating_add(frame.delay_centiseconds)
+            }),
+        })
+        .collect()
+}
+
+/// One GIF frame fitted to the panel, before palette reduction.
+///
+/// Returned by [`ImagePreprocessor::prepare_gif_frames`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PreparedGifFrame {
     rgba_pixels: Vec<u8>,
     delay_centiseconds: u16,
 }
 
+impl PreparedGifFrame {
+    /// Returns the frame's opaque RGBA pixels, row-major at panel size.
+    #[must_use]
+    pub fn rgba_pixels(&self) -> &[u8] {
+        &self.rgba_pixels
+    }
+
+    /// Returns how long the frame is shown, in GIF centiseconds.
+    ///
+    /// Merged duplicate frames carry the sum of their delays.
+    #[must_use]
+    pub fn delay_centiseconds(&self) -> u16 {
+        self.delay_centiseconds
+    }
+}
+
 enum SharedGifPaletteIndexer {
     Exact(HashMap<[u8; 4], u8>),
     Quantised(color_quant::NeuQuant),
@@ -215,13 +893,13 @@ struct SharedGifPalette {
 }
 
 impl SharedGifPalette {
-    fn build(frames: &[PreparedGifFrame]) -> Self {
+    fn build(frames: &[PreparedGifFrame], max_colours: usize) -> Self {
         let mut unique_colours = HashSet::new();
         for frame in frames {
             for rgba_pixel in frame.rgba_pixels.chunks_exact(4) {
                 unique_colours.insert([rgba_pixel[0], rgba_pixel[1], rgba_pixel[2], rgba_pixel[3]]);
-                if unique_colours.len() > MAX_GIF_PALETTE_COLOURS {
-                    return Self::build_quantised(frames);
+                if unique_colours.len() > max_colours {
+                    return Self::build_quantised(frames, max_colours);
                 }
             }
         }
@@ -245,18 +923,15 @@ impl SharedGifPalette {
         }
     }
 
-    fn build_quantised(frames: &[PreparedGifFrame]) -> Self {
+    fn build_quantised(frames: &[PreparedGifFrame], max_colours: usize) -> Self {
         let sample_size = frames.iter().map(|frame| frame.rgba_pixels.len()).sum();
         let mut sampled_pixels = Vec::with_capacity(sample_size);
         for frame in frames {
             sampled_pixels.extend_from_slice(&frame.rgba_pixels);
         }
 
-        let quantiser = color_quant::NeuQuant::new(
-            GIF_QUANTISATION_SPEED,
-            MAX_GIF_PALETTE_COLOURS,
-            &sampled_pixels,
-        );
+        let quantiser =
+            color_quant::NeuQuant::new(GIF_QUANTISATION_SPEED, max_colours, &sampled_pixels);
         let palette_bytes = quantiser.color_map_rgb();
         Self {
             palette_bytes,
@@ -268,7 +943,46 @@ impl SharedGifPalette {
         &self.palette_bytes
     }
 
-    fn index_pixels(&self, rgba_pixels: &[u8]) -> Vec<u8> {
+    /// Maps `rgba_pixels` onto palette indices, dithering per `mode`.
+    ///
+    /// Only quantised palettes are dithered; an exact palette already holds
+    /// every colour. While dithering, a pixel unchanged from `previous`
+    /// keeps its previous index, so diffused error cannot make static areas
+    /// shimmer from frame to frame.
+    fn index_pixels(
+        &self,
+        rgba_pixels: &[u8],
+        width: usize,
+        mode: DitherMode,
+        previous: Option<(&[u8], &[u8])>,
+    ) -> Vec<u8> {
+        if let (
+            SharedGifPaletteIndexer::Quantised(quantiser),
+            DitherMode::FloydSteinberg | DitherMode::Ordered,
+        ) = (&self.indexer, mode)
+        {
+            return dither(
+                mode,
+                rgba_pixels,
+                4,
+                width,
+                GIF_PALETTE_ORDERED_SPREAD,
+                |pixel, [r, g, b]| {
+                    let offset = pixel * 4;
+                    let rgba_pixel = &rgba_pixels[offset..offset + 4];
+                    let index = match previous {
+                        Some((previous_rgba, previous_indices))
+                            if previous_rgba.get(offset..offset + 4) == Some(rgba_pixel) =>
+                        {
+                            previous_indices[pixel]
+                        }
+                        _ => quantiser.index_of(&[r, g, b, rgba_pixel[3]]) as u8,
+                    };
+                    (index, self.colour_of(index))
+                },
+            );
+        }
+
         match &self.indexer {
             SharedGifPaletteIndexer::Exact(lookup) => rgba_pixels
                 .chunks_exact(4)
@@ -283,14 +997,24 @@ impl SharedGifPalette {
                 .collect(),
         }
     }
+
+    fn colour_of(&self, index: u8) -> [u8; 3] {
+        let offset = usize::from(index) * 3;
+        self.palette_bytes
+            .get(offset..offset + 3)
+            .and_then(|rgb| rgb.try_into().ok())
+            .unwrap_or_default()
+    }
 }
 
 fn encode_gif_frames_with_shared_palette(
     panel_width: u16,
     panel_height: u16,
     frames: &[PreparedGifFrame],
+    max_colours: usize,
+    dither: DitherMode,
 ) -> Result<Vec<u8>, ImagePreparationError> {
-    let shared_palette = SharedGifPalette::build(frames);
+    let shared_palette = SharedGifPalette::build(frames, max_colours);
     let frame_palette = shared_palette.palette_bytes().to_vec();
     let mut transformed_payload = Vec::new();
     {
@@ -301,12 +1025,20 @@ fn encode_gif_frames_with_shared_palette(
             .set_repeat(gif::Repeat::Infinite)
             .map_err(|source| ImagePreparationError::GifEncode { source })?;
 
+        let mut previous: Option<(&[u8], Vec<u8>)> = None;
         for frame in frames {
-            let indexed_pixels = shared_palette.index_pixels(&frame.rgba_pixels);
+            let indexed_pixels = shared_palette.index_pixels(
+                &frame.rgba_pixels,
+                usize::from(panel_width),
+                dither,
+                previous
+                    .as_ref()
+                    .map(|(rgba_pixels, indices)| (*rgba_pixels, indices.as_slice())),
+            );
             let mut encoded_frame = gif::Frame::from_palette_pixels(
                 panel_width,
                 panel_height,
-                indexed_pixels,
+                indexed_pixels.clone(),
                 frame_palette.clone(),
                 None,
             );
@@ -315,6 +1047,7 @@ fn encode_gif_frames_with_shared_palette(
             encoder
                 .write_frame(&encoded_frame)
                 .map_err(|source| ImagePreparationError::GifEncode { source })?;
+            previous = Some((frame.rgba_pixels.as_slice(), indexed_pixels));
         }
     }
     Ok(transformed_payload)
@@ -397,7 +1130,14 @@ fn composite_indexed_frame(
     }
 }
 
-fn clear_rect(canvas: &mut image::RgbaImage, left: u32, top: u32, width: u32, height: u32) {
+fn clear_rect(
+    canvas: &mut image::RgbaImage,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+    colour: image::Rgba<u8>,
+) {
     if width == 0 || height == 0 {
         return;
     }
@@ -406,7 +1146,7 @@ fn clear_rect(canvas: &mut image::RgbaImage, left: u32, top: u32, width: u32, he
     let y_end = top.saturating_add(height).min(canvas.height());
     for y in top..y_end {
         for x in left..x_end {
-            canvas.put_pixel(x, y, image::Rgba([0x00, 0x00, 0x00, 0xFF]));
+            canvas.put_pixel(x, y, colour);
         }
     }
 }
@@ -424,6 +1164,26 @@ fn apply_orientation(image: DynamicImage, orientation: Option<u32>) -> DynamicIm
     }
 }
 
+/// Resolves source alpha ahead of resizing according to `mode`.
+///
+/// `FlattenToPadding` leaves alpha in place so padding blends it away later.
+fn apply_transparency(image: DynamicImage, mode: TransparencyMode) -> DynamicImage {
+    match mode {
+        TransparencyMode::FlattenToPadding => image,
+        TransparencyMode::OffAsBlack => {
+            let mut rgba = image.into_rgba8();
+            for pixel in rgba.pixels_mut() {
+                *pixel = if pixel[3] == 0 {
+                    image::Rgba([0x00, 0x00, 0x00, 0xFF])
+                } else {
+                    image::Rgba([pixel[0], pixel[1], pixel[2], 0xFF])
+                };
+            }
+            DynamicImage::ImageRgba8(rgba)
+        }
+    }
+}
+
 fn exif_orientation(source_bytes: &[u8]) -> Option<u32> {
     let mut cursor = Cursor::new(source_bytes);
     let exif = exif::Reader::new().read_from_container(&mut cursor).ok()?;
@@ -432,46 +1192,78 @@ fn exif_orientation(source_bytes: &[u8]) -> Option<u32> {
         .get_uint(0)
 }
 
-fn resize_and_pad_rgba(image: DynamicImage, panel_dimensions: PanelDimensions) -> image::RgbaImage {
+/// Scales `image` onto a panel-sized canvas filled with `pad_colour`.
+///
+/// The scaled image is centred, so [`ScalingMode::Contain`] leaves equal
+/// margins and [`ScalingMode::Cover`] crops equally from both ends.
+fn resize_and_pad_rgba(
+    image: DynamicImage,
+    panel_dimensions: PanelDimensions,
+    scaling: ScalingMode,
+    pad_colour: Rgb,
+) -> image::RgbaImage {
     let panel_width = u32::from(panel_dimensions.width());
     let panel_height = u32::from(panel_dimensions.height());
-    let (source_width, source_height) = image.dimensions();
-
-    let width_scaled_height =
-        u64::from(source_height) * u64::from(panel_width) / u64::from(source_width);
-    let (target_width, target_height) = if width_scaled_height <= u64::from(panel_height) {
-        let safe_height = u32::try_from(width_scaled_height)
-            .unwrap_or(panel_height)
-            .max(1);
-        (panel_width, safe_height)
-    } else {
-        let height_scaled_width =
-            u64::from(source_width) * u64::from(panel_height) / u64::from(source_height);
-        let safe_width = u32::try_from(height_scaled_width)
-            .unwrap_or(panel_width)
-            .max(1);
-        (safe_width, panel_height)
-    };
+    let (target_width, target_height) =
+        scaled_dimensions(image.dimensions(), (panel_width, panel_height), scaling);
 
     let resized = image
         .resize_exact(target_width, target_height, FilterType::Lanczos3)
         .to_rgba8();
-    let mut canvas = image::RgbaImage::from_pixel(
-        panel_width,
-        panel_height,
-        image::Rgba([0x00, 0x00, 0x00, 0xFF]),
-    );
-
-    let offset_x = i64::from((panel_width - target_width) / 2);
-    let offset_y = i64::from((panel_height - target_height) / 2);
+    let mut canvas = image::RgbaImage::from_pixel(panel_width, panel_height, opaque(pad_colour));
+
+    let offset_x = (i64::from(panel_width) - i64::from(target_width)) / 2;
+    let offset_y = (i64::from(panel_height) - i64::from(target_height)) / 2;
     image::imageops::overlay(&mut canvas, &resized, offset_x, offset_y);
     canvas
 }
 
+fn opaque(colour: Rgb) -> image::Rgba<u8> {
+    image::Rgba([colour.r, colour.g, colour.b, 0xFF])
+}
+
+/// Returns the size `source` is resized to before centring on `panel`.
+fn scaled_dimensions(
+    (source_width, source_height): (u32, u32),
+    (panel_width, panel_height): (u32, u32),
+    scaling: ScalingMode,
+) -> (u32, u32) {
+    let (source_width, source_height) = (u64::from(source_width), u64::from(source_height));
+    let (panel_width_u64, panel_height_u64) = (u64::from(panel_width), u64::from(panel_height));
+    let clamp = |edge: u64, fallback: u32| u32::try_from(edge).unwrap_or(fallback).max(1);
+
+    match scaling {
+        ScalingMode::Contain => {
+            let width_scaled_height = source_height * panel_width_u64 / source_width;
+            if width_scaled_height <= panel_height_u64 {
+                (panel_width, clamp(width_scaled_height, panel_height))
+            } else {
+                let height_scaled_width = source_width * panel_height_u64 / source_height;
+                (clamp(height_scaled_width, panel_width), panel_height)
+            }
+        }
+        ScalingMode::Cover => {
+            // Matching the panel width covers the height exactly when the
+            // source is at least as tall relative to its width as the panel.
+            // Scaled edges round up so they never fall short of the panel.
+            if source_height * panel_width_u64 >= panel_height_u64 * source_width {
+                let width_scaled_height = (source_height * panel_width_u64).div_ceil(source_width);
+                (panel_width, clamp(width_scaled_height, panel_height))
+            } else {
+                let height_scaled_width = (source_width * panel_height_u64).div_ceil(source_height);
+                (clamp(height_scaled_width, panel_width), panel_height)
+            }
+        }
+        ScalingMode::Stretch => (panel_width, panel_height),
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use image::{AnimationDecoder, ImageEncoder};
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     use super::*;
 
@@ -494,7 +1286,11 @@ mod tests {
         )?;
 
         let panel = PanelDimensions::new(4, 4).expect("4x4 should be valid");
-        let prepared = ImagePreprocessor::prepare_for_upload(&png_bytes, panel)?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &png_bytes,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
 
         match prepared {
             PreparedImageUpload::Still(still) => {
@@ -511,7 +1307,11 @@ mod tests {
     fn prepare_for_upload_transforms_gif_to_panel_dimensions()
     -> Result<(), Box<dyn std::error::Error>> {
         let panel = PanelDimensions::new(4, 4).expect("4x4 should be valid");
-        let prepared = ImagePreprocessor::prepare_for_upload(&MINIMAL_GIF_1X1, panel)?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &MINIMAL_GIF_1X1,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
 
         match prepared {
             PreparedImageUpload::Still(_still) => panic!("gif should produce gif upload"),
@@ -528,7 +1328,11 @@ mod tests {
     -> Result<(), Box<dyn std::error::Error>> {
         let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
         let source = make_source_gif(1, 0, [0xFF, 0x00, 0x00, 0x00])?;
-        let prepared = ImagePreprocessor::prepare_for_upload(&source, panel)?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &source,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
 
         let PreparedImageUpload::Gif(gif) = prepared else {
             panic!("gif should produce gif upload");
@@ -550,7 +1354,11 @@ mod tests {
     fn prepare_for_upload_limits_gif_frame_count() -> Result<(), Box<dyn std::error::Error>> {
         let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
         let source = make_source_gif(MAX_GIF_FRAMES + 8, 2, [0x10, 0x20, 0x30, 0xFF])?;
-        let prepared = ImagePreprocessor::prepare_for_upload(&source, panel)?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &source,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
 
         let PreparedImageUpload::Gif(gif) = prepared else {
             panic!("gif should produce gif upload");
@@ -559,11 +1367,38 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::within_limit(MAX_GIF_FRAMES, vec![])]
+    #[case::beyond_limit(
+        MAX_GIF_FRAMES + 8,
+        vec![Warning::GifFramesTruncated { dropped: 8 }],
+    )]
+    fn prepare_for_upload_with_warnings_reports_truncated_frames(
+        #[case] source_frames: usize,
+        #[case] expected: Vec<Warning>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let source = make_source_gif(source_frames, 2, [0x10, 0x20, 0x30, 0xFF])?;
+
+        let (_prepared, warnings) = ImagePreprocessor::prepare_for_upload_with_warnings(
+            &source,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
+
+        assert_eq!(expected, warnings);
+        Ok(())
+    }
+
     #[test]
     fn prepare_for_upload_preserves_native_panel_gif_bytes()
     -> Result<(), Box<dyn std::error::Error>> {
         let panel = PanelDimensions::new(1, 1).expect("1x1 should be valid");
-        let prepared = ImagePreprocessor::prepare_for_upload(&MINIMAL_GIF_1X1, panel)?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &MINIMAL_GIF_1X1,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
 
         let PreparedImageUpload::Gif(gif) = prepared else {
             panic!("gif should produce gif upload");
@@ -577,7 +1412,11 @@ mod tests {
     -> Result<(), Box<dyn std::error::Error>> {
         let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
         let source = make_transparent_delta_source_gif()?;
-        let prepared = ImagePreprocessor::prepare_for_upload(&source, panel)?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &source,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
         let PreparedImageUpload::Gif(gif) = prepared else {
             panic!("gif should produce gif upload");
         };
@@ -598,6 +1437,772 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::disabled(false, vec![5, 7, 4])]
+    #[case::enabled(true, vec![12, 4])]
+    fn prepare_for_upload_merges_identical_consecutive_frames_when_deduping(
+        #[case] dedupe_frames: bool,
+        #[case] expected_delays: Vec<u16>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let source = make_coloured_source_gif(&[
+            ([0xFF, 0x00, 0x00, 0xFF], 5),
+            ([0xFF, 0x00, 0x00, 0xFF], 7),
+            ([0x00, 0x00, 0xFF, 0xFF], 4),
+        ])?;
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &source,
+            panel,
+            ImagePreparationOptions::builder()
+                .dedupe_frames(dedupe_frames)
+                .build(),
+        )?;
+        let PreparedImageUpload::Gif(gif) = prepared else {
+            panic!("gif should produce gif upload");
+        };
+
+        let mut reader = gif::DecodeOptions::new().read_info(Cursor::new(gif.payload()))?;
+        let mut delays = Vec::new();
+        while let Some(frame) = reader.read_next_frame()? {
+            delays.push(frame.delay);
+        }
+        assert_eq!(expected_delays, delays);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_gif_frames_returns_panel_sized_frames_with_source_delays()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(16, 8).expect("16x8 should be valid");
+        let source = make_coloured_source_gif(&[
+            ([0xFF, 0x00, 0x00, 0xFF], 5),
+            ([0x00, 0xFF, 0x00, 0xFF], 7),
+            ([0x00, 0x00, 0xFF, 0xFF], 4),
+        ])?;
+
+        let frames = ImagePreprocessor::prepare_gif_frames(
+            &source,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
+
+        assert_eq!(
+            vec![16 * 8 * 4; 3],
+            frames
+                .iter()
+                .map(|frame| frame.rgba_pixels().len())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![5, 7, 4],
+            frames
+                .iter()
+                .map(PreparedGifFrame::delay_centiseconds)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    /// Encodes `count` frames of pseudo-random pixels, which compress poorly.
+    fn make_noisy_source_gif(
+        panel: PanelDimensions,
+        count: usize,
+    ) -> Result<GifAnimation, ImagePreparationError> {
+        let mut state = 0x2545_F491_u32;
+        let pixels = usize::from(panel.width()) * usize::from(panel.height());
+        let frames = (0..count)
+            .map(|_index| {
+                let rgb = (0..pixels * 3)
+                    .map(|_byte| {
+                        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                        state.to_be_bytes()[0]
+                    })
+                    .collect::<Vec<_>>();
+                Rgb888Frame::try_from((panel, rgb))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        encode_animation(&frames, Duration::from_millis(100), None)
+    }
+
+    #[rstest]
+    #[case::none(DitherMode::None, vec![4])]
+    #[case::floyd_steinberg(DitherMode::FloydSteinberg, vec![0, 8])]
+    fn prepare_for_upload_dithers_stills_only_when_asked(
+        #[case] dither: DitherMode,
+        #[case] expected_reds: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut png_bytes = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            &[0x04, 0x04, 0x04].repeat(16 * 16),
+            16,
+            16,
+            image::ExtendedColorType::Rgb8,
+        )?;
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &png_bytes,
+            panel,
+            ImagePreparationOptions::builder().dither(dither).build(),
+        )?;
+        let PreparedImageUpload::Still(still) = prepared else {
+            panic!("png should produce still upload");
+        };
+
+        let mut reds = still
+            .frame()
+            .payload()
+            .chunks_exact(3)
+            .map(|rgb| rgb[0])
+            .collect::<Vec<_>>();
+        reds.sort_unstable();
+        reds.dedup();
+        assert_eq!(expected_reds, reds);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_for_upload_dithers_unchanged_gif_regions_identically_across_frames()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let source_panel = PanelDimensions::new(32, 32).expect("32x32 should be valid");
+        let noisy = make_noisy_source_gif(source_panel, 1)?;
+        let decoder = image::codecs::gif::GifDecoder::new(Cursor::new(noisy.payload()))?;
+        let noise = decoder.into_frames().collect_frames()?[0].buffer().clone();
+        let first = DynamicImage::ImageRgba8(noise).to_rgb8();
+        let mut second = first.clone();
+        for y in 0..4 {
+            for x in 0..4 {
+                second.put_pixel(x, y, image::Rgb([0xFF, 0xFF, 0xFF]));
+            }
+        }
+        let source = encode_animation(
+            &[
+                Rgb888Frame::try_from((source_panel, first.into_raw()))?,
+                Rgb888Frame::try_from((source_panel, second.into_raw()))?,
+            ],
+            Duration::from_millis(100),
+            None,
+        )?;
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            source.payload(),
+            panel,
+            ImagePreparationOptions::builder()
+                .dither(DitherMode::FloydSteinberg)
+                .build(),
+        )?;
+        let PreparedImageUpload::Gif(gif) = prepared else {
+            panic!("gif should produce gif upload");
+        };
+
+        let mut reader = gif::DecodeOptions::new().read_info(Cursor::new(gif.payload()))?;
+        let mut frames = Vec::new();
+        while let Some(frame) = reader.read_next_frame()? {
+            frames.push(frame.buffer.to_vec());
+        }
+        assert_eq!(2, frames.len());
+        // The white corner covers panel pixels 0..2; Lanczos3 spreads it by
+        // up to three more, so everything from 6 onwards is unchanged.
+        let unchanged = |index: &usize| index % 16 >= 6 || index / 16 >= 6;
+        let differing = (0..16 * 16)
+            .filter(unchanged)
+            .filter(|index| frames[0][*index] != frames[1][*index])
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::<usize>::new(), differing);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::default_black(None, [0x00, 0x00, 0x00])]
+    #[case::white_bars(Some(Rgb::new(0xFF, 0xFF, 0xFF)), [0xFF, 0xFF, 0xFF])]
+    #[case::coloured_bars(Some(Rgb::new(0x20, 0x40, 0x80)), [0x20, 0x40, 0x80])]
+    fn prepare_for_upload_pads_wide_images_on_tall_panels_with_pad_colour(
+        #[case] pad_colour: Option<Rgb>,
+        #[case] expected_margin: [u8; 3],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut png_bytes = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            &[0xFF, 0x00, 0x00].repeat(4 * 2),
+            4,
+            2,
+            image::ExtendedColorType::Rgb8,
+        )?;
+        let panel = PanelDimensions::new(4, 8).expect("4x8 should be valid");
+        let options = ImagePreparationOptions::builder()
+            .maybe_pad_colour(pad_colour)
+            .build();
+
+        let prepared = ImagePreprocessor::prepare_for_upload(&png_bytes, panel, options)?;
+        let PreparedImageUpload::Still(still) = prepared else {
+            panic!("png should produce still upload");
+        };
+
+        // The 4x2 image sits in rows 3 and 4, with margins above and below.
+        let rows = still
+            .frame()
+            .payload()
+            .chunks_exact(4 * 3)
+            .collect::<Vec<_>>();
+        for row in [0, 1, 2, 5, 6, 7] {
+            assert_eq!(expected_margin.repeat(4), rows[row], "row {row}");
+        }
+        for row in [3, 4] {
+            assert_eq!([0xFF, 0x00, 0x00].repeat(4), rows[row], "row {row}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_for_upload_composites_gif_frames_over_pad_colour()
+    -> Result<(), Box<dyn std::error::Error>> {
+        // A 2x2 red frame in the corner of a 16x16 screen leaves the rest of
+        // the screen to the composite canvas.
+        let mut source = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut source, 16, 16, &[])?;
+            encoder.write_frame(&gif::Frame::from_rgb(2, 2, &[0xFF, 0x00, 0x00].repeat(4)))?;
+        }
+        let panel = PanelDimensions::new(8, 8).expect("8x8 should be valid");
+        let options = ImagePreparationOptions::builder()
+            .pad_colour(Rgb::new(0xFF, 0xFF, 0xFF))
+            .build();
+
+        let prepared = ImagePreprocessor::prepare_for_upload(&source, panel, options)?;
+        let PreparedImageUpload::Gif(gif) = prepared else {
+            panic!("gif should produce gif upload");
+        };
+
+        let decoder = image::codecs::gif::GifDecoder::new(Cursor::new(gif.payload()))?;
+        let frames = decoder.into_frames().collect_frames()?;
+        assert_eq!(
+            image::Rgba([0xFF, 0xFF, 0xFF, 0xFF]),
+            *frames[0].buffer().get_pixel(7, 7)
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::contain_wide(ScalingMode::Contain, (64, 32), (32, 16))]
+    #[case::contain_tall(ScalingMode::Contain, (32, 64), (16, 32))]
+    #[case::cover_wide(ScalingMode::Cover, (64, 32), (64, 32))]
+    #[case::cover_tall(ScalingMode::Cover, (32, 64), (32, 64))]
+    #[case::cover_rounds_up(ScalingMode::Cover, (3, 2), (48, 32))]
+    #[case::stretch(ScalingMode::Stretch, (64, 32), (32, 32))]
+    fn scaled_dimensions_fit_the_panel_per_scaling_mode(
+        #[case] scaling: ScalingMode,
+        #[case] source: (u32, u32),
+        #[case] expected: (u32, u32),
+    ) {
+        assert_eq!(expected, scaled_dimensions(source, (32, 32), scaling));
+    }
+
+    #[rstest]
+    #[case::contain_pads(ScalingMode::Contain, [0x00, 0xFF, 0x00])]
+    #[case::cover_crops(ScalingMode::Cover, [0xFF, 0x00, 0x00])]
+    #[case::stretch_fills(ScalingMode::Stretch, [0xFF, 0x00, 0x00])]
+    fn prepare_for_upload_fits_wide_stills_per_scaling_mode(
+        #[case] scaling: ScalingMode,
+        #[case] expected_bottom_row: [u8; 3],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut png_bytes = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            &[0xFF, 0x00, 0x00].repeat(4 * 2),
+            4,
+            2,
+            image::ExtendedColorType::Rgb8,
+        )?;
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let options = ImagePreparationOptions::builder()
+            .scaling(scaling)
+            .pad_colour(Rgb::new(0x00, 0xFF, 0x00))
+            .build();
+
+        let prepared = ImagePreprocessor::prepare_for_upload(&png_bytes, panel, options)?;
+        let PreparedImageUpload::Still(still) = prepared else {
+            panic!("png should produce still upload");
+        };
+
+        let rows = still.frame().payload().chunks_exact(6).collect::<Vec<_>>();
+        assert_eq!([0xFF, 0x00, 0x00].repeat(2), rows[0]);
+        assert_eq!(expected_bottom_row.repeat(2), rows[1]);
+        Ok(())
+    }
+
+    /// Returns the length of the smallest encoding of every frame of `source`
+    /// across the palette steps.
+    fn smallest_all_frame_len(
+        source: &GifAnimation,
+        panel: PanelDimensions,
+    ) -> Result<usize, ImagePreparationError> {
+        let options = ImagePreparationOptions::default();
+        let frames = ImagePreprocessor::prepare_gif_frames(source.payload(), panel, options)?;
+        let mut smallest_len = usize::MAX;
+        for max_colours in std::iter::once(MAX_GIF_PALETTE_COLOURS).chain(REDUCED_PALETTE_COLOURS) {
+            let payload = encode_gif_frames_with_shared_palette(
+                panel.width(),
+                panel.height(),
+                &frames,
+                max_colours,
+                options.dither(),
+            )
+            .map(strip_empty_global_palette)?;
+            smallest_len = smallest_len.min(payload.len());
+        }
+        Ok(smallest_len)
+    }
+
+    fn decoded_delays(gif: &GifAnimation) -> Result<Vec<u16>, Box<dyn std::error::Error>> {
+        let mut reader = gif::DecodeOptions::new().read_info(Cursor::new(gif.payload()))?;
+        let mut delays = Vec::new();
+        while let Some(frame) = reader.read_next_frame()? {
+            delays.push(frame.delay);
+        }
+        Ok(delays)
+    }
+
+    #[test]
+    fn prepare_for_upload_shrinks_the_palette_before_dropping_frames()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+        let source = make_noisy_source_gif(panel, 8)?;
+        let target_max_bytes = smallest_all_frame_len(&source, panel)?;
+        assert!(target_max_bytes < source.payload().len());
+
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            source.payload(),
+            panel,
+            ImagePreparationOptions::builder()
+                .target_max_bytes(target_max_bytes)
+                .build(),
+        )?;
+        let PreparedImageUpload::Gif(gif) = prepared else {
+            panic!("gif should produce gif upload");
+        };
+
+        assert!(gif.payload().len() <= target_max_bytes);
+        assert_eq!(vec![10; 8], decoded_delays(&gif)?);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_for_upload_drops_frames_once_the_palette_floor_is_too_large()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+        let source = make_noisy_source_gif(panel, 8)?;
+        let target_max_bytes = smallest_all_frame_len(&source, panel)? - 1;
+
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            source.payload(),
+            panel,
+            ImagePreparationOptions::builder()
+                .target_max_bytes(target_max_bytes)
+                .build(),
+        )?;
+        let PreparedImageUpload::Gif(gif) = prepared else {
+            panic!("gif should produce gif upload");
+        };
+
+        assert!(gif.payload().len() <= target_max_bytes);
+        let delays = decoded_delays(&gif)?;
+        assert!(
+            delays.len() < 8,
+            "expected fewer than 8 frames, got {delays:?}"
+        );
+        assert_eq!(80, delays.iter().sum::<u16>());
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_for_upload_leaves_gifs_within_the_byte_target_untouched()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(4, 4).expect("4x4 should be valid");
+        let source = make_noisy_source_gif(panel, 3)?;
+
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            source.payload(),
+            panel,
+            ImagePreparationOptions::builder()
+                .target_max_bytes(source.payload().len())
+                .build(),
+        )?;
+
+        assert_eq!(PreparedImageUpload::Gif(source), prepared);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_for_upload_rejects_unreachable_byte_target() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+        let source = make_noisy_source_gif(panel, 4)?;
+
+        let error = ImagePreprocessor::prepare_for_upload(
+            source.payload(),
+            panel,
+            ImagePreparationOptions::builder()
+                .target_max_bytes(16)
+                .build(),
+        )
+        .expect_err("no gif fits in 16 bytes");
+
+        assert_matches!(
+            error,
+            ImagePreparationError::TargetSizeUnreachable {
+                target_max_bytes: 16,
+                smallest_len,
+            } if smallest_len > 16
+        );
+        Ok(())
+    }
+
+    fn solid_frame(panel: PanelDimensions, rgb: [u8; 3]) -> Rgb888Frame {
+        let pixels = usize::from(panel.width()) * usize::from(panel.height());
+        Rgb888Frame::try_from((panel, rgb.repeat(pixels)))
+            .expect("solid frame should match panel dimensions")
+    }
+
+    #[test]
+    fn encode_animation_assembles_one_gif_frame_per_still() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let panel = PanelDimensions::new(4, 4).expect("4x4 should be valid");
+        let frames = [
+            solid_frame(panel, [0xFF, 0x00, 0x00]),
+            solid_frame(panel, [0x00, 0x00, 0xFF]),
+        ];
+
+        let gif = encode_animation(&frames, Duration::from_millis(200), None)?;
+
+        assert_eq!(panel, gif.dimensions());
+        assert_eq!(2, gif_frame_count(gif.payload())?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::even_step(8, 15)]
+    #[case::uneven_step(7, 17)]
+    #[case::panel_width_step(16, 8)]
+    fn prepare_scroll_emits_one_frame_per_pan_step(
+        #[case] step: u16,
+        #[case] expected_frames: usize,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // A 64x8 banner scaled onto a 16x16 panel becomes a 128px-wide strip,
+        // so the window pans 112px.
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+        let banner = image::RgbaImage::from_fn(64, 8, |x, _y| {
+            if x < 32 {
+                image::Rgba([0xFF, 0x00, 0x00, 0xFF])
+            } else {
+                image::Rgba([0x00, 0x00, 0xFF, 0xFF])
+            }
+        });
+        let mut png_bytes = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            banner.as_raw(),
+            64,
+            8,
+            image::ExtendedColorType::Rgba8,
+        )?;
+
+        let step = NonZeroU16::new(step).expect("test steps are non-zero");
+        let gif = ImagePreprocessor::prepare_scroll(
+            &png_bytes,
+            panel,
+            step,
+            Duration::from_millis(100),
+            ImagePreparationOptions::default(),
+        )?;
+
+        assert_eq!(panel, gif.dimensions());
+        assert_eq!(112 / usize::from(step.get()) + 1, expected_frames);
+        assert_eq!(expected_frames, gif_frame_count(gif.payload())?);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_scroll_grows_step_to_stay_within_frame_limit()
+    -> Result<(), Box<dyn std::error::Error>> {
+        // A 64x8 banner on a 16x16 panel pans 112px; at one pixel per frame
+        // that is 113 frames, so the step grows to ceil(112 / 63) = 2.
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+        let banner = image::RgbaImage::from_fn(64, 8, |x, _y| {
+            image::Rgba([u8::try_from(x * 4).unwrap_or(u8::MAX), 0x00, 0x00, 0xFF])
+        });
+        let mut png_bytes = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            banner.as_raw(),
+            64,
+            8,
+            image::ExtendedColorType::Rgba8,
+        )?;
+
+        let step = NonZeroU16::new(1).expect("1 is non-zero");
+        let gif = ImagePreprocessor::prepare_scroll(
+            &png_bytes,
+            panel,
+            step,
+            Duration::from_millis(100),
+            ImagePreparationOptions::default(),
+        )?;
+
+        let frames = gif_frame_count(gif.payload())?;
+        assert!(frames <= MAX_GIF_FRAMES, "{frames} frames");
+        assert_eq!(112 / 2 + 1, frames);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_scroll_keeps_narrow_source_as_single_frame() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let panel = PanelDimensions::new(16, 16).expect("16x16 should be valid");
+        let mut png_bytes = Vec::new();
+        let source = image::RgbaImage::from_pixel(4, 8, image::Rgba([0x12, 0x34, 0x56, 0xFF]));
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            source.as_raw(),
+            4,
+            8,
+            image::ExtendedColorType::Rgba8,
+        )?;
+
+        let step = NonZeroU16::new(2).expect("2 is non-zero");
+        let gif = ImagePreprocessor::prepare_scroll(
+            &png_bytes,
+            panel,
+            step,
+            Duration::from_millis(100),
+            ImagePreparationOptions::default(),
+        )?;
+
+        assert_eq!(1, gif_frame_count(gif.payload())?);
+        Ok(())
+    }
+
+    #[test]
+    fn encode_animation_rejects_mixed_frame_dimensions() {
+        let small = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let large = PanelDimensions::new(4, 4).expect("4x4 should be valid");
+        let frames = [
+            solid_frame(small, [0xFF, 0x00, 0x00]),
+            solid_frame(large, [0x00, 0x00, 0xFF]),
+        ];
+
+        let result = encode_animation(&frames, Duration::from_millis(200), None);
+
+        assert_matches!(
+            result,
+            Err(ImagePreparationError::AnimationFrameDimensionsMismatch { index: 1, .. })
+        );
+    }
+
+    #[test]
+    fn encode_animation_rejects_empty_frame_list() {
+        let result = encode_animation(&[], Duration::from_millis(200), None);
+
+        assert_matches!(result, Err(ImagePreparationError::NoAnimationFrames));
+    }
+
+    #[test]
+    fn prepare_first_frame_extracts_opening_gif_frame_as_still()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let source = make_transparent_delta_source_gif()?;
+        assert_eq!(2, gif_frame_count(&source)?);
+
+        let still = ImagePreprocessor::prepare_first_frame(
+            &source,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
+
+        assert_eq!(image::ImageFormat::Gif, still.source_format());
+        assert_eq!(panel, still.frame().dimensions());
+        assert_eq!(2 * 2 * 3, still.frame().payload().len());
+        let first_pixel = &still.frame().payload()[0..3];
+        assert_eq!([0xFF, 0x00, 0x00].as_slice(), first_pixel);
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_first_frame_prepares_non_gif_sources_as_stills()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let mut png_bytes = Vec::new();
+        let source = image::RgbaImage::from_pixel(1, 1, image::Rgba([0x12, 0x34, 0x56, 0xFF]));
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            source.as_raw(),
+            1,
+            1,
+            image::ExtendedColorType::Rgba8,
+        )?;
+
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+        let still = ImagePreprocessor::prepare_first_frame(
+            &png_bytes,
+            panel,
+            ImagePreparationOptions::default(),
+        )?;
+
+        assert_eq!(image::ImageFormat::Png, still.source_format());
+        assert_eq!(panel, still.frame().dimensions());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::upload(|source: &[u8], panel| {
+        ImagePreprocessor::prepare_for_upload(source, panel, ImagePreparationOptions::default()).map(drop)
+    })]
+    #[case::first_frame(|source: &[u8], panel| {
+        ImagePreprocessor::prepare_first_frame(source, panel, ImagePreparationOptions::default()).map(drop)
+    })]
+    #[case::scroll(|source: &[u8], panel| {
+        ImagePreprocessor::prepare_scroll(
+            source,
+            panel,
+            NonZeroU16::MIN,
+            Duration::from_millis(100),
+            ImagePreparationOptions::default(),
+        )
+        .map(drop)
+    })]
+    fn prepare_rejects_header_declared_huge_source_before_decode(
+        #[case] prepare: fn(&[u8], PanelDimensions) -> Result<(), ImagePreparationError>,
+    ) {
+        let source = make_header_only_png(200_000, 100_000);
+        let panel = PanelDimensions::new(32, 32).expect("32x32 should be valid");
+
+        let error = prepare(&source, panel).expect_err("huge source should be rejected");
+
+        assert_matches!(
+            error,
+            ImagePreparationError::SourceTooLarge {
+                width: 200_000,
+                height: 100_000,
+                max_pixels: DEFAULT_MAX_SOURCE_PIXELS,
+            }
+        );
+    }
+
+    #[test]
+    fn prepare_for_upload_accepts_source_at_pixel_limit() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut png_bytes = Vec::new();
+        let source = image::RgbaImage::from_pixel(2, 2, image::Rgba([0x12, 0x34, 0x56, 0xFF]));
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            source.as_raw(),
+            2,
+            2,
+            image::ExtendedColorType::Rgba8,
+        )?;
+        let panel = PanelDimensions::new(2, 2).expect("2x2 should be valid");
+
+        let prepared = ImagePreprocessor::prepare_for_upload(
+            &png_bytes,
+            panel,
+            ImagePreparationOptions::builder()
+                .max_source_pixels(4)
+                .build(),
+        )?;
+        let error = ImagePreprocessor::prepare_for_upload(
+            &png_bytes,
+            panel,
+            ImagePreparationOptions::builder()
+                .max_source_pixels(3)
+                .build(),
+        )
+        .expect_err("source above the limit should be rejected");
+
+        assert_matches!(prepared, PreparedImageUpload::Still(_));
+        assert_matches!(
+            error,
+            ImagePreparationError::SourceTooLarge {
+                width: 2,
+                height: 2,
+                max_pixels: 3,
+            }
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::off_as_black(TransparencyMode::OffAsBlack)]
+    #[case::flatten_to_padding(TransparencyMode::FlattenToPadding)]
+    fn prepare_for_upload_renders_fully_transparent_pixels_black(
+        #[case] transparency: TransparencyMode,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let frame = prepare_half_transparent_png(transparency)?;
+
+        assert_eq!(&[0x00, 0x00, 0x00], &frame.payload()[3..6]);
+        Ok(())
+    }
+
+    #[test]
+    fn off_as_black_shows_partially_transparent_pixels_at_full_colour()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let frame = prepare_half_transparent_png(TransparencyMode::OffAsBlack)?;
+
+        assert_eq!(vec![0xFF, 0x00, 0x00, 0x00, 0x00, 0x00], frame.payload());
+        Ok(())
+    }
+
+    #[test]
+    fn flatten_to_padding_blends_partially_transparent_pixels()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let frame = prepare_half_transparent_png(TransparencyMode::FlattenToPadding)?;
+
+        assert_matches!(frame.payload(), [0x7F..=0x80, 0x00, 0x00, ..]);
+        Ok(())
+    }
+
+    /// Prepares a 2x1 PNG whose left pixel is half-transparent red and whose
+    /// right pixel is fully transparent green, on a 2x1 panel.
+    fn prepare_half_transparent_png(
+        transparency: TransparencyMode,
+    ) -> Result<Rgb888Frame, Box<dyn std::error::Error>> {
+        let mut png_bytes = Vec::new();
+        let pixels = [0xFF, 0x00, 0x00, 0x80, 0x00, 0xFF, 0x00, 0x00];
+        image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+            &pixels,
+            2,
+            1,
+            image::ExtendedColorType::Rgba8,
+        )?;
+        let panel = PanelDimensions::new(2, 1).expect("2x1 should be valid");
+        let options = ImagePreparationOptions::builder()
+            .transparency(transparency)
+            .build();
+
+        let prepared = ImagePreprocessor::prepare_for_upload(&png_bytes, panel, options)?;
+        let PreparedImageUpload::Still(still) = prepared else {
+            panic!("png should produce still upload");
+        };
+        Ok(still.into_frame())
+    }
+
+    /// Builds a PNG whose header declares `width`x`height` but which carries
+    /// no pixel data, so only a header-level check can succeed on it.
+    fn make_header_only_png(width: u32, height: u32) -> Vec<u8> {
+        fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+            let len = u32::try_from(data.len()).expect("chunk data should fit in u32");
+            png.extend_from_slice(&len.to_be_bytes());
+            let crc_start = png.len();
+            png.extend_from_slice(kind);
+            png.extend_from_slice(data);
+            let crc = crc32fast::hash(&png[crc_start..]);
+            png.extend_from_slice(&crc.to_be_bytes());
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        // 8-bit RGB, deflate, adaptive filtering, no interlace.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        push_chunk(&mut png, b"IHDR", &header);
+        push_chunk(&mut png, b"IDAT", &[]);
+        push_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
     fn make_source_gif(
         frames: usize,
         delay_centiseconds: u16,
@@ -618,6 +2223,22 @@ mod tests {
         Ok(payload)
     }
 
+    fn make_coloured_source_gif(frames: &[([u8; 4], u16)]) -> Result<Vec<u8>, gif::EncodingError> {
+        let mut payload = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut payload, 1, 1, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+
+            for (rgba_pixel, delay_centiseconds) in frames {
+                let mut rgba = Vec::from(*rgba_pixel);
+                let mut frame = gif::Frame::from_rgba_speed(1, 1, &mut rgba, 10);
+                frame.delay = *delay_centiseconds;
+                encoder.write_frame(&frame)?;
+            }
+        }
+        Ok(payload)
+    }
+
     fn gif_frame_count(payload: &[u8]) -> Result<usize, gif::DecodingError> {
         let options = gif::DecodeOptions::new();
         let mut reader = options.read_info(Cursor::new(payload))?;
@@ -1,9 +1,12 @@
+mod dither;
 mod gif_animation;
 mod image_preprocessor;
 mod rgb888_frame;
 
+pub use self::dither::DitherMode;
 pub use self::gif_animation::{GifAnimation, GifAnimationError};
 pub use self::image_preprocessor::{
-    ImagePreparationError, ImagePreprocessor, PreparedImageUpload, PreparedStillImage,
+    DEFAULT_MAX_SOURCE_PIXELS, ImagePreparationError, ImagePreparationOptions, ImagePreprocessor,
+    PreparedGifFrame, PreparedImageUpload, PreparedStillImage, ScalingMode, TransparencyMode,
 };
 pub use self::rgb888_frame::{Rgb888Frame, Rgb888FrameError};
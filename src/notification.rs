@@ -2,9 +2,11 @@ use std::fmt::{self, Display, Formatter};
 
 use strum_macros::Display as StrumDisplay;
 use thiserror::Error;
+use tokio_stream::{Stream, StreamExt};
 use tracing::instrument;
 
 use crate::hw::LedInfoResponse;
+use crate::{DecodedNotification, FrameCodec};
 
 /// Transfer families used by notification flow-control responses.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, StrumDisplay)]
@@ -135,6 +137,91 @@ impl Display for NotifyEvent {
     }
 }
 
+/// Set of transfer status bytes, stored as a 256-bit mask so it stays `Copy`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+struct StatusSet([u64; 4]);
+
+impl StatusSet {
+    const fn with(mut self, status: u8) -> Self {
+        self.0[(status / 64) as usize] |= 1 << (status % 64);
+        self
+    }
+
+    const fn contains(self, status: u8) -> bool {
+        self.0[(status / 64) as usize] & (1 << (status % 64)) != 0
+    }
+}
+
+/// Transfer-acknowledgement status overrides for firmware variants.
+///
+/// The default policy accepts exactly the statuses the notification decoder
+/// maps to `NextPackage` and `Finished`. Extra statuses added here are
+/// reclassified from [`NotifyEvent::Error`] before upload handlers act on them.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct AckPolicy {
+    continue_statuses: StatusSet,
+    finished_statuses: StatusSet,
+}
+
+impl AckPolicy {
+    /// Returns a policy that also treats `status` as "send the next chunk".
+    ///
+    /// ```
+    /// use idm::{AckPolicy, NotifyEvent, TransferFamily};
+    ///
+    /// let policy = AckPolicy::default().with_continue_status(0x07);
+    /// assert_eq!(
+    ///     NotifyEvent::NextPackage(TransferFamily::Gif),
+    ///     policy.classify(NotifyEvent::Error(TransferFamily::Gif, 0x07)),
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_continue_status(mut self, status: u8) -> Self {
+        self.continue_statuses = self.continue_statuses.with(status);
+        self
+    }
+
+    /// Returns a policy that also treats `status` as "transfer finished".
+    ///
+    /// ```
+    /// use idm::{AckPolicy, NotifyEvent, TransferFamily};
+    ///
+    /// let policy = AckPolicy::default().with_finished_status(0x04);
+    /// assert_eq!(
+    ///     NotifyEvent::Finished(TransferFamily::Text),
+    ///     policy.classify(NotifyEvent::Error(TransferFamily::Text, 0x04)),
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_finished_status(mut self, status: u8) -> Self {
+        self.finished_statuses = self.finished_statuses.with(status);
+        self
+    }
+
+    /// Reclassifies a transfer error status according to this policy.
+    ///
+    /// Events other than [`NotifyEvent::Error`] are returned unchanged.
+    ///
+    /// ```
+    /// use idm::{AckPolicy, NotifyEvent, TransferFamily};
+    ///
+    /// let event = NotifyEvent::Error(TransferFamily::Image, 0x02);
+    /// assert_eq!(event.clone(), AckPolicy::default().classify(event));
+    /// ```
+    #[must_use]
+    pub fn classify(self, event: NotifyEvent) -> NotifyEvent {
+        match event {
+            NotifyEvent::Error(family, status) if self.continue_statuses.contains(status) => {
+                NotifyEvent::NextPackage(family)
+            }
+            NotifyEvent::Error(family, status) if self.finished_statuses.contains(status) => {
+                NotifyEvent::Finished(family)
+            }
+            other => other,
+        }
+    }
+}
+
 /// Errors returned while decoding notification payloads.
 #[derive(Debug, Error, Clone, Eq, PartialEq)]
 pub enum NotificationDecodeError {
@@ -142,8 +229,19 @@ pub enum NotificationDecodeError {
     EmptyPayload,
 }
 
-/// Decodes raw `fa03` notification payloads into typed events.
-pub(crate) struct NotificationHandler;
+/// Decodes raw `fa03` notification payloads into typed events, and encodes
+/// events back into the payloads a device sends.
+///
+/// ```
+/// use idm::{NotificationHandler, NotifyEvent, TransferFamily};
+///
+/// let event = NotifyEvent::Finished(TransferFamily::Gif);
+/// let payload = NotificationHandler::encode(&event);
+/// assert_eq!(vec![0x05, 0x00, 0x01, 0x00, 0x03], payload);
+/// assert_eq!(Ok(event), NotificationHandler::decode(&payload));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NotificationHandler;
 
 impl NotificationHandler {
     /// Decodes one notification payload.
@@ -161,36 +259,143 @@ impl NotificationHandler {
             return Ok(NotifyEvent::LedInfo(led_info));
         }
 
-        if payload.len() >= 5 && payload[0] == 0x05 && payload[1] == 0x00 {
-            if payload[2] == 0x0F && payload[3] == 0x80 {
-                return Ok(NotifyEvent::ScreenLightTimeout(payload[4]));
+        if let Ok(DecodedNotification::Status {
+            command_id,
+            command_ns,
+            status,
+        }) = FrameCodec::decode_notification(payload)
+            && let Some(event) = decode_status_reply(command_id, command_ns, status)
+        {
+            return Ok(event);
+        }
+
+        Ok(NotifyEvent::Unknown(payload.to_vec()))
+    }
+
+    /// Encodes one event as the notification payload a device sends for it.
+    ///
+    /// Every event [`NotificationHandler::decode`] returns encodes back to a
+    /// payload that decodes to the same event. Statuses with more than one
+    /// wire value, such as a DIY transfer finishing, use the first listed in
+    /// the protocol table, and [`NotifyEvent::Unknown`] yields its bytes
+    /// unchanged.
+    ///
+    /// ```
+    /// use idm::{NotificationHandler, NotifyEvent};
+    ///
+    /// let payload = NotificationHandler::encode(&NotifyEvent::ScreenLightTimeout(0x1E));
+    /// assert_eq!(vec![0x05, 0x00, 0x0F, 0x80, 0x1E], payload);
+    /// ```
+    #[must_use]
+    pub fn encode(event: &NotifyEvent) -> Vec<u8> {
+        match event {
+            NotifyEvent::NextPackage(family) => {
+                status_reply(transfer_command(*family), next_package_status(*family))
             }
-            if payload[2] == 0x05 && payload[3] == 0x80 {
-                return Ok(NotifyEvent::ScheduleSetup(payload[4].into()));
+            NotifyEvent::Finished(family) => {
+                status_reply(transfer_command(*family), finished_status(*family))
             }
-            if payload[2] == 0x07 && payload[3] == 0x80 {
-                return Ok(NotifyEvent::ScheduleMasterSwitch(payload[4].into()));
+            NotifyEvent::Error(family, status) => status_reply(transfer_command(*family), *status),
+            NotifyEvent::ScheduleSetup(status) => {
+                let status = match status {
+                    ScheduleSetupStatus::Success => 0x01,
+                    ScheduleSetupStatus::Continue => 0x03,
+                    ScheduleSetupStatus::Failed(other) => *other,
+                };
+                status_reply((0x05, 0x80), status)
             }
-        }
-
-        if payload.len() >= 5 {
-            let status = payload[4];
-            let maybe_family = match (payload[1], payload[2], payload[3]) {
-                (0x00, 0x03, 0x00) => Some(TransferFamily::Text),
-                (0x00, 0x01, 0x00) => Some(TransferFamily::Gif),
-                (0x00, 0x02, 0x00) => Some(TransferFamily::Image),
-                (0x00, 0x00, 0x00) => Some(TransferFamily::Diy),
-                (0x00, 0x00, 0x80) => Some(TransferFamily::Timer),
-                (0x00, 0x01, 0xC0) => Some(TransferFamily::Ota),
-                _ => None,
-            };
-
-            if let Some(family) = maybe_family {
-                return Ok(decode_transfer_status(family, status));
+            NotifyEvent::ScheduleMasterSwitch(status) => {
+                let status = match status {
+                    ScheduleMasterSwitchStatus::Success => 0x01,
+                    ScheduleMasterSwitchStatus::Failed(other) => *other,
+                };
+                status_reply((0x07, 0x80), status)
             }
+            NotifyEvent::LedInfo(response) => vec![
+                0x09,
+                0x00,
+                0x01,
+                0x80,
+                response.mcu_major_version,
+                response.mcu_minor_version,
+                response.status,
+                response.screen_type,
+                u8::from(response.password_enabled),
+            ],
+            NotifyEvent::ScreenLightTimeout(value) => status_reply((0x0F, 0x80), *value),
+            NotifyEvent::Unknown(payload) => payload.clone(),
         }
+    }
+}
 
-        Ok(NotifyEvent::Unknown(payload.to_vec()))
+/// Decodes a stream of raw `fa03` notification payloads into typed events.
+///
+/// This applies the same decoding as a live notification subscription, so
+/// payloads from other sources (for example a replay file) can be decoded
+/// without a connected device.
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use idm::{NotifyEvent, TransferFamily, decode_notifications};
+/// use tokio_stream::StreamExt;
+///
+/// let payloads = tokio_stream::iter([vec![0x05, 0x00, 0x01, 0x00, 0x03]]);
+/// let events = decode_notifications(payloads).collect::<Vec<_>>().await;
+/// assert_eq!(vec![Ok(NotifyEvent::Finished(TransferFamily::Gif))], events);
+/// # }
+/// ```
+pub fn decode_notifications(
+    payloads: impl Stream<Item = Vec<u8>>,
+) -> impl Stream<Item = Result<NotifyEvent, NotificationDecodeError>> {
+    payloads.map(|payload| NotificationHandler::decode(&payload))
+}
+
+/// Maps a five-byte status reply to its event, by the command it answers.
+fn decode_status_reply(command_id: u8, command_ns: u8, status: u8) -> Option<NotifyEvent> {
+    let family = match (command_id, command_ns) {
+        (0x0F, 0x80) => return Some(NotifyEvent::ScreenLightTimeout(status)),
+        (0x05, 0x80) => return Some(NotifyEvent::ScheduleSetup(status.into())),
+        (0x07, 0x80) => return Some(NotifyEvent::ScheduleMasterSwitch(status.into())),
+        (0x03, 0x00) => TransferFamily::Text,
+        (0x01, 0x00) => TransferFamily::Gif,
+        (0x02, 0x00) => TransferFamily::Image,
+        (0x00, 0x00) => TransferFamily::Diy,
+        (0x00, 0x80) => TransferFamily::Timer,
+        (0x01, 0xC0) => TransferFamily::Ota,
+        _ => return None,
+    };
+    Some(decode_transfer_status(family, status))
+}
+
+/// Builds a five-byte status reply to the `(command_id, command_ns)` command.
+fn status_reply((command_id, command_ns): (u8, u8), status: u8) -> Vec<u8> {
+    vec![0x05, 0x00, command_id, command_ns, status]
+}
+
+/// Returns the `(command_id, command_ns)` a transfer family's replies answer.
+fn transfer_command(family: TransferFamily) -> (u8, u8) {
+    match family {
+        TransferFamily::Text => (0x03, 0x00),
+        TransferFamily::Gif => (0x01, 0x00),
+        TransferFamily::Image => (0x02, 0x00),
+        TransferFamily::Diy => (0x00, 0x00),
+        TransferFamily::Timer => (0x00, 0x80),
+        TransferFamily::Ota => (0x01, 0xC0),
+    }
+}
+
+fn next_package_status(family: TransferFamily) -> u8 {
+    match family {
+        TransferFamily::Diy => 0x02,
+        _other => 0x01,
+    }
+}
+
+fn finished_status(family: TransferFamily) -> u8 {
+    match family {
+        TransferFamily::Diy => 0x00,
+        _other => 0x03,
     }
 }
 
@@ -297,6 +502,15 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::length_mismatch(&[0x06, 0x00, 0x01, 0x00, 0x03])]
+    #[case::trailing_bytes(&[0x05, 0x00, 0x01, 0x00, 0x03, 0x00])]
+    fn decode_keeps_malformed_status_frames_as_unknown(#[case] payload: &[u8]) {
+        let decoded = NotificationHandler::decode(payload)
+            .expect("malformed non-empty payload should decode as Unknown");
+        assert_eq!(NotifyEvent::Unknown(payload.to_vec()), decoded);
+    }
+
     #[test]
     fn decode_preserves_unknown_payload() {
         let payload = [0xAA, 0x55, 0x01];
@@ -305,9 +519,121 @@ mod tests {
         assert_eq!(NotifyEvent::Unknown(payload.to_vec()), decoded);
     }
 
+    #[rstest]
+    #[case::continue_status(
+        AckPolicy::default().with_continue_status(0x07),
+        NotifyEvent::NextPackage(TransferFamily::Gif)
+    )]
+    #[case::finished_status(
+        AckPolicy::default().with_finished_status(0x07),
+        NotifyEvent::Finished(TransferFamily::Gif)
+    )]
+    #[case::default_policy(AckPolicy::default(), NotifyEvent::Error(TransferFamily::Gif, 0x07))]
+    #[case::other_status(
+        AckPolicy::default().with_continue_status(0xFF),
+        NotifyEvent::Error(TransferFamily::Gif, 0x07)
+    )]
+    fn ack_policy_reclassifies_listed_error_statuses(
+        #[case] policy: AckPolicy,
+        #[case] expected: NotifyEvent,
+    ) {
+        assert_eq!(
+            expected,
+            policy.classify(NotifyEvent::Error(TransferFamily::Gif, 0x07))
+        );
+    }
+
+    #[rstest]
+    #[case::text_next(NotifyEvent::NextPackage(TransferFamily::Text))]
+    #[case::text_finished(NotifyEvent::Finished(TransferFamily::Text))]
+    #[case::text_error(NotifyEvent::Error(TransferFamily::Text, 0x02))]
+    #[case::gif_next(NotifyEvent::NextPackage(TransferFamily::Gif))]
+    #[case::gif_finished(NotifyEvent::Finished(TransferFamily::Gif))]
+    #[case::gif_error(NotifyEvent::Error(TransferFamily::Gif, 0x00))]
+    #[case::image_next(NotifyEvent::NextPackage(TransferFamily::Image))]
+    #[case::image_finished(NotifyEvent::Finished(TransferFamily::Image))]
+    #[case::image_error(NotifyEvent::Error(TransferFamily::Image, 0x04))]
+    #[case::diy_next(NotifyEvent::NextPackage(TransferFamily::Diy))]
+    #[case::diy_finished(NotifyEvent::Finished(TransferFamily::Diy))]
+    #[case::diy_error(NotifyEvent::Error(TransferFamily::Diy, 0x03))]
+    #[case::timer_next(NotifyEvent::NextPackage(TransferFamily::Timer))]
+    #[case::timer_finished(NotifyEvent::Finished(TransferFamily::Timer))]
+    #[case::timer_error(NotifyEvent::Error(TransferFamily::Timer, 0x02))]
+    #[case::ota_next(NotifyEvent::NextPackage(TransferFamily::Ota))]
+    #[case::ota_finished(NotifyEvent::Finished(TransferFamily::Ota))]
+    #[case::ota_error(NotifyEvent::Error(TransferFamily::Ota, 0x02))]
+    #[case::schedule_success(NotifyEvent::ScheduleSetup(ScheduleSetupStatus::Success))]
+    #[case::schedule_continue(NotifyEvent::ScheduleSetup(ScheduleSetupStatus::Continue))]
+    #[case::schedule_failed(NotifyEvent::ScheduleSetup(ScheduleSetupStatus::Failed(0x02)))]
+    #[case::master_switch_success(NotifyEvent::ScheduleMasterSwitch(
+        ScheduleMasterSwitchStatus::Success
+    ))]
+    #[case::master_switch_failed(NotifyEvent::ScheduleMasterSwitch(
+        ScheduleMasterSwitchStatus::Failed(0x03)
+    ))]
+    #[case::led_info(NotifyEvent::LedInfo(LedInfoResponse {
+        mcu_major_version: 0x02,
+        mcu_minor_version: 0x0A,
+        status: 0x01,
+        screen_type: 0x04,
+        password_enabled: true,
+    }))]
+    #[case::screen_light_timeout(NotifyEvent::ScreenLightTimeout(0x1E))]
+    #[case::unknown(NotifyEvent::Unknown(vec![0xAA, 0x55, 0x01]))]
+    fn encode_round_trips_through_decode(#[case] event: NotifyEvent) {
+        let payload = NotificationHandler::encode(&event);
+
+        assert_eq!(Ok(event), NotificationHandler::decode(&payload));
+    }
+
+    #[rstest]
+    #[case::diy_next(
+        NotifyEvent::NextPackage(TransferFamily::Diy),
+        vec![0x05, 0x00, 0x00, 0x00, 0x02]
+    )]
+    #[case::diy_finished(
+        NotifyEvent::Finished(TransferFamily::Diy),
+        vec![0x05, 0x00, 0x00, 0x00, 0x00]
+    )]
+    #[case::ota_finished(
+        NotifyEvent::Finished(TransferFamily::Ota),
+        vec![0x05, 0x00, 0x01, 0xC0, 0x03]
+    )]
+    #[case::schedule_continue(
+        NotifyEvent::ScheduleSetup(ScheduleSetupStatus::Continue),
+        vec![0x05, 0x00, 0x05, 0x80, 0x03]
+    )]
+    fn encode_writes_expected_wire_bytes(#[case] event: NotifyEvent, #[case] expected: Vec<u8>) {
+        assert_eq!(expected, NotificationHandler::encode(&event));
+    }
+
     #[test]
     fn decode_rejects_empty_payload() {
         let decoded = NotificationHandler::decode(&[]);
         assert_matches!(decoded, Err(NotificationDecodeError::EmptyPayload));
     }
+
+    #[tokio::test]
+    async fn decode_notifications_decodes_each_payload_in_order() {
+        let payloads = tokio_stream::iter([
+            vec![0x05, 0x00, 0x01, 0x00, 0x01],
+            vec![],
+            vec![0x05, 0x00, 0x0F, 0x80, 0x1E],
+            vec![0xAA, 0x55],
+            vec![0x05, 0x00, 0x01, 0x00, 0x03],
+        ]);
+
+        let events = decode_notifications(payloads).collect::<Vec<_>>().await;
+
+        assert_eq!(
+            vec![
+                Ok(NotifyEvent::NextPackage(TransferFamily::Gif)),
+                Err(NotificationDecodeError::EmptyPayload),
+                Ok(NotifyEvent::ScreenLightTimeout(0x1E)),
+                Ok(NotifyEvent::Unknown(vec![0xAA, 0x55])),
+                Ok(NotifyEvent::Finished(TransferFamily::Gif)),
+            ],
+            events
+        );
+    }
 }
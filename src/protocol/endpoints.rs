@@ -3,7 +3,7 @@ use std::sync::LazyLock;
 
 use serde_with::SerializeDisplay;
 use strum::IntoEnumIterator;
-use strum_macros::{Display, EnumIter};
+use strum_macros::{Display, EnumIter, EnumString};
 
 /// ATT MTU requested during session setup.
 pub(crate) const REQUESTED_ATT_MTU: usize = 512;
@@ -15,7 +15,19 @@ pub(crate) const TRANSPORT_CHUNK_MTU_READY: usize = 509;
 pub(crate) const TRANSPORT_CHUNK_FALLBACK: usize = 18;
 
 /// Known iDotMatrix protocol endpoints.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter, Display, SerializeDisplay)]
+///
+/// Endpoints parse from their snake-case labels.
+///
+/// ```
+/// use idm::EndpointId;
+///
+/// let endpoint: EndpointId = "write_characteristic".parse().expect("known endpoint");
+/// assert_eq!(EndpointId::WriteCharacteristic, endpoint);
+/// assert!("telemetry".parse::<EndpointId>().is_err());
+/// ```
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter, EnumString, Display, SerializeDisplay,
+)]
 pub enum EndpointId {
     /// iDotMatrix primary control service.
     #[strum(to_string = "control_service")]
@@ -28,6 +40,46 @@ pub enum EndpointId {
     ReadNotifyCharacteristic,
 }
 
+impl EndpointId {
+    /// Returns every known protocol endpoint.
+    ///
+    /// ```
+    /// use idm::EndpointId;
+    ///
+    /// assert_eq!(3, EndpointId::all().count());
+    /// ```
+    pub fn all() -> impl Iterator<Item = Self> {
+        known_endpoints()
+    }
+
+    /// Returns the human-readable endpoint name.
+    ///
+    /// ```
+    /// use idm::EndpointId;
+    ///
+    /// assert_eq!("iDotMatrix write data", EndpointId::WriteCharacteristic.name());
+    /// ```
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        endpoint_metadata(self).name()
+    }
+
+    /// Returns the endpoint's GATT UUID.
+    ///
+    /// ```
+    /// use idm::EndpointId;
+    ///
+    /// assert_eq!(
+    ///     "0000fa02-0000-1000-8000-00805f9b34fb",
+    ///     EndpointId::WriteCharacteristic.uuid(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn uuid(self) -> &'static str {
+        endpoint_metadata(self).uuid()
+    }
+}
+
 /// Endpoint category in GATT.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Display)]
 pub(crate) enum EndpointKind {
@@ -125,4 +177,30 @@ mod tests {
         let write = endpoint_metadata(EndpointId::WriteCharacteristic);
         assert_eq!("iDotMatrix write data", write.name());
     }
+
+    #[test]
+    fn endpoint_accessors_label_every_endpoint() {
+        let endpoints = EndpointId::all().collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                EndpointId::ControlService,
+                EndpointId::WriteCharacteristic,
+                EndpointId::ReadNotifyCharacteristic,
+            ],
+            endpoints
+        );
+        for endpoint in endpoints {
+            assert!(!endpoint.name().is_empty(), "{endpoint} name");
+            assert!(!endpoint.uuid().is_empty(), "{endpoint} uuid");
+            assert_eq!(endpoint_metadata(endpoint).uuid(), endpoint.uuid());
+        }
+    }
+
+    #[test]
+    fn endpoint_labels_parse_back_to_the_same_endpoint() {
+        for endpoint in EndpointId::all() {
+            assert_eq!(Ok(endpoint), endpoint.to_string().parse::<EndpointId>());
+        }
+    }
 }
@@ -0,0 +1,218 @@
+//! Byte layouts of the framed headers, as offsets, widths and byte order.
+//!
+//! Every multi-byte field in these headers is little-endian. That does not
+//! hold for the whole protocol: the text metadata character count follows
+//! [`TextCharCountEndian`](crate::TextCharCountEndian) and may be big-endian.
+//! Encoders and decoders read and write through these descriptors instead of
+//! indexing header bytes directly.
+
+use std::ops::Range;
+
+/// One-byte field at a fixed offset.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct U8Field {
+    offset: usize,
+}
+
+impl U8Field {
+    const fn at(offset: usize) -> Self {
+        Self { offset }
+    }
+
+    /// Returns the byte range the field occupies.
+    pub(crate) const fn range(self) -> Range<usize> {
+        self.offset..self.offset + 1
+    }
+
+    /// Reads the field from `bytes`, which must cover [`Self::range`].
+    pub(crate) fn read(self, bytes: &[u8]) -> u8 {
+        bytes[self.offset]
+    }
+
+    /// Writes `value` into `bytes`, which must cover [`Self::range`].
+    pub(crate) fn write(self, bytes: &mut [u8], value: u8) {
+        bytes[self.range()].copy_from_slice(&[value]);
+    }
+}
+
+/// Little-endian `u16` field at a fixed offset.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct U16LeField {
+    offset: usize,
+}
+
+impl U16LeField {
+    const fn at(offset: usize) -> Self {
+        Self { offset }
+    }
+
+    /// Returns the byte range the field occupies.
+    pub(crate) const fn range(self) -> Range<usize> {
+        self.offset..self.offset + 2
+    }
+
+    /// Reads the field from `bytes`, which must cover [`Self::range`].
+    pub(crate) fn read(self, bytes: &[u8]) -> u16 {
+        u16::from_le_bytes([bytes[self.offset], bytes[self.offset + 1]])
+    }
+
+    /// Writes `value` into `bytes`, which must cover [`Self::range`].
+    pub(crate) fn write(self, bytes: &mut [u8], value: u16) {
+        bytes[self.range()].copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Little-endian `u32` field at a fixed offset.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct U32LeField {
+    offset: usize,
+}
+
+impl U32LeField {
+    const fn at(offset: usize) -> Self {
+        Self { offset }
+    }
+
+    /// Returns the byte range the field occupies.
+    pub(crate) const fn range(self) -> Range<usize> {
+        self.offset..self.offset + 4
+    }
+
+    /// Reads the field from `bytes`, which must cover [`Self::range`].
+    pub(crate) fn read(self, bytes: &[u8]) -> u32 {
+        u32::from_le_bytes([
+            bytes[self.offset],
+            bytes[self.offset + 1],
+            bytes[self.offset + 2],
+            bytes[self.offset + 3],
+        ])
+    }
+
+    /// Writes `value` into `bytes`, which must cover [`Self::range`].
+    pub(crate) fn write(self, bytes: &mut [u8], value: u32) {
+        bytes[self.range()].copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Short control frame: `{len:u16le} {command_id} {command_ns} {payload...}`.
+pub(crate) struct ShortFrameLayout;
+
+impl ShortFrameLayout {
+    /// Header length before the payload.
+    pub(crate) const HEADER_LEN: usize = 4;
+    /// Total frame length, header included.
+    pub(crate) const FRAME_LEN: U16LeField = U16LeField::at(0);
+    /// Command id.
+    pub(crate) const COMMAND_ID: U8Field = U8Field::at(2);
+    /// Command namespace.
+    pub(crate) const COMMAND_NS: U8Field = U8Field::at(3);
+}
+
+/// 16-byte text, GIF, and image upload header.
+pub(crate) struct MediaHeaderLayout;
+
+impl MediaHeaderLayout {
+    /// Header length before the chunk payload.
+    pub(crate) const LEN: u16 = 16;
+    /// Block length, header included.
+    pub(crate) const BLOCK_LEN: U16LeField = U16LeField::at(0);
+    /// Media type (`0x01` GIF, `0x02` image, `0x03` text).
+    pub(crate) const MEDIA_TYPE: U8Field = U8Field::at(2);
+    /// Reserved, always `0x00`.
+    pub(crate) const RESERVED: U8Field = U8Field::at(3);
+    /// Chunk flag (`0x00` first, `0x02` continuation).
+    pub(crate) const CHUNK_FLAG: U8Field = U8Field::at(4);
+    /// Total payload length across every chunk.
+    pub(crate) const PAYLOAD_LEN: U32LeField = U32LeField::at(5);
+    /// CRC32 of the whole payload.
+    pub(crate) const CRC32: U32LeField = U32LeField::at(9);
+    /// Slideshow display duration in seconds.
+    pub(crate) const DURATION_SECONDS: U16LeField = U16LeField::at(13);
+    /// Material slot.
+    pub(crate) const SLOT: U8Field = U8Field::at(15);
+}
+
+/// 9-byte DIY transfer prefix.
+pub(crate) struct DiyPrefixLayout;
+
+impl DiyPrefixLayout {
+    /// Prefix length before the chunk payload.
+    pub(crate) const LEN: u16 = 9;
+    /// Block length, prefix included.
+    pub(crate) const BLOCK_LEN: U16LeField = U16LeField::at(0);
+    /// Command id, always `0x00`.
+    pub(crate) const COMMAND_ID: U8Field = U8Field::at(2);
+    /// Command namespace, always `0x00`.
+    pub(crate) const COMMAND_NS: U8Field = U8Field::at(3);
+    /// Chunk flag (`0x00` first, `0x02` continuation).
+    pub(crate) const CHUNK_FLAG: U8Field = U8Field::at(4);
+    /// Total payload length across every chunk.
+    pub(crate) const PAYLOAD_LEN: U32LeField = U32LeField::at(5);
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn covered_offsets(ranges: &[Range<usize>]) -> Vec<usize> {
+        let mut offsets = ranges.iter().cloned().flatten().collect::<Vec<_>>();
+        offsets.sort_unstable();
+        offsets
+    }
+
+    #[rstest]
+    #[case::short_frame(
+        vec![
+            ShortFrameLayout::FRAME_LEN.range(),
+            ShortFrameLayout::COMMAND_ID.range(),
+            ShortFrameLayout::COMMAND_NS.range(),
+        ],
+        u16::try_from(ShortFrameLayout::HEADER_LEN).expect("short header length fits u16"),
+    )]
+    #[case::media_header(
+        vec![
+            MediaHeaderLayout::BLOCK_LEN.range(),
+            MediaHeaderLayout::MEDIA_TYPE.range(),
+            MediaHeaderLayout::RESERVED.range(),
+            MediaHeaderLayout::CHUNK_FLAG.range(),
+            MediaHeaderLayout::PAYLOAD_LEN.range(),
+            MediaHeaderLayout::CRC32.range(),
+            MediaHeaderLayout::DURATION_SECONDS.range(),
+            MediaHeaderLayout::SLOT.range(),
+        ],
+        MediaHeaderLayout::LEN,
+    )]
+    #[case::diy_prefix(
+        vec![
+            DiyPrefixLayout::BLOCK_LEN.range(),
+            DiyPrefixLayout::COMMAND_ID.range(),
+            DiyPrefixLayout::COMMAND_NS.range(),
+            DiyPrefixLayout::CHUNK_FLAG.range(),
+            DiyPrefixLayout::PAYLOAD_LEN.range(),
+        ],
+        DiyPrefixLayout::LEN,
+    )]
+    fn fields_cover_every_header_byte_exactly_once(
+        #[case] ranges: Vec<Range<usize>>,
+        #[case] len: u16,
+    ) {
+        assert_eq!(
+            (0..usize::from(len)).collect::<Vec<_>>(),
+            covered_offsets(&ranges)
+        );
+    }
+
+    #[test]
+    fn multi_byte_fields_round_trip_little_endian() {
+        let mut bytes = [0_u8; 8];
+        U16LeField::at(0).write(&mut bytes, 0x1234);
+        U32LeField::at(2).write(&mut bytes, 0xAABB_CCDD);
+
+        assert_eq!([0x34, 0x12, 0xDD, 0xCC, 0xBB, 0xAA, 0x00, 0x00], bytes);
+        assert_eq!(0x1234, U16LeField::at(0).read(&bytes));
+        assert_eq!(0xAABB_CCDD, U32LeField::at(2).read(&bytes));
+    }
+}
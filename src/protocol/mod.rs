@@ -0,0 +1,9 @@
+mod endpoints;
+mod layout;
+
+pub use self::endpoints::EndpointId;
+pub(crate) use self::endpoints::{
+    MTU_READY_THRESHOLD, REQUESTED_ATT_MTU, TRANSPORT_CHUNK_FALLBACK, TRANSPORT_CHUNK_MTU_READY,
+    empty_presence_map, endpoint_metadata, known_endpoints,
+};
+pub(crate) use self::layout::{DiyPrefixLayout, MediaHeaderLayout, ShortFrameLayout};
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+/// Braille dots matching indicatif's default spinner, followed by the
+/// finished-state character.
+const UNICODE_TICK_CHARS: &str = "⠁⠂⠄⡀⢀⠠⠐⠈ ";
+/// Classic ASCII spinner, followed by the finished-state character.
+const ASCII_TICK_CHARS: &str = "|/-\\ ";
+const DEFAULT_TICK_INTERVAL: Duration = Duration::from_millis(90);
+
+/// Character set used to animate progress spinners.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum SpinnerCharset {
+    /// Braille dots, for terminals with Unicode-capable fonts.
+    #[default]
+    Unicode,
+    /// `|/-\` frames, for terminals and screen readers limited to ASCII.
+    Ascii,
+}
+
+/// Appearance of the progress spinner shown on interactive terminals.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SpinnerStyle {
+    charset: SpinnerCharset,
+    tick_interval: Duration,
+}
+
+impl Default for SpinnerStyle {
+    fn default() -> Self {
+        Self::new(SpinnerCharset::default())
+    }
+}
+
+impl SpinnerStyle {
+    /// Creates a spinner style using `charset` and the default tick interval.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::{SpinnerCharset, SpinnerStyle};
+    ///
+    /// let style = SpinnerStyle::new(SpinnerCharset::Ascii);
+    /// assert_eq!(SpinnerCharset::Ascii, style.charset());
+    /// assert_eq!(Duration::from_millis(90), style.tick_interval());
+    /// ```
+    #[must_use]
+    pub const fn new(charset: SpinnerCharset) -> Self {
+        Self {
+            charset,
+            tick_interval: DEFAULT_TICK_INTERVAL,
+        }
+    }
+
+    /// Returns a copy of this style that advances one frame per `tick_interval`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use idm::SpinnerStyle;
+    ///
+    /// let style = SpinnerStyle::default().with_tick_interval(Duration::from_millis(250));
+    /// assert_eq!(Duration::from_millis(250), style.tick_interval());
+    /// ```
+    #[must_use]
+    pub const fn with_tick_interval(mut self, tick_interval: Duration) -> Self {
+        self.tick_interval = tick_interval;
+        self
+    }
+
+    /// Returns the spinner character set.
+    #[must_use]
+    pub const fn charset(self) -> SpinnerCharset {
+        self.charset
+    }
+
+    /// Returns the interval between spinner frames.
+    #[must_use]
+    pub const fn tick_interval(self) -> Duration {
+        self.tick_interval
+    }
+
+    /// Returns the animation frames in display order.
+    ///
+    /// ```
+    /// use idm::{SpinnerCharset, SpinnerStyle};
+    ///
+    /// let frames: String = SpinnerStyle::new(SpinnerCharset::Ascii).frames().collect();
+    /// assert_eq!("|/-\\", frames);
+    /// ```
+    pub fn frames(self) -> impl Iterator<Item = char> + Clone {
+        let tick_chars = self.tick_chars();
+        let frame_count = tick_chars.chars().count() - 1;
+        tick_chars.chars().take(frame_count)
+    }
+
+    /// Returns the indicatif tick string: every frame plus the finished-state
+    /// character.
+    pub(crate) const fn tick_chars(self) -> &'static str {
+        match self.charset {
+            SpinnerCharset::Unicode => UNICODE_TICK_CHARS,
+            SpinnerCharset::Ascii => ASCII_TICK_CHARS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn ascii_spinner_cycles_through_expected_characters() {
+        let frames: Vec<char> = SpinnerStyle::new(SpinnerCharset::Ascii)
+            .frames()
+            .cycle()
+            .take(6)
+            .collect();
+        assert_eq!(vec!['|', '/', '-', '\\', '|', '/'], frames);
+    }
+
+    #[rstest]
+    #[case::unicode(SpinnerCharset::Unicode)]
+    #[case::ascii(SpinnerCharset::Ascii)]
+    fn tick_chars_are_accepted_by_indicatif(#[case] charset: SpinnerCharset) {
+        let style = SpinnerStyle::new(charset);
+        let _ = indicatif::ProgressStyle::default_spinner().tick_chars(style.tick_chars());
+        assert!(style.frames().all(|frame| !frame.is_whitespace()));
+    }
+}
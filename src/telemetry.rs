@@ -18,20 +18,23 @@ use tracing_subscriber::util::SubscriberInitExt;
 
 use crate::cli::OutputFormat;
 use crate::error::TelemetryError;
+use crate::spinner::SpinnerStyle;
 
 static TRACING_INITIALISED: OnceLock<Result<(), TelemetryError>> = OnceLock::new();
 const PROGRESS_TEMPLATE: &str = "{spinner:.cyan.bold} {msg}";
 
 /// Initialises structured logging and OpenTelemetry tracing support.
 ///
-/// When `output_format` is [`OutputFormat::Json`], the non-interactive path is
-/// forced regardless of terminal state so progress bars do not interfere with
-/// machine-readable output.
+/// Unless `output_format` is [`OutputFormat::Pretty`], the non-interactive path
+/// is forced regardless of terminal state so progress bars do not interfere with
+/// machine-readable or single-line output. `spinner` controls how progress
+/// spans animate on the interactive path.
 pub(crate) fn initialise_tracing(
     service_name: &str,
     interactive_terminal: bool,
     log_level_override: Option<LevelFilter>,
     output_format: OutputFormat,
+    spinner: SpinnerStyle,
 ) -> Result<(), &'static TelemetryError> {
     TRACING_INITIALISED
         .get_or_init(|| {
@@ -40,6 +43,7 @@ pub(crate) fn initialise_tracing(
                 interactive_terminal,
                 log_level_override,
                 output_format,
+                spinner,
             )
         })
         .as_ref()
@@ -51,6 +55,7 @@ fn initialise_tracing_once(
     interactive_terminal: bool,
     log_level_override: Option<LevelFilter>,
     output_format: OutputFormat,
+    spinner: SpinnerStyle,
 ) -> Result<(), TelemetryError> {
     let tracer_provider = opentelemetry_sdk::trace::SdkTracerProvider::builder().build();
     let tracer = tracer_provider.tracer(service_name.to_owned());
@@ -62,8 +67,8 @@ fn initialise_tracing_once(
 
     if is_interactive {
         let indicatif_layer = IndicatifLayer::new()
-            .with_progress_style(progress_style())
-            .with_tick_settings(progress_tick_settings());
+            .with_progress_style(progress_style(spinner))
+            .with_tick_settings(progress_tick_settings(spinner));
         let formatting_layer = fmt::layer()
             .pretty()
             .with_target(false)
@@ -100,14 +105,15 @@ fn configured_log_filter(log_level_override: Option<LevelFilter>) -> EnvFilter {
     }
 }
 
-fn progress_style() -> ProgressStyle {
+fn progress_style(spinner: SpinnerStyle) -> ProgressStyle {
     ProgressStyle::with_template(PROGRESS_TEMPLATE)
         .unwrap_or_else(|_error| ProgressStyle::default_spinner())
+        .tick_chars(spinner.tick_chars())
 }
 
-fn progress_tick_settings() -> TickSettings {
+fn progress_tick_settings(spinner: SpinnerStyle) -> TickSettings {
     TickSettings {
-        default_tick_interval: Some(std::time::Duration::from_millis(90)),
+        default_tick_interval: Some(spinner.tick_interval()),
         ..TickSettings::default()
     }
 }
@@ -1,4 +1,25 @@
+use std::env;
 use std::io::{self, IsTerminal};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use idm_macros::progress;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use crate::cli::OutputFormat;
+use crate::error::InteractionError;
+use crate::hw::{
+    ConnectionObserver, DeviceConnectAttempt, DeviceSession, EndpointProbe, HardwareClient,
+    ModelResolutionConfig, NoopConnectionObserver,
+};
+use crate::spinner::SpinnerStyle;
+use crate::telemetry;
+const CONNECT_PROGRESS_MESSAGE: &str = "Scanning for iDotMatrix devices and connecting";
+const CONNECT_ALL_PROGRESS_MESSAGE: &str = "Scanning for every matching iDotMatrix device";
+
+const NO_COLOUR_ENV: &str = "NO_COLOR";
 
 /// Provides terminal capability checks used by CLI rendering.
 pub trait TerminalClient: Send + Sync {
@@ -7,11 +28,114 @@ pub trait TerminalClient: Send + Sync {
 
     /// Returns whether standard error should be treated as a terminal.
     fn stderr_is_terminal(&self) -> bool;
+
+    /// Returns whether styled output on standard output may use ANSI colour.
+    ///
+    /// Defaults to colouring only when standard output is a terminal.
+    fn stdout_supports_colour(&self) -> bool {
+        self.stdout_is_terminal()
+    }
+
+    /// Returns the spinner appearance for progress shown on standard error.
+    fn spinner_style(&self) -> SpinnerStyle {
+        SpinnerStyle::default()
+    }
+
+    /// Wraps `client` so connecting through it drives a terminal spinner.
+    ///
+    /// The first connect installs the same indicatif progress layer the CLI
+    /// uses, styled with [`TerminalClient::spinner_style`], when standard error
+    /// is a terminal. If the application already installed its own tracing
+    /// subscriber that subscriber is left in place and receives the progress
+    /// spans instead. Upload handlers report progress through the same spans,
+    /// so they animate too once the layer is installed.
+    ///
+    /// The returned client delegates to `client`, which stays usable on its
+    /// own without this wrapper.
+    ///
+    /// ```
+    /// # async fn demo() -> anyhow::Result<()> {
+    /// use idm::{HardwareClient, SystemTerminalClient, TerminalClient};
+    ///
+    /// let fake = idm::FakeArgs::builder()
+    ///     .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+    ///     .build();
+    /// let client = SystemTerminalClient::default().wrap(idm::fake_hardware_client(fake));
+    /// let session = client.connect_first_device("IDM-").await?;
+    /// session.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn wrap(&self, client: Box<dyn HardwareClient>) -> Box<dyn HardwareClient> {
+        Box::new(ProgressHardwareClient {
+            inner: client,
+            interactive: self.stderr_is_terminal(),
+            spinner_style: self.spinner_style(),
+        })
+    }
 }
 
 /// Terminal capability provider backed by the host process stdio streams.
+///
+/// Colour is only used when standard output is a terminal, so piped output
+/// never carries ANSI sequences. Forcing colour overrides both that check and
+/// the `NO_COLOR` environment variable; disabling colour explicitly wins over
+/// everything.
 #[derive(Debug, Default)]
-pub struct SystemTerminalClient;
+pub struct SystemTerminalClient {
+    no_colour: bool,
+    force_colour: bool,
+    spinner_style: SpinnerStyle,
+}
+
+impl SystemTerminalClient {
+    /// Creates a terminal client, optionally forcing plain uncoloured output.
+    ///
+    /// ```
+    /// use idm::{SystemTerminalClient, TerminalClient};
+    ///
+    /// let terminal = SystemTerminalClient::new(true);
+    /// assert!(!terminal.stdout_supports_colour());
+    /// ```
+    #[must_use]
+    pub fn new(no_colour: bool) -> Self {
+        Self {
+            no_colour,
+            force_colour: false,
+            spinner_style: SpinnerStyle::default(),
+        }
+    }
+
+    /// Returns a copy of this client that colours output even when standard
+    /// output is not a terminal.
+    ///
+    /// ```
+    /// use idm::{SystemTerminalClient, TerminalClient};
+    ///
+    /// let terminal = SystemTerminalClient::default().with_force_colour(true);
+    /// assert!(terminal.stdout_supports_colour());
+    /// ```
+    #[must_use]
+    pub fn with_force_colour(mut self, force_colour: bool) -> Self {
+        self.force_colour = force_colour;
+        self
+    }
+
+    /// Returns a copy of this client that animates progress with `spinner_style`.
+    ///
+    /// ```
+    /// use idm::{SpinnerCharset, SpinnerStyle, SystemTerminalClient, TerminalClient};
+    ///
+    /// let terminal = SystemTerminalClient::default()
+    ///     .with_spinner_style(SpinnerStyle::new(SpinnerCharset::Ascii));
+    /// assert_eq!(SpinnerCharset::Ascii, terminal.spinner_style().charset());
+    /// ```
+    #[must_use]
+    pub fn with_spinner_style(mut self, spinner_style: SpinnerStyle) -> Self {
+        self.spinner_style = spinner_style;
+        self
+    }
+}
 
 impl TerminalClient for SystemTerminalClient {
     fn stdout_is_terminal(&self) -> bool {
@@ -21,4 +145,190 @@ impl TerminalClient for SystemTerminalClient {
     fn stderr_is_terminal(&self) -> bool {
         io::stderr().is_terminal()
     }
+
+    fn stdout_supports_colour(&self) -> bool {
+        if self.no_colour {
+            return false;
+        }
+        if self.force_colour {
+            return true;
+        }
+        !no_colour_requested_by_env() && self.stdout_is_terminal()
+    }
+
+    fn spinner_style(&self) -> SpinnerStyle {
+        self.spinner_style
+    }
+}
+
+/// Hardware client that reports connection progress to the terminal.
+struct ProgressHardwareClient {
+    inner: Box<dyn HardwareClient>,
+    interactive: bool,
+    spinner_style: SpinnerStyle,
+}
+
+#[async_trait]
+impl HardwareClient for ProgressHardwareClient {
+    async fn adapter_names(&self) -> Result<Vec<String>, InteractionError> {
+        self.inner.adapter_names().await
+    }
+
+    fn model_resolution(&self) -> ModelResolutionConfig {
+        self.inner.model_resolution()
+    }
+
+    async fn connect_first_device(
+        self: Box<Self>,
+        name_prefix: &str,
+    ) -> Result<DeviceSession, InteractionError> {
+        let Self {
+            inner,
+            interactive,
+            spinner_style,
+        } = *self;
+        initialise_progress_tracing(interactive, spinner_style);
+        connect_with_progress(inner, name_prefix, Arc::new(NoopConnectionObserver)).await
+    }
+
+    async fn connect_first_device_observed(
+        self: Box<Self>,
+        name_prefix: &str,
+        observer: Arc<dyn ConnectionObserver>,
+    ) -> Result<DeviceSession, InteractionError> {
+        let Self {
+            inner,
+            interactive,
+            spinner_style,
+        } = *self;
+        initialise_progress_tracing(interactive, spinner_style);
+        connect_with_progress(inner, name_prefix, observer).await
+    }
+
+    async fn connect_all_devices(
+        self: Box<Self>,
+        name_prefix: &str,
+        scan_window: Duration,
+    ) -> Result<Vec<DeviceConnectAttempt>, InteractionError> {
+        let Self {
+            inner,
+            interactive,
+            spinner_style,
+        } = *self;
+        initialise_progress_tracing(interactive, spinner_style);
+        connect_all_with_progress(inner, name_prefix, scan_window).await
+    }
+
+    async fn probe_first_device(
+        self: Box<Self>,
+        name_prefix: &str,
+    ) -> Result<EndpointProbe, InteractionError> {
+        let Self {
+            inner,
+            interactive,
+            spinner_style,
+        } = *self;
+        initialise_progress_tracing(interactive, spinner_style);
+        probe_with_progress(inner, name_prefix).await
+    }
+}
+
+fn initialise_progress_tracing(interactive: bool, spinner_style: SpinnerStyle) {
+    if let Err(error) = telemetry::initialise_tracing(
+        "idm",
+        interactive,
+        None,
+        OutputFormat::Pretty,
+        spinner_style,
+    ) {
+        debug!(
+            ?error,
+            "keeping the existing tracing subscriber for progress spans"
+        );
+    }
+}
+
+#[progress(
+    message = CONNECT_PROGRESS_MESSAGE,
+    finished = match result {
+        Ok(_session) => format!("{} Connected", "✓".green()),
+        Err(_error) => format!("{} Connection failed", "✗".red()),
+    },
+    skip(inner, observer),
+    level = "info",
+    fields(prefix = name_prefix, progress_message = CONNECT_PROGRESS_MESSAGE),
+)]
+async fn connect_with_progress(
+    inner: Box<dyn HardwareClient>,
+    name_prefix: &str,
+    observer: Arc<dyn ConnectionObserver>,
+) -> Result<DeviceSession, InteractionError> {
+    inner
+        .connect_first_device_observed(name_prefix, observer)
+        .await
+}
+
+#[progress(
+    message = CONNECT_ALL_PROGRESS_MESSAGE,
+    finished = match result {
+        Ok(attempts) => format!("{} Found {} device(s)", "✓".green(), attempts.len()),
+        Err(_error) => format!("{} Discovery failed", "✗".red()),
+    },
+    skip(inner),
+    level = "info",
+    fields(prefix = name_prefix, progress_message = CONNECT_ALL_PROGRESS_MESSAGE),
+)]
+async fn connect_all_with_progress(
+    inner: Box<dyn HardwareClient>,
+    name_prefix: &str,
+    scan_window: Duration,
+) -> Result<Vec<DeviceConnectAttempt>, InteractionError> {
+    inner.connect_all_devices(name_prefix, scan_window).await
+}
+
+#[progress(
+    message = CONNECT_PROGRESS_MESSAGE,
+    finished = match result {
+        Ok(_probe) => format!("{} Probed endpoints", "✓".green()),
+        Err(_error) => format!("{} Connection failed", "✗".red()),
+    },
+    skip(inner),
+    level = "info",
+    fields(prefix = name_prefix, progress_message = CONNECT_PROGRESS_MESSAGE),
+)]
+async fn probe_with_progress(
+    inner: Box<dyn HardwareClient>,
+    name_prefix: &str,
+) -> Result<EndpointProbe, InteractionError> {
+    inner.probe_first_device(name_prefix).await
+}
+
+fn no_colour_requested_by_env() -> bool {
+    env::var_os(NO_COLOUR_ENV).is_some_and(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn explicit_no_colour_disables_stdout_colour() {
+        let terminal = SystemTerminalClient::new(true);
+        assert_eq!(false, terminal.stdout_supports_colour());
+    }
+
+    #[rstest]
+    #[case::forced(false, true, true)]
+    #[case::disabled_wins(true, true, false)]
+    fn explicit_colour_flags_override_terminal_detection(
+        #[case] no_colour: bool,
+        #[case] force_colour: bool,
+        #[case] expected: bool,
+    ) {
+        let terminal = SystemTerminalClient::new(no_colour).with_force_colour(force_colour);
+        assert_eq!(expected, terminal.stdout_supports_colour());
+    }
 }
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+/// Non-fatal condition noticed while a command ran.
+///
+/// Warnings are collected into command outcomes so library callers can see
+/// them without installing a tracing subscriber.
+///
+/// ```
+/// use idm::Warning;
+///
+/// let warning = Warning::GifFramesTruncated { dropped: 8 };
+/// assert_eq!(
+///     "gif frames beyond the 64-frame limit were dropped: 8 frame(s)",
+///     warning.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, derive_more::Display)]
+pub enum Warning {
+    /// Source GIF frames past the upload frame limit were not sent.
+    #[display("gif frames beyond the 64-frame limit were dropped: {dropped} frame(s)")]
+    GifFramesTruncated {
+        /// Number of source frames left out of the prepared GIF.
+        dropped: usize,
+    },
+    /// Transport writes failed and the session fell back to smaller chunks.
+    #[display("transport degraded: writes fell back to {chunk_size}-byte chunks")]
+    TransportDegraded {
+        /// Transport chunk size in use when the command finished.
+        chunk_size: usize,
+    },
+    /// Uploaded text takes longer than the configured threshold to scroll once.
+    #[display(
+        "text takes about {} to scroll once, over the {} threshold",
+        humantime::format_duration(*estimated),
+        humantime::format_duration(*threshold)
+    )]
+    TextScrollTooLong {
+        /// Estimated time for the text to scroll across the panel once.
+        estimated: Duration,
+        /// Threshold the estimate was checked against.
+        threshold: Duration,
+    },
+}
@@ -1,3 +1,4 @@
+use assert_matches::assert_matches;
 use insta::assert_snapshot;
 use std::time::{Duration, Instant};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -6,6 +7,7 @@ use clap::Parser;
 use clap::error::ErrorKind;
 use image::ImageEncoder;
 use pretty_assertions::assert_eq;
+use rstest::rstest;
 
 #[derive(Debug, Default)]
 struct FakeTerminalClient;
@@ -21,6 +23,13 @@ impl idm::TerminalClient for FakeTerminalClient {
 }
 
 async fn run_with_parsed_args(args: idm::Args) -> anyhow::Result<String> {
+    run_with_parsed_args_and_format(args, idm::OutputFormat::Pretty).await
+}
+
+async fn run_with_parsed_args_and_format(
+    args: idm::Args,
+    output_format: idm::OutputFormat,
+) -> anyhow::Result<String> {
     let mut output = Vec::new();
     let model_resolution = args.model_resolution();
     let (command, maybe_fake_args) = args.into_command_and_fake_args()?;
@@ -33,7 +42,7 @@ async fn run_with_parsed_args(args: idm::Args) -> anyhow::Result<String> {
         &mut output,
         &FakeTerminalClient,
         hardware_client,
-        idm::OutputFormat::Pretty,
+        output_format,
     )
     .await?;
     Ok(String::from_utf8(output)?)
@@ -49,7 +58,7 @@ async fn inspect_command_prints_gatt_details_from_fake_backend() -> anyhow::Resu
     let fake = idm::FakeArgs::builder()
         .scan("hci1|00:11:22|Speaker|-65;hci0|AA:BB:CC|IDM-Clock|-43")?
         .build();
-    let args = idm::Args::new(idm::Command::Inspect).with_fake(fake);
+    let args = idm::Args::new(idm::Command::Inspect(idm::InspectArgs::default())).with_fake(fake);
 
     let stdout = run_with_parsed_args(args).await?;
     assert_snapshot!("inspect_command_stdout", stdout.trim_end());
@@ -57,6 +66,61 @@ async fn inspect_command_prints_gatt_details_from_fake_backend() -> anyhow::Resu
     Ok(())
 }
 
+#[tokio::test]
+async fn inspect_json_includes_connection_diagnostics_from_fake_backend() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43|5452007004010200010520002000")?
+        .build();
+    let args = idm::Args::new(idm::Command::Inspect(idm::InspectArgs::default())).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!("inspect", report["command"]);
+    let scan_identity = report["data"]["session_metadata"]["connection_diagnostics"]["sections"]
+        .as_array()
+        .and_then(|sections| {
+            sections
+                .iter()
+                .find(|section| section["id"] == "scan_identity")
+        })
+        .expect("inspect JSON should include a scan identity diagnostics section");
+
+    assert_eq!(
+        &serde_json::json!({
+            "id": "scan_identity",
+            "name": "Scan identity",
+            "rows": [
+                { "label": "Identity present", "value": "yes" },
+                { "label": "Shape", "value": "4" },
+                { "label": "CID", "value": "1" },
+                { "label": "PID", "value": "5" },
+            ],
+        }),
+        scan_identity
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inspect_compact_prints_single_device_line() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci1|00:11:22|Speaker|-65;hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let args = idm::Args::new(idm::Command::Inspect(idm::InspectArgs::default())).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Compact).await?;
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(1, lines.len());
+    assert!(
+        lines[0].starts_with("IDM-Clock AA:BB:CC -43dBm "),
+        "unexpected compact line: {}",
+        lines[0]
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn listen_command_reads_once_then_streams_notifications() -> anyhow::Result<()> {
     let fake = idm::FakeArgs::builder()
@@ -72,12 +136,374 @@ async fn listen_command_reads_once_then_streams_notifications() -> anyhow::Resul
     Ok(())
 }
 
+#[tokio::test]
+async fn listen_json_summary_tallies_each_event_type() -> anyhow::Result<()> {
+    let notifications = vec![
+        idm::ListenNotification::Event(idm::NotifyEvent::NextPackage(idm::TransferFamily::Gif)),
+        idm::ListenNotification::Event(idm::NotifyEvent::NextPackage(idm::TransferFamily::Gif)),
+        idm::ListenNotification::Event(idm::NotifyEvent::Error(idm::TransferFamily::Gif, 0x02)),
+        idm::ListenNotification::Raw(vec![0xAA, 0x55]),
+        idm::ListenNotification::Event(idm::NotifyEvent::Finished(idm::TransferFamily::Gif)),
+    ];
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(
+            idm::ListenScenario::builder()
+                .notifications(notifications)
+                .build(),
+        )
+        .build();
+    let args = idm::Args::new(idm::Command::Listen(idm::ListenArgs::new(Some(5)))).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let summary = serde_json::Deserializer::from_str(&stdout)
+        .into_iter::<serde_json::Value>()
+        .collect::<Result<Vec<_>, _>>()?
+        .pop()
+        .expect("listen JSON should end with the command outcome");
+    assert_eq!("listen", summary["command"]);
+
+    assert_eq!(
+        serde_json::json!({
+            "next_package": 2,
+            "finished": 1,
+            "error": 1,
+            "schedule_setup": 0,
+            "schedule_master_switch": 0,
+            "led_info": 0,
+            "screen_light_timeout": 0,
+            "unknown": 1,
+            "decode_error": 0,
+        }),
+        summary["data"]["event_counts"]
+    );
+
+    Ok(())
+}
+
+#[rstest]
+#[case::included(true, Some("0500010003"))]
+#[case::omitted_by_default(false, None)]
+#[tokio::test]
+async fn listen_json_includes_raw_payload_hex_when_requested(
+    #[case] include_raw: bool,
+    #[case] expected_raw: Option<&str>,
+) -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(
+            idm::ListenScenario::builder()
+                .notifications(vec![idm::ListenNotification::Raw(vec![
+                    0x05, 0x00, 0x01, 0x00, 0x03,
+                ])])
+                .build(),
+        )
+        .build();
+    let args = idm::Args::new(idm::Command::Listen(
+        idm::ListenArgs::new(Some(1)).with_include_raw(include_raw),
+    ))
+    .with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let documents = serde_json::Deserializer::from_str(&stdout)
+        .into_iter::<serde_json::Value>()
+        .collect::<Result<Vec<_>, _>>()?;
+    let notification = documents
+        .iter()
+        .find(|document| document["type"] == "notification")
+        .expect("listen JSON should include the replayed notification");
+
+    assert_eq!(
+        expected_raw,
+        notification.get("raw").and_then(serde_json::Value::as_str)
+    );
+    assert_eq!(
+        idm::NotifyEvent::Finished(idm::TransferFamily::Gif).to_string(),
+        notification["event_label"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn listen_ndjson_streams_one_line_per_notification_then_the_summary() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(
+            idm::ListenScenario::builder()
+                .notifications(vec![
+                    idm::ListenNotification::Event(idm::NotifyEvent::Finished(
+                        idm::TransferFamily::Gif,
+                    )),
+                    idm::ListenNotification::Raw(Vec::new()),
+                ])
+                .build(),
+        )
+        .build();
+    let args = idm::Args::new(idm::Command::Listen(
+        idm::ListenArgs::new(Some(2)).with_include_raw(true),
+    ))
+    .with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Ndjson).await?;
+    let lines = stdout
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(4, lines.len(), "{stdout}");
+    assert_eq!("ready", lines[0]["type"]);
+    assert_eq!(
+        serde_json::json!({
+            "type": "notification",
+            "index": 1,
+            "event_label": idm::NotifyEvent::Finished(idm::TransferFamily::Gif).to_string(),
+            "raw": "0500010003",
+        }),
+        lines[1]
+    );
+    assert_eq!(
+        serde_json::json!({
+            "type": "decode_error",
+            "index": 2,
+            "error": {
+                "kind": "empty_payload",
+                "message": "notification payload was empty",
+            },
+            "raw": "",
+        }),
+        lines[2]
+    );
+    assert_eq!("listen", lines[3]["command"]);
+    assert_eq!(1, lines[3]["data"]["event_counts"]["decode_error"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn listen_auto_advance_is_rejected_on_a_multi_thread_runtime() -> anyhow::Result<()> {
+    let args = idm::Args::try_parse_from([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "--listen-auto-advance",
+        "10ms",
+        "listen",
+        "--max-notifications",
+        "1",
+    ])?;
+
+    let error = run_with_parsed_args(args)
+        .await
+        .expect_err("auto-advance should be rejected on a multi-thread runtime");
+
+    assert!(
+        format!("{error:#}").contains("current-thread runtime"),
+        "{error:#}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn listen_endpoint_streams_from_a_notifiable_write_characteristic() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(idm::ListenFixture::GifTransferHappyPath)
+        .write_notify_delivery(idm::NotifyDelivery::Notify)
+        .build();
+    let args = idm::Args::new(idm::Command::Listen(
+        idm::ListenArgs::new(Some(2)).with_endpoint(idm::EndpointId::WriteCharacteristic),
+    ))
+    .with_fake(fake);
+
+    let stdout = run_with_parsed_args(args).await?;
+    assert!(
+        stdout.contains("0000fa02-0000-1000-8000-00805f9b34fb"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("[0002]"), "{stdout}");
+    assert!(
+        stdout.contains("Stopped: reached max notifications (2)"),
+        "{stdout}"
+    );
+
+    Ok(())
+}
+
+#[rstest]
+#[case::service("control_service")]
+#[case::write_only("write_characteristic")]
+#[tokio::test]
+async fn listen_endpoint_rejects_endpoints_without_notify(
+    #[case] endpoint: &str,
+) -> anyhow::Result<()> {
+    let parsed_args = idm::Args::try_parse_from([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "listen",
+        "--max-notifications",
+        "1",
+        "--endpoint",
+        endpoint,
+    ])?;
+
+    let error = run_with_parsed_args(parsed_args)
+        .await
+        .expect_err("listening on an endpoint without notify should fail");
+    assert_matches!(
+        error.downcast_ref::<idm::InteractionError>(),
+        Some(idm::InteractionError::EndpointNotSubscribable { .. })
+    );
+
+    Ok(())
+}
+
+async fn run_listen_capturing_output(
+    notifications: Vec<idm::ListenNotification>,
+) -> anyhow::Result<(String, anyhow::Result<()>)> {
+    let limit = notifications.len();
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(
+            idm::ListenScenario::builder()
+                .notifications(notifications)
+                .build(),
+        )
+        .build();
+    let args = idm::Args::new(idm::Command::Listen(
+        idm::ListenArgs::new(Some(limit))
+            .with_errors_only(true)
+            .with_fail_on_error(true),
+    ))
+    .with_fake(fake);
+    let (command, fake_args) = args.into_command_and_fake_args()?;
+    let fake_args = fake_args.expect("listen test should use the fake backend");
+
+    let mut output = Vec::new();
+    let result = idm::run_with_clients(
+        command,
+        &mut output,
+        &FakeTerminalClient,
+        idm::fake_hardware_client(fake_args),
+        idm::OutputFormat::Pretty,
+    )
+    .await;
+    Ok((String::from_utf8(output)?, result))
+}
+
+#[tokio::test]
+async fn listen_fail_on_error_prints_only_errors_and_fails() -> anyhow::Result<()> {
+    let (stdout, result) = run_listen_capturing_output(vec![
+        idm::ListenNotification::Event(idm::NotifyEvent::NextPackage(idm::TransferFamily::Gif)),
+        idm::ListenNotification::Event(idm::NotifyEvent::Error(idm::TransferFamily::Gif, 0x02)),
+        idm::ListenNotification::Event(idm::NotifyEvent::Finished(idm::TransferFamily::Gif)),
+    ])
+    .await?;
+
+    let error = result.expect_err("a received transfer error should fail the command");
+    assert_eq!(
+        "received 1 transfer error notification(s)",
+        format!("{error:#}")
+    );
+    assert!(stdout.contains("[0002] GIF error (0x02)"), "{stdout}");
+    assert!(!stdout.contains("[0001]"), "{stdout}");
+    assert!(!stdout.contains("[0003]"), "{stdout}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn listen_fail_on_error_succeeds_without_errors() -> anyhow::Result<()> {
+    let (stdout, result) = run_listen_capturing_output(vec![
+        idm::ListenNotification::Event(idm::NotifyEvent::NextPackage(idm::TransferFamily::Gif)),
+        idm::ListenNotification::Event(idm::NotifyEvent::Finished(idm::TransferFamily::Gif)),
+    ])
+    .await?;
+
+    result?;
+    assert!(!stdout.contains("[0001]"), "{stdout}");
+    assert!(
+        stdout.contains("Stopped: reached max notifications (2)"),
+        "{stdout}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fake_fixture_files_drive_listen_command() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let fixture_path = |kind: &str| {
+        std::env::temp_dir().join(format!(
+            "idm-fixture-{kind}-{}-{timestamp}.txt",
+            std::process::id()
+        ))
+    };
+    let scan_path = fixture_path("scan");
+    let listen_path = fixture_path("listen");
+    std::fs::write(
+        &scan_path,
+        "hci1|00:11:22|Speaker|-65\n\nname=IDM-Clock,addr=AA:BB:CC,rssi=-43\n",
+    )?;
+    std::fs::write(&listen_path, "0500010001\n0500010003\n")?;
+
+    let scan_arg = scan_path.display().to_string();
+    let listen_arg = listen_path.display().to_string();
+    let parsed_args = idm::Args::try_parse_from([
+        "idm",
+        "--fake",
+        "--fake-scan-file",
+        scan_arg.as_str(),
+        "--fake-listen-file",
+        listen_arg.as_str(),
+        "listen",
+        "--max-notifications",
+        "2",
+    ])?;
+    let stdout = run_with_parsed_args_and_format(parsed_args, idm::OutputFormat::Json).await?;
+    let summary = serde_json::Deserializer::from_str(&stdout)
+        .into_iter::<serde_json::Value>()
+        .collect::<Result<Vec<_>, _>>()?
+        .pop()
+        .expect("listen JSON should end with the command outcome");
+
+    assert_eq!("IDM-Clock", summary["data"]["device"]["local_name"]);
+    assert_eq!(2, summary["data"]["received_notifications"]);
+
+    std::fs::remove_file(scan_path)?;
+    std::fs::remove_file(listen_path)?;
+    Ok(())
+}
+
+#[test]
+fn scan_scenario_from_file_reports_missing_file() {
+    let missing =
+        std::env::temp_dir().join(format!("idm-fixture-missing-{}.txt", std::process::id()));
+
+    let result = idm::ScanScenario::from_file(&missing);
+
+    assert!(matches!(
+        result,
+        Err(idm::FixtureError::ReadFile { path, .. }) if path == missing
+    ));
+}
+
 #[test]
 fn inspect_command_fails_for_invalid_fixture() {
     let result = idm::FakeArgs::builder().scan("invalid-record");
     assert!(matches!(
         result,
-        Err(idm::FixtureError::InvalidRecordFieldCount)
+        Err(idm::FixtureError::InvalidScanRecord {
+            record: 1,
+            field: None,
+            ..
+        })
     ));
 }
 
@@ -133,141 +559,707 @@ async fn control_power_command_applies_state() -> anyhow::Result<()> {
 }
 
 #[tokio::test]
-async fn control_brightness_command_applies_value() -> anyhow::Result<()> {
+async fn screen_timeout_command_confirms_rounded_minutes() -> anyhow::Result<()> {
     let stdout = run_with_argv([
         "idm",
         "--fake",
         "--fake-scan",
         "hci0|AA:BB:CC|IDM-Clock|-43",
-        "control",
-        "brightness",
-        "80",
+        "screen-timeout",
+        "5m",
     ])
     .await?;
 
-    assert_snapshot!("control_brightness_command_stdout", stdout.trim_end());
+    assert_eq!("Applied screen-light timeout: 5 minute(s)\n", stdout);
     Ok(())
 }
 
 #[tokio::test]
-async fn control_colour_command_applies_rgb_value() -> anyhow::Result<()> {
-    let stdout = run_with_argv([
+async fn ping_command_prints_endpoint_presence_without_resolving_profile() -> anyhow::Result<()> {
+    let ambiguous_scan = "hci0|AAMBIG:01|IDM-1+3|-43|5452007081010200010720002000";
+    let inspect_error = run_with_argv(["idm", "--fake", "--fake-scan", ambiguous_scan, "inspect"])
+        .await
+        .expect_err("inspect should need the ambiguous shape resolved");
+    assert!(
+        inspect_error.to_string().contains("ambiguous model shape"),
+        "unexpected error: {inspect_error:#}"
+    );
+
+    let stdout = run_with_argv(["idm", "--fake", "--fake-scan", ambiguous_scan, "ping"]).await?;
+
+    assert_eq!(
+        "IDM-1+3 (AAMBIG:01): GATT profile fa_fa02\n\
+         \x20 control_service: present\n\
+         \x20 write_characteristic: present\n\
+         \x20 read_notify_characteristic: present\n",
+        stdout
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn factory_reset_command_refuses_without_yes() -> anyhow::Result<()> {
+    let error = run_with_argv([
         "idm",
         "--fake",
         "--fake-scan",
         "hci0|AA:BB:CC|IDM-Clock|-43",
-        "control",
-        "colour",
-        "17",
-        "34",
-        "51",
+        "factory-reset",
     ])
-    .await?;
+    .await
+    .expect_err("factory-reset should refuse to run without --yes");
 
-    assert_snapshot!("control_colour_command_stdout", stdout.trim_end());
+    assert!(
+        error.to_string().contains("pass --yes to confirm"),
+        "unexpected error: {error:#}"
+    );
     Ok(())
 }
 
 #[tokio::test]
-async fn control_sync_time_command_uses_explicit_unix_timestamp() -> anyhow::Result<()> {
+async fn factory_reset_command_proceeds_with_yes() -> anyhow::Result<()> {
     let stdout = run_with_argv([
         "idm",
         "--fake",
         "--fake-scan",
         "hci0|AA:BB:CC|IDM-Clock|-43",
-        "control",
-        "sync-time",
-        "--unix",
-        "1700000000",
+        "factory-reset",
+        "--yes",
     ])
     .await?;
 
-    assert_snapshot!("control_sync_time_command_stdout", stdout.trim_end());
+    assert_eq!("Factory reset acknowledged\n", stdout);
     Ok(())
 }
 
+#[rstest]
+#[case::on("on", "Joint mode on\n")]
+#[case::off("off", "Joint mode off\n")]
 #[tokio::test]
-async fn control_text_command_uploads_payload() -> anyhow::Result<()> {
+async fn joint_mode_command_applies_state(
+    #[case] state: &str,
+    #[case] expected: &str,
+) -> anyhow::Result<()> {
     let stdout = run_with_argv([
         "idm",
         "--fake",
         "--fake-scan",
         "hci0|AA:BB:CC|IDM-Clock|-43",
-        "control",
-        "text",
-        "Hi",
+        "joint-mode",
+        state,
     ])
     .await?;
 
-    assert_snapshot!("control_text_command_stdout", stdout.trim_end());
+    assert_eq!(expected, stdout);
     Ok(())
 }
 
 #[tokio::test]
-async fn image_command_uploads_gif_payload() -> anyhow::Result<()> {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("system clock should be after unix epoch")
-        .as_nanos();
-    let file_path = std::env::temp_dir().join(format!(
-        "idm-control-gif-cli-{}-{timestamp}.gif",
-        std::process::id()
-    ));
-    std::fs::write(
-        &file_path,
-        [
-            0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00, 0x00, 0x00, 0x00, 0x2C,
-            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00,
-            0x3B,
-        ],
-    )?;
-
-    let fake = idm::FakeArgs::builder()
-        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
-        .build();
-    let args = idm::Args::new(idm::Command::Image(idm::ImageArgs::new(&file_path))).with_fake(fake);
-
-    let stdout = run_with_parsed_args(args).await?;
-    assert_snapshot!("image_command_uploads_gif_stdout", stdout.trim_end());
+async fn scene_command_reports_applied_steps_in_order() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "scene",
+        "--brightness",
+        "40",
+        "--power",
+        "on",
+        "--colour",
+        "17,34,51",
+    ])
+    .await?;
 
-    std::fs::remove_file(file_path)?;
+    assert_eq!("Applied scene: power, colour, brightness\n", stdout);
     Ok(())
 }
 
 #[tokio::test]
-async fn image_command_uploads_transformed_payload() -> anyhow::Result<()> {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("system clock should be after unix epoch")
-        .as_nanos();
-    let file_path = std::env::temp_dir().join(format!(
-        "idm-image-cli-{}-{timestamp}.png",
-        std::process::id()
-    ));
-
-    let source = image::RgbaImage::from_pixel(2, 1, image::Rgba([0x11, 0x22, 0x33, 0xFF]));
-    let mut encoded = Vec::new();
-    image::codecs::png::PngEncoder::new(&mut encoded).write_image(
-        source.as_raw(),
-        2,
-        1,
-        image::ExtendedColorType::Rgba8,
-    )?;
-    std::fs::write(&file_path, encoded)?;
-
-    let fake = idm::FakeArgs::builder()
-        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
-        .build();
-    let args = idm::Args::new(idm::Command::Image(idm::ImageArgs::new(&file_path))).with_fake(fake);
+async fn slots_command_lists_stored_slots() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "--fake-material-slots",
+        "0B00108002000101040303",
+        "slots",
+    ])
+    .await?;
 
-    let stdout = run_with_parsed_args(args).await?;
-    assert_snapshot!(
-        "image_command_uploads_transformed_stdout",
-        stdout.trim_end()
+    assert_eq!(
+        "╭──────┬──────┬──────────╮\n\
+         │ slot │ kind │ duration │\n\
+         ├──────┼──────┼──────────┤\n\
+         │    0 │ gif  │      10s │\n\
+         │    4 │ text │      60s │\n\
+         ╰──────┴──────┴──────────╯\n",
+        stdout
     );
-
-    std::fs::remove_file(file_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn reconnect_loop_command_reports_every_cycle() -> anyhow::Result<()> {
+    let parsed_args = idm::Args::try_parse_from([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "reconnect-loop",
+        "--count",
+        "3",
+    ])?;
+    let stdout = run_with_parsed_args_and_format(parsed_args, idm::OutputFormat::Json).await?;
+    let outcome: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!("reconnect-loop", outcome["command"]);
+    let data = &outcome["data"];
+    assert_eq!(3, data["succeeded"]);
+    assert_eq!(0, data["failed"]);
+    let cycles = data["cycles"]
+        .as_array()
+        .expect("cycles should be a JSON array");
+    assert_eq!(
+        vec![1, 2, 3],
+        cycles
+            .iter()
+            .map(|cycle| cycle["cycle"].as_u64().expect("cycle should be a number"))
+            .collect::<Vec<_>>()
+    );
+    assert!(cycles.iter().all(|cycle| cycle.get("error").is_none()));
+    for stat in ["min_ms", "mean_ms", "max_ms"] {
+        assert!(data[stat].is_u64(), "{stat} should be populated: {data}");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn reconnect_loop_records_failed_cycles() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|Speaker|-43")?
+        .build();
+    let count = std::num::NonZeroUsize::new(2).expect("2 is non-zero");
+
+    let cycles = idm::SessionHandler::new(idm::fake_hardware_client(fake_args))
+        .reconnect_cycles(count)
+        .await;
+    let outcome = idm::ReconnectLoopOutcome { cycles };
+
+    assert_eq!(0, outcome.succeeded());
+    assert_eq!(2, outcome.failed());
+    assert_eq!(None, outcome.timing());
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_brightness_command_applies_value() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "brightness",
+        "80",
+    ])
+    .await?;
+
+    assert_snapshot!("control_brightness_command_stdout", stdout.trim_end());
+    Ok(())
+}
+
+#[rstest]
+#[case::piped_default(&[], 1)]
+#[case::forced_pretty(&["--json-pretty"], 6)]
+#[tokio::test]
+async fn piped_json_is_single_line_unless_forced_pretty(
+    #[case] flags: &[&str],
+    #[case] expected_lines: usize,
+) -> anyhow::Result<()> {
+    let argv = [
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+    ]
+    .into_iter()
+    .chain(flags.iter().copied())
+    .chain(["control", "brightness", "50"]);
+    let args = idm::Args::try_parse_from(argv)?;
+    let output_format = args.resolve_output_format(false);
+
+    let stdout = run_with_parsed_args_and_format(args, output_format).await?;
+
+    assert_eq!(expected_lines, stdout.lines().count(), "stdout: {stdout}");
+    let result: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(
+        serde_json::json!({ "command": "brightness", "data": { "value": 50 } }),
+        result
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_with_result_returns_brightness_outcome() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let hardware_client = idm::fake_hardware_client(fake);
+    let control = idm::ControlArgs::new(idm::ControlAction::Brightness(idm::BrightnessArgs::new(
+        50,
+    )?));
+
+    let outcome = idm::run_with_result(idm::Command::Control(control), hardware_client).await?;
+
+    assert_eq!(
+        idm::CommandOutcome::Brightness(idm::Brightness::new(50)?),
+        outcome
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_with_result_returns_crc_outcome_without_device() -> anyhow::Result<()> {
+    let command = idm::Command::Crc(idm::CrcArgs::from_hex("0102"));
+
+    let outcome = idm::run_with_result(command, idm::real_hardware_client()).await?;
+
+    assert_eq!(
+        idm::CommandOutcome::Crc(idm::CrcOutcome {
+            payload_len: 2,
+            crc32: crc32fast::hash(&[0x01, 0x02]),
+        }),
+        outcome
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_all_applies_brightness_to_every_matching_device() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43;hci1|00:11:22|Speaker|-65;hci0|DD:EE:FF|IDM-Desk|-50",
+        "control",
+        "--all",
+        "brightness",
+        "50",
+    ])
+    .await?;
+
+    assert_eq!(
+        "IDM-Clock (AA:BB:CC): Applied brightness: 50\n\
+         IDM-Desk (DD:EE:FF): Applied brightness: 50\n\
+         Applied to 2 of 2 device(s)\n",
+        stdout
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_all_json_reports_each_device() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43;hci0|DD:EE:FF|IDM-Desk|-50")?
+        .build();
+    let control = idm::ControlArgs::new(idm::ControlAction::Colour(idm::ColourArgs::new(
+        idm::Rgb::new(0x11, 0x22, 0x33),
+    )))
+    .with_all_devices(Duration::ZERO);
+    let args = idm::Args::new(idm::Command::Control(control)).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!("control", report["command"]);
+    let report = &report["data"];
+    assert_eq!(2, report["succeeded"]);
+    assert_eq!(0, report["failed"]);
+    let device_ids = report["devices"]
+        .as_array()
+        .expect("devices should be an array")
+        .iter()
+        .map(|device| device["device_id"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["AA:BB:CC", "DD:EE:FF"], device_ids);
+    assert_eq!("colour", report["devices"][1]["outcome"]["command"]);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn daemon_command_applies_brightness_from_socket_client() -> anyhow::Result<()> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let socket_path = std::env::temp_dir().join(format!(
+        "idm-daemon-cli-{}-{timestamp}.sock",
+        std::process::id()
+    ));
+
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let args =
+        idm::Args::new(idm::Command::Daemon(idm::DaemonArgs::new(&socket_path))).with_fake(fake);
+
+    let client = async {
+        let stream = loop {
+            match tokio::net::UnixStream::connect(&socket_path).await {
+                Ok(stream) => break stream,
+                Err(_error) => tokio::time::sleep(Duration::from_millis(5)).await,
+            }
+        };
+        let (reader, mut writer) = stream.into_split();
+        writer.write_all(b"brightness 50\n").await?;
+
+        let mut response = String::new();
+        BufReader::new(reader).read_line(&mut response).await?;
+        anyhow::Ok(response)
+    };
+
+    let response = tokio::select! {
+        result = run_with_parsed_args(args) => {
+            anyhow::bail!("daemon exited before responding: {result:?}")
+        }
+        response = tokio::time::timeout(Duration::from_secs(5), client) => response??,
+    };
+
+    assert_eq!("Applied brightness: 50\n", response);
+
+    if socket_path.exists() {
+        std::fs::remove_file(socket_path)?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_colour_command_applies_rgb_value() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "colour",
+        "17,34,51",
+    ])
+    .await?;
+
+    assert_snapshot!("control_colour_command_stdout", stdout.trim_end());
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_sync_time_command_uses_explicit_unix_timestamp() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "sync-time",
+        "--unix",
+        "1700000000",
+    ])
+    .await?;
+
+    assert_snapshot!("control_sync_time_command_stdout", stdout.trim_end());
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_text_command_uploads_payload() -> anyhow::Result<()> {
+    let args = idm::Args::try_parse_from([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "text",
+        "Hi",
+    ])?;
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Compact).await?;
+
+    assert_snapshot!("control_text_command_stdout", stdout.trim_end());
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_text_command_prints_upload_summary_in_pretty_mode() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "text",
+        "Hi",
+    ])
+    .await?;
+
+    let summary = stdout.trim_end();
+    assert!(
+        summary.starts_with("Uploaded 70 B in 1 chunk over "),
+        "unexpected summary: {summary}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_text_command_warns_when_scroll_exceeds_max_scroll() -> anyhow::Result<()> {
+    let stdout = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "text",
+        "Hello",
+        "--max-scroll",
+        "1s",
+    ])
+    .await?;
+
+    assert!(
+        stdout.contains("Warning: text takes about "),
+        "unexpected output: {stdout}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_text_command_fails_over_max_scroll_under_strict() -> anyhow::Result<()> {
+    let result = run_with_argv([
+        "idm",
+        "--fake",
+        "--fake-scan",
+        "hci0|AA:BB:CC|IDM-Clock|-43",
+        "control",
+        "text",
+        "Hello",
+        "--max-scroll",
+        "1s",
+        "--strict",
+    ])
+    .await;
+
+    let error = result.expect_err("strict scroll check should fail the upload");
+    assert!(
+        error
+            .to_string()
+            .contains("refusing to upload under --strict"),
+        "unexpected error: {error:#}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn image_command_uploads_gif_payload() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let file_path = std::env::temp_dir().join(format!(
+        "idm-control-gif-cli-{}-{timestamp}.gif",
+        std::process::id()
+    ));
+    std::fs::write(
+        &file_path,
+        [
+            0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00, 0x00, 0x00, 0x00, 0x2C,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00,
+            0x3B,
+        ],
+    )?;
+
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
+        .build();
+    let args = idm::Args::new(idm::Command::Image(idm::ImageArgs::new(&file_path))).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Compact).await?;
+    assert_snapshot!("image_command_uploads_gif_stdout", stdout.trim_end());
+
+    std::fs::remove_file(file_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn image_all_uploads_to_three_devices_with_bounded_concurrency() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let file_path = std::env::temp_dir().join(format!(
+        "idm-image-all-{}-{timestamp}.gif",
+        std::process::id()
+    ));
+    std::fs::write(
+        &file_path,
+        [
+            0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00, 0x00, 0x00, 0x00, 0x2C,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00,
+            0x3B,
+        ],
+    )?;
+
+    let fake = idm::FakeArgs::builder()
+        .scan(
+            "hci0|AA:BB:CC|IDM-16-Clock|-43;hci0|DD:EE:FF|IDM-16-Desk|-50;hci0|11:22:33|IDM-16-Hall|-61",
+        )?
+        .build();
+    let concurrency = std::num::NonZeroUsize::new(2).expect("2 is non-zero");
+    let image = idm::ImageArgs::new(&file_path).with_all_devices(Duration::ZERO, concurrency);
+    let args = idm::Args::new(idm::Command::Image(image)).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    std::fs::remove_file(file_path)?;
+
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!("image", report["command"]);
+    let report = &report["data"];
+    assert_eq!(3, report["succeeded"]);
+    assert_eq!(0, report["failed"]);
+    let devices = report["devices"]
+        .as_array()
+        .expect("devices should be an array");
+    let device_ids = devices
+        .iter()
+        .map(|device| device["device_id"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["AA:BB:CC", "DD:EE:FF", "11:22:33"], device_ids);
+    for device in devices {
+        assert_eq!("image", device["outcome"]["command"]);
+        assert_eq!("gif", device["outcome"]["data"]["media_type"]);
+        assert_eq!(1, device["outcome"]["data"]["uploads"]);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn image_command_first_frame_uploads_gif_as_still() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let file_path = std::env::temp_dir().join(format!(
+        "idm-image-first-frame-{}-{timestamp}.gif",
+        std::process::id()
+    ));
+
+    let mut encoded = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut encoded, 2, 2, &[])?;
+        for rgba_pixel in [[0xFF, 0x00, 0x00, 0xFF], [0x00, 0x00, 0xFF, 0xFF]] {
+            let mut rgba = rgba_pixel.repeat(4);
+            encoder.write_frame(&gif::Frame::from_rgba_speed(2, 2, &mut rgba, 10))?;
+        }
+    }
+    std::fs::write(&file_path, encoded)?;
+
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
+        .build();
+    let args = idm::Args::new(idm::Command::Image(
+        idm::ImageArgs::new(&file_path).with_first_frame(true),
+    ))
+    .with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let result: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!("image", result["command"]);
+    assert_eq!("image", result["data"]["media_type"]);
+    let bytes_written = result["data"]["bytes_written"]
+        .as_u64()
+        .expect("image JSON should report bytes written");
+    assert!(
+        bytes_written >= 16 * 16 * 3,
+        "still upload should carry a full 16x16 RGB frame, wrote {bytes_written} bytes"
+    );
+
+    std::fs::remove_file(file_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn image_command_reports_truncated_gif_frames_as_warning() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let file_path = std::env::temp_dir().join(format!(
+        "idm-image-truncated-{}-{timestamp}.gif",
+        std::process::id()
+    ));
+
+    let mut encoded = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut encoded, 1, 1, &[])?;
+        for _frame in 0..72 {
+            let mut rgba = vec![0x10, 0x20, 0x30, 0xFF];
+            encoder.write_frame(&gif::Frame::from_rgba_speed(1, 1, &mut rgba, 10))?;
+        }
+    }
+    std::fs::write(&file_path, encoded)?;
+
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
+        .build();
+    let args = idm::Args::new(idm::Command::Image(idm::ImageArgs::new(&file_path))).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    std::fs::remove_file(file_path)?;
+
+    let result: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!("gif", result["data"]["media_type"]);
+    assert_eq!(
+        serde_json::json!([{ "kind": "gif-frames-truncated", "dropped": 8 }]),
+        result["warnings"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn image_command_uploads_transformed_payload() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let file_path = std::env::temp_dir().join(format!(
+        "idm-image-cli-{}-{timestamp}.png",
+        std::process::id()
+    ));
+
+    let source = image::RgbaImage::from_pixel(2, 1, image::Rgba([0x11, 0x22, 0x33, 0xFF]));
+    let mut encoded = Vec::new();
+    image::codecs::png::PngEncoder::new(&mut encoded).write_image(
+        source.as_raw(),
+        2,
+        1,
+        image::ExtendedColorType::Rgba8,
+    )?;
+    std::fs::write(&file_path, encoded)?;
+
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
+        .build();
+    let args = idm::Args::new(idm::Command::Image(idm::ImageArgs::new(&file_path))).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Compact).await?;
+    assert_snapshot!(
+        "image_command_uploads_transformed_stdout",
+        stdout.trim_end()
+    );
+
+    std::fs::remove_file(file_path)?;
     Ok(())
 }
 
@@ -319,3 +1311,392 @@ async fn image_command_saves_preprocessed_gif_when_requested() -> anyhow::Result
     std::fs::remove_file(output_path)?;
     Ok(())
 }
+
+#[tokio::test]
+async fn image_preview_dir_writes_one_panel_sized_png_per_gif_frame() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let source_path = std::env::temp_dir().join(format!(
+        "idm-image-preview-source-{}-{timestamp}.gif",
+        std::process::id()
+    ));
+    let preview_dir = std::env::temp_dir().join(format!(
+        "idm-image-preview-frames-{}-{timestamp}",
+        std::process::id()
+    ));
+
+    let mut encoded = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut encoded, 4, 4, &[])?;
+        for colour in [[0xFF, 0x00, 0x00, 0xFF], [0x00, 0x00, 0xFF, 0xFF]] {
+            let mut rgba = colour.repeat(16);
+            encoder.write_frame(&gif::Frame::from_rgba_speed(4, 4, &mut rgba, 10))?;
+        }
+    }
+    std::fs::write(&source_path, encoded)?;
+
+    let source_arg = source_path.display().to_string();
+    let preview_arg = preview_dir.display().to_string();
+    let stdout = run_with_parsed_args_and_format(
+        idm::Args::try_parse_from([
+            "idm",
+            "image",
+            &source_arg,
+            "--preview-dir",
+            &preview_arg,
+            "--panel",
+            "16x16",
+        ])?,
+        idm::OutputFormat::Json,
+    )
+    .await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    let frames = [
+        image::open(preview_dir.join("frame-000.png"))?.into_rgb8(),
+        image::open(preview_dir.join("frame-001.png"))?.into_rgb8(),
+    ];
+    let written = std::fs::read_dir(&preview_dir)?.count();
+    std::fs::remove_file(source_path)?;
+    std::fs::remove_dir_all(preview_dir)?;
+
+    assert_eq!(serde_json::json!("image-preview"), report["command"]);
+    assert_eq!(2, written);
+    assert_eq!(
+        [(16, 16), (16, 16)],
+        [frames[0].dimensions(), frames[1].dimensions()]
+    );
+    assert_eq!(
+        [[0xFF, 0x00, 0x00], [0x00, 0x00, 0xFF]],
+        [frames[0].get_pixel(8, 8).0, frames[1].get_pixel(8, 8).0]
+    );
+    Ok(())
+}
+
+fn write_validation_gif(
+    label: &str,
+    width: u16,
+    height: u16,
+) -> anyhow::Result<std::path::PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let path = std::env::temp_dir().join(format!(
+        "idm-validate-gif-{label}-{}-{timestamp}.gif",
+        std::process::id()
+    ));
+    let mut bytes = vec![
+        0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x01, 0x00, 0x00, 0x00, 0x00, 0x2C, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x3B,
+    ];
+    bytes[6..8].copy_from_slice(&width.to_le_bytes());
+    bytes[8..10].copy_from_slice(&height.to_le_bytes());
+    std::fs::write(&path, bytes)?;
+    Ok(path)
+}
+
+#[tokio::test]
+async fn validate_gif_command_passes_gif_matching_panel() -> anyhow::Result<()> {
+    let gif_path = write_validation_gif("valid", 32, 32)?;
+    let args = idm::Args::try_parse_from([
+        "idm",
+        "validate-gif",
+        &gif_path.display().to_string(),
+        "--panel",
+        "32x32",
+    ])?;
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!(serde_json::json!("validate-gif"), report["command"]);
+    let report = &report["data"];
+    assert_eq!(serde_json::json!(true), report["passed"]);
+    assert_eq!(serde_json::json!(1), report["frame_count"]);
+    assert_eq!(serde_json::json!(43), report["payload_len"]);
+    assert_eq!(serde_json::json!([]), report["reasons"]);
+
+    std::fs::remove_file(gif_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn validate_gif_command_reports_oversized_gif() -> anyhow::Result<()> {
+    let gif_path = write_validation_gif("oversized", 64, 64)?;
+    let args = idm::Args::try_parse_from([
+        "idm",
+        "validate-gif",
+        &gif_path.display().to_string(),
+        "--panel",
+        "32x32",
+        "--max-bytes",
+        "16",
+    ])?;
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    let report = &report["data"];
+    assert_eq!(serde_json::json!(false), report["passed"]);
+    assert_eq!(
+        serde_json::json!({ "width": 64, "height": 64 }),
+        report["dimensions"]
+    );
+    assert_eq!(
+        serde_json::json!([
+            "gif upload dimensions 64x64 do not match device panel dimensions 32x32",
+            "gif upload payload is too large: 43 bytes exceeds max 16",
+        ]),
+        report["reasons"]
+    );
+
+    std::fs::remove_file(gif_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_text_preview_writes_panel_sized_png_offline() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let png_path = std::env::temp_dir().join(format!(
+        "idm-text-preview-{}-{timestamp}.png",
+        std::process::id()
+    ));
+    let args = idm::Args::try_parse_from([
+        "idm",
+        "control",
+        "text",
+        "A",
+        "--preview",
+        &png_path.display().to_string(),
+        "--panel",
+        "16x16",
+    ])?;
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    let png = image::open(&png_path)?.into_rgb8();
+    std::fs::remove_file(&png_path)?;
+
+    assert_eq!(serde_json::json!("text-preview"), report["command"]);
+    assert_eq!(
+        serde_json::json!({ "width": 16, "height": 16 }),
+        report["data"]["panel"]
+    );
+    assert_eq!((16, 16), png.dimensions());
+    let panel = idm::PanelDimensions::new(16, 16).expect("16x16 should be valid dimensions");
+    let expected = idm::TextUploadRequest::new("A").render_preview(panel)?;
+    assert_eq!(expected.payload(), png.as_raw().as_slice());
+    Ok(())
+}
+
+#[rstest]
+#[case::preview_without_panel(&["idm", "control", "text", "A", "--preview", "out.png"])]
+#[case::panel_without_preview(&["idm", "control", "text", "A", "--panel", "16x16"])]
+fn control_text_preview_needs_both_path_and_panel(#[case] argv: &[&str]) {
+    let error = idm::Args::try_parse_from(argv).expect_err("incomplete preview should not parse");
+
+    assert_eq!(ErrorKind::MissingRequiredArgument, error.kind());
+}
+
+#[tokio::test]
+async fn crc_command_prints_checksum_and_header_byte_order() -> anyhow::Result<()> {
+    let stdout = run_with_argv(["idm", "crc", "--hex", "313233343536373839"]).await?;
+
+    assert_eq!(
+        "Payload length: 9 bytes\nCRC32: 0xCBF43926\nHeader bytes (LE): 26 39 F4 CB\n",
+        stdout
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn crc_command_reads_payload_file() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let file_path = std::env::temp_dir().join(format!(
+        "idm-crc-cli-{}-{timestamp}.bin",
+        std::process::id()
+    ));
+    std::fs::write(&file_path, b"123456789")?;
+
+    let args = idm::Args::new(idm::Command::Crc(idm::CrcArgs::from_file(&file_path)));
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!(serde_json::json!("crc"), report["command"]);
+    assert_eq!(serde_json::json!(0xCBF4_3926_u32), report["data"]["crc32"]);
+    assert_eq!(
+        serde_json::json!("2639f4cb"),
+        report["data"]["header_bytes"]
+    );
+
+    std::fs::remove_file(file_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn describe_command_explains_text_header() -> anyhow::Result<()> {
+    let stdout = run_with_argv(["idm", "describe", "1E000300000E00000044332211000", "00C"]).await?;
+
+    assert_eq!(
+        "text header, block len 30, payload_len 14, crc 0x11223344, no-time-signature\n",
+        stdout
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn describe_command_reports_gif_header_fields_as_json() -> anyhow::Result<()> {
+    let args = idm::Args::new(idm::Command::Describe(idm::DescribeArgs::new(
+        "C908010002B9180000DB42CB1400000C",
+    )));
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!(serde_json::json!("describe"), report["command"]);
+    assert_eq!(serde_json::json!("gif header"), report["data"]["kind"]);
+    assert_eq!(
+        serde_json::json!([
+            "block len 2249",
+            "continuation chunk",
+            "payload_len 6329",
+            "crc 0x14CB42DB",
+            "no-time-signature",
+        ]),
+        report["data"]["fields"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn self_test_command_reports_all_checks_passing() -> anyhow::Result<()> {
+    let args = idm::Args::new(idm::Command::SelfTest);
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!(serde_json::json!("self-test"), report["command"]);
+    assert_eq!(serde_json::json!(true), report["data"]["passed"]);
+    assert_eq!(serde_json::json!(0), report["data"]["failed"]);
+    let checks = report["data"]["checks"]
+        .as_array()
+        .expect("self-test should list its checks");
+    assert!(
+        checks
+            .iter()
+            .all(|check| check["passed"] == serde_json::json!(true))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn self_test_command_prints_summary_line() -> anyhow::Result<()> {
+    let stdout = run_with_argv(["idm", "self-test"]).await?;
+
+    assert_eq!(
+        Some("13 of 13 checks passed"),
+        stdout.lines().last(),
+        "unexpected output:\n{stdout}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn doctor_command_reports_fake_adapters_without_connecting() -> anyhow::Result<()> {
+    let fake = idm::FakeArgs::builder()
+        .scan("hci1|00:11:22|Speaker|-65;hci0|AA:BB:CC|IDM-Clock|-43;hci1|DD:EE:FF|IDM-Cube|-50")?
+        .build();
+    let args = idm::Args::new(idm::Command::Doctor).with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!(serde_json::json!("doctor"), report["command"]);
+    assert_eq!(
+        serde_json::json!({
+            "name": "bluetooth adapter",
+            "status": "ok",
+            "detail": "hci1, hci0",
+        }),
+        report["data"]["checks"][0]
+    );
+    let names = report["data"]["checks"]
+        .as_array()
+        .expect("doctor should list its checks")
+        .iter()
+        .map(|check| check["name"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            serde_json::json!("bluetooth adapter"),
+            serde_json::json!("adapter permissions"),
+            serde_json::json!("tokio runtime"),
+            serde_json::json!("model overrides file"),
+            serde_json::json!("profile cache file"),
+        ],
+        names
+    );
+    Ok(())
+}
+
+#[test]
+fn crc_command_requires_file_or_hex() {
+    let result = idm::Args::try_parse_from(["idm", "crc"]);
+
+    let error = result.expect_err("crc without input should fail command parsing");
+    assert_eq!(ErrorKind::MissingRequiredArgument, error.kind());
+}
+
+#[tokio::test]
+async fn animate_command_uploads_two_pngs_as_two_frame_gif() -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let mut paths = Vec::new();
+    for (index, colour) in [[0xFF, 0x00, 0x00, 0xFF], [0x00, 0x00, 0xFF, 0xFF]]
+        .into_iter()
+        .enumerate()
+    {
+        let path = std::env::temp_dir().join(format!(
+            "idm-animate-cli-{}-{timestamp}-{index}.png",
+            std::process::id()
+        ));
+        let source = image::RgbaImage::from_pixel(2, 2, image::Rgba(colour));
+        let mut encoded = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut encoded).write_image(
+            source.as_raw(),
+            2,
+            2,
+            image::ExtendedColorType::Rgba8,
+        )?;
+        std::fs::write(&path, encoded)?;
+        paths.push(path);
+    }
+
+    let fake = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-16-Clock|-43")?
+        .build();
+    let args = idm::Args::new(idm::Command::Animate(
+        idm::AnimateArgs::new(paths.clone()).with_delay(Duration::from_millis(200)),
+    ))
+    .with_fake(fake);
+
+    let stdout = run_with_parsed_args_and_format(args, idm::OutputFormat::Json).await?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+
+    assert_eq!(serde_json::json!("animate"), report["command"]);
+    assert_eq!(serde_json::json!(2), report["data"]["frames"]);
+
+    for path in paths {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use assert_matches::assert_matches;
 use pretty_assertions::assert_eq;
+use rstest::rstest;
 use std::time::Duration;
 use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
 
@@ -87,6 +88,25 @@ async fn control_handlers_apply_commands_against_fake_session() -> anyhow::Resul
     Ok(())
 }
 
+#[tokio::test]
+async fn screen_light_timeout_handler_confirms_timeout_against_fake_session() -> anyhow::Result<()>
+{
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let timeout = idm::ScreenLightTimeout::from_minutes(5)?;
+    let confirmed =
+        idm::ScreenLightTimeoutHandler::set_timeout_confirmed(&session, timeout).await?;
+
+    assert_eq!(timeout, confirmed);
+
+    session.close().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn screen_light_timeout_handler_reads_timeout_from_fake_readback() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -145,6 +165,145 @@ async fn screen_light_timeout_handler_reports_invalid_readback_payload() -> anyh
     Ok(())
 }
 
+#[tokio::test]
+async fn screen_light_timeout_handler_confirms_set_value_from_fake_status() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let requested = idm::ScreenLightTimeout::from_duration(Duration::from_secs(5 * 60))?;
+    let confirmed =
+        idm::ScreenLightTimeoutHandler::set_timeout_confirmed(&session, requested).await?;
+
+    assert_eq!(requested, confirmed);
+    assert_eq!(5, confirmed.minutes());
+
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn screen_light_timeout_handler_rejects_mismatched_status() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(stale_listen_scenario(
+            idm::NotifyEvent::ScreenLightTimeout(30),
+            1,
+        ))
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let requested = idm::ScreenLightTimeout::from_minutes(5)?;
+    let result = idm::ScreenLightTimeoutHandler::set_timeout_confirmed(&session, requested).await;
+
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::ScreenLightTimeout(error))
+            if *error == idm::ScreenLightTimeoutError::Mismatch { expected: 5, actual: 30 }
+    );
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn schedule_handler_programs_entry() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let entry = idm::ScheduleEntry::new(
+        0b001_1111,
+        Time::from_hms(7, 30, 0)?,
+        Time::from_hms(22, 0, 0)?,
+    )?;
+    idm::ScheduleHandler::set_schedule(&session, entry).await?;
+
+    session.close().await?;
+    Ok(())
+}
+
+#[rstest]
+#[case::setup_continue(
+    idm::NotifyEvent::ScheduleSetup(idm::ScheduleSetupStatus::Continue),
+    idm::ScheduleError::ResourceRequested
+)]
+#[case::setup_failed(
+    idm::NotifyEvent::ScheduleSetup(idm::ScheduleSetupStatus::Failed(0x02)),
+    idm::ScheduleError::SetupRejected { status: 0x02 }
+)]
+#[tokio::test]
+async fn schedule_handler_surfaces_setup_status_as_typed_error(
+    #[case] status: idm::NotifyEvent,
+    #[case] expected: idm::ScheduleError,
+) -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(stale_listen_scenario(status, 1))
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let entry = idm::ScheduleEntry::new(0x7F, Time::MIDNIGHT, Time::from_hms(6, 0, 0)?)?;
+    let result = idm::ScheduleHandler::set_schedule(&session, entry).await;
+
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::Schedule(error)) if *error == expected
+    );
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn material_slots_handler_lists_slots_from_fake_response() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .material_slots("0E00108003000101020300050204")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let slots = idm::MaterialSlotsHandler::read_slots(&session).await?;
+
+    assert_eq!(
+        vec![
+            (0, idm::MaterialKind::Gif, Duration::from_secs(10)),
+            (2, idm::MaterialKind::Text, Duration::from_secs(5)),
+            (5, idm::MaterialKind::Image, Duration::from_secs(300)),
+        ],
+        slots
+            .iter()
+            .map(|stored| (stored.slot().value(), stored.kind(), stored.duration()))
+            .collect::<Vec<_>>()
+    );
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn material_slots_handler_reports_unsupported_without_response() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let result = idm::MaterialSlotsHandler::read_slots(&session).await;
+
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::MaterialSlots(error))
+            if *error == idm::MaterialSlotsError::Unsupported { timeout_ms: 1_000 }
+    );
+    session.close().await?;
+    Ok(())
+}
+
 #[test]
 fn brightness_rejects_values_outside_range() {
     let result = idm::Brightness::new(101);
@@ -193,6 +352,32 @@ async fn text_upload_handler_supports_notify_ack_pacing() -> anyhow::Result<()>
     Ok(())
 }
 
+#[tokio::test]
+async fn text_upload_handler_surfaces_device_rejection_status() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .text(
+            idm::TextScenario::builder()
+                .first_chunk(idm::AckAction::Error(0x05))
+                .build(),
+        )
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let request = idm::TextUploadRequest::new("Hi");
+    let result = idm::TextUploadHandler::upload(&session, request).await;
+
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::UploadAck(error))
+            if matches!(*error, idm::UploadAckError::TransferRejected { status: 0x05 })
+    );
+
+    session.close().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn text_upload_rejects_unresolved_text_path_routing_profile() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -229,7 +414,7 @@ async fn gif_upload_handler_reports_cache_hit_on_first_chunk_finish() -> anyhow:
     let mut payload_bytes = tiny_gif_payload();
     payload_bytes.extend(std::iter::repeat_n(0x00, 5000));
     let payload = idm::GifAnimation::try_from(payload_bytes)?;
-    let request = idm::GifUploadRequest::new(payload);
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
     let receipt = idm::GifUploadHandler::upload(&session, request).await?;
 
     assert_eq!(true, receipt.cached());
@@ -241,6 +426,207 @@ async fn gif_upload_handler_reports_cache_hit_on_first_chunk_finish() -> anyhow:
     Ok(())
 }
 
+async fn upload_padded_gif_with_write_mtu(
+    write_mtu: Option<usize>,
+) -> anyhow::Result<idm::GifUploadReceipt> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .maybe_write_mtu(write_mtu)
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let payload = idm::GifAnimation::try_from(gif_payload_with_padding(5000))?;
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
+    let receipt = idm::GifUploadHandler::upload(&session, request).await?;
+
+    session.close().await?;
+    Ok(receipt)
+}
+
+#[rstest]
+#[case::reported_limit(128)]
+#[case::rejected_until_reduced(20)]
+#[tokio::test]
+async fn gif_upload_handler_chunks_smaller_for_short_write_mtu(
+    #[case] write_mtu: usize,
+) -> anyhow::Result<()> {
+    let baseline = upload_padded_gif_with_write_mtu(None).await?;
+    let constrained = upload_padded_gif_with_write_mtu(Some(write_mtu)).await?;
+
+    assert_eq!(baseline.bytes_written(), constrained.bytes_written());
+    assert_eq!(
+        baseline.logical_chunks_sent(),
+        constrained.logical_chunks_sent()
+    );
+    assert!(
+        constrained.chunks_written() > baseline.chunks_written(),
+        "expected more than {} transport chunks at a {write_mtu}-byte MTU, got {}",
+        baseline.chunks_written(),
+        constrained.chunks_written()
+    );
+    Ok(())
+}
+
+async fn timed_padded_gif_upload(
+    pacing: idm::GifPacing,
+) -> anyhow::Result<(idm::GifUploadReceipt, Duration)> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let payload = idm::GifAnimation::try_from(gif_payload_with_padding(5000))?;
+    let request = idm::GifUploadRequest::new(payload)
+        .with_allow_unknown_dimensions(true)
+        .with_pacing(pacing);
+    let started = tokio::time::Instant::now();
+    let receipt = idm::GifUploadHandler::upload(&session, request).await?;
+    let elapsed = started.elapsed();
+
+    session.close().await?;
+    Ok((receipt, elapsed))
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn gif_upload_handler_skips_fragment_delay_with_notify_only_pacing() -> anyhow::Result<()> {
+    let (delayed, delayed_elapsed) = timed_padded_gif_upload(idm::GifPacing::FragmentDelay).await?;
+    let (notify_only, notify_only_elapsed) =
+        timed_padded_gif_upload(idm::GifPacing::NotifyOnly).await?;
+
+    assert_eq!(delayed.chunks_written(), notify_only.chunks_written());
+    let fragment_sleeps = Duration::from_millis(20)
+        * u32::try_from(delayed.chunks_written()).expect("chunk count fits in u32");
+    assert_eq!(fragment_sleeps, delayed_elapsed - notify_only_elapsed);
+    Ok(())
+}
+
+#[tokio::test]
+async fn gif_upload_handler_sends_every_chunk_of_multi_chunk_happy_path() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(idm::ListenFixture::GifMultiChunkHappyPath)
+        .gif(
+            idm::GifScenario::builder()
+                .first_chunk(idm::AckAction::NextPackage)
+                .non_final_chunk(idm::AckAction::NextPackage)
+                .last_chunk(idm::AckAction::Finished)
+                .build(),
+        )
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let payload = idm::GifAnimation::try_from(gif_payload_with_padding(13_000))?;
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
+    let receipt = idm::GifUploadHandler::upload(&session, request).await?;
+
+    assert_eq!(false, receipt.cached());
+    assert_eq!(4, receipt.logical_chunks_sent());
+
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn gif_upload_handler_repeats_upload_requested_number_of_times() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_allow_unknown_dimensions(true);
+    let times = std::num::NonZeroU8::new(3).expect("3 is non-zero");
+    let receipts = idm::GifUploadHandler::upload_repeated(&session, request, times).await?;
+
+    assert_eq!(3, receipts.len());
+    for receipt in &receipts {
+        assert_eq!(tiny_gif_payload().len() + 16, receipt.bytes_written());
+        assert_eq!(1, receipt.logical_chunks_sent());
+    }
+
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn gif_upload_handler_requires_known_panel_dimensions_unless_allowed() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+    assert_eq!(None, session.device_profile().panel_dimensions());
+
+    let strict = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?);
+    let result = idm::GifUploadHandler::upload(&session, strict).await;
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::GifUpload(error))
+            if matches!(*error, idm::GifUploadError::PanelDimensionsUnknown)
+    );
+
+    let forced = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_allow_unknown_dimensions(true);
+    let receipt = idm::GifUploadHandler::upload(&session, forced).await?;
+    assert_eq!(1, receipt.logical_chunks_sent());
+
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn gif_upload_handler_auto_resizes_mismatched_gif() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder().scan(FAKE_SCAN_64X64)?.build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+    let panel = idm::PanelDimensions::new(64, 64).expect("64x64 should be valid");
+
+    let strict = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?);
+    let result = idm::GifUploadHandler::upload(&session, strict).await;
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::GifUpload(error))
+            if matches!(*error, idm::GifUploadError::PanelDimensionsMismatch { .. })
+    );
+
+    let resized = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_auto_resize(panel);
+    let receipt = idm::GifUploadHandler::upload(&session, resized).await?;
+    assert_eq!(false, receipt.cached());
+
+    session.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn gif_upload_handler_continues_on_status_accepted_by_ack_policy() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .gif(
+            idm::GifScenario::builder()
+                .first_chunk(idm::AckAction::Error(0x07))
+                .build(),
+        )
+        .ack_policy(idm::AckPolicy::default().with_continue_status(0x07))
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let payload = idm::GifAnimation::try_from(gif_payload_with_padding(5000))?;
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
+    let receipt = idm::GifUploadHandler::upload(&session, request).await?;
+
+    assert_eq!(false, receipt.cached());
+    assert_eq!(2, receipt.logical_chunks_sent());
+
+    session.close().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn gif_upload_handler_surfaces_device_rejection_status() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -254,7 +640,8 @@ async fn gif_upload_handler_surfaces_device_rejection_status() -> anyhow::Result
     let client = idm::fake_hardware_client(fake_args);
     let session = client.connect_first_device("IDM-").await?;
 
-    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?);
+    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -354,7 +741,8 @@ async fn gif_upload_handler_times_out_when_ack_is_missing() -> anyhow::Result<()
     let client = idm::fake_hardware_client(fake_args);
     let session = client.connect_first_device("IDM-").await?;
 
-    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?);
+    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -375,7 +763,8 @@ async fn gif_upload_handler_surfaces_stream_closure_as_missing_ack() -> anyhow::
     let client = idm::fake_hardware_client(fake_args);
     let session = client.connect_first_device("IDM-").await?;
 
-    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?);
+    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -400,7 +789,8 @@ async fn gif_upload_handler_rejects_unexpected_ack_event() -> anyhow::Result<()>
     let client = idm::fake_hardware_client(fake_args);
     let session = client.connect_first_device("IDM-").await?;
 
-    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?);
+    let request = idm::GifUploadRequest::new(idm::GifAnimation::try_from(tiny_gif_payload())?)
+        .with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -427,7 +817,7 @@ async fn gif_upload_handler_surfaces_premature_finish_on_non_final_chunk() -> an
     let session = client.connect_first_device("IDM-").await?;
 
     let payload = idm::GifAnimation::try_from(gif_payload_with_padding(9000))?;
-    let request = idm::GifUploadRequest::new(payload);
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -454,7 +844,7 @@ async fn gif_upload_handler_surfaces_non_final_chunk_rejection() -> anyhow::Resu
     let session = client.connect_first_device("IDM-").await?;
 
     let payload = idm::GifAnimation::try_from(gif_payload_with_padding(9000))?;
-    let request = idm::GifUploadRequest::new(payload);
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -481,7 +871,7 @@ async fn gif_upload_handler_surfaces_last_chunk_rejection() -> anyhow::Result<()
     let session = client.connect_first_device("IDM-").await?;
 
     let payload = idm::GifAnimation::try_from(gif_payload_with_padding(5000))?;
-    let request = idm::GifUploadRequest::new(payload);
+    let request = idm::GifUploadRequest::new(payload).with_allow_unknown_dimensions(true);
     let result = idm::GifUploadHandler::upload(&session, request).await;
 
     assert_matches!(
@@ -680,6 +1070,26 @@ async fn diy_upload_handler_writes_expected_payload_size() -> anyhow::Result<()>
     Ok(())
 }
 
+#[tokio::test]
+async fn diy_upload_handler_rejects_frame_not_matching_panel() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder().scan(FAKE_SCAN_64X64)?.build();
+    let client = idm::fake_hardware_client(fake_args);
+    let session = client.connect_first_device("IDM-").await?;
+
+    let dimensions = idm::PanelDimensions::new(32, 32).expect("32x32 should be valid");
+    let frame = idm::Rgb888Frame::try_from((dimensions, vec![0x00; 32 * 32 * 3]))?;
+    let result = idm::diy::upload(&session, idm::diy::UploadRequest::new(frame)).await;
+
+    assert_matches!(
+        result,
+        Err(idm::ProtocolError::Diy(error))
+            if matches!(*error, idm::diy::Error::PanelDimensionsMismatch { .. })
+    );
+
+    session.close().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn diy_upload_handler_surfaces_stream_closure_as_missing_ack() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -40,6 +40,51 @@ async fn fake_session_profile_uses_scan_model_payload_when_available() -> anyhow
     Ok(())
 }
 
+#[tokio::test]
+async fn fake_session_profile_uses_generated_scan_payload() -> anyhow::Result<()> {
+    let identity = idm::ScanIdentity {
+        cid: 1,
+        pid: 4,
+        shape: 7,
+        reverse: false,
+        group_id: 1,
+        device_id: 2,
+        lamp_count: 16,
+        lamp_num: 32,
+    };
+    let payload = hex::encode(idm::ScanModelHandler::build_identity_payload(&identity));
+    let fake_args = idm::FakeArgs::builder()
+        .scan(format!("hci0|AA:BB:CC|IDM-Strip|-43|{payload}").as_str())?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let profile = session.device_profile();
+    assert_eq!(Some(dimensions(16, 32)), profile.panel_dimensions());
+    assert_eq!(Some(7), profile.led_type());
+    session.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fake_session_profile_uses_led_info_screen_type_without_scan_model() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .initial_read("090001800100000300")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let profile = session.device_profile();
+    assert_eq!(Some(dimensions(32, 32)), profile.panel_dimensions());
+    assert_eq!(idm::ImageUploadMode::PngFile, profile.image_upload_mode());
+    assert_eq!(Some(3), profile.led_type());
+    session.close().await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn ambiguous_shape_requires_resolution_when_no_led_type_is_available() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -77,6 +122,42 @@ async fn ambiguous_shape_resolves_when_led_info_response_is_available() -> anyho
     Ok(())
 }
 
+#[rstest]
+#[case::ambiguous_led_type_two(
+    "hci0|AAMBIG:02|IDM-1+3|-43|5452007081010200010720002000",
+    "090001800100000200",
+    Some(2)
+)]
+#[case::ambiguous_led_type_three(
+    "hci0|AAMBIG:03|IDM-1+3|-43|5452007083010200010720002000",
+    "090001800100000300",
+    Some(5)
+)]
+#[case::unambiguous(
+    "hci0|AA:BB:CC|IDM-Clock|-43|5452007004010200010520002000",
+    "0500010001",
+    None
+)]
+#[tokio::test]
+async fn inspect_report_records_joint_mode_applied_during_connect(
+    #[case] scan: &str,
+    #[case] initial_read: &str,
+    #[case] expected: Option<u8>,
+) -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan(scan)?
+        .initial_read(initial_read)?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let report = session.inspect_report();
+    assert_eq!(expected, report.session_metadata().joint_mode());
+    session.close().await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn cid_pid_capability_fallback_resolves_profile_when_shape_is_unknown() -> anyhow::Result<()>
 {
@@ -113,3 +194,77 @@ async fn ambiguous_cid_pid_family_requires_resolution_when_shape_is_unknown() ->
 
     Ok(())
 }
+
+#[tokio::test]
+async fn skip_led_info_ignores_led_info_response_and_uses_scan_model() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43|5452007004010200010520002000")?
+        .initial_read("090001800100000200")?
+        .skip_led_info(true)
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let profile = session.device_profile();
+    assert_eq!(Some(dimensions(64, 64)), profile.panel_dimensions());
+    assert_eq!(Some(4), profile.led_type());
+    assert_eq!(Some(idm::TextPath::Path6464), profile.text_path());
+    session.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn profile_cache_skips_led_info_probe_on_reconnect() -> anyhow::Result<()> {
+    let cache_path = std::env::temp_dir().join(format!(
+        "idm-profile-cache-{}.tsv",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_nanos()
+    ));
+
+    let first_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .initial_read("090001800100000300")?
+        .profile_cache_path(cache_path.clone())
+        .build();
+    let first = idm::fake_hardware_client(first_args)
+        .connect_first_device("IDM-")
+        .await?;
+    assert_eq!(
+        Some(dimensions(32, 32)),
+        first.device_profile().panel_dimensions()
+    );
+    first.close().await?;
+
+    let second_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .initial_read("0500010001")?
+        .profile_cache_path(cache_path.clone())
+        .build();
+    let second = idm::fake_hardware_client(second_args)
+        .connect_first_device("IDM-")
+        .await?;
+    assert_eq!(
+        Some(dimensions(32, 32)),
+        second.device_profile().panel_dimensions()
+    );
+    let report = serde_json::to_value(second.inspect_report())?;
+    let led_info_rows = report["session_metadata"]["connection_diagnostics"]["sections"]
+        .as_array()
+        .and_then(|sections| {
+            sections
+                .iter()
+                .find(|section| section["id"] == "led_info_probe")
+        })
+        .map(|section| section["rows"].to_string())
+        .unwrap_or_default();
+    assert!(
+        led_info_rows.contains("cached_profile"),
+        "LED-info probe should report a cache hit: {led_info_rows}"
+    );
+    second.close().await?;
+
+    std::fs::remove_file(&cache_path)?;
+    Ok(())
+}
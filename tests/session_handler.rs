@@ -1,7 +1,85 @@
+use std::io;
+use std::sync::{Arc, Mutex};
+
 use assert_matches::assert_matches;
+use idm::TerminalClient as _;
 use pretty_assertions::assert_eq;
+use rstest::rstest;
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        let bytes = self
+            .0
+            .lock()
+            .expect("captured logs lock should not be poisoned");
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .expect("captured logs lock should not be poisoned")
+            .extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct PipedTerminal;
+
+impl idm::TerminalClient for PipedTerminal {
+    fn stdout_is_terminal(&self) -> bool {
+        false
+    }
+
+    fn stderr_is_terminal(&self) -> bool {
+        false
+    }
+}
+
+#[tokio::test]
+async fn terminal_wrapped_client_emits_connect_spinner_message() -> anyhow::Result<()> {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_span_events(FmtSpan::NEW)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = PipedTerminal.wrap(idm::fake_hardware_client(fake_args));
+
+    let session = client.connect_first_device("IDM-").await?;
+    session.close().await?;
+
+    let logs = logs.contents();
+    assert!(
+        logs.contains("progress_message=\"Scanning for iDotMatrix devices and connecting\""),
+        "missing connect spinner message in captured logs:\n{logs}"
+    );
+    assert!(
+        logs.contains("Connected"),
+        "missing connect finished message in captured logs:\n{logs}"
+    );
+
+    Ok(())
+}
 
 #[tokio::test]
 async fn fake_session_connect_populates_report_metadata() -> anyhow::Result<()> {
@@ -25,6 +103,8 @@ async fn fake_session_connect_populates_report_metadata() -> anyhow::Result<()>
         Some(509),
         report.session_metadata().write_without_response_limit()
     );
+    assert_eq!(Some(512), report.session_metadata().negotiated_mtu());
+    assert_eq!(Some(512), session.negotiated_mtu());
     assert_eq!(
         Some("0000fa02-0000-1000-8000-00805f9b34fb"),
         report
@@ -76,6 +156,93 @@ async fn fake_session_connect_populates_report_metadata() -> anyhow::Result<()>
     Ok(())
 }
 
+#[derive(Default)]
+struct RecordingObserver(Mutex<Vec<idm::ConnectionEvent>>);
+
+impl RecordingObserver {
+    fn events(&self) -> Vec<idm::ConnectionEvent> {
+        self.0
+            .lock()
+            .expect("recorded events lock should not be poisoned")
+            .clone()
+    }
+}
+
+impl idm::ConnectionObserver for RecordingObserver {
+    fn on_event(&self, event: &idm::ConnectionEvent) {
+        self.0
+            .lock()
+            .expect("recorded events lock should not be poisoned")
+            .push(event.clone());
+    }
+}
+
+#[tokio::test]
+async fn connection_observer_receives_each_connect_milestone_in_order() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let observer = Arc::new(RecordingObserver::default());
+
+    let session = idm::SessionHandler::new(idm::fake_hardware_client(fake_args))
+        .with_connection_observer(observer.clone())
+        .connect_first()
+        .await?;
+    let report = session.inspect_report();
+    session.close().await?;
+
+    assert_eq!(
+        vec![
+            idm::ConnectionEvent::ScanStarted {
+                name_prefix: "IDM-".to_string(),
+            },
+            idm::ConnectionEvent::DeviceFound(report.device().clone()),
+            idm::ConnectionEvent::EndpointsNegotiated(report.endpoint_presence().clone()),
+            idm::ConnectionEvent::ProfileResolved(report.session_metadata().device_profile()),
+        ],
+        observer.events()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn connection_observer_stops_at_failed_discovery() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci1|00:11:22|Speaker|-65")?
+        .build();
+    let observer = Arc::new(RecordingObserver::default());
+
+    let result = idm::SessionHandler::new(idm::fake_hardware_client(fake_args))
+        .with_connection_observer(observer.clone())
+        .connect_first()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        vec![idm::ConnectionEvent::ScanStarted {
+            name_prefix: "IDM-".to_string(),
+        }],
+        observer.events()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn fake_session_reports_alive_until_closed() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let observer = session.clone();
+    assert_eq!(true, observer.is_alive().await);
+
+    session.close().await?;
+    assert_eq!(false, observer.is_alive().await);
+    Ok(())
+}
+
 #[tokio::test]
 async fn fake_session_notification_stream_emits_typed_items() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -107,6 +274,7 @@ async fn fake_session_notification_stream_emits_typed_items() -> anyhow::Result<
         idm::NotificationMessage {
             index: 1,
             event: Ok(idm::NotifyEvent::NextPackage(idm::TransferFamily::Text)),
+            raw: vec![0x05, 0x00, 0x03, 0x00, 0x01],
         },
         first
     );
@@ -114,6 +282,7 @@ async fn fake_session_notification_stream_emits_typed_items() -> anyhow::Result<
         idm::NotificationMessage {
             index: 2,
             event: Ok(idm::NotifyEvent::Finished(idm::TransferFamily::Text)),
+            raw: vec![0x05, 0x00, 0x03, 0x00, 0x03],
         },
         second
     );
@@ -129,6 +298,77 @@ async fn fake_session_notification_stream_emits_typed_items() -> anyhow::Result<
     Ok(())
 }
 
+#[tokio::test]
+async fn fake_session_replays_gif_transfer_error_fixture() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(idm::ListenFixture::GifTransferError)
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let mut stream = session
+        .notification_stream(
+            idm::EndpointId::ReadNotifyCharacteristic,
+            Some(1),
+            CancellationToken::new(),
+        )
+        .await?;
+
+    let message = stream
+        .next()
+        .await
+        .expect("stream should emit the error item")?;
+
+    assert_eq!(
+        idm::NotificationMessage {
+            index: 1,
+            event: Ok(idm::NotifyEvent::Error(idm::TransferFamily::Gif, 0x02)),
+            raw: vec![0x05, 0x00, 0x01, 0x00, 0x02],
+        },
+        message
+    );
+    assert!(stream.next().await.is_none());
+
+    session.close().await?;
+    Ok(())
+}
+
+#[rstest]
+#[case::notify(idm::NotifyDelivery::Notify)]
+#[case::indicate_only(idm::NotifyDelivery::Indicate)]
+#[case::both(idm::NotifyDelivery::NotifyAndIndicate)]
+#[tokio::test]
+async fn fake_session_subscribes_for_each_notify_delivery(
+    #[case] delivery: idm::NotifyDelivery,
+) -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(idm::ListenFixture::GifTransferError)
+        .notify_delivery(delivery)
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let report = session.inspect_report();
+    let mut stream = session
+        .notification_stream(
+            idm::EndpointId::ReadNotifyCharacteristic,
+            Some(1),
+            CancellationToken::new(),
+        )
+        .await?;
+    let message = stream
+        .next()
+        .await
+        .expect("stream should emit the fixture item")?;
+
+    assert_eq!(Some(delivery), report.notify_delivery());
+    assert_eq!(1, message.index);
+    session.close().await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn fake_session_notification_stream_into_summary_requires_completion() -> anyhow::Result<()> {
     let fake_args = idm::FakeArgs::builder()
@@ -221,3 +461,59 @@ async fn fake_session_notification_stream_cancel_produces_interrupted() -> anyho
     session.close().await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn fake_session_rejects_second_concurrent_notification_stream() -> anyhow::Result<()> {
+    let fake_args = idm::FakeArgs::builder()
+        .scan("hci0|AA:BB:CC|IDM-Clock|-43")?
+        .listen(idm::ListenFixture::TextTransferHappyPath)
+        .build();
+    let client = idm::fake_hardware_client(fake_args);
+
+    let session = client.connect_first_device("IDM-").await?;
+    let mut first = session
+        .notification_stream(
+            idm::EndpointId::ReadNotifyCharacteristic,
+            None,
+            CancellationToken::new(),
+        )
+        .await?;
+
+    let second = session
+        .clone()
+        .notification_stream(
+            idm::EndpointId::ReadNotifyCharacteristic,
+            None,
+            CancellationToken::new(),
+        )
+        .await;
+    assert_matches!(
+        second.err(),
+        Some(idm::InteractionError::NotificationStreamBusy {
+            endpoint: idm::EndpointId::ReadNotifyCharacteristic,
+        })
+    );
+
+    let item = first
+        .next()
+        .await
+        .expect("first stream should still emit after the rejected subscribe")?;
+    assert_eq!(1, item.index);
+    drop(first);
+
+    let reopened = session
+        .notification_stream(
+            idm::EndpointId::ReadNotifyCharacteristic,
+            Some(0),
+            CancellationToken::new(),
+        )
+        .await;
+    assert!(
+        reopened.is_ok(),
+        "dropping the first stream should free the slot once it has unsubscribed"
+    );
+    drop(reopened);
+
+    session.close().await?;
+    Ok(())
+}